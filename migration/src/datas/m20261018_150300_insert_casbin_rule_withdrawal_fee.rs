@@ -0,0 +1,47 @@
+use sea_orm_migration::{prelude::*, sea_orm::Statement};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        let insert_casbin_rules_stmt = Statement::from_string(
+            manager.get_database_backend(),
+            r#"
+            INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
+            VALUES
+            ('p', 'ROLE_SUPER', 'built-in', '/withdrawal-fee', 'GET', '', ''),
+            ('p', 'ROLE_SUPER', 'built-in', '/withdrawal-fee', 'POST', '', ''),
+            ('p', 'ROLE_SUPER', 'built-in', '/withdrawal-fee', 'PUT', '', ''),
+            ('p', 'ROLE_SUPER', 'built-in', '/withdrawal-fee/:id', 'GET', '', ''),
+            ('p', 'ROLE_SUPER', 'built-in', '/withdrawal-fee/:id', 'DELETE', '', ''),
+            ('p', 'ROLE_SUPER', 'built-in', '/withdrawal-fee/quote', 'GET', '', ''),
+            ('p', 'ROLE_SUPER', 'built-in', '/withdrawal-fee/withdraw', 'POST', '', ''),
+            ('p', 'ROLE_SUPER', 'built-in', '/withdrawal-fee/records', 'GET', '', '');
+        "#
+            .to_string(),
+        );
+
+        db.execute(insert_casbin_rules_stmt).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        let delete_stmt = Statement::from_string(
+            manager.get_database_backend(),
+            r#"
+        DELETE FROM casbin_rule WHERE ptype = 'p' AND v2 LIKE '/withdrawal-fee%';
+        "#
+            .to_string(),
+        );
+
+        db.execute(delete_stmt).await?;
+        Ok(())
+    }
+}
@@ -5,3 +5,19 @@ pub mod m20241024_034305_insert_sys_role_menu;
 pub mod m20241024_034526_insert_sys_role;
 pub mod m20241024_034744_insert_sys_menu;
 pub mod m20241024_082926_insert_casbin_rule;
+pub mod m20261018_090300_insert_casbin_rule_reserves;
+pub mod m20261018_100300_insert_casbin_rule_auto_convert;
+pub mod m20261018_120000_insert_casbin_rule_solana;
+pub mod m20261018_130100_insert_casbin_rule_rent_reclamation;
+pub mod m20261018_140100_insert_casbin_rule_asset;
+pub mod m20261018_150300_insert_casbin_rule_withdrawal_fee;
+pub mod m20261018_160200_insert_casbin_rule_payout;
+pub mod m20261018_170100_insert_casbin_rule_lookup_table;
+pub mod m20261018_180200_insert_casbin_rule_mint_admin;
+pub mod m20261018_190100_insert_casbin_rule_payment_invoice;
+pub mod m20261018_200200_insert_casbin_rule_stake;
+pub mod m20261018_210200_insert_casbin_rule_custody_hold;
+pub mod m20261018_220200_insert_casbin_rule_key_rotation;
+pub mod m20261018_230400_insert_casbin_rule_keystore;
+pub mod m20261019_000200_insert_casbin_rule_webhook;
+pub mod m20261019_010100_insert_casbin_rule_job;
@@ -22,6 +22,42 @@ impl MigratorTrait for Migrator {
             Box::new(schemas::m20241023_091204_create_sys_tokens::Migration),
             Box::new(schemas::m20241023_091210_create_sys_user_role::Migration),
             Box::new(schemas::m20241023_091159_create_sys_role_menu::Migration),
+            Box::new(schemas::m20261018_090000_create_sys_custody_wallet::Migration),
+            Box::new(schemas::m20261018_090100_create_sys_reserve_snapshot::Migration),
+            Box::new(schemas::m20261018_090200_create_sys_reserve_liability::Migration),
+            Box::new(schemas::m20261018_100000_create_sys_auto_convert_policy::Migration),
+            Box::new(schemas::m20261018_100100_create_sys_auto_convert_job::Migration),
+            Box::new(schemas::m20261018_100200_create_sys_auto_convert_leg::Migration),
+            Box::new(schemas::m20261018_110000_alter_sys_auto_convert_leg_add_prices::Migration),
+            Box::new(schemas::m20261018_130000_create_sys_rent_reclamation::Migration),
+            Box::new(schemas::m20261018_140000_create_sys_asset::Migration),
+            Box::new(schemas::m20261018_150000_create_sys_withdrawal_fee::Migration),
+            Box::new(schemas::m20261018_150100_create_sys_withdrawal_fee_tier::Migration),
+            Box::new(schemas::m20261018_150200_create_sys_withdrawal_fee_record::Migration),
+            Box::new(schemas::m20261018_160000_create_sys_payout_batch::Migration),
+            Box::new(schemas::m20261018_160100_create_sys_payout_row::Migration),
+            Box::new(schemas::m20261018_170000_create_sys_lookup_table::Migration),
+            Box::new(schemas::m20261018_180000_create_sys_mint_operation::Migration),
+            Box::new(schemas::m20261018_180100_create_sys_mint_operation_audit::Migration),
+            Box::new(schemas::m20261018_190000_create_sys_payment_invoice::Migration),
+            Box::new(schemas::m20261018_200000_create_sys_stake_account::Migration),
+            Box::new(schemas::m20261018_200100_create_sys_stake_reward::Migration),
+            Box::new(schemas::m20261018_210000_create_sys_custody_hold::Migration),
+            Box::new(schemas::m20261018_210100_create_sys_custody_hold_audit::Migration),
+            Box::new(schemas::m20261018_220000_alter_sys_custody_wallet_add_retired::Migration),
+            Box::new(schemas::m20261018_220100_create_sys_key_rotation::Migration),
+            Box::new(schemas::m20261018_230000_alter_sys_custody_wallet_add_encrypted_key::Migration),
+            Box::new(schemas::m20261018_230100_create_sys_key_export_policy::Migration),
+            Box::new(schemas::m20261018_230200_create_sys_key_export::Migration),
+            Box::new(schemas::m20261018_230300_create_sys_keystore_audit::Migration),
+            Box::new(schemas::m20261019_000000_create_sys_webhook_endpoint::Migration),
+            Box::new(schemas::m20261019_000100_create_sys_webhook_delivery::Migration),
+            Box::new(schemas::m20261019_010000_create_sys_job_run::Migration),
+            Box::new(schemas::m20261019_020000_create_sys_outbox::Migration),
+            Box::new(schemas::m20261019_030000_create_sys_risk_screening::Migration),
+            Box::new(schemas::m20261019_040000_create_sys_user_balance::Migration),
+            Box::new(schemas::m20261019_040100_create_sys_balance_entry::Migration),
+            Box::new(schemas::m20261019_050000_alter_sys_withdrawal_fee_record_add_status::Migration),
             // 数据迁移
             Box::new(datas::m20241023_102950_insert_sys_domain::Migration),
             Box::new(datas::m20241024_033005_insert_sys_user::Migration),
@@ -30,6 +66,22 @@ impl MigratorTrait for Migrator {
             Box::new(datas::m20241024_033933_insert_sys_user_role::Migration),
             Box::new(datas::m20241024_034305_insert_sys_role_menu::Migration),
             Box::new(datas::m20241024_082926_insert_casbin_rule::Migration),
+            Box::new(datas::m20261018_090300_insert_casbin_rule_reserves::Migration),
+            Box::new(datas::m20261018_100300_insert_casbin_rule_auto_convert::Migration),
+            Box::new(datas::m20261018_120000_insert_casbin_rule_solana::Migration),
+            Box::new(datas::m20261018_130100_insert_casbin_rule_rent_reclamation::Migration),
+            Box::new(datas::m20261018_140100_insert_casbin_rule_asset::Migration),
+            Box::new(datas::m20261018_150300_insert_casbin_rule_withdrawal_fee::Migration),
+            Box::new(datas::m20261018_160200_insert_casbin_rule_payout::Migration),
+            Box::new(datas::m20261018_170100_insert_casbin_rule_lookup_table::Migration),
+            Box::new(datas::m20261018_180200_insert_casbin_rule_mint_admin::Migration),
+            Box::new(datas::m20261018_190100_insert_casbin_rule_payment_invoice::Migration),
+            Box::new(datas::m20261018_200200_insert_casbin_rule_stake::Migration),
+            Box::new(datas::m20261018_210200_insert_casbin_rule_custody_hold::Migration),
+            Box::new(datas::m20261018_220200_insert_casbin_rule_key_rotation::Migration),
+            Box::new(datas::m20261018_230400_insert_casbin_rule_keystore::Migration),
+            Box::new(datas::m20261019_000200_insert_casbin_rule_webhook::Migration),
+            Box::new(datas::m20261019_010100_insert_casbin_rule_job::Migration),
         ]
     }
 }
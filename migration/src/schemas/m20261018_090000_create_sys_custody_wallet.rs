@@ -0,0 +1,100 @@
+use sea_orm::Iterable;
+use sea_orm_migration::prelude::*;
+
+use super::m20240815_082808_create_enum_status::Status;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysCustodyWallet::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysCustodyWallet::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyWallet::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyWallet::UserId)
+                            .string()
+                            .null()
+                            .comment("所属用户，系统钱包与热钱包为空"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyWallet::Address)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                            .comment("钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyWallet::WalletType)
+                            .string()
+                            .not_null()
+                            .comment("钱包类型: user/system/hot"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyWallet::Status)
+                            .enumeration(Alias::new("status"), Status::iter())
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyWallet::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysCustodyWallet::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysCustodyWallet::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysCustodyWallet::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysCustodyWallet::Table)
+                    .name("idx_sys_custody_wallet_domain_user_id")
+                    .col(SysCustodyWallet::Domain)
+                    .col(SysCustodyWallet::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysCustodyWallet::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysCustodyWallet {
+    Table,
+    Id,
+    Domain,
+    UserId,
+    Address,
+    WalletType,
+    Status,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
@@ -0,0 +1,130 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysReserveSnapshot::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysReserveSnapshot::Domain).string().not_null())
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::Mint)
+                            .string()
+                            .not_null()
+                            .comment("Token mint"),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::Slot)
+                            .big_integer()
+                            .not_null()
+                            .comment("余额读取所在 slot"),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::TotalReserves)
+                            .big_integer()
+                            .not_null()
+                            .comment("储备总额"),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::TotalLiabilities)
+                            .big_integer()
+                            .not_null()
+                            .comment("负债总额"),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::LiabilitiesRoot)
+                            .string()
+                            .not_null()
+                            .comment("负债 Merkle 求和树根哈希"),
+                    )
+                    .col(ColumnDef::new(SysReserveSnapshot::LeafCount).integer().not_null())
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::ReportJson)
+                            .text()
+                            .not_null()
+                            .comment("JSON 报告原文"),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::ReportJsonSignature)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::ReportCsv)
+                            .text()
+                            .not_null()
+                            .comment("CSV 报告原文"),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::ReportCsvSignature)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::Signer)
+                            .string()
+                            .not_null()
+                            .comment("报告签名者公钥"),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveSnapshot::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysReserveSnapshot::CreatedBy).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysReserveSnapshot::Table)
+                    .name("idx_sys_reserve_snapshot_domain_mint")
+                    .col(SysReserveSnapshot::Domain)
+                    .col(SysReserveSnapshot::Mint)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysReserveSnapshot::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysReserveSnapshot {
+    Table,
+    Id,
+    Domain,
+    Mint,
+    Slot,
+    TotalReserves,
+    TotalLiabilities,
+    LiabilitiesRoot,
+    LeafCount,
+    ReportJson,
+    ReportJsonSignature,
+    ReportCsv,
+    ReportCsvSignature,
+    Signer,
+    CreatedAt,
+    CreatedBy,
+}
@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysReserveLiability::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysReserveLiability::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveLiability::SnapshotId)
+                            .string()
+                            .not_null()
+                            .comment("储备金快照 ID"),
+                    )
+                    .col(ColumnDef::new(SysReserveLiability::UserId).string().not_null())
+                    .col(
+                        ColumnDef::new(SysReserveLiability::LeafIndex)
+                            .integer()
+                            .not_null()
+                            .comment("负债树叶子位置"),
+                    )
+                    .col(
+                        ColumnDef::new(SysReserveLiability::Amount)
+                            .big_integer()
+                            .not_null()
+                            .comment("用户负债"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysReserveLiability::Table)
+                    .name("idx_sys_reserve_liability_snapshot_user")
+                    .col(SysReserveLiability::SnapshotId)
+                    .col(SysReserveLiability::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysReserveLiability::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysReserveLiability {
+    Table,
+    Id,
+    SnapshotId,
+    UserId,
+    LeafIndex,
+    Amount,
+}
@@ -0,0 +1,123 @@
+use sea_orm::Iterable;
+use sea_orm_migration::prelude::*;
+
+use super::m20240815_082808_create_enum_status::Status;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAutoConvertPolicy::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::Domain)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                            .comment("域，每个域一条策略"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::SourceMints)
+                            .text()
+                            .not_null()
+                            .comment("触发兑换的入金币种，逗号分隔"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::StablecoinMint)
+                            .string()
+                            .not_null()
+                            .comment("中间稳定币"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::TargetTokenMint)
+                            .string()
+                            .not_null()
+                            .comment("目标代币"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::ConvertBps)
+                            .integer()
+                            .not_null()
+                            .comment("兑换比例（万分比）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::MinTradeAmount)
+                            .big_integer()
+                            .not_null()
+                            .comment("最小交易额"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::MaxSlippageBps)
+                            .integer()
+                            .not_null()
+                            .comment("最大滑点（万分比）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::DryRun)
+                            .boolean()
+                            .not_null()
+                            .default(true)
+                            .comment("只报价不发送交易"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::MaxRetries)
+                            .integer()
+                            .not_null()
+                            .comment("每一腿最大重试次数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::Status)
+                            .enumeration(Alias::new("status"), Status::iter())
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertPolicy::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysAutoConvertPolicy::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysAutoConvertPolicy::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysAutoConvertPolicy::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysAutoConvertPolicy::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysAutoConvertPolicy {
+    Table,
+    Id,
+    Domain,
+    SourceMints,
+    StablecoinMint,
+    TargetTokenMint,
+    ConvertBps,
+    MinTradeAmount,
+    MaxSlippageBps,
+    DryRun,
+    MaxRetries,
+    Status,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
@@ -0,0 +1,117 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAutoConvertJob::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAutoConvertJob::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysAutoConvertJob::Domain).string().not_null().comment("域"))
+                    .col(
+                        ColumnDef::new(SysAutoConvertJob::PolicyId)
+                            .string()
+                            .not_null()
+                            .comment("触发时的策略"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertJob::DepositId)
+                            .string()
+                            .not_null()
+                            .comment("入金标识，同域内唯一"),
+                    )
+                    .col(ColumnDef::new(SysAutoConvertJob::UserId).string().null().comment("入金用户"))
+                    .col(
+                        ColumnDef::new(SysAutoConvertJob::SourceMint)
+                            .string()
+                            .not_null()
+                            .comment("入金币种"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertJob::DepositAmount)
+                            .big_integer()
+                            .not_null()
+                            .comment("入金数量"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertJob::ConvertAmount)
+                            .big_integer()
+                            .null()
+                            .comment("实际兑换数量"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertJob::Status)
+                            .string()
+                            .not_null()
+                            .comment("任务状态: pending/running/succeeded/failed/skipped"),
+                    )
+                    .col(ColumnDef::new(SysAutoConvertJob::DryRun).boolean().not_null())
+                    .col(
+                        ColumnDef::new(SysAutoConvertJob::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .comment("任务执行次数"),
+                    )
+                    .col(ColumnDef::new(SysAutoConvertJob::Error).text().null())
+                    .col(
+                        ColumnDef::new(SysAutoConvertJob::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysAutoConvertJob::UpdatedAt).timestamp())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysAutoConvertJob::Table)
+                    .name("idx_sys_auto_convert_job_domain_deposit_id")
+                    .col(SysAutoConvertJob::Domain)
+                    .col(SysAutoConvertJob::DepositId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysAutoConvertJob::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysAutoConvertJob {
+    Table,
+    Id,
+    Domain,
+    PolicyId,
+    DepositId,
+    UserId,
+    SourceMint,
+    DepositAmount,
+    ConvertAmount,
+    Status,
+    DryRun,
+    Attempts,
+    Error,
+    CreatedAt,
+    UpdatedAt,
+}
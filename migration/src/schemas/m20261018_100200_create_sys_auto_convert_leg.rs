@@ -0,0 +1,94 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAutoConvertLeg::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAutoConvertLeg::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysAutoConvertLeg::JobId).string().not_null().comment("兑换任务"))
+                    .col(
+                        ColumnDef::new(SysAutoConvertLeg::LegIndex)
+                            .integer()
+                            .not_null()
+                            .comment("腿序号，从 0 开始"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAutoConvertLeg::Attempt)
+                            .integer()
+                            .not_null()
+                            .comment("尝试次数，从 1 开始"),
+                    )
+                    .col(ColumnDef::new(SysAutoConvertLeg::FromMint).string().not_null())
+                    .col(ColumnDef::new(SysAutoConvertLeg::ToMint).string().not_null())
+                    .col(ColumnDef::new(SysAutoConvertLeg::FromAmount).big_integer().not_null())
+                    .col(ColumnDef::new(SysAutoConvertLeg::ToAmount).big_integer().not_null())
+                    .col(ColumnDef::new(SysAutoConvertLeg::MinToAmount).big_integer().not_null())
+                    .col(ColumnDef::new(SysAutoConvertLeg::Slippage).double().not_null())
+                    .col(ColumnDef::new(SysAutoConvertLeg::Signature).string().null().comment("交易签名"))
+                    .col(
+                        ColumnDef::new(SysAutoConvertLeg::Status)
+                            .string()
+                            .not_null()
+                            .comment("腿状态: dry_run/landed/failed"),
+                    )
+                    .col(ColumnDef::new(SysAutoConvertLeg::Error).text().null())
+                    .col(
+                        ColumnDef::new(SysAutoConvertLeg::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysAutoConvertLeg::Table)
+                    .name("idx_sys_auto_convert_leg_job_id")
+                    .col(SysAutoConvertLeg::JobId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysAutoConvertLeg::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysAutoConvertLeg {
+    Table,
+    Id,
+    JobId,
+    LegIndex,
+    Attempt,
+    FromMint,
+    ToMint,
+    FromAmount,
+    ToAmount,
+    MinToAmount,
+    Slippage,
+    Signature,
+    Status,
+    Error,
+    CreatedAt,
+}
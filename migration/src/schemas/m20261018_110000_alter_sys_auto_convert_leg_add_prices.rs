@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261018_100200_create_sys_auto_convert_leg::SysAutoConvertLeg;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysAutoConvertLeg::Table)
+                    .add_column(
+                        ColumnDef::new(AutoConvertLegPrice::ExecutionPrice)
+                            .double()
+                            .null()
+                            .comment("实际成交价格"),
+                    )
+                    .add_column(
+                        ColumnDef::new(AutoConvertLegPrice::OraclePrice)
+                            .double()
+                            .null()
+                            .comment("执行前的参考价格"),
+                    )
+                    .add_column(
+                        ColumnDef::new(AutoConvertLegPrice::PriceImpactBps)
+                            .integer()
+                            .null()
+                            .comment("报价相对参考价的价格冲击（bps）"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysAutoConvertLeg::Table)
+                    .drop_column(AutoConvertLegPrice::ExecutionPrice)
+                    .drop_column(AutoConvertLegPrice::OraclePrice)
+                    .drop_column(AutoConvertLegPrice::PriceImpactBps)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AutoConvertLegPrice {
+    ExecutionPrice,
+    OraclePrice,
+    PriceImpactBps,
+}
@@ -0,0 +1,127 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysRentReclamation::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysRentReclamation::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::WalletId)
+                            .string()
+                            .not_null()
+                            .comment("托管钱包"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::WalletAddress)
+                            .string()
+                            .not_null()
+                            .comment("托管钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::TokenAccount)
+                            .string()
+                            .not_null()
+                            .comment("被关闭的 Token 账户"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::Mint)
+                            .string()
+                            .not_null()
+                            .comment("mint"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::Reason)
+                            .string()
+                            .not_null()
+                            .comment("回收原因: unsupported_mint/idle"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: closed/dry_run/skipped/failed"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::Lamports)
+                            .big_integer()
+                            .not_null()
+                            .comment("实际回收的 lamports"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::Signature)
+                            .string()
+                            .null()
+                            .comment("关闭交易签名"),
+                    )
+                    .col(ColumnDef::new(SysRentReclamation::Error).text().null())
+                    .col(
+                        ColumnDef::new(SysRentReclamation::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysRentReclamation::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysRentReclamation::Table)
+                    .name("idx_sys_rent_reclamation_domain_wallet_address")
+                    .col(SysRentReclamation::Domain)
+                    .col(SysRentReclamation::WalletAddress)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysRentReclamation::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysRentReclamation {
+    Table,
+    Id,
+    Domain,
+    WalletId,
+    WalletAddress,
+    TokenAccount,
+    Mint,
+    Reason,
+    Status,
+    Lamports,
+    Signature,
+    Error,
+    CreatedAt,
+    CreatedBy,
+}
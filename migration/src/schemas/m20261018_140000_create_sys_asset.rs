@@ -0,0 +1,156 @@
+use sea_orm::Iterable;
+use sea_orm_migration::prelude::*;
+
+use super::m20240815_082808_create_enum_status::Status;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAsset::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAsset::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::Mint)
+                            .string()
+                            .not_null()
+                            .comment("mint 地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::Symbol)
+                            .string()
+                            .not_null()
+                            .comment("符号"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::Name)
+                            .string()
+                            .not_null()
+                            .comment("名称"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::Decimals)
+                            .integer()
+                            .not_null()
+                            .comment("精度"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::TokenProgram)
+                            .string()
+                            .not_null()
+                            .comment("代币程序: spl_token/token_2022"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::MinWithdrawal)
+                            .big_integer()
+                            .not_null()
+                            .comment("单笔最小提现数量（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::MaxWithdrawal)
+                            .big_integer()
+                            .not_null()
+                            .comment("单笔最大提现数量（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::WithdrawalFee)
+                            .big_integer()
+                            .not_null()
+                            .comment("提现手续费（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::DepositEnabled)
+                            .boolean()
+                            .not_null()
+                            .comment("是否允许充值"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::WithdrawalEnabled)
+                            .boolean()
+                            .not_null()
+                            .comment("是否允许提现"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::Status)
+                            .enumeration(Alias::new("status"), Status::iter())
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::IconKey)
+                            .string()
+                            .null()
+                            .comment("图标在 S3 中的对象键"),
+                    )
+                    .col(
+                        ColumnDef::new(SysAsset::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysAsset::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysAsset::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysAsset::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysAsset::Table)
+                    .name("idx_sys_asset_domain_mint")
+                    .col(SysAsset::Domain)
+                    .col(SysAsset::Mint)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysAsset::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysAsset {
+    Table,
+    Id,
+    Domain,
+    Mint,
+    Symbol,
+    Name,
+    Decimals,
+    TokenProgram,
+    MinWithdrawal,
+    MaxWithdrawal,
+    WithdrawalFee,
+    DepositEnabled,
+    WithdrawalEnabled,
+    Status,
+    IconKey,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
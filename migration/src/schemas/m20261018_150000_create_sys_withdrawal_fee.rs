@@ -0,0 +1,131 @@
+use sea_orm::Iterable;
+use sea_orm_migration::prelude::*;
+
+use super::m20240815_082808_create_enum_status::Status;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysWithdrawalFee::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::Mint)
+                            .string()
+                            .null()
+                            .comment("mint 地址，为空时作为域内默认规则"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::FeeType)
+                            .string()
+                            .not_null()
+                            .comment("计费方式: flat/percentage/tiered"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::FlatFee)
+                            .big_integer()
+                            .not_null()
+                            .comment("固定费用（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::PercentageBps)
+                            .integer()
+                            .not_null()
+                            .comment("比例费用（基点）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::MinFee)
+                            .big_integer()
+                            .not_null()
+                            .comment("最低手续费（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::MaxFee)
+                            .big_integer()
+                            .null()
+                            .comment("最高手续费（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::FeeWallet)
+                            .string()
+                            .not_null()
+                            .comment("手续费收款钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::Status)
+                            .enumeration(Alias::new("status"), Status::iter())
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFee::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysWithdrawalFee::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysWithdrawalFee::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysWithdrawalFee::Table)
+                    .name("idx_sys_withdrawal_fee_domain_mint")
+                    .col(SysWithdrawalFee::Domain)
+                    .col(SysWithdrawalFee::Mint)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysWithdrawalFee::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysWithdrawalFee {
+    Table,
+    Id,
+    Domain,
+    Mint,
+    FeeType,
+    FlatFee,
+    PercentageBps,
+    MinFee,
+    MaxFee,
+    FeeWallet,
+    Status,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
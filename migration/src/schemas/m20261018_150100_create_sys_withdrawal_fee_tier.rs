@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysWithdrawalFeeTier::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeTier::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeTier::FeeId)
+                            .string()
+                            .not_null()
+                            .comment("所属手续费规则"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeTier::RoleCode)
+                            .string()
+                            .not_null()
+                            .comment("角色编码"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeTier::FlatFee)
+                            .big_integer()
+                            .not_null()
+                            .comment("固定费用（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeTier::PercentageBps)
+                            .integer()
+                            .not_null()
+                            .comment("比例费用（基点）"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysWithdrawalFeeTier::Table)
+                    .name("idx_sys_withdrawal_fee_tier_fee_role")
+                    .col(SysWithdrawalFeeTier::FeeId)
+                    .col(SysWithdrawalFeeTier::RoleCode)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysWithdrawalFeeTier::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysWithdrawalFeeTier {
+    Table,
+    Id,
+    FeeId,
+    RoleCode,
+    FlatFee,
+    PercentageBps,
+}
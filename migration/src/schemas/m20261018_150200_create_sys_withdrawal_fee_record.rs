@@ -0,0 +1,143 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysWithdrawalFeeRecord::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::UserId)
+                            .string()
+                            .not_null()
+                            .comment("发起提现的用户"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::Mint)
+                            .string()
+                            .not_null()
+                            .comment("mint 地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::Destination)
+                            .string()
+                            .not_null()
+                            .comment("收款钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::Amount)
+                            .big_integer()
+                            .not_null()
+                            .comment("提现金额（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::Fee)
+                            .big_integer()
+                            .not_null()
+                            .comment("手续费（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::NetAmount)
+                            .big_integer()
+                            .not_null()
+                            .comment("实际到账金额（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::FeeWallet)
+                            .string()
+                            .not_null()
+                            .comment("手续费收款钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::FeeId)
+                            .string()
+                            .null()
+                            .comment("适用的手续费规则，为空表示使用资产的默认手续费"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::Tier)
+                            .string()
+                            .null()
+                            .comment("命中的角色档位"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::Signature)
+                            .string()
+                            .not_null()
+                            .comment("交易签名"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysWithdrawalFeeRecord::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysWithdrawalFeeRecord::Table)
+                    .name("idx_sys_withdrawal_fee_record_domain_user")
+                    .col(SysWithdrawalFeeRecord::Domain)
+                    .col(SysWithdrawalFeeRecord::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(SysWithdrawalFeeRecord::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysWithdrawalFeeRecord {
+    Table,
+    Id,
+    Domain,
+    UserId,
+    Mint,
+    Destination,
+    Amount,
+    Fee,
+    NetAmount,
+    FeeWallet,
+    FeeId,
+    Tier,
+    Signature,
+    CreatedAt,
+    CreatedBy,
+}
@@ -0,0 +1,153 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysPayoutBatch::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::Name)
+                            .string()
+                            .not_null()
+                            .comment("批次名称"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::SourceKey)
+                            .string()
+                            .not_null()
+                            .comment("上传文件在 S3 中的对象键"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::SourceFormat)
+                            .string()
+                            .not_null()
+                            .comment("上传文件格式: csv/json"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: pending_approval/approved/rejected/executing/completed/partially_failed/failed"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::TotalRows)
+                            .integer()
+                            .not_null()
+                            .comment("总行数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::InvalidRows)
+                            .integer()
+                            .not_null()
+                            .comment("未通过校验的行数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::TransactionCount)
+                            .integer()
+                            .not_null()
+                            .comment("打包后的交易笔数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::EstimatedFeeLamports)
+                            .big_integer()
+                            .not_null()
+                            .comment("预估网络费用（lamports）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::ReportKey)
+                            .string()
+                            .null()
+                            .comment("执行结果报告在 S3 中的对象键"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::ApprovedBy)
+                            .string()
+                            .null()
+                            .comment("审批人"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::ApprovedAt)
+                            .timestamp()
+                            .null()
+                            .comment("审批时间"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::ExecutedAt)
+                            .timestamp()
+                            .null()
+                            .comment("执行完成时间"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutBatch::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysPayoutBatch::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysPayoutBatch::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysPayoutBatch::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysPayoutBatch::Table)
+                    .name("idx_sys_payout_batch_domain_status")
+                    .col(SysPayoutBatch::Domain)
+                    .col(SysPayoutBatch::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysPayoutBatch::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysPayoutBatch {
+    Table,
+    Id,
+    Domain,
+    Name,
+    SourceKey,
+    SourceFormat,
+    Status,
+    TotalRows,
+    InvalidRows,
+    TransactionCount,
+    EstimatedFeeLamports,
+    ReportKey,
+    ApprovedBy,
+    ApprovedAt,
+    ExecutedAt,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
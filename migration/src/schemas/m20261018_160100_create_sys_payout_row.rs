@@ -0,0 +1,108 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysPayoutRow::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysPayoutRow::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutRow::BatchId)
+                            .string()
+                            .not_null()
+                            .comment("所属批次"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutRow::RowIndex)
+                            .integer()
+                            .not_null()
+                            .comment("在上传文件中的行号，从 1 开始"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutRow::Address)
+                            .string()
+                            .not_null()
+                            .comment("收款钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutRow::Mint)
+                            .string()
+                            .not_null()
+                            .comment("mint 地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutRow::Amount)
+                            .big_integer()
+                            .not_null()
+                            .comment("数量（最小单位），校验失败时为 0"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutRow::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: invalid/pending/succeeded/failed"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutRow::Error)
+                            .text()
+                            .null()
+                            .comment("校验或执行失败原因"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPayoutRow::Signature)
+                            .string()
+                            .null()
+                            .comment("交易签名"),
+                    )
+                    .col(ColumnDef::new(SysPayoutRow::UpdatedAt).timestamp())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysPayoutRow::Table)
+                    .name("idx_sys_payout_row_batch_row")
+                    .col(SysPayoutRow::BatchId)
+                    .col(SysPayoutRow::RowIndex)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysPayoutRow::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysPayoutRow {
+    Table,
+    Id,
+    BatchId,
+    RowIndex,
+    Address,
+    Mint,
+    Amount,
+    Status,
+    Error,
+    Signature,
+    UpdatedAt,
+}
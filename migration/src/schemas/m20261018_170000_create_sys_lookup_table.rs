@@ -0,0 +1,105 @@
+use sea_orm::Iterable;
+use sea_orm_migration::prelude::*;
+
+use super::m20240815_082808_create_enum_status::Status;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysLookupTable::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysLookupTable::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysLookupTable::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysLookupTable::Address)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                            .comment("查找表地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysLookupTable::Authority)
+                            .string()
+                            .not_null()
+                            .comment("管理查找表的钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysLookupTable::AddressCount)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .comment("表中地址数量"),
+                    )
+                    .col(
+                        ColumnDef::new(SysLookupTable::Status)
+                            .enumeration(Alias::new("status"), Status::iter())
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysLookupTable::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysLookupTable::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysLookupTable::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysLookupTable::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysLookupTable::Table)
+                    .name("idx_sys_lookup_table_domain_status")
+                    .col(SysLookupTable::Domain)
+                    .col(SysLookupTable::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysLookupTable::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysLookupTable {
+    Table,
+    Id,
+    Domain,
+    Address,
+    Authority,
+    AddressCount,
+    Status,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
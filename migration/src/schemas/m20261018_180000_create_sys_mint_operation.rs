@@ -0,0 +1,146 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysMintOperation::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysMintOperation::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::Mint)
+                            .string()
+                            .not_null()
+                            .comment("mint"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::OperationType)
+                            .string()
+                            .not_null()
+                            .comment("操作类型: mint_to/burn/freeze_account/thaw_account/set_authority"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::Target)
+                            .string()
+                            .null()
+                            .comment("增发接收钱包、冻结/解冻的 Token 账户或新权限地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::Amount)
+                            .big_integer()
+                            .null()
+                            .comment("增发或销毁数量（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::AuthorityType)
+                            .string()
+                            .null()
+                            .comment("转移的权限: mint_tokens/freeze_account"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::Reason)
+                            .text()
+                            .not_null()
+                            .comment("操作理由"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: pending_approval/approved/rejected/executing/executed/failed"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::RequiredApprovals)
+                            .integer()
+                            .not_null()
+                            .comment("所需审批人数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::Approvals)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .comment("已审批人数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperation::Signature)
+                            .string()
+                            .null()
+                            .comment("交易签名"),
+                    )
+                    .col(ColumnDef::new(SysMintOperation::Error).text().null())
+                    .col(ColumnDef::new(SysMintOperation::ExecutedBy).string().null())
+                    .col(ColumnDef::new(SysMintOperation::ExecutedAt).timestamp().null())
+                    .col(
+                        ColumnDef::new(SysMintOperation::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysMintOperation::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysMintOperation::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysMintOperation::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysMintOperation::Table)
+                    .name("idx_sys_mint_operation_domain_mint")
+                    .col(SysMintOperation::Domain)
+                    .col(SysMintOperation::Mint)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysMintOperation::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysMintOperation {
+    Table,
+    Id,
+    Domain,
+    Mint,
+    OperationType,
+    Target,
+    Amount,
+    AuthorityType,
+    Reason,
+    Status,
+    RequiredApprovals,
+    Approvals,
+    Signature,
+    Error,
+    ExecutedBy,
+    ExecutedAt,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
@@ -0,0 +1,85 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysMintOperationAudit::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysMintOperationAudit::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperationAudit::OperationId)
+                            .string()
+                            .not_null()
+                            .comment("mint 管理操作"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperationAudit::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperationAudit::Action)
+                            .string()
+                            .not_null()
+                            .comment("动作: created/approved/rejected/executed/failed"),
+                    )
+                    .col(
+                        ColumnDef::new(SysMintOperationAudit::Operator)
+                            .string()
+                            .not_null()
+                            .comment("操作人"),
+                    )
+                    .col(ColumnDef::new(SysMintOperationAudit::Detail).text().null())
+                    .col(
+                        ColumnDef::new(SysMintOperationAudit::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysMintOperationAudit::Table)
+                    .name("idx_sys_mint_operation_audit_operation_id")
+                    .col(SysMintOperationAudit::OperationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysMintOperationAudit::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysMintOperationAudit {
+    Table,
+    Id,
+    OperationId,
+    Domain,
+    Action,
+    Operator,
+    Detail,
+    CreatedAt,
+}
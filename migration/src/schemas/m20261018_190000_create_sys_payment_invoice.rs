@@ -0,0 +1,146 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysPaymentInvoice::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::UserId)
+                            .string()
+                            .not_null()
+                            .comment("收款的托管钱包所属用户"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::Recipient)
+                            .string()
+                            .not_null()
+                            .comment("收款钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::Mint)
+                            .string()
+                            .null()
+                            .comment("收款 mint，为空表示 SOL"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::Amount)
+                            .big_integer()
+                            .not_null()
+                            .comment("应收数量（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::Decimals)
+                            .integer()
+                            .not_null()
+                            .comment("小数位数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::Reference)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                            .comment("Solana Pay 引用公钥"),
+                    )
+                    .col(ColumnDef::new(SysPaymentInvoice::Label).string().null())
+                    .col(ColumnDef::new(SysPaymentInvoice::Message).string().null())
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: pending/paid/underpaid/expired/cancelled"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::Signature)
+                            .string()
+                            .null()
+                            .comment("付款交易签名"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::ReceivedAmount)
+                            .big_integer()
+                            .null()
+                            .comment("实收数量（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::ExpiresAt)
+                            .timestamp()
+                            .not_null()
+                            .comment("过期时间"),
+                    )
+                    .col(ColumnDef::new(SysPaymentInvoice::PaidAt).timestamp().null())
+                    .col(
+                        ColumnDef::new(SysPaymentInvoice::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysPaymentInvoice::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysPaymentInvoice::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysPaymentInvoice::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysPaymentInvoice::Table)
+                    .name("idx_sys_payment_invoice_status_expires_at")
+                    .col(SysPaymentInvoice::Status)
+                    .col(SysPaymentInvoice::ExpiresAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysPaymentInvoice::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysPaymentInvoice {
+    Table,
+    Id,
+    Domain,
+    UserId,
+    Recipient,
+    Mint,
+    Amount,
+    Decimals,
+    Reference,
+    Label,
+    Message,
+    Status,
+    Signature,
+    ReceivedAmount,
+    ExpiresAt,
+    PaidAt,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
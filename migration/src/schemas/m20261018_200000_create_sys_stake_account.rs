@@ -0,0 +1,156 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysStakeAccount::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysStakeAccount::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::UserId)
+                            .string()
+                            .null()
+                            .comment("奖励归属的用户，为空表示系统自有质押"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::Funder)
+                            .string()
+                            .not_null()
+                            .comment("出资钱包，同时持有质押与提取权限"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::Address)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                            .comment("质押账户地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::VoteAccount)
+                            .string()
+                            .not_null()
+                            .comment("委托的验证者投票账户"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::Lamports)
+                            .big_integer()
+                            .not_null()
+                            .comment("委托数量，不含租金储备"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: activating/active/deactivating/inactive/withdrawn"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::TotalRewards)
+                            .big_integer()
+                            .not_null()
+                            .default(0)
+                            .comment("累计奖励"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::LastRewardEpoch)
+                            .big_integer()
+                            .null()
+                            .comment("最近一次拉取奖励的 epoch"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::WithdrawnLamports)
+                            .big_integer()
+                            .null()
+                            .comment("提取数量，含租金储备与累计奖励"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::CreateSignature)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::DeactivateSignature)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::WithdrawSignature)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeAccount::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysStakeAccount::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysStakeAccount::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysStakeAccount::Table)
+                    .name("idx_sys_stake_account_domain_user_id")
+                    .col(SysStakeAccount::Domain)
+                    .col(SysStakeAccount::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysStakeAccount::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysStakeAccount {
+    Table,
+    Id,
+    Domain,
+    UserId,
+    Funder,
+    Address,
+    VoteAccount,
+    Lamports,
+    Status,
+    TotalRewards,
+    LastRewardEpoch,
+    WithdrawnLamports,
+    CreateSignature,
+    DeactivateSignature,
+    WithdrawSignature,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
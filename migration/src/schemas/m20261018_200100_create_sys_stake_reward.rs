@@ -0,0 +1,113 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysStakeReward::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysStakeReward::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeReward::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeReward::StakeAccountId)
+                            .string()
+                            .not_null()
+                            .comment("质押账户记录 ID"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeReward::UserId)
+                            .string()
+                            .null()
+                            .comment("奖励归属的用户"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeReward::Address)
+                            .string()
+                            .not_null()
+                            .comment("质押账户地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeReward::Epoch)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeReward::Amount)
+                            .big_integer()
+                            .not_null()
+                            .comment("奖励数量（lamports）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeReward::PostBalance)
+                            .big_integer()
+                            .not_null()
+                            .comment("奖励入账后的账户余额"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeReward::Commission)
+                            .integer()
+                            .null()
+                            .comment("验证者佣金比例（%）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysStakeReward::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 同一账户每个 epoch 只记一次奖励，重复拉取不会重复计入
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysStakeReward::Table)
+                    .name("idx_sys_stake_reward_account_epoch")
+                    .col(SysStakeReward::StakeAccountId)
+                    .col(SysStakeReward::Epoch)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysStakeReward::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysStakeReward {
+    Table,
+    Id,
+    Domain,
+    StakeAccountId,
+    UserId,
+    Address,
+    Epoch,
+    Amount,
+    PostBalance,
+    Commission,
+    CreatedAt,
+}
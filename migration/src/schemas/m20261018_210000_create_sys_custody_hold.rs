@@ -0,0 +1,119 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysCustodyHold::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysCustodyHold::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHold::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHold::UserId)
+                            .string()
+                            .not_null()
+                            .comment("被冻结托管钱包的所属用户"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHold::Source)
+                            .string()
+                            .not_null()
+                            .comment("来源: manual/user_disabled"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHold::Reason)
+                            .string()
+                            .not_null()
+                            .comment("冻结原因"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHold::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: active/released"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHold::ExpiresAt)
+                            .timestamp()
+                            .null()
+                            .comment("到期时间，为空表示直到人工解除"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHold::ReleasedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(SysCustodyHold::ReleasedBy).string().null())
+                    .col(
+                        ColumnDef::new(SysCustodyHold::ReleaseReason)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHold::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHold::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysCustodyHold::Table)
+                    .name("idx_sys_custody_hold_domain_user_id_status")
+                    .col(SysCustodyHold::Domain)
+                    .col(SysCustodyHold::UserId)
+                    .col(SysCustodyHold::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysCustodyHold::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysCustodyHold {
+    Table,
+    Id,
+    Domain,
+    UserId,
+    Source,
+    Reason,
+    Status,
+    ExpiresAt,
+    ReleasedAt,
+    ReleasedBy,
+    ReleaseReason,
+    CreatedAt,
+    CreatedBy,
+}
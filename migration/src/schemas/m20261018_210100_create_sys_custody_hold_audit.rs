@@ -0,0 +1,85 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysCustodyHoldAudit::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysCustodyHoldAudit::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHoldAudit::HoldId)
+                            .string()
+                            .not_null()
+                            .comment("冻结记录"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHoldAudit::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHoldAudit::Action)
+                            .string()
+                            .not_null()
+                            .comment("动作: placed/released"),
+                    )
+                    .col(
+                        ColumnDef::new(SysCustodyHoldAudit::Operator)
+                            .string()
+                            .not_null()
+                            .comment("操作人"),
+                    )
+                    .col(ColumnDef::new(SysCustodyHoldAudit::Detail).text().null())
+                    .col(
+                        ColumnDef::new(SysCustodyHoldAudit::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysCustodyHoldAudit::Table)
+                    .name("idx_sys_custody_hold_audit_hold_id")
+                    .col(SysCustodyHoldAudit::HoldId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysCustodyHoldAudit::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysCustodyHoldAudit {
+    Table,
+    Id,
+    HoldId,
+    Domain,
+    Action,
+    Operator,
+    Detail,
+    CreatedAt,
+}
@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261018_090000_create_sys_custody_wallet::SysCustodyWallet;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysCustodyWallet::Table)
+                    .add_column(
+                        ColumnDef::new(CustodyWalletRetirement::RetiredAt)
+                            .timestamp()
+                            .null()
+                            .comment("密钥轮换后停用的时间"),
+                    )
+                    .add_column(
+                        ColumnDef::new(CustodyWalletRetirement::ReplacedBy)
+                            .string()
+                            .null()
+                            .comment("轮换生成的新钱包"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysCustodyWallet::Table)
+                    .drop_column(CustodyWalletRetirement::RetiredAt)
+                    .drop_column(CustodyWalletRetirement::ReplacedBy)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustodyWalletRetirement {
+    RetiredAt,
+    ReplacedBy,
+}
@@ -0,0 +1,151 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysKeyRotation::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysKeyRotation::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::WalletId)
+                            .string()
+                            .null()
+                            .comment("被轮换的托管钱包，系统钱包未登记时为空"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::WalletType)
+                            .string()
+                            .not_null()
+                            .comment("钱包类型: user/system/hot"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::UserId)
+                            .string()
+                            .null()
+                            .comment("所属用户"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::OldAddress)
+                            .string()
+                            .not_null()
+                            .comment("旧钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::NewAddress)
+                            .string()
+                            .not_null()
+                            .comment("新钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::NewWalletId)
+                            .string()
+                            .null()
+                            .comment("新钱包记录，迁移全部失败时为空"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: completed/partial/failed"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::MigratedLamports)
+                            .big_integer()
+                            .not_null()
+                            .default(0)
+                            .comment("转入新钱包的 SOL"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::MigratedTokenAccounts)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .comment("已迁移的 Token 账户数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::SkippedTokenAccounts)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .comment("跳过的 Token 账户数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::Report)
+                            .json_binary()
+                            .not_null()
+                            .comment("迁移明细：交易签名、迁移与跳过的账户"),
+                    )
+                    .col(ColumnDef::new(SysKeyRotation::Error).text().null())
+                    .col(
+                        ColumnDef::new(SysKeyRotation::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyRotation::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysKeyRotation::Table)
+                    .name("idx_sys_key_rotation_domain_wallet_id")
+                    .col(SysKeyRotation::Domain)
+                    .col(SysKeyRotation::WalletId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysKeyRotation::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysKeyRotation {
+    Table,
+    Id,
+    Domain,
+    WalletId,
+    WalletType,
+    UserId,
+    OldAddress,
+    NewAddress,
+    NewWalletId,
+    Status,
+    MigratedLamports,
+    MigratedTokenAccounts,
+    SkippedTokenAccounts,
+    Report,
+    Error,
+    CreatedAt,
+    CreatedBy,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261018_090000_create_sys_custody_wallet::SysCustodyWallet;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysCustodyWallet::Table)
+                    .add_column(
+                        ColumnDef::new(CustodyWalletKeystore::EncryptedKey)
+                            .text()
+                            .null()
+                            .comment("主口令加密的私钥，为空表示密钥不在密钥库中"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysCustodyWallet::Table)
+                    .drop_column(CustodyWalletKeystore::EncryptedKey)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustodyWalletKeystore {
+    EncryptedKey,
+}
@@ -0,0 +1,83 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysKeyExportPolicy::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysKeyExportPolicy::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExportPolicy::Domain)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                            .comment("域，每个域一条策略"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExportPolicy::AllowExport)
+                            .boolean()
+                            .not_null()
+                            .default(false)
+                            .comment("是否允许用户导出私钥"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExportPolicy::CoolingHours)
+                            .integer()
+                            .not_null()
+                            .comment("申请后需等待的小时数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExportPolicy::DownloadWindowHours)
+                            .integer()
+                            .not_null()
+                            .comment("冷静期结束后可下载的小时数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExportPolicy::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExportPolicy::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysKeyExportPolicy::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysKeyExportPolicy::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysKeyExportPolicy::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysKeyExportPolicy {
+    Table,
+    Id,
+    Domain,
+    AllowExport,
+    CoolingHours,
+    DownloadWindowHours,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
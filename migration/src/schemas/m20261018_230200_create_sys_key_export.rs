@@ -0,0 +1,125 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysKeyExport::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysKeyExport::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::UserId)
+                            .string()
+                            .not_null()
+                            .comment("申请导出的用户"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::WalletId)
+                            .string()
+                            .not_null()
+                            .comment("导出的托管钱包"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::Address)
+                            .string()
+                            .not_null()
+                            .comment("钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: pending/downloaded/cancelled"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::TokenHash)
+                            .string()
+                            .not_null()
+                            .comment("一次性下载凭证的哈希"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::AvailableAt)
+                            .timestamp()
+                            .not_null()
+                            .comment("冷静期结束时间"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::ExpiresAt)
+                            .timestamp()
+                            .not_null()
+                            .comment("下载截止时间"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::DownloadedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeyExport::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysKeyExport::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysKeyExport::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysKeyExport::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysKeyExport::Table)
+                    .name("idx_sys_key_export_domain_user_id_status")
+                    .col(SysKeyExport::Domain)
+                    .col(SysKeyExport::UserId)
+                    .col(SysKeyExport::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysKeyExport::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysKeyExport {
+    Table,
+    Id,
+    Domain,
+    UserId,
+    WalletId,
+    Address,
+    Status,
+    TokenHash,
+    AvailableAt,
+    ExpiresAt,
+    DownloadedAt,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
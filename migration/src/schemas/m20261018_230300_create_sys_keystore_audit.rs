@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysKeystoreAudit::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysKeystoreAudit::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeystoreAudit::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeystoreAudit::WalletId)
+                            .string()
+                            .not_null()
+                            .comment("涉及的托管钱包"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeystoreAudit::ExportId)
+                            .string()
+                            .null()
+                            .comment("关联的导出申请，导入时为空"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeystoreAudit::Action)
+                            .string()
+                            .not_null()
+                            .comment(
+                                "操作: imported/export_requested/export_downloaded/export_cancelled",
+                            ),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeystoreAudit::Operator)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeystoreAudit::Detail)
+                            .string()
+                            .null()
+                            .comment("补充说明"),
+                    )
+                    .col(
+                        ColumnDef::new(SysKeystoreAudit::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysKeystoreAudit::Table)
+                    .name("idx_sys_keystore_audit_wallet_id")
+                    .col(SysKeystoreAudit::WalletId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysKeystoreAudit::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysKeystoreAudit {
+    Table,
+    Id,
+    Domain,
+    WalletId,
+    ExportId,
+    Action,
+    Operator,
+    Detail,
+    CreatedAt,
+}
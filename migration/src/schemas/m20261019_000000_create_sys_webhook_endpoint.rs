@@ -0,0 +1,107 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysWebhookEndpoint::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysWebhookEndpoint::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookEndpoint::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookEndpoint::Url)
+                            .string()
+                            .not_null()
+                            .comment("接收通知的地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookEndpoint::Secret)
+                            .string()
+                            .not_null()
+                            .comment("签名密钥"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookEndpoint::Events)
+                            .json_binary()
+                            .not_null()
+                            .comment("订阅的事件类型"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookEndpoint::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: enabled/disabled"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookEndpoint::Description)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookEndpoint::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookEndpoint::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysWebhookEndpoint::UpdatedAt).timestamp())
+                    .col(ColumnDef::new(SysWebhookEndpoint::UpdatedBy).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysWebhookEndpoint::Table)
+                    .name("idx_sys_webhook_endpoint_domain_status")
+                    .col(SysWebhookEndpoint::Domain)
+                    .col(SysWebhookEndpoint::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysWebhookEndpoint::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysWebhookEndpoint {
+    Table,
+    Id,
+    Domain,
+    Url,
+    Secret,
+    Events,
+    Status,
+    Description,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
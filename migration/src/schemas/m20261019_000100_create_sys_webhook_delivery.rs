@@ -0,0 +1,147 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysWebhookDelivery::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::Domain)
+                            .string()
+                            .not_null()
+                            .comment("域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::EndpointId)
+                            .string()
+                            .not_null()
+                            .comment("接收端点"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::EventId)
+                            .string()
+                            .not_null()
+                            .comment("事件 ID，同一事件投递到多个端点时相同"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::EventType)
+                            .string()
+                            .not_null()
+                            .comment("事件类型"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::Payload)
+                            .json_binary()
+                            .not_null()
+                            .comment("事件内容"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: pending/delivering/delivered/dead_lettered"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .comment("已尝试次数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::NextAttemptAt)
+                            .timestamp()
+                            .not_null()
+                            .comment("下次投递时间"),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::ResponseStatus)
+                            .integer()
+                            .null()
+                            .comment("最近一次响应的 HTTP 状态码"),
+                    )
+                    .col(ColumnDef::new(SysWebhookDelivery::LastError).text().null())
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::DeliveredAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::DeadLetteredAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysWebhookDelivery::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysWebhookDelivery::UpdatedAt).timestamp())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysWebhookDelivery::Table)
+                    .name("idx_sys_webhook_delivery_status_next_attempt_at")
+                    .col(SysWebhookDelivery::Status)
+                    .col(SysWebhookDelivery::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysWebhookDelivery::Table)
+                    .name("idx_sys_webhook_delivery_domain_endpoint_id")
+                    .col(SysWebhookDelivery::Domain)
+                    .col(SysWebhookDelivery::EndpointId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysWebhookDelivery::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysWebhookDelivery {
+    Table,
+    Id,
+    Domain,
+    EndpointId,
+    EventId,
+    EventType,
+    Payload,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    ResponseStatus,
+    LastError,
+    DeliveredAt,
+    DeadLetteredAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,111 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysJobRun::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysJobRun::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysJobRun::JobName)
+                            .string()
+                            .not_null()
+                            .comment("任务名称"),
+                    )
+                    .col(
+                        ColumnDef::new(SysJobRun::Trigger)
+                            .string()
+                            .not_null()
+                            .comment("触发方式: schedule/manual"),
+                    )
+                    .col(
+                        ColumnDef::new(SysJobRun::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: running/succeeded/failed"),
+                    )
+                    .col(
+                        ColumnDef::new(SysJobRun::InstanceId)
+                            .string()
+                            .not_null()
+                            .comment("执行任务的实例"),
+                    )
+                    .col(
+                        ColumnDef::new(SysJobRun::ScheduledAt)
+                            .timestamp()
+                            .null()
+                            .comment("按计划触发时的计划时间"),
+                    )
+                    .col(ColumnDef::new(SysJobRun::StartedAt).timestamp().not_null())
+                    .col(ColumnDef::new(SysJobRun::FinishedAt).timestamp().null())
+                    .col(
+                        ColumnDef::new(SysJobRun::DurationMs)
+                            .big_integer()
+                            .null()
+                            .comment("耗时（毫秒）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysJobRun::Output)
+                            .text()
+                            .null()
+                            .comment("执行结果摘要"),
+                    )
+                    .col(ColumnDef::new(SysJobRun::Error).text().null())
+                    .col(
+                        ColumnDef::new(SysJobRun::TriggeredBy)
+                            .string()
+                            .null()
+                            .comment("手动触发的操作人"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysJobRun::Table)
+                    .name("idx_sys_job_run_job_name_started_at")
+                    .col(SysJobRun::JobName)
+                    .col(SysJobRun::StartedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysJobRun::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysJobRun {
+    Table,
+    Id,
+    JobName,
+    Trigger,
+    Status,
+    InstanceId,
+    ScheduledAt,
+    StartedAt,
+    FinishedAt,
+    DurationMs,
+    Output,
+    Error,
+    TriggeredBy,
+}
@@ -0,0 +1,106 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysOutbox::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysOutbox::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOutbox::EventType)
+                            .string()
+                            .not_null()
+                            .comment("事件类型"),
+                    )
+                    .col(
+                        ColumnDef::new(SysOutbox::DedupKey)
+                            .string()
+                            .not_null()
+                            .unique_key()
+                            .comment("去重键，同一业务事件重复写入时只保留一条"),
+                    )
+                    .col(
+                        ColumnDef::new(SysOutbox::Payload)
+                            .json_binary()
+                            .not_null()
+                            .comment("事件内容"),
+                    )
+                    .col(
+                        ColumnDef::new(SysOutbox::Status)
+                            .string()
+                            .not_null()
+                            .comment("状态: pending/publishing/published/dead_lettered"),
+                    )
+                    .col(
+                        ColumnDef::new(SysOutbox::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .comment("已尝试次数"),
+                    )
+                    .col(
+                        ColumnDef::new(SysOutbox::NextAttemptAt)
+                            .timestamp()
+                            .not_null()
+                            .comment("下次发布时间"),
+                    )
+                    .col(ColumnDef::new(SysOutbox::LastError).text().null())
+                    .col(ColumnDef::new(SysOutbox::PublishedAt).timestamp().null())
+                    .col(
+                        ColumnDef::new(SysOutbox::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysOutbox::UpdatedAt).timestamp())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysOutbox::Table)
+                    .name("idx_sys_outbox_status_next_attempt_at")
+                    .col(SysOutbox::Status)
+                    .col(SysOutbox::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysOutbox::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysOutbox {
+    Table,
+    Id,
+    EventType,
+    DedupKey,
+    Payload,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    LastError,
+    PublishedAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,122 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysRiskScreening::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysRiskScreening::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysRiskScreening::FromAddress)
+                            .string()
+                            .not_null()
+                            .comment("转出方钱包地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRiskScreening::ToAddress)
+                            .string()
+                            .not_null()
+                            .comment("交易对手地址"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRiskScreening::Mint)
+                            .string()
+                            .null()
+                            .comment("Token mint，SOL 转账为空"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRiskScreening::Amount)
+                            .big_integer()
+                            .not_null()
+                            .comment("转账数量（最小单位）"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRiskScreening::Reference)
+                            .string()
+                            .null()
+                            .comment("业务侧引用 ID"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRiskScreening::Decision)
+                            .string()
+                            .not_null()
+                            .comment("结论: allow/manual_review/deny"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRiskScreening::Reasons)
+                            .json_binary()
+                            .not_null()
+                            .comment("命中原因"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRiskScreening::Provider)
+                            .string()
+                            .not_null()
+                            .comment("给出结论的筛查器"),
+                    )
+                    .col(
+                        ColumnDef::new(SysRiskScreening::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysRiskScreening::Table)
+                    .name("idx_sys_risk_screening_to_address")
+                    .col(SysRiskScreening::ToAddress)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysRiskScreening::Table)
+                    .name("idx_sys_risk_screening_decision_created_at")
+                    .col(SysRiskScreening::Decision)
+                    .col(SysRiskScreening::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysRiskScreening::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysRiskScreening {
+    Table,
+    Id,
+    FromAddress,
+    ToAddress,
+    Mint,
+    Amount,
+    Reference,
+    Decision,
+    Reasons,
+    Provider,
+    CreatedAt,
+}
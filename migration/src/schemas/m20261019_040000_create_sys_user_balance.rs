@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysUserBalance::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysUserBalance::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysUserBalance::Domain)
+                            .string()
+                            .not_null()
+                            .comment("所属域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysUserBalance::UserId)
+                            .string()
+                            .not_null()
+                            .comment("用户 ID"),
+                    )
+                    .col(
+                        ColumnDef::new(SysUserBalance::Mint)
+                            .string()
+                            .not_null()
+                            .comment("资产 mint"),
+                    )
+                    .col(
+                        ColumnDef::new(SysUserBalance::Balance)
+                            .big_integer()
+                            .not_null()
+                            .default(0)
+                            .comment("可用余额（最小单位），即平台对用户的负债"),
+                    )
+                    .col(
+                        ColumnDef::new(SysUserBalance::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysUserBalance::UpdatedAt).timestamp())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysUserBalance::Table)
+                    .name("idx_sys_user_balance_domain_user_mint")
+                    .col(SysUserBalance::Domain)
+                    .col(SysUserBalance::UserId)
+                    .col(SysUserBalance::Mint)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysUserBalance::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysUserBalance {
+    Table,
+    Id,
+    Domain,
+    UserId,
+    Mint,
+    Balance,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,121 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysBalanceEntry::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysBalanceEntry::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysBalanceEntry::Domain)
+                            .string()
+                            .not_null()
+                            .comment("所属域"),
+                    )
+                    .col(
+                        ColumnDef::new(SysBalanceEntry::UserId)
+                            .string()
+                            .not_null()
+                            .comment("用户 ID"),
+                    )
+                    .col(
+                        ColumnDef::new(SysBalanceEntry::Mint)
+                            .string()
+                            .not_null()
+                            .comment("资产 mint"),
+                    )
+                    .col(
+                        ColumnDef::new(SysBalanceEntry::EntryType)
+                            .string()
+                            .not_null()
+                            .comment(
+                                "类型: deposit/withdrawal/withdrawal_reversal/convert_out/convert_in",
+                            ),
+                    )
+                    .col(
+                        ColumnDef::new(SysBalanceEntry::Amount)
+                            .big_integer()
+                            .not_null()
+                            .comment("变动数量（最小单位），入账为正、扣减为负"),
+                    )
+                    .col(
+                        ColumnDef::new(SysBalanceEntry::BalanceAfter)
+                            .big_integer()
+                            .not_null()
+                            .comment("变动后余额"),
+                    )
+                    .col(
+                        ColumnDef::new(SysBalanceEntry::Reference)
+                            .string()
+                            .not_null()
+                            .comment("业务引用 ID，如入金 ID、提现记录 ID、兑换任务 ID"),
+                    )
+                    .col(
+                        ColumnDef::new(SysBalanceEntry::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysBalanceEntry::Table)
+                    .name("idx_sys_balance_entry_domain_type_reference")
+                    .col(SysBalanceEntry::Domain)
+                    .col(SysBalanceEntry::EntryType)
+                    .col(SysBalanceEntry::Reference)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(SysBalanceEntry::Table)
+                    .name("idx_sys_balance_entry_domain_user_created_at")
+                    .col(SysBalanceEntry::Domain)
+                    .col(SysBalanceEntry::UserId)
+                    .col(SysBalanceEntry::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysBalanceEntry::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysBalanceEntry {
+    Table,
+    Id,
+    Domain,
+    UserId,
+    Mint,
+    EntryType,
+    Amount,
+    BalanceAfter,
+    Reference,
+    CreatedAt,
+}
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261018_150200_create_sys_withdrawal_fee_record::SysWithdrawalFeeRecord;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysWithdrawalFeeRecord::Table)
+                    .add_column(
+                        ColumnDef::new(WithdrawalFeeRecordStatus::Status)
+                            .string()
+                            .not_null()
+                            .default("sent")
+                            .comment("状态: pending/sent/failed"),
+                    )
+                    .add_column(
+                        ColumnDef::new(WithdrawalFeeRecordStatus::Error)
+                            .text()
+                            .null()
+                            .comment("发送失败或结果未知时的错误信息"),
+                    )
+                    .add_column(
+                        ColumnDef::new(WithdrawalFeeRecordStatus::UpdatedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .modify_column(
+                        ColumnDef::new(SysWithdrawalFeeRecord::Signature)
+                            .string()
+                            .null()
+                            .comment("交易签名，发送前为空"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysWithdrawalFeeRecord::Table)
+                    .drop_column(WithdrawalFeeRecordStatus::Status)
+                    .drop_column(WithdrawalFeeRecordStatus::Error)
+                    .drop_column(WithdrawalFeeRecordStatus::UpdatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WithdrawalFeeRecordStatus {
+    Status,
+    Error,
+    UpdatedAt,
+}
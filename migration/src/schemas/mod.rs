@@ -11,3 +11,39 @@ pub mod m20241023_091155_create_sys_organization;
 pub mod m20241023_091159_create_sys_role_menu;
 pub mod m20241023_091204_create_sys_tokens;
 pub mod m20241023_091210_create_sys_user_role;
+pub mod m20261018_090000_create_sys_custody_wallet;
+pub mod m20261018_090100_create_sys_reserve_snapshot;
+pub mod m20261018_090200_create_sys_reserve_liability;
+pub mod m20261018_100000_create_sys_auto_convert_policy;
+pub mod m20261018_100100_create_sys_auto_convert_job;
+pub mod m20261018_100200_create_sys_auto_convert_leg;
+pub mod m20261018_110000_alter_sys_auto_convert_leg_add_prices;
+pub mod m20261018_130000_create_sys_rent_reclamation;
+pub mod m20261018_140000_create_sys_asset;
+pub mod m20261018_150000_create_sys_withdrawal_fee;
+pub mod m20261018_150100_create_sys_withdrawal_fee_tier;
+pub mod m20261018_150200_create_sys_withdrawal_fee_record;
+pub mod m20261018_160000_create_sys_payout_batch;
+pub mod m20261018_160100_create_sys_payout_row;
+pub mod m20261018_170000_create_sys_lookup_table;
+pub mod m20261018_180000_create_sys_mint_operation;
+pub mod m20261018_180100_create_sys_mint_operation_audit;
+pub mod m20261018_190000_create_sys_payment_invoice;
+pub mod m20261018_200000_create_sys_stake_account;
+pub mod m20261018_200100_create_sys_stake_reward;
+pub mod m20261018_210000_create_sys_custody_hold;
+pub mod m20261018_210100_create_sys_custody_hold_audit;
+pub mod m20261018_220000_alter_sys_custody_wallet_add_retired;
+pub mod m20261018_220100_create_sys_key_rotation;
+pub mod m20261018_230000_alter_sys_custody_wallet_add_encrypted_key;
+pub mod m20261018_230100_create_sys_key_export_policy;
+pub mod m20261018_230200_create_sys_key_export;
+pub mod m20261018_230300_create_sys_keystore_audit;
+pub mod m20261019_000000_create_sys_webhook_endpoint;
+pub mod m20261019_000100_create_sys_webhook_delivery;
+pub mod m20261019_010000_create_sys_job_run;
+pub mod m20261019_020000_create_sys_outbox;
+pub mod m20261019_030000_create_sys_risk_screening;
+pub mod m20261019_040000_create_sys_user_balance;
+pub mod m20261019_040100_create_sys_balance_entry;
+pub mod m20261019_050000_alter_sys_withdrawal_fee_record_add_status;
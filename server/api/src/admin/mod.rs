@@ -1,24 +1,56 @@
 pub use sys_access_key_api::SysAccessKeyApi;
+pub use sys_asset_api::SysAssetApi;
 pub use sys_authentication_api::SysAuthenticationApi;
+pub use sys_auto_convert_api::SysAutoConvertApi;
+pub use sys_custody_hold_api::SysCustodyHoldApi;
 pub use sys_domain_api::SysDomainApi;
 pub use sys_endpoint_api::SysEndpointApi;
+pub use sys_job_api::SysJobApi;
+pub use sys_key_rotation_api::SysKeyRotationApi;
+pub use sys_keystore_api::SysKeystoreApi;
 pub use sys_login_log_api::SysLoginLogApi;
+pub use sys_lookup_table_api::SysLookupTableApi;
 pub use sys_menu_api::SysMenuApi;
+pub use sys_mint_admin_api::SysMintAdminApi;
 pub use sys_operation_log_api::SysOperationLogApi;
 pub use sys_organization_api::SysOrganizationApi;
+pub use sys_payment_invoice_api::SysPaymentInvoiceApi;
+pub use sys_payout_api::SysPayoutApi;
+pub use sys_rent_reclamation_api::SysRentReclamationApi;
+pub use sys_reserves_api::SysReservesApi;
 pub use sys_role_api::SysRoleApi;
 pub use sys_sandbox_api::SysSandboxApi;
+pub use sys_solana_api::SysSolanaApi;
+pub use sys_stake_api::SysStakeApi;
 pub use sys_user_api::SysUserApi;
+pub use sys_webhook_api::SysWebhookApi;
+pub use sys_withdrawal_fee_api::SysWithdrawalFeeApi;
 
 mod sys_access_key_api;
+mod sys_asset_api;
 mod sys_authentication_api;
+mod sys_auto_convert_api;
+mod sys_custody_hold_api;
 mod sys_domain_api;
 mod sys_endpoint_api;
+mod sys_job_api;
+mod sys_key_rotation_api;
+mod sys_keystore_api;
 mod sys_login_log_api;
+mod sys_lookup_table_api;
 mod sys_menu_api;
+mod sys_mint_admin_api;
 mod sys_operation_log_api;
 mod sys_organization_api;
+mod sys_payment_invoice_api;
+mod sys_payout_api;
+mod sys_rent_reclamation_api;
+mod sys_reserves_api;
 mod sys_role_api;
 mod sys_sandbox_api;
+mod sys_solana_api;
+mod sys_stake_api;
 mod sys_user_api;
+mod sys_webhook_api;
+mod sys_withdrawal_fee_api;
 
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Multipart, Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    AssetPageRequest, CreateAssetInput, SysAssetModel, SysAssetService, TAssetService,
+    UpdateAssetInput,
+};
+
+pub struct SysAssetApi;
+
+impl SysAssetApi {
+    pub async fn get_paginated_assets(
+        Query(params): Query<AssetPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAssetService>>,
+    ) -> Result<Res<PaginatedData<SysAssetModel>>, AppError> {
+        service
+            .find_paginated_assets(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn create_asset(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAssetService>>,
+        ValidatedForm(input): ValidatedForm<CreateAssetInput>,
+    ) -> Result<Res<SysAssetModel>, AppError> {
+        service
+            .create_asset(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_asset(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAssetService>>,
+    ) -> Result<Res<SysAssetModel>, AppError> {
+        service
+            .get_asset(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn update_asset(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAssetService>>,
+        ValidatedForm(input): ValidatedForm<UpdateAssetInput>,
+    ) -> Result<Res<SysAssetModel>, AppError> {
+        service
+            .update_asset(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn delete_asset(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAssetService>>,
+    ) -> Result<Res<()>, AppError> {
+        service
+            .delete_asset(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    /// 上传图标，multipart 表单中的 `file` 字段为图片内容
+    pub async fn upload_icon(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAssetService>>,
+        mut multipart: Multipart,
+    ) -> Result<Res<SysAssetModel>, AppError> {
+        let bad_request = |message: String| AppError { code: 400, message };
+
+        while let Some(field) = multipart
+            .next_field()
+            .await
+            .map_err(|e| bad_request(e.to_string()))?
+        {
+            if field.name() != Some("file") {
+                continue;
+            }
+
+            let content_type = field.content_type().unwrap_or_default().to_string();
+            let data = field
+                .bytes()
+                .await
+                .map_err(|e| bad_request(e.to_string()))?;
+            return service
+                .upload_icon(
+                    &user.domain(),
+                    &id,
+                    &content_type,
+                    data.to_vec(),
+                    &user.user_id(),
+                )
+                .await
+                .map(Res::new_data);
+        }
+
+        Err(bad_request("Missing multipart field 'file'".to_string()))
+    }
+
+    pub async fn get_icon_url(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAssetService>>,
+    ) -> Result<Res<String>, AppError> {
+        service
+            .get_icon_url(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+}
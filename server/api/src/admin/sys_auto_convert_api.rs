@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    AutoConvertJobPageRequest, DepositNotificationInput, SysAutoConvertJobModel,
+    SysAutoConvertLegModel, SysAutoConvertPolicyModel, SysAutoConvertService, TAutoConvertService,
+    UpsertAutoConvertPolicyInput,
+};
+
+pub struct SysAutoConvertApi;
+
+impl SysAutoConvertApi {
+    pub async fn get_policy(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAutoConvertService>>,
+    ) -> Result<Res<SysAutoConvertPolicyModel>, AppError> {
+        service.get_policy(&user.domain()).await.map(Res::new_data)
+    }
+
+    pub async fn upsert_policy(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAutoConvertService>>,
+        ValidatedForm(input): ValidatedForm<UpsertAutoConvertPolicyInput>,
+    ) -> Result<Res<SysAutoConvertPolicyModel>, AppError> {
+        service
+            .upsert_policy(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn notify_deposit(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAutoConvertService>>,
+        ValidatedForm(input): ValidatedForm<DepositNotificationInput>,
+    ) -> Result<Res<SysAutoConvertJobModel>, AppError> {
+        service
+            .notify_deposit(&user.domain(), input)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_paginated_jobs(
+        Query(params): Query<AutoConvertJobPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAutoConvertService>>,
+    ) -> Result<Res<PaginatedData<SysAutoConvertJobModel>>, AppError> {
+        service
+            .find_paginated_jobs(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_job_legs(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAutoConvertService>>,
+    ) -> Result<Res<Vec<SysAutoConvertLegModel>>, AppError> {
+        service
+            .find_job_legs(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn retry_job(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAutoConvertService>>,
+    ) -> Result<Res<SysAutoConvertJobModel>, AppError> {
+        service
+            .retry_job(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    CustodyHoldOutput, CustodyHoldPageRequest, PlaceCustodyHoldInput, ReleaseCustodyHoldInput,
+    SysCustodyHoldModel, SysCustodyHoldService, TCustodyHoldService,
+};
+
+pub struct SysCustodyHoldApi;
+
+impl SysCustodyHoldApi {
+    pub async fn get_paginated_holds(
+        Query(params): Query<CustodyHoldPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysCustodyHoldService>>,
+    ) -> Result<Res<PaginatedData<SysCustodyHoldModel>>, AppError> {
+        service
+            .find_paginated_holds(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn place_hold(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysCustodyHoldService>>,
+        ValidatedForm(input): ValidatedForm<PlaceCustodyHoldInput>,
+    ) -> Result<Res<SysCustodyHoldModel>, AppError> {
+        service
+            .place_hold(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_hold(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysCustodyHoldService>>,
+    ) -> Result<Res<CustodyHoldOutput>, AppError> {
+        service
+            .get_hold(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn release_hold(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysCustodyHoldService>>,
+        ValidatedForm(input): ValidatedForm<ReleaseCustodyHoldInput>,
+    ) -> Result<Res<SysCustodyHoldModel>, AppError> {
+        service
+            .release_hold(&user.domain(), &id, input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+}
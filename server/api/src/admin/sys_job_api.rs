@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{auth::User, error::AppError, page::PaginatedData, res::Res};
+use server_service::admin::{
+    JobOutput, JobRunPageRequest, SysJobRunModel, SysJobService, TJobService,
+};
+
+pub struct SysJobApi;
+
+impl SysJobApi {
+    pub async fn list_jobs(
+        Extension(service): Extension<Arc<SysJobService>>,
+    ) -> Result<Res<Vec<JobOutput>>, AppError> {
+        service.list_jobs().await.map(Res::new_data)
+    }
+
+    pub async fn trigger_job(
+        Path(name): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysJobService>>,
+    ) -> Result<Res<SysJobRunModel>, AppError> {
+        service
+            .trigger_job(&name, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn pause_job(
+        Path(name): Path<String>,
+        Extension(service): Extension<Arc<SysJobService>>,
+    ) -> Result<Res<()>, AppError> {
+        service.pause_job(&name).await.map(Res::new_data)
+    }
+
+    pub async fn resume_job(
+        Path(name): Path<String>,
+        Extension(service): Extension<Arc<SysJobService>>,
+    ) -> Result<Res<()>, AppError> {
+        service.resume_job(&name).await.map(Res::new_data)
+    }
+
+    pub async fn get_paginated_runs(
+        Query(params): Query<JobRunPageRequest>,
+        Extension(service): Extension<Arc<SysJobService>>,
+    ) -> Result<Res<PaginatedData<SysJobRunModel>>, AppError> {
+        service.find_paginated_runs(params).await.map(Res::new_data)
+    }
+
+    pub async fn get_run(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysJobService>>,
+    ) -> Result<Res<SysJobRunModel>, AppError> {
+        service.get_run(&id).await.map(Res::new_data)
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    KeyRotationPageRequest, RotateWalletKeyInput, SysKeyRotationModel,
+    SysKeyRotationService, TKeyRotationService,
+};
+
+pub struct SysKeyRotationApi;
+
+impl SysKeyRotationApi {
+    pub async fn get_paginated_rotations(
+        Query(params): Query<KeyRotationPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeyRotationService>>,
+    ) -> Result<Res<PaginatedData<SysKeyRotationModel>>, AppError> {
+        service
+            .find_paginated_rotations(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_rotation(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeyRotationService>>,
+    ) -> Result<Res<SysKeyRotationModel>, AppError> {
+        service
+            .get_rotation(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn rotate_wallet_key(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeyRotationService>>,
+        ValidatedForm(input): ValidatedForm<RotateWalletKeyInput>,
+    ) -> Result<Res<SysKeyRotationModel>, AppError> {
+        service
+            .rotate_wallet_key(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn rotate_system_key(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeyRotationService>>,
+    ) -> Result<Res<SysKeyRotationModel>, AppError> {
+        service
+            .rotate_system_key(&user.domain(), &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    DownloadKeyExportInput, EncryptedKey, ImportKeyResultOutput, ImportKeystoreInput,
+    KeyExportOutput, KeyExportPageRequest, KeyExportTicketOutput, RequestKeyExportInput,
+    SysKeyExportModel, SysKeyExportPolicyModel, SysKeystoreService, TKeystoreService,
+    UpsertKeyExportPolicyInput,
+};
+
+pub struct SysKeystoreApi;
+
+impl SysKeystoreApi {
+    pub async fn get_policy(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeystoreService>>,
+    ) -> Result<Res<SysKeyExportPolicyModel>, AppError> {
+        service.get_policy(&user.domain()).await.map(Res::new_data)
+    }
+
+    pub async fn upsert_policy(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeystoreService>>,
+        ValidatedForm(input): ValidatedForm<UpsertKeyExportPolicyInput>,
+    ) -> Result<Res<SysKeyExportPolicyModel>, AppError> {
+        service
+            .upsert_policy(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn import_keys(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeystoreService>>,
+        ValidatedForm(input): ValidatedForm<ImportKeystoreInput>,
+    ) -> Result<Res<Vec<ImportKeyResultOutput>>, AppError> {
+        service
+            .import_keys(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_paginated_exports(
+        Query(params): Query<KeyExportPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeystoreService>>,
+    ) -> Result<Res<PaginatedData<SysKeyExportModel>>, AppError> {
+        service
+            .find_paginated_exports(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_export(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeystoreService>>,
+    ) -> Result<Res<KeyExportOutput>, AppError> {
+        service
+            .get_export(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn request_export(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeystoreService>>,
+        ValidatedForm(input): ValidatedForm<RequestKeyExportInput>,
+    ) -> Result<Res<KeyExportTicketOutput>, AppError> {
+        service
+            .request_export(&user.domain(), &user.user_id(), input)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn download_export(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeystoreService>>,
+        ValidatedForm(input): ValidatedForm<DownloadKeyExportInput>,
+    ) -> Result<Res<EncryptedKey>, AppError> {
+        service
+            .download_export(&user.domain(), &user.user_id(), &id, input)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn cancel_export(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysKeystoreService>>,
+    ) -> Result<Res<SysKeyExportModel>, AppError> {
+        service
+            .cancel_export(&user.domain(), &user.user_id(), &id)
+            .await
+            .map(Res::new_data)
+    }
+}
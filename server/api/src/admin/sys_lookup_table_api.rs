@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    CreateLookupTableInput, ExtendLookupTableInput, LookupTableOutput, LookupTablePageRequest,
+    SysLookupTableModel, SysLookupTableService, TLookupTableService, UpdateLookupTableStatusInput,
+};
+
+pub struct SysLookupTableApi;
+
+impl SysLookupTableApi {
+    pub async fn get_paginated_lookup_tables(
+        Query(params): Query<LookupTablePageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysLookupTableService>>,
+    ) -> Result<Res<PaginatedData<SysLookupTableModel>>, AppError> {
+        service
+            .find_paginated_lookup_tables(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn create_lookup_table(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysLookupTableService>>,
+        ValidatedForm(input): ValidatedForm<CreateLookupTableInput>,
+    ) -> Result<Res<SysLookupTableModel>, AppError> {
+        service
+            .create_lookup_table(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_lookup_table(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysLookupTableService>>,
+    ) -> Result<Res<LookupTableOutput>, AppError> {
+        service
+            .get_lookup_table(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn extend_lookup_table(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysLookupTableService>>,
+        ValidatedForm(input): ValidatedForm<ExtendLookupTableInput>,
+    ) -> Result<Res<SysLookupTableModel>, AppError> {
+        service
+            .extend_lookup_table(&user.domain(), &id, input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn update_lookup_table_status(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysLookupTableService>>,
+        ValidatedForm(input): ValidatedForm<UpdateLookupTableStatusInput>,
+    ) -> Result<Res<SysLookupTableModel>, AppError> {
+        service
+            .update_lookup_table_status(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    CreateMintOperationInput, MintHolderCountOutput, MintOperationOutput, MintOperationPageRequest,
+    MintQuery, MintSupplyOutput, SysMintAdminService, SysMintOperationModel, TMintAdminService,
+};
+
+pub struct SysMintAdminApi;
+
+impl SysMintAdminApi {
+    pub async fn get_paginated_operations(
+        Query(params): Query<MintOperationPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysMintAdminService>>,
+    ) -> Result<Res<PaginatedData<SysMintOperationModel>>, AppError> {
+        service
+            .find_paginated_operations(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn create_operation(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysMintAdminService>>,
+        ValidatedForm(input): ValidatedForm<CreateMintOperationInput>,
+    ) -> Result<Res<SysMintOperationModel>, AppError> {
+        service
+            .create_operation(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_operation(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysMintAdminService>>,
+    ) -> Result<Res<MintOperationOutput>, AppError> {
+        service
+            .get_operation(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn approve_operation(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysMintAdminService>>,
+    ) -> Result<Res<SysMintOperationModel>, AppError> {
+        service
+            .approve_operation(&user.domain(), &id, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn reject_operation(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysMintAdminService>>,
+    ) -> Result<Res<SysMintOperationModel>, AppError> {
+        service
+            .reject_operation(&user.domain(), &id, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn execute_operation(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysMintAdminService>>,
+    ) -> Result<Res<SysMintOperationModel>, AppError> {
+        service
+            .execute_operation(&user.domain(), &id, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_supply(
+        Query(params): Query<MintQuery>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysMintAdminService>>,
+    ) -> Result<Res<MintSupplyOutput>, AppError> {
+        service
+            .get_supply(&user.domain(), &params.mint)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_holder_count(
+        Query(params): Query<MintQuery>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysMintAdminService>>,
+    ) -> Result<Res<MintHolderCountOutput>, AppError> {
+        service
+            .get_holder_count(&user.domain(), &params.mint)
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    CreatePaymentInvoiceInput, PaymentInvoiceOutput, PaymentInvoicePageRequest,
+    SysPaymentInvoiceModel, SysPaymentInvoiceService, TPaymentInvoiceService,
+};
+
+pub struct SysPaymentInvoiceApi;
+
+impl SysPaymentInvoiceApi {
+    pub async fn get_paginated_invoices(
+        Query(params): Query<PaymentInvoicePageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPaymentInvoiceService>>,
+    ) -> Result<Res<PaginatedData<SysPaymentInvoiceModel>>, AppError> {
+        service
+            .find_paginated_invoices(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn create_invoice(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPaymentInvoiceService>>,
+        ValidatedForm(input): ValidatedForm<CreatePaymentInvoiceInput>,
+    ) -> Result<Res<PaymentInvoiceOutput>, AppError> {
+        service
+            .create_invoice(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_invoice(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPaymentInvoiceService>>,
+    ) -> Result<Res<PaymentInvoiceOutput>, AppError> {
+        service
+            .get_invoice(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn cancel_invoice(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPaymentInvoiceService>>,
+    ) -> Result<Res<SysPaymentInvoiceModel>, AppError> {
+        service
+            .cancel_invoice(&user.domain(), &id, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+}
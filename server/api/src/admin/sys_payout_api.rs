@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Multipart, Path, Query},
+    Extension,
+};
+use server_core::web::{auth::User, error::AppError, page::PaginatedData, res::Res};
+use server_service::admin::{
+    PayoutBatchOutput, PayoutBatchPageRequest, PayoutRowPageRequest, SysPayoutBatchModel,
+    SysPayoutRowModel, SysPayoutService, TPayoutService,
+};
+
+pub struct SysPayoutApi;
+
+impl SysPayoutApi {
+    pub async fn get_paginated_batches(
+        Query(params): Query<PayoutBatchPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPayoutService>>,
+    ) -> Result<Res<PaginatedData<SysPayoutBatchModel>>, AppError> {
+        service
+            .find_paginated_batches(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    /// 上传发放文件，multipart 表单中的 `file` 字段为 CSV 或 JSON 文件，`name` 字段为可选的批次名称
+    pub async fn create_batch(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPayoutService>>,
+        mut multipart: Multipart,
+    ) -> Result<Res<PayoutBatchOutput>, AppError> {
+        let bad_request = |message: String| AppError { code: 400, message };
+
+        let mut name = None;
+        let mut file = None;
+        while let Some(field) = multipart
+            .next_field()
+            .await
+            .map_err(|e| bad_request(e.to_string()))?
+        {
+            match field.name() {
+                Some("name") => {
+                    name = Some(field.text().await.map_err(|e| bad_request(e.to_string()))?);
+                },
+                Some("file") => {
+                    let file_name = field.file_name().unwrap_or_default().to_string();
+                    let data = field
+                        .bytes()
+                        .await
+                        .map_err(|e| bad_request(e.to_string()))?;
+                    file = Some((file_name, data));
+                },
+                _ => {},
+            }
+        }
+
+        let (file_name, data) =
+            file.ok_or_else(|| bad_request("Missing multipart field 'file'".to_string()))?;
+        service
+            .create_batch(
+                &user.domain(),
+                name,
+                &file_name,
+                data.to_vec(),
+                &user.user_id(),
+            )
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_batch(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPayoutService>>,
+    ) -> Result<Res<PayoutBatchOutput>, AppError> {
+        service
+            .get_batch(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_paginated_rows(
+        Path(id): Path<String>,
+        Query(params): Query<PayoutRowPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPayoutService>>,
+    ) -> Result<Res<PaginatedData<SysPayoutRowModel>>, AppError> {
+        service
+            .find_paginated_rows(&user.domain(), &id, params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn approve_batch(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPayoutService>>,
+    ) -> Result<Res<SysPayoutBatchModel>, AppError> {
+        service
+            .approve_batch(&user.domain(), &id, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn reject_batch(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPayoutService>>,
+    ) -> Result<Res<SysPayoutBatchModel>, AppError> {
+        service
+            .reject_batch(&user.domain(), &id, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn execute_batch(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPayoutService>>,
+    ) -> Result<Res<SysPayoutBatchModel>, AppError> {
+        service
+            .execute_batch(&user.domain(), &id, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_report_url(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysPayoutService>>,
+    ) -> Result<Res<String>, AppError> {
+        service
+            .get_report_url(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use axum::{extract::Query, Extension};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    RentReclaimedWalletOutput, RentReclamationPageRequest, RunRentReclamationInput,
+    SysRentReclamationModel, SysRentReclamationService, TRentReclamationService,
+};
+
+pub struct SysRentReclamationApi;
+
+impl SysRentReclamationApi {
+    pub async fn run_reclamation(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysRentReclamationService>>,
+        ValidatedForm(input): ValidatedForm<RunRentReclamationInput>,
+    ) -> Result<Res<Vec<SysRentReclamationModel>>, AppError> {
+        service
+            .run_reclamation(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_paginated_records(
+        Query(params): Query<RentReclamationPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysRentReclamationService>>,
+    ) -> Result<Res<PaginatedData<SysRentReclamationModel>>, AppError> {
+        service
+            .find_paginated_records(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_wallet_totals(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysRentReclamationService>>,
+    ) -> Result<Res<Vec<RentReclaimedWalletOutput>>, AppError> {
+        service
+            .find_wallet_totals(&user.domain())
+            .await
+            .map(Res::new_data)
+    }
+}
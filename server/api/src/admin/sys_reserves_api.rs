@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    CreateReservesSnapshotInput, ReservesPageRequest, ReservesProofOutput, ReservesProofQuery,
+    ReservesReportOutput, ReservesReportQuery, SysReserveSnapshotModel, SysReservesService,
+    TReservesService,
+};
+
+pub struct SysReservesApi;
+
+impl SysReservesApi {
+    pub async fn get_paginated_snapshots(
+        Query(params): Query<ReservesPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysReservesService>>,
+    ) -> Result<Res<PaginatedData<SysReserveSnapshotModel>>, AppError> {
+        service
+            .find_paginated_snapshots(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn take_snapshot(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysReservesService>>,
+        ValidatedForm(input): ValidatedForm<CreateReservesSnapshotInput>,
+    ) -> Result<Res<SysReserveSnapshotModel>, AppError> {
+        service
+            .take_snapshot(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_report(
+        Path(id): Path<String>,
+        Query(query): Query<ReservesReportQuery>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysReservesService>>,
+    ) -> Result<Res<ReservesReportOutput>, AppError> {
+        service
+            .get_report(&user.domain(), &id, query)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_proof(
+        Query(query): Query<ReservesProofQuery>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysReservesService>>,
+    ) -> Result<Res<ReservesProofOutput>, AppError> {
+        service
+            .get_user_proof(&user.domain(), &user.user_id(), query)
+            .await
+            .map(Res::new_data)
+    }
+}
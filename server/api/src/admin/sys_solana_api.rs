@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{error::AppError, res::Res};
+use server_service::admin::{RpcPoolMetrics, SysSolanaService, TSolanaService};
+
+pub struct SysSolanaApi;
+
+impl SysSolanaApi {
+    pub async fn get_rpc_pool_metrics(
+        Extension(service): Extension<Arc<SysSolanaService>>,
+    ) -> Result<Res<RpcPoolMetrics>, AppError> {
+        service.get_rpc_pool_metrics().await.map(Res::new_data)
+    }
+}
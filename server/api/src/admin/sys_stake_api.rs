@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    CreateStakeAccountInput, StakeAccountPageRequest, StakeRewardPageRequest,
+    StakeRewardSyncOutput, SyncStakeRewardsInput, SysStakeAccountModel, SysStakeRewardModel,
+    SysStakeService, TStakeService,
+};
+
+pub struct SysStakeApi;
+
+impl SysStakeApi {
+    pub async fn get_validators(
+        Extension(service): Extension<Arc<SysStakeService>>,
+    ) -> Result<Res<Vec<String>>, AppError> {
+        service.list_validators().await.map(Res::new_data)
+    }
+
+    pub async fn get_paginated_accounts(
+        Query(params): Query<StakeAccountPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStakeService>>,
+    ) -> Result<Res<PaginatedData<SysStakeAccountModel>>, AppError> {
+        service
+            .find_paginated_accounts(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn create_account(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStakeService>>,
+        ValidatedForm(input): ValidatedForm<CreateStakeAccountInput>,
+    ) -> Result<Res<SysStakeAccountModel>, AppError> {
+        service
+            .create_stake_account(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_account(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStakeService>>,
+    ) -> Result<Res<SysStakeAccountModel>, AppError> {
+        service
+            .get_stake_account(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn deactivate_account(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStakeService>>,
+    ) -> Result<Res<SysStakeAccountModel>, AppError> {
+        service
+            .deactivate_stake_account(&user.domain(), &id, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn withdraw_account(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStakeService>>,
+    ) -> Result<Res<SysStakeAccountModel>, AppError> {
+        service
+            .withdraw_stake_account(&user.domain(), &id, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_paginated_rewards(
+        Query(params): Query<StakeRewardPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStakeService>>,
+    ) -> Result<Res<PaginatedData<SysStakeRewardModel>>, AppError> {
+        service
+            .find_paginated_rewards(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn sync_rewards(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStakeService>>,
+        ValidatedForm(input): ValidatedForm<SyncStakeRewardsInput>,
+    ) -> Result<Res<StakeRewardSyncOutput>, AppError> {
+        service
+            .sync_rewards(&user.domain(), input)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_my_accounts(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStakeService>>,
+    ) -> Result<Res<Vec<SysStakeAccountModel>>, AppError> {
+        service
+            .find_user_accounts(&user.domain(), &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_my_rewards(
+        Query(mut params): Query<StakeRewardPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStakeService>>,
+    ) -> Result<Res<PaginatedData<SysStakeRewardModel>>, AppError> {
+        params.user_id = Some(user.user_id());
+        service
+            .find_paginated_rewards(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+}
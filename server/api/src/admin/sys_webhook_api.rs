@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    CreateWebhookEndpointInput, SysWebhookDeliveryModel, SysWebhookEndpointModel,
+    SysWebhookService, TWebhookService, UpdateWebhookEndpointInput, WebhookDeliveryPageRequest,
+    WebhookEndpointOutput, WebhookEndpointPageRequest,
+};
+
+pub struct SysWebhookApi;
+
+impl SysWebhookApi {
+    pub async fn get_paginated_endpoints(
+        Query(params): Query<WebhookEndpointPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWebhookService>>,
+    ) -> Result<Res<PaginatedData<SysWebhookEndpointModel>>, AppError> {
+        service
+            .find_paginated_endpoints(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn create_endpoint(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWebhookService>>,
+        ValidatedForm(input): ValidatedForm<CreateWebhookEndpointInput>,
+    ) -> Result<Res<WebhookEndpointOutput>, AppError> {
+        service
+            .create_endpoint(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn update_endpoint(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWebhookService>>,
+        ValidatedForm(input): ValidatedForm<UpdateWebhookEndpointInput>,
+    ) -> Result<Res<WebhookEndpointOutput>, AppError> {
+        service
+            .update_endpoint(&user.domain(), &id, input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn delete_endpoint(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWebhookService>>,
+    ) -> Result<Res<()>, AppError> {
+        service
+            .delete_endpoint(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_paginated_deliveries(
+        Query(params): Query<WebhookDeliveryPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWebhookService>>,
+    ) -> Result<Res<PaginatedData<SysWebhookDeliveryModel>>, AppError> {
+        service
+            .find_paginated_deliveries(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_delivery(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWebhookService>>,
+    ) -> Result<Res<SysWebhookDeliveryModel>, AppError> {
+        service
+            .get_delivery(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn replay_delivery(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWebhookService>>,
+    ) -> Result<Res<SysWebhookDeliveryModel>, AppError> {
+        service
+            .replay_delivery(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    CreateWithdrawalFeeInput, SysWithdrawalFeeModel, SysWithdrawalFeeRecordModel,
+    SysWithdrawalFeeService, TWithdrawalFeeService, UpdateWithdrawalFeeInput, WithdrawInput,
+    WithdrawalFeeOutput, WithdrawalFeePageRequest, WithdrawalFeeQuoteOutput,
+    WithdrawalFeeQuoteQuery, WithdrawalFeeRecordPageRequest,
+};
+
+pub struct SysWithdrawalFeeApi;
+
+impl SysWithdrawalFeeApi {
+    pub async fn get_paginated_fees(
+        Query(params): Query<WithdrawalFeePageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWithdrawalFeeService>>,
+    ) -> Result<Res<PaginatedData<SysWithdrawalFeeModel>>, AppError> {
+        service
+            .find_paginated_fees(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn create_fee(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWithdrawalFeeService>>,
+        ValidatedForm(input): ValidatedForm<CreateWithdrawalFeeInput>,
+    ) -> Result<Res<WithdrawalFeeOutput>, AppError> {
+        service
+            .create_fee(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_fee(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWithdrawalFeeService>>,
+    ) -> Result<Res<WithdrawalFeeOutput>, AppError> {
+        service
+            .get_fee(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn update_fee(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWithdrawalFeeService>>,
+        ValidatedForm(input): ValidatedForm<UpdateWithdrawalFeeInput>,
+    ) -> Result<Res<WithdrawalFeeOutput>, AppError> {
+        service
+            .update_fee(&user.domain(), input, &user.user_id())
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn delete_fee(
+        Path(id): Path<String>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWithdrawalFeeService>>,
+    ) -> Result<Res<()>, AppError> {
+        service
+            .delete_fee(&user.domain(), &id)
+            .await
+            .map(Res::new_data)
+    }
+
+    /// 按当前用户的角色计算手续费，供用户确认提现前展示
+    pub async fn quote(
+        Query(query): Query<WithdrawalFeeQuoteQuery>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWithdrawalFeeService>>,
+    ) -> Result<Res<WithdrawalFeeQuoteOutput>, AppError> {
+        service
+            .quote(&user.domain(), &user.subject(), query)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn withdraw(
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWithdrawalFeeService>>,
+        ValidatedForm(input): ValidatedForm<WithdrawInput>,
+    ) -> Result<Res<SysWithdrawalFeeRecordModel>, AppError> {
+        service
+            .withdraw(
+                &user.domain(),
+                &user.user_id(),
+                &user.subject(),
+                input,
+                &user.user_id(),
+            )
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_paginated_records(
+        Query(params): Query<WithdrawalFeeRecordPageRequest>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysWithdrawalFeeService>>,
+    ) -> Result<Res<PaginatedData<SysWithdrawalFeeRecordModel>>, AppError> {
+        service
+            .find_paginated_records(&user.domain(), params)
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -25,6 +25,9 @@ async fn main() {
     server_initialize::init_redis_pools().await;
     server_initialize::init_primary_mongo().await;
     server_initialize::init_mongo_pools().await;
+    server_initialize::init_primary_solana().await;
+    server_initialize::init_solana_pools().await;
+    server_initialize::restore_system_keypair().await;
 
     // build our application with a route
     let app = server_initialize::initialize_admin_router().await;
@@ -32,6 +35,10 @@ async fn main() {
 
     //需要初始化验证器init_validators之后才能初始化访问密钥
     server_initialize::initialize_access_key().await;
+    server_initialize::initialize_payment_invoice_checker().await;
+    server_initialize::initialize_webhook_dispatcher().await;
+    server_initialize::initialize_outbox_relay().await;
+    server_initialize::initialize_scheduler().await;
 
     let addr = match server_initialize::get_server_address().await {
         Ok(addr) => addr,
@@ -8,8 +8,8 @@ use crate::{
     model::{Config, OptionalConfigs},
     multi_instance_env::MultiInstanceEnvProcessor,
     project_error, project_info, DatabaseConfig, DatabasesInstancesConfig, JwtConfig, MongoConfig,
-    MongoInstancesConfig, RedisConfig, RedisInstancesConfig, S3Config, S3InstancesConfig,
-    ServerConfig,
+    MongoInstancesConfig, OutboxConfig, RedisConfig, RedisInstancesConfig, RiskConfig, S3Config,
+    S3InstancesConfig, ServerConfig, SolanaConfig, SolanaInstancesConfig,
 };
 
 #[derive(Debug, Error)]
@@ -82,6 +82,20 @@ pub async fn init_from_file(file_path: &str) -> Result<(), ConfigError> {
     }
     global::init_config::<OptionalConfigs<S3InstancesConfig>>(config.s3_instances.into()).await;
 
+    if let Some(solana_config) = config.solana {
+        global::init_config::<SolanaConfig>(solana_config).await;
+    }
+    global::init_config::<OptionalConfigs<SolanaInstancesConfig>>(config.solana_instances.into())
+        .await;
+
+    if let Some(outbox_config) = config.outbox {
+        global::init_config::<OutboxConfig>(outbox_config).await;
+    }
+
+    if let Some(risk_config) = config.risk {
+        global::init_config::<RiskConfig>(risk_config).await;
+    }
+
     project_info!("Configuration initialized successfully");
     Ok(())
 }
@@ -260,6 +274,19 @@ pub async fn init_from_file_with_multi_instance_env(
             ));
         }
 
+        // 合并 Solana 实例配置
+        let env_solana_instances = multi_processor.parse_solana_instances();
+        if !env_solana_instances.is_empty() {
+            project_info!(
+                "Merging {} Solana instances from environment variables",
+                env_solana_instances.len()
+            );
+            config.solana_instances = Some(merge_solana_instances(
+                config.solana_instances.unwrap_or_default(),
+                env_solana_instances,
+            ));
+        }
+
         // 调试输出
         multi_processor.debug_print_instances();
     }
@@ -392,6 +419,35 @@ fn merge_s3_instances(
     result
 }
 
+/// 合并 Solana 实例配置（环境变量优先）
+fn merge_solana_instances(
+    file_instances: Vec<SolanaInstancesConfig>,
+    env_instances: Vec<SolanaInstancesConfig>,
+) -> Vec<SolanaInstancesConfig> {
+    let mut result = file_instances;
+
+    for env_instance in env_instances {
+        if let Some(pos) = result
+            .iter()
+            .position(|item| item.name == env_instance.name)
+        {
+            project_info!(
+                "Overriding Solana instance '{}' with environment variable",
+                env_instance.name
+            );
+            result[pos] = env_instance;
+        } else {
+            project_info!(
+                "Adding new Solana instance '{}' from environment variable",
+                env_instance.name
+            );
+            result.push(env_instance);
+        }
+    }
+
+    result
+}
+
 /// 初始化全局配置状态
 ///
 /// 将配置注入到全局状态管理器中，供应用程序其他部分使用
@@ -423,6 +479,20 @@ async fn init_global_config(config: Config) {
         global::init_config::<S3Config>(s3_config).await;
     }
     global::init_config::<OptionalConfigs<S3InstancesConfig>>(config.s3_instances.into()).await;
+
+    if let Some(solana_config) = config.solana {
+        global::init_config::<SolanaConfig>(solana_config).await;
+    }
+    global::init_config::<OptionalConfigs<SolanaInstancesConfig>>(config.solana_instances.into())
+        .await;
+
+    if let Some(outbox_config) = config.outbox {
+        global::init_config::<OutboxConfig>(outbox_config).await;
+    }
+
+    if let Some(risk_config) = config.risk {
+        global::init_config::<RiskConfig>(risk_config).await;
+    }
 }
 
 #[cfg(test)]
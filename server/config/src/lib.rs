@@ -5,8 +5,8 @@ pub use config_init::{
 pub use env_config::{load_config_from_env, load_config_with_env, EnvConfigLoader};
 pub use model::{
     Config, DatabaseConfig, DatabasesInstancesConfig, JwtConfig, MongoConfig, MongoInstancesConfig,
-    OptionalConfigs, RedisConfig, RedisInstancesConfig, RedisMode, S3Config, S3InstancesConfig,
-    ServerConfig,
+    OptionalConfigs, OutboxConfig, RedisConfig, RedisInstancesConfig, RedisMode, RiskConfig,
+    S3Config, S3InstancesConfig, ServerConfig, SolanaConfig, SolanaInstancesConfig,
 };
 pub use server_global::{project_error, project_info};
 
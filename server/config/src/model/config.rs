@@ -2,7 +2,8 @@ use serde::Deserialize;
 
 use super::{
     DatabaseConfig, DatabasesInstancesConfig, JwtConfig, MongoConfig, MongoInstancesConfig,
-    RedisConfig, RedisInstancesConfig, S3Config, S3InstancesConfig, ServerConfig,
+    OutboxConfig, RedisConfig, RedisInstancesConfig, RiskConfig, S3Config, S3InstancesConfig,
+    ServerConfig, SolanaConfig, SolanaInstancesConfig,
 };
 
 /// 应用程序配置结构
@@ -57,6 +58,10 @@ use super::{
 /// - `redis_instances`: 可选的 Redis 连接池配置，用于配置多个命名的 Redis 连接
 /// - `mongo`: 主 MongoDB 配置，用于配置默认的 MongoDB 连接
 /// - `mongo_instances`: 可选的 MongoDB 连接池配置，用于配置多个命名的 MongoDB 连接
+/// - `solana`: 主 Solana 配置，用于配置默认的 RPC 端点与系统钱包
+/// - `solana_instances`: 可选的 Solana 实例配置，用于按名称并列配置多个网络
+/// - `outbox`: 可选的事件发件箱配置，用于把事件同时发布到 Redis Stream
+/// - `risk`: 可选的转出风险筛查配置，用于配置黑名单文件与 HTTP 风控服务
 ///
 /// # 示例配置（YAML）
 /// ```yaml
@@ -86,6 +91,37 @@ use super::{
 ///     urls:
 ///       - "redis://:password@localhost:6379"
 ///       - "redis://:password@localhost:6380"
+///
+/// solana:
+///   rpc_url: "https://api.mainnet-beta.solana.com"
+///   ws_url: "wss://api.mainnet-beta.solana.com"
+///   network: "mainnet-beta"
+///   rpc_endpoints:
+///     - url: "https://rpc-a.example.com"
+///       weight: 3
+///       role: "read"
+///     - url: "https://rpc-b.example.com"
+///       role: "write"
+///
+/// solana_instances:
+///   - name: "devnet"
+///     solana:
+///       rpc_url: "https://api.devnet.solana.com"
+///       ws_url: "wss://api.devnet.solana.com"
+///       network: "devnet"
+///   - name: "local"
+///     solana:
+///       rpc_url: "http://127.0.0.1:8899"
+///       ws_url: "ws://127.0.0.1:8900"
+///       network: "localhost"
+///
+/// outbox:
+///   redis_stream_enabled: true
+///   redis_stream_prefix: "outbox"
+///
+/// risk:
+///   deny_list_path: "resources/deny_list.txt"
+///   provider_url: "https://risk.example.com/screen"
 /// ```
 #[derive(Deserialize, Debug, Clone)]
 pub struct Config {
@@ -122,4 +158,17 @@ pub struct Config {
     /// 可选的 S3 连接池配置
     /// 用于配置多个命名的 S3 连接
     pub s3_instances: Option<Vec<S3InstancesConfig>>,
+
+    /// 主 Solana 配置
+    pub solana: Option<SolanaConfig>,
+
+    /// 可选的 Solana 实例配置
+    /// 用于按名称配置多个 Solana 网络
+    pub solana_instances: Option<Vec<SolanaInstancesConfig>>,
+
+    /// 事件发件箱配置
+    pub outbox: Option<OutboxConfig>,
+
+    /// 转出风险筛查配置
+    pub risk: Option<RiskConfig>,
 }
@@ -2,9 +2,12 @@ pub use config::Config;
 pub use database_config::{DatabaseConfig, DatabasesInstancesConfig};
 pub use jwt_config::JwtConfig;
 pub use mongo_config::{MongoConfig, MongoInstancesConfig};
+pub use outbox_config::OutboxConfig;
 pub use redis_config::{RedisConfig, RedisInstancesConfig, RedisMode};
+pub use risk_config::RiskConfig;
 pub use s3_config::{S3Config, S3InstancesConfig};
 pub use server_config::ServerConfig;
+pub use solana_config::{SolanaConfig, SolanaInstancesConfig};
 
 /// 可选配置集合的包装类
 #[allow(dead_code)]
@@ -23,6 +26,9 @@ mod config;
 mod database_config;
 mod jwt_config;
 mod mongo_config;
+mod outbox_config;
 mod redis_config;
+mod risk_config;
 mod s3_config;
 mod server_config;
+mod solana_config;
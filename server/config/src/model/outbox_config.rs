@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+/// 事件发件箱配置
+///
+/// 支持的环境变量：
+/// - APP_OUTBOX_REDIS_STREAM_ENABLED: 是否同时发布到 Redis Stream
+/// - APP_OUTBOX_REDIS_STREAM_PREFIX: Stream 键前缀
+/// - APP_OUTBOX_REDIS_STREAM_MAX_LEN: 每个 Stream 保留的近似最大条数
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutboxConfig {
+    /// 是否在投递给进程内监听器的同时发布到主 Redis 的 Stream，每种事件一个 Stream
+    /// 环境变量: APP_OUTBOX_REDIS_STREAM_ENABLED
+    #[serde(default)]
+    pub redis_stream_enabled: bool,
+
+    /// Stream 键前缀，完整键为 `{prefix}:{event_type}`
+    /// 环境变量: APP_OUTBOX_REDIS_STREAM_PREFIX
+    #[serde(default = "default_redis_stream_prefix")]
+    pub redis_stream_prefix: String,
+
+    /// 每个 Stream 保留的近似最大条数
+    /// 环境变量: APP_OUTBOX_REDIS_STREAM_MAX_LEN
+    #[serde(default = "default_redis_stream_max_len")]
+    pub redis_stream_max_len: usize,
+}
+
+fn default_redis_stream_prefix() -> String {
+    "outbox".to_string()
+}
+
+fn default_redis_stream_max_len() -> usize {
+    100_000
+}
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+
+/// 转出风险筛查配置
+///
+/// 支持的环境变量：
+/// - APP_RISK_DENY_LIST_PATH: 本地黑名单文件路径
+/// - APP_RISK_DENY_LIST_RELOAD_SECS: 黑名单文件检查间隔（秒）
+/// - APP_RISK_PROVIDER_URL: HTTP 风控服务地址
+/// - APP_RISK_PROVIDER_API_KEY: HTTP 风控服务的 Bearer 令牌
+/// - APP_RISK_PROVIDER_TIMEOUT_SECS: HTTP 风控服务超时（秒）
+#[derive(Debug, Clone, Deserialize)]
+pub struct RiskConfig {
+    /// 本地黑名单文件路径，为空时不启用，格式见 `DenyListScreener`
+    /// 环境变量: APP_RISK_DENY_LIST_PATH
+    #[serde(default)]
+    pub deny_list_path: String,
+
+    /// 黑名单文件检查间隔（秒），文件修改后自动重新加载
+    /// 环境变量: APP_RISK_DENY_LIST_RELOAD_SECS
+    #[serde(default = "default_deny_list_reload_secs")]
+    pub deny_list_reload_secs: u64,
+
+    /// HTTP 风控服务地址，为空时不启用
+    /// 环境变量: APP_RISK_PROVIDER_URL
+    #[serde(default)]
+    pub provider_url: String,
+
+    /// HTTP 风控服务的 Bearer 令牌
+    /// 环境变量: APP_RISK_PROVIDER_API_KEY
+    #[serde(default)]
+    pub provider_api_key: String,
+
+    /// HTTP 风控服务超时（秒），超时按筛查失败处理，转出被拒绝
+    /// 环境变量: APP_RISK_PROVIDER_TIMEOUT_SECS
+    #[serde(default = "default_provider_timeout_secs")]
+    pub provider_timeout_secs: u64,
+}
+
+fn default_deny_list_reload_secs() -> u64 {
+    60
+}
+
+fn default_provider_timeout_secs() -> u64 {
+    5
+}
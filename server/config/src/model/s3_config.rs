@@ -7,6 +7,7 @@ use serde::Deserialize;
 /// - APP_S3_ACCESS_KEY_ID: S3 访问密钥ID
 /// - APP_S3_SECRET_ACCESS_KEY: S3 秘密访问密钥
 /// - APP_S3_ENDPOINT: S3 端点URL (可选)
+/// - APP_S3_BUCKET: 默认存储桶 (可选)
 #[derive(Debug, Clone, Deserialize)]
 pub struct S3Config {
     /// S3 区域
@@ -24,6 +25,11 @@ pub struct S3Config {
     /// S3 端点URL (可选，用于自定义S3兼容服务)
     /// 环境变量: APP_S3_ENDPOINT
     pub endpoint: Option<String>,
+
+    /// 默认存储桶 (可选，资产图标等服务端上传的对象存放于此)
+    /// 环境变量: APP_S3_BUCKET
+    #[serde(default)]
+    pub bucket: Option<String>,
 }
 
 /// S3 实例配置
@@ -34,6 +40,7 @@ pub struct S3Config {
 /// - APP_S3_INSTANCES_0_S3_ACCESS_KEY_ID: 第一个实例访问密钥ID
 /// - APP_S3_INSTANCES_0_S3_SECRET_ACCESS_KEY: 第一个实例秘密访问密钥
 /// - APP_S3_INSTANCES_0_S3_ENDPOINT: 第一个实例端点URL
+/// - APP_S3_INSTANCES_0_S3_BUCKET: 第一个实例默认存储桶
 /// 以此类推...
 #[derive(Debug, Clone, Deserialize)]
 pub struct S3InstancesConfig {
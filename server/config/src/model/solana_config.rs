@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+/// Solana 配置
+///
+/// 与 `sol_spl_token` 中各管理器使用的配置为同一结构，不再单独维护一份。
+///
+/// 支持的环境变量：
+/// - APP_SOLANA_RPC_URL: RPC 端点 URL
+/// - APP_SOLANA_WS_URL: WebSocket 端点 URL
+/// - APP_SOLANA_NETWORK: 网络类型
+/// - APP_SOLANA_SYSTEM_WALLET_PRIVATE_KEY: 系统钱包私钥
+/// - APP_SOLANA_KEYSTORE_PASSPHRASE: 密钥库主口令
+/// - APP_SOLANA_DEFAULT_STABLECOIN_MINT: 默认稳定币 mint 地址
+/// - APP_SOLANA_TARGET_TOKEN_MINT: 目标代币 mint 地址
+pub use sol_spl_token::SolanaConfig;
+
+/// Solana 实例配置
+///
+/// 用于按名称并列配置多个网络，如 mainnet、devnet 与本地验证节点
+///
+/// 支持的环境变量（数组形式）：
+/// - APP_SOLANA_INSTANCES_0_NAME: 第一个实例名称
+/// - APP_SOLANA_INSTANCES_0_SOLANA_RPC_URL: 第一个实例 RPC 端点
+/// - APP_SOLANA_INSTANCES_0_SOLANA_WS_URL: 第一个实例 WebSocket 端点
+/// - APP_SOLANA_INSTANCES_0_SOLANA_NETWORK: 第一个实例网络类型
+/// - APP_SOLANA_INSTANCES_0_SOLANA_SYSTEM_WALLET_PRIVATE_KEY: 第一个实例系统钱包私钥
+/// - APP_SOLANA_INSTANCES_0_SOLANA_KEYSTORE_PASSPHRASE: 第一个实例密钥库主口令
+/// - APP_SOLANA_INSTANCES_0_SOLANA_DEFAULT_STABLECOIN_MINT: 第一个实例稳定币 mint
+/// - APP_SOLANA_INSTANCES_0_SOLANA_TARGET_TOKEN_MINT: 第一个实例目标代币 mint
+/// 以此类推...
+#[derive(Debug, Clone, Deserialize)]
+pub struct SolanaInstancesConfig {
+    /// 实例名称
+    pub name: String,
+
+    /// Solana 配置
+    pub solana: SolanaConfig,
+}
@@ -32,4 +32,10 @@ pub enum SystemEvent {
     AuditOperationLoggedEvent,
     /// API密钥验证事件
     AuthApiKeyValidatedEvent,
+    /// 自动兑换任务入队事件
+    AutoConvertJobQueuedEvent,
+    /// 批量发放批次入队事件
+    PayoutBatchQueuedEvent,
+    /// Webhook 事件入队事件
+    WebhookEventQueuedEvent,
 }
@@ -33,6 +33,46 @@ impl Default for SignatureAlgorithm {
     }
 }
 
+impl SignatureAlgorithm {
+    /// Calculates the signature of a signing string with this algorithm.
+    ///
+    /// The secret is appended as `&key={secret}` before hashing, so inbound request
+    /// validation and outbound webhook signing produce the same signature for the
+    /// same input.
+    ///
+    /// # Arguments
+    /// * `signing_string` - The string to sign
+    /// * `secret` - The secret key to use for signing
+    ///
+    /// # Returns
+    /// The calculated signature as a hexadecimal string
+    pub fn sign(self, signing_string: &str, secret: &str) -> String {
+        let signing_string = format!("{}&key={}", signing_string, secret);
+        match self {
+            SignatureAlgorithm::Md5 => {
+                let mut hasher = Md5::new();
+                hasher.update(signing_string.as_bytes());
+                hex::encode(hasher.finalize())
+            },
+            SignatureAlgorithm::Sha1 => {
+                let mut context = digest::Context::new(&digest::SHA1_FOR_LEGACY_USE_ONLY);
+                context.update(signing_string.as_bytes());
+                hex::encode(context.finish())
+            },
+            SignatureAlgorithm::Sha256 => {
+                let mut context = digest::Context::new(&digest::SHA256);
+                context.update(signing_string.as_bytes());
+                hex::encode(context.finish())
+            },
+            SignatureAlgorithm::HmacSha256 => {
+                let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+                let tag = hmac::sign(&key, signing_string.as_bytes());
+                hex::encode(tag.as_ref())
+            },
+        }
+    }
+}
+
 /// Configuration for API key validation.
 ///
 /// This struct holds configuration options for the API key validation system.
@@ -188,29 +228,7 @@ impl ComplexApiKeyValidator {
     /// The calculated signature as a hexadecimal string
     #[inline]
     pub fn calculate_signature(&self, signing_string: &str, secret: &str) -> String {
-        let signing_string = format!("{}&key={}", signing_string, secret);
-        match self.config.algorithm {
-            SignatureAlgorithm::Md5 => {
-                let mut hasher = Md5::new();
-                hasher.update(signing_string.as_bytes());
-                hex::encode(hasher.finalize())
-            },
-            SignatureAlgorithm::Sha1 => {
-                let mut context = digest::Context::new(&digest::SHA1_FOR_LEGACY_USE_ONLY);
-                context.update(signing_string.as_bytes());
-                hex::encode(context.finish())
-            },
-            SignatureAlgorithm::Sha256 => {
-                let mut context = digest::Context::new(&digest::SHA256);
-                context.update(signing_string.as_bytes());
-                hex::encode(context.finish())
-            },
-            SignatureAlgorithm::HmacSha256 => {
-                let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
-                let tag = hmac::sign(&key, signing_string.as_bytes());
-                hex::encode(tag.as_ref())
-            },
-        }
+        self.config.algorithm.sign(signing_string, secret)
     }
 
     /// Validates a signed API request.
@@ -378,4 +396,23 @@ mod tests {
             handle.join().unwrap();
         }
     }
+
+    #[test]
+    fn test_algorithm_sign_matches_validator() {
+        let validator = ComplexApiKeyValidator::new(Some(ApiKeyConfig {
+            algorithm: SignatureAlgorithm::HmacSha256,
+        }));
+        let signing_string = "event=deposit.detected&timestamp=1700000000000";
+
+        let signature = SignatureAlgorithm::HmacSha256.sign(signing_string, "test-secret");
+
+        assert_eq!(
+            signature,
+            validator.calculate_signature(signing_string, "test-secret")
+        );
+        assert_ne!(
+            signature,
+            SignatureAlgorithm::HmacSha256.sign(signing_string, "other-secret")
+        );
+    }
 }
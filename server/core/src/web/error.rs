@@ -1,9 +1,13 @@
-use axum::response::{IntoResponse, Response};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
 use mongodb::error::{Error as MongoError, ErrorKind};
 use redis::RedisError;
 use sea_orm::DbErr;
+use sol_spl_token::SolanaError;
 
-use crate::web::{jwt::JwtError, res::Res};
+use crate::web::{error_code::CustodyErrorCode, jwt::JwtError, res::Res};
 
 pub trait ApiError {
     fn code(&self) -> u16;
@@ -18,7 +22,15 @@ pub struct AppError {
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        Res::<()>::new_error(self.code, self.message.as_str()).into_response()
+        let custody_code = CustodyErrorCode::from_code(self.code);
+        let res = Res::<()>::new_error(self.code, self.message.as_str())
+            .with_i18n_key(custody_code.map(CustodyErrorCode::i18n_key));
+
+        // 目录内的错误码按语义返回 HTTP 状态，其余错误沿用 200 + 业务码
+        match custody_code.and_then(|code| StatusCode::from_u16(code.http_status()).ok()) {
+            Some(status) => (status, res).into_response(),
+            None => res.into_response(),
+        }
     }
 }
 
@@ -134,3 +146,22 @@ impl From<MongoError> for AppError {
         }
     }
 }
+
+impl ApiError for SolanaError {
+    fn code(&self) -> u16 {
+        CustodyErrorCode::from(self).code()
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl From<SolanaError> for AppError {
+    fn from(err: SolanaError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! 托管错误码目录
+//!
+//! 错误码一经发布不再变更含义，前端依据 `code` 与 `i18nKey` 展示文案：
+//! - 41xx：用户可自行纠正的错误（余额不足、账户冻结、滑点等）
+//! - 51xx：链上或基础设施错误（RPC 不可用、发送失败、确认超时等）
+//!
+//! 4001-4002 与 5001 已被角色与 AccessKey 模块占用，托管错误从 4101 / 5101 起编号。
+//! 各托管业务模块的错误枚举统一映射到本目录，不再自行分配号段
+
+use serde::Serialize;
+use sol_spl_token::SolanaError;
+use utoipa::{
+    openapi::{ObjectBuilder, RefOr, Schema, Type},
+    PartialSchema, ToSchema,
+};
+
+macro_rules! custody_error_codes {
+    ($($variant:ident = $code:literal, $key:literal, $status:literal;)+) => {
+        /// 托管错误码
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+        #[serde(into = "u16")]
+        #[repr(u16)]
+        pub enum CustodyErrorCode {
+            $($variant = $code,)+
+        }
+
+        impl CustodyErrorCode {
+            /// 全部错误码
+            pub const ALL: &'static [CustodyErrorCode] = &[$(CustodyErrorCode::$variant,)+];
+
+            /// i18n 文案键
+            pub fn i18n_key(self) -> &'static str {
+                match self {
+                    $(CustodyErrorCode::$variant => $key,)+
+                }
+            }
+
+            /// 对应的 HTTP 语义状态码
+            pub fn http_status(self) -> u16 {
+                match self {
+                    $(CustodyErrorCode::$variant => $status,)+
+                }
+            }
+        }
+    };
+}
+
+custody_error_codes! {
+    InsufficientBalance = 4101, "custody.error.insufficient_balance", 400;
+    AccountNotFound = 4102, "custody.error.account_not_found", 404;
+    TokenAccountNotFound = 4103, "custody.error.token_account_not_found", 404;
+    AccountFrozen = 4104, "custody.error.account_frozen", 403;
+    SlippageExceeded = 4105, "custody.error.slippage_exceeded", 409;
+    QuoteRejected = 4106, "custody.error.quote_rejected", 422;
+    StaleQuote = 4107, "custody.error.stale_quote", 409;
+    RiskDenied = 4108, "custody.error.risk_denied", 403;
+    RiskReviewRejected = 4109, "custody.error.risk_review_rejected", 403;
+    SimulationFailed = 4110, "custody.error.simulation_failed", 422;
+    AccountOnHold = 4111, "custody.error.account_on_hold", 403;
+    IdempotencyKeyReused = 4112, "custody.error.idempotency_key_reused", 409;
+    IdempotentRequestInProgress = 4113, "custody.error.idempotent_request_in_progress", 409;
+    WalletBusy = 4114, "custody.error.wallet_busy", 409;
+    InvalidAddress = 4115, "custody.error.invalid_address", 400;
+    AmountOutOfRange = 4116, "custody.error.amount_out_of_range", 400;
+    AmountBelowFee = 4117, "custody.error.amount_below_fee", 400;
+    AssetNotFound = 4118, "custody.error.asset_not_found", 404;
+    AssetNotEnabled = 4119, "custody.error.asset_not_enabled", 422;
+    DuplicateAsset = 4120, "custody.error.duplicate_asset", 409;
+    InvalidWithdrawalLimits = 4121, "custody.error.invalid_withdrawal_limits", 400;
+    UnsupportedIconType = 4122, "custody.error.unsupported_icon_type", 415;
+    IconTooLarge = 4123, "custody.error.icon_too_large", 413;
+    IconNotFound = 4124, "custody.error.icon_not_found", 404;
+    PolicyNotFound = 4125, "custody.error.policy_not_found", 404;
+    PolicyDisabled = 4126, "custody.error.policy_disabled", 409;
+    InvalidPolicy = 4127, "custody.error.invalid_policy", 400;
+    ConvertJobNotFound = 4128, "custody.error.convert_job_not_found", 404;
+    ConvertJobNotRetryable = 4129, "custody.error.convert_job_not_retryable", 409;
+    DuplicateDeposit = 4130, "custody.error.duplicate_deposit", 409;
+    HoldNotFound = 4131, "custody.error.hold_not_found", 404;
+    UserNotFound = 4132, "custody.error.user_not_found", 404;
+    HoldAlreadyReleased = 4133, "custody.error.hold_already_released", 409;
+    HoldManagedByUserStatus = 4134, "custody.error.hold_managed_by_user_status", 409;
+    RotationNotFound = 4135, "custody.error.rotation_not_found", 404;
+    WalletNotFound = 4136, "custody.error.wallet_not_found", 404;
+    InvalidPrivateKey = 4137, "custody.error.invalid_private_key", 400;
+    KeyMismatch = 4138, "custody.error.key_mismatch", 400;
+    ReplacementWalletNotFound = 4139, "custody.error.replacement_wallet_not_found", 404;
+    KeyExportNotFound = 4140, "custody.error.key_export_not_found", 404;
+    KeyExportNotAllowed = 4141, "custody.error.key_export_not_allowed", 403;
+    KeyNotInKeystore = 4142, "custody.error.key_not_in_keystore", 409;
+    KeyExportAlreadyPending = 4143, "custody.error.key_export_already_pending", 409;
+    KeyExportNotPending = 4144, "custody.error.key_export_not_pending", 409;
+    KeyExportCoolingPeriod = 4145, "custody.error.key_export_cooling_period", 409;
+    KeyExportExpired = 4146, "custody.error.key_export_expired", 410;
+    InvalidDownloadToken = 4147, "custody.error.invalid_download_token", 401;
+    ReauthenticationFailed = 4148, "custody.error.reauthentication_failed", 401;
+    LookupTableNotFound = 4149, "custody.error.lookup_table_not_found", 404;
+    LookupTableDisabled = 4150, "custody.error.lookup_table_disabled", 409;
+    MintOperationNotFound = 4151, "custody.error.mint_operation_not_found", 404;
+    InvalidMintOperation = 4152, "custody.error.invalid_mint_operation", 400;
+    InvalidState = 4153, "custody.error.invalid_state", 409;
+    SelfApproval = 4154, "custody.error.self_approval", 403;
+    AlreadyReviewed = 4155, "custody.error.already_reviewed", 409;
+    InvoiceNotFound = 4156, "custody.error.invoice_not_found", 404;
+    PayoutBatchNotFound = 4157, "custody.error.payout_batch_not_found", 404;
+    InvalidPayoutFile = 4158, "custody.error.invalid_payout_file", 400;
+    PayoutHasInvalidRows = 4159, "custody.error.payout_has_invalid_rows", 422;
+    PayoutReportNotFound = 4160, "custody.error.payout_report_not_found", 404;
+    StakeAccountNotFound = 4161, "custody.error.stake_account_not_found", 404;
+    ValidatorNotConfigured = 4162, "custody.error.validator_not_configured", 400;
+    WebhookEndpointNotFound = 4163, "custody.error.webhook_endpoint_not_found", 404;
+    WebhookDeliveryNotFound = 4164, "custody.error.webhook_delivery_not_found", 404;
+    WebhookDeliveryInFlight = 4165, "custody.error.webhook_delivery_in_flight", 409;
+    WithdrawalFeeNotFound = 4166, "custody.error.withdrawal_fee_not_found", 404;
+    DuplicateWithdrawalFee = 4167, "custody.error.duplicate_withdrawal_fee", 409;
+    InvalidFeeSchedule = 4168, "custody.error.invalid_fee_schedule", 400;
+    WithdrawalDisabled = 4169, "custody.error.withdrawal_disabled", 403;
+    ReservesSnapshotNotFound = 4170, "custody.error.reserves_snapshot_not_found", 404;
+    ReservesProofNotFound = 4171, "custody.error.reserves_proof_not_found", 404;
+    NoCustodyWallets = 4172, "custody.error.no_custody_wallets", 409;
+    InvalidReportFormat = 4173, "custody.error.invalid_report_format", 400;
+    KeyExported = 4174, "custody.error.key_exported", 409;
+    BalanceNotSettled = 4175, "custody.error.balance_not_settled", 409;
+    RpcUnavailable = 5101, "custody.error.rpc_unavailable", 503;
+    SendFailed = 5102, "custody.error.send_failed", 502;
+    ConfirmationTimeout = 5103, "custody.error.confirmation_timeout", 504;
+    BlockhashNotFound = 5104, "custody.error.blockhash_not_found", 503;
+    RiskScreeningUnavailable = 5105, "custody.error.risk_screening_unavailable", 503;
+    Misconfigured = 5106, "custody.error.misconfigured", 500;
+    SigningFailed = 5107, "custody.error.signing_failed", 500;
+    LockUnavailable = 5108, "custody.error.lock_unavailable", 503;
+    StorageNotConfigured = 5109, "custody.error.storage_not_configured", 503;
+    StorageFailed = 5110, "custody.error.storage_failed", 502;
+    AmountOverflow = 5111, "custody.error.amount_overflow", 500;
+    LiabilityRootMismatch = 5112, "custody.error.liability_root_mismatch", 500;
+    ChainError = 5199, "custody.error.chain_error", 500;
+}
+
+impl CustodyErrorCode {
+    /// 数值错误码
+    pub fn code(self) -> u16 {
+        self as u16
+    }
+
+    /// 是否为用户可自行纠正的错误
+    pub fn is_user_correctable(self) -> bool {
+        self.code() < 5000
+    }
+
+    /// 交易可能已经广播、成败未知的错误，与 [`SolanaError::is_outcome_unknown`] 对应
+    pub fn is_outcome_unknown(self) -> bool {
+        matches!(self, Self::SendFailed | Self::ConfirmationTimeout)
+    }
+
+    /// 按数值查找错误码
+    pub fn from_code(code: u16) -> Option<Self> {
+        Self::ALL.iter().copied().find(|c| c.code() == code)
+    }
+}
+
+impl From<CustodyErrorCode> for u16 {
+    fn from(code: CustodyErrorCode) -> Self {
+        code.code()
+    }
+}
+
+impl From<&SolanaError> for CustodyErrorCode {
+    fn from(err: &SolanaError) -> Self {
+        match err {
+            SolanaError::InsufficientBalance(_) => Self::InsufficientBalance,
+            SolanaError::AccountNotFound(_) => Self::AccountNotFound,
+            SolanaError::TokenAccountNotFound(_) => Self::TokenAccountNotFound,
+            SolanaError::AccountFrozen(_) => Self::AccountFrozen,
+            SolanaError::AccountOnHold(_) => Self::AccountOnHold,
+            SolanaError::WalletBusy(_) => Self::WalletBusy,
+            SolanaError::SlippageExceeded(_) => Self::SlippageExceeded,
+            SolanaError::QuoteRejected(_) => Self::QuoteRejected,
+            SolanaError::StaleQuote(_) => Self::StaleQuote,
+            SolanaError::RiskDenied(_) => Self::RiskDenied,
+            SolanaError::RiskReviewRejected(_) => Self::RiskReviewRejected,
+            SolanaError::SimulationFailed { .. } => Self::SimulationFailed,
+            SolanaError::RpcError(_) => Self::RpcUnavailable,
+            SolanaError::SendError(_) => Self::SendFailed,
+            SolanaError::ConfirmationError(_) => Self::ConfirmationTimeout,
+            SolanaError::BlockhashNotFound(_) => Self::BlockhashNotFound,
+            SolanaError::RiskScreeningError(_) => Self::RiskScreeningUnavailable,
+            SolanaError::ConfigError(_) | SolanaError::KeystoreError(_) => Self::Misconfigured,
+            SolanaError::SignError(_) => Self::SigningFailed,
+            SolanaError::LockError(_) => Self::LockUnavailable,
+            _ => Self::ChainError,
+        }
+    }
+}
+
+impl PartialSchema for CustodyErrorCode {
+    fn schema() -> RefOr<Schema> {
+        let description = Self::ALL
+            .iter()
+            .map(|c| format!("- `{}` {:?} (`{}`)", c.code(), c, c.i18n_key()))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .enum_values(Some(Self::ALL.iter().map(|c| c.code())))
+            .description(Some(format!(
+                "托管错误码，41xx 为用户可纠正错误，51xx 为链上或基础设施错误\n\n{}",
+                description
+            )))
+            .into()
+    }
+}
+
+impl ToSchema for CustodyErrorCode {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_are_unique_and_in_range() {
+        for (i, code) in CustodyErrorCode::ALL.iter().enumerate() {
+            assert!((4100..4200).contains(&code.code()) || (5100..5200).contains(&code.code()));
+            assert!(CustodyErrorCode::ALL[i + 1..]
+                .iter()
+                .all(|other| other.code() != code.code() && other.i18n_key() != code.i18n_key()));
+        }
+    }
+
+    #[test]
+    fn test_solana_error_mapping() {
+        let code = CustodyErrorCode::from(&SolanaError::AccountFrozen("frozen".to_string()));
+
+        assert_eq!(code.code(), 4104);
+        assert_eq!(code.http_status(), 403);
+        assert!(code.is_user_correctable());
+        assert_eq!(CustodyErrorCode::from_code(4104), Some(code));
+        assert!(
+            !CustodyErrorCode::from(&SolanaError::RpcError("down".to_string()))
+                .is_user_correctable()
+        );
+    }
+
+    #[test]
+    fn test_outcome_unknown_matches_solana_error() {
+        let errors = [
+            SolanaError::SendError("timeout".to_string()),
+            SolanaError::ConfirmationError("timeout".to_string()),
+            SolanaError::RpcError("down".to_string()),
+            SolanaError::BlockhashNotFound("expired".to_string()),
+        ];
+        for error in errors {
+            assert_eq!(
+                CustodyErrorCode::from(&error).is_outcome_unknown(),
+                error.is_outcome_unknown()
+            );
+        }
+    }
+}
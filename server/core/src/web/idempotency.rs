@@ -0,0 +1,532 @@
+//! `Idempotency-Key` 中间件
+//!
+//! 带 `Idempotency-Key` 请求头的 POST 请求只执行一次：首次请求记录请求指纹并在
+//! 处理完成后保存响应，之后同一用户以同一个键重试时直接返回保存的响应。
+//! 请求体不同则返回冲突，首次请求尚未完成时也返回冲突。
+//! 只保存确定的结果；RPC 不可用、数据库异常等暂时性错误会释放幂等键，重试时重新执行。
+//!
+//! 已初始化主 Redis 时记录保存在 Redis，多实例间共享；否则退回进程内缓存
+
+use std::{
+    convert::Infallible,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use axum::{
+    body::{to_bytes, Body, Bytes},
+    extract::Request,
+    response::{IntoResponse, Response},
+};
+use futures::future::BoxFuture;
+use http::{header, HeaderValue, Method, StatusCode};
+use moka::sync::Cache;
+use ring::digest;
+use serde::{Deserialize, Serialize};
+use server_global::global::GLOBAL_PRIMARY_REDIS;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use super::{auth::User, error::AppError, error_code::CustodyErrorCode};
+
+/// 幂等键请求头
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// 重放的响应附带的标记头
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// 已完成请求的响应默认保存时间
+const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// 处理中的占位记录在 Redis 中的保存时间，实例在处理中退出时到期后可以重试
+const PROCESSING_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// 幂等键最大长度
+const MAX_KEY_LEN: usize = 255;
+
+/// 参与指纹计算的请求体最大长度
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+const REDIS_KEY_PREFIX: &str = "idempotency";
+
+/// 保存的响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StoredResponse {
+    status: u16,
+    content_type: Option<String>,
+    body: String,
+}
+
+/// 响应体中的业务码，业务错误可能以 HTTP 200 返回
+#[derive(Deserialize)]
+struct ResponseCode {
+    code: u16,
+}
+
+/// 幂等记录，`response` 为空表示首次请求仍在处理
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IdempotencyRecord {
+    fingerprint: String,
+    response: Option<StoredResponse>,
+}
+
+/// 幂等中间件
+///
+/// 按路由挂载在会转出资金的接口上，需位于鉴权中间件之内，以便按用户区分幂等键
+#[derive(Clone)]
+pub struct IdempotencyLayer {
+    ttl: Duration,
+    memory: Arc<Cache<String, IdempotencyRecord>>,
+}
+
+impl IdempotencyLayer {
+    /// 创建中间件，`ttl` 为已完成请求的响应保存时间
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            memory: Arc::new(Cache::builder().time_to_live(ttl).build()),
+        }
+    }
+}
+
+impl Default for IdempotencyLayer {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL)
+    }
+}
+
+impl<S> Layer<S> for IdempotencyLayer {
+    type Service = IdempotencyMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        IdempotencyMiddleware {
+            inner,
+            ttl: self.ttl,
+            memory: self.memory.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct IdempotencyMiddleware<S> {
+    inner: S,
+    ttl: Duration,
+    memory: Arc<Cache<String, IdempotencyRecord>>,
+}
+
+impl<S> Service<Request<Body>> for IdempotencyMiddleware<S>
+where
+    S: Service<Request<Body>, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+    type Response = Response;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        // 保证被调用的是已就绪的服务
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let key = match req.headers().get(IDEMPOTENCY_KEY_HEADER) {
+            Some(key) if req.method() == Method::POST => key.to_str().map(str::to_owned),
+            _ => return Box::pin(async move { inner.call(req).await }),
+        };
+
+        let store = IdempotencyStore {
+            ttl: self.ttl,
+            memory: self.memory.clone(),
+        };
+        Box::pin(async move {
+            let key = match key {
+                Ok(key) if !key.is_empty() && key.len() <= MAX_KEY_LEN => key,
+                _ => return Ok(invalid_key()),
+            };
+
+            let (parts, body) = req.into_parts();
+            let bytes = match to_bytes(body, MAX_BODY_SIZE).await {
+                Ok(bytes) => bytes,
+                Err(_) => return Ok(body_too_large()),
+            };
+
+            let scope = parts
+                .extensions
+                .get::<User>()
+                .map(|user| format!("{}:{}", user.domain(), user.user_id()))
+                .unwrap_or_else(|| "anonymous".to_string());
+            let storage_key = format!(
+                "{}:{}:{}:{}:{}",
+                REDIS_KEY_PREFIX,
+                scope,
+                parts.method,
+                parts.uri.path(),
+                key
+            );
+            let fingerprint = fingerprint(&parts.method, &parts.uri, &bytes);
+
+            match store.reserve(&storage_key, &fingerprint).await {
+                Ok(None) => {},
+                Ok(Some(existing)) => return Ok(replay(existing, &fingerprint)),
+                Err(e) => return Ok(e.into_response()),
+            }
+
+            // 在独立任务中执行，客户端断开连接时请求仍会完成并保存结果
+            let req = Request::from_parts(parts, Body::from(bytes));
+            let handle = tokio::spawn(async move {
+                let response = inner.call(req).await.into_response();
+                let (parts, body) = response.into_parts();
+                let body = to_bytes(body, usize::MAX).await.unwrap_or_default();
+                let stored = StoredResponse {
+                    status: parts.status.as_u16(),
+                    content_type: parts
+                        .headers
+                        .get(header::CONTENT_TYPE)
+                        .and_then(|value| value.to_str().ok())
+                        .map(str::to_owned),
+                    body: String::from_utf8_lossy(&body).into_owned(),
+                };
+                let result = if is_definitive(&stored) {
+                    let record = IdempotencyRecord {
+                        fingerprint,
+                        response: Some(stored),
+                    };
+                    store.save(&storage_key, &record).await
+                } else {
+                    store.release(&storage_key).await
+                };
+                if let Err(e) = result {
+                    tracing::error!(
+                        "Failed to save idempotent response for {}: {}",
+                        storage_key,
+                        e.message
+                    );
+                }
+                Response::from_parts(parts, Body::from(body))
+            });
+
+            match handle.await {
+                Ok(response) => Ok(response),
+                Err(e) => {
+                    tracing::error!("Idempotent request failed: {}", e);
+                    Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response())
+                },
+            }
+        })
+    }
+}
+
+/// 请求指纹：方法、路径与查询参数、请求体的 SHA-256
+fn fingerprint(method: &Method, uri: &http::Uri, body: &Bytes) -> String {
+    let mut context = digest::Context::new(&digest::SHA256);
+    context.update(method.as_str().as_bytes());
+    context.update(b"\n");
+    context.update(
+        uri.path_and_query()
+            .map(|path| path.as_str())
+            .unwrap_or_default()
+            .as_bytes(),
+    );
+    context.update(b"\n");
+    context.update(body);
+    hex::encode(context.finish())
+}
+
+/// 结果是否确定，确定的结果才保存供重放
+///
+/// 按响应体中的业务码判断，无法解析时按 HTTP 状态判断。暂时性错误（5xx、账户忙）重试可能成功；
+/// 发送失败、确认超时等成败未知的错误交易可能已上链，仍保存结果，避免重试再次转出
+fn is_definitive(stored: &StoredResponse) -> bool {
+    let code = serde_json::from_str::<ResponseCode>(&stored.body)
+        .map(|response| response.code)
+        .unwrap_or(stored.status);
+    match CustodyErrorCode::from_code(code) {
+        Some(code) if code.is_outcome_unknown() => true,
+        Some(CustodyErrorCode::WalletBusy) => false,
+        Some(code) => code.http_status() < 500,
+        None => {
+            code < 500
+                && code != StatusCode::REQUEST_TIMEOUT.as_u16()
+                && code != StatusCode::TOO_MANY_REQUESTS.as_u16()
+        },
+    }
+}
+
+fn error_response(code: CustodyErrorCode, message: &str) -> Response {
+    AppError {
+        code: code.code(),
+        message: message.to_string(),
+    }
+    .into_response()
+}
+
+fn invalid_key() -> Response {
+    AppError {
+        code: StatusCode::BAD_REQUEST.as_u16(),
+        message: format!(
+            "Idempotency-Key must be 1 to {} visible ASCII characters",
+            MAX_KEY_LEN
+        ),
+    }
+    .into_response()
+}
+
+fn body_too_large() -> Response {
+    AppError {
+        code: StatusCode::PAYLOAD_TOO_LARGE.as_u16(),
+        message: "Request body is too large for an idempotent request".to_string(),
+    }
+    .into_response()
+}
+
+/// 对已存在的记录作出响应
+fn replay(existing: IdempotencyRecord, fingerprint: &str) -> Response {
+    if existing.fingerprint != fingerprint {
+        return error_response(
+            CustodyErrorCode::IdempotencyKeyReused,
+            "Idempotency-Key was already used with a different request",
+        );
+    }
+
+    let Some(stored) = existing.response else {
+        return error_response(
+            CustodyErrorCode::IdempotentRequestInProgress,
+            "A request with this Idempotency-Key is still being processed",
+        );
+    };
+
+    let mut response = Response::new(Body::from(stored.body));
+    *response.status_mut() = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    if let Some(value) = stored
+        .content_type
+        .and_then(|content_type| HeaderValue::from_str(&content_type).ok())
+    {
+        response.headers_mut().insert(header::CONTENT_TYPE, value);
+    }
+    response
+        .headers_mut()
+        .insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+/// 幂等记录存储，主 Redis 未初始化时使用进程内缓存
+struct IdempotencyStore {
+    ttl: Duration,
+    memory: Arc<Cache<String, IdempotencyRecord>>,
+}
+
+impl IdempotencyStore {
+    /// 占用幂等键，已被占用时返回已有记录
+    async fn reserve(
+        &self,
+        key: &str,
+        fingerprint: &str,
+    ) -> Result<Option<IdempotencyRecord>, AppError> {
+        let record = IdempotencyRecord {
+            fingerprint: fingerprint.to_string(),
+            response: None,
+        };
+
+        let Some(redis) = GLOBAL_PRIMARY_REDIS.read().await.clone() else {
+            let entry = self.memory.entry(key.to_string()).or_insert(record);
+            return Ok((!entry.is_fresh()).then(|| entry.into_value()));
+        };
+
+        let value = serde_json::to_string(&record).unwrap_or_default();
+        let mut set = redis::cmd("SET");
+        set.arg(key)
+            .arg(value)
+            .arg("NX")
+            .arg("EX")
+            .arg(PROCESSING_TTL.as_secs());
+        let reserved: Option<String> = redis.query(&set).await.map_err(AppError::from)?;
+        if reserved.is_some() {
+            return Ok(None);
+        }
+
+        let mut get = redis::cmd("GET");
+        get.arg(key);
+        let existing: Option<String> = redis.query(&get).await.map_err(AppError::from)?;
+        match existing.and_then(|value| serde_json::from_str(&value).ok()) {
+            Some(existing) => Ok(Some(existing)),
+            // 占位记录恰好过期，按处理中处理，由客户端稍后重试
+            None => Ok(Some(record)),
+        }
+    }
+
+    /// 保存处理结果
+    async fn save(&self, key: &str, record: &IdempotencyRecord) -> Result<(), AppError> {
+        let Some(redis) = GLOBAL_PRIMARY_REDIS.read().await.clone() else {
+            self.memory.insert(key.to_string(), record.clone());
+            return Ok(());
+        };
+
+        let value = serde_json::to_string(record).unwrap_or_default();
+        let mut set = redis::cmd("SET");
+        set.arg(key).arg(value).arg("EX").arg(self.ttl.as_secs());
+        redis.query::<()>(&set).await.map_err(AppError::from)
+    }
+
+    /// 释放幂等键，之后同一个键的请求重新执行
+    async fn release(&self, key: &str) -> Result<(), AppError> {
+        let Some(redis) = GLOBAL_PRIMARY_REDIS.read().await.clone() else {
+            self.memory.invalidate(key);
+            return Ok(());
+        };
+
+        let mut del = redis::cmd("DEL");
+        del.arg(key);
+        redis.query::<()>(&del).await.map_err(AppError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use axum::{routing::post, Router};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn request(key: &str, body: &'static str) -> Request<Body> {
+        Request::builder()
+            .method(Method::POST)
+            .uri("/withdraw")
+            .header(IDEMPOTENCY_KEY_HEADER, key)
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    async fn body_string(response: Response) -> String {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_replays_response_and_rejects_different_body() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let app = Router::new().route(
+            "/withdraw",
+            post(move || {
+                let counter = counter.clone();
+                async move { format!("call {}", counter.fetch_add(1, Ordering::SeqCst)) }
+            })
+            .layer(IdempotencyLayer::default()),
+        );
+
+        let first = app
+            .clone()
+            .oneshot(request("key-1", "amount=1"))
+            .await
+            .unwrap();
+        assert_eq!(body_string(first).await, "call 0");
+
+        let replayed = app
+            .clone()
+            .oneshot(request("key-1", "amount=1"))
+            .await
+            .unwrap();
+        assert_eq!(
+            replayed.headers().get(IDEMPOTENT_REPLAYED_HEADER).unwrap(),
+            "true"
+        );
+        assert_eq!(body_string(replayed).await, "call 0");
+
+        let conflict = app
+            .clone()
+            .oneshot(request("key-1", "amount=2"))
+            .await
+            .unwrap();
+        assert!(body_string(conflict)
+            .await
+            .contains(&CustodyErrorCode::IdempotencyKeyReused.code().to_string()));
+
+        let other = app.oneshot(request("key-2", "amount=1")).await.unwrap();
+        assert_eq!(body_string(other).await, "call 1");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_transient_error_is_not_replayed() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let app = Router::new().route(
+            "/withdraw",
+            post(move || {
+                let counter = counter.clone();
+                async move {
+                    // 首次返回 HTTP 200 + 业务码 500，之后返回确定的余额不足
+                    let code = match counter.fetch_add(1, Ordering::SeqCst) {
+                        0 => 500,
+                        _ => CustodyErrorCode::InsufficientBalance.code(),
+                    };
+                    AppError {
+                        code,
+                        message: "failed".to_string(),
+                    }
+                }
+            })
+            .layer(IdempotencyLayer::default()),
+        );
+
+        let mut replayed = Vec::new();
+        for _ in 0..3 {
+            let response = app
+                .clone()
+                .oneshot(request("key-1", "amount=1"))
+                .await
+                .unwrap();
+            replayed.push(response.headers().contains_key(IDEMPOTENT_REPLAYED_HEADER));
+        }
+        assert_eq!(replayed, vec![false, false, true]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_outcome_unknown_error_is_replayed() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let app = Router::new().route(
+            "/withdraw",
+            post(move || {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    AppError {
+                        code: CustodyErrorCode::SendFailed.code(),
+                        message: "send failed".to_string(),
+                    }
+                }
+            })
+            .layer(IdempotencyLayer::default()),
+        );
+
+        let first = app
+            .clone()
+            .oneshot(request("key-1", "amount=1"))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::BAD_GATEWAY);
+
+        // 交易可能已广播，重试不得再次执行
+        let replayed = app
+            .clone()
+            .oneshot(request("key-1", "amount=1"))
+            .await
+            .unwrap();
+        assert_eq!(replayed.status(), StatusCode::BAD_GATEWAY);
+        assert_eq!(
+            replayed.headers().get(IDEMPOTENT_REPLAYED_HEADER).unwrap(),
+            "true"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
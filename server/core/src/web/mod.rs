@@ -1,12 +1,15 @@
 pub mod auth;
 pub mod error;
+pub mod error_code;
 pub mod jwt;
 pub mod page;
 pub mod res;
 pub mod util;
 pub mod validator;
 
+pub use idempotency::IdempotencyLayer;
 pub use request_id::{RequestId, RequestIdLayer};
 
+mod idempotency;
 pub mod operation_log;
 mod request_id;
@@ -20,6 +20,7 @@ use server_constant::definition::consts::SystemEvent;
 use server_global::global::{self, OperationLogContext};
 use tower_layer::Layer;
 use tower_service::Service;
+use ulid::Ulid;
 
 use super::{auth::User, RequestId};
 
@@ -115,6 +116,12 @@ where
                 let end_time = Local::now().naive_local();
                 let duration = (end_time - start_time).num_milliseconds() as i32;
 
+                // 未知请求 ID 不能用于去重
+                let dedup_key = if request_id == UNKNOWN_REQUEST_ID {
+                    Ulid::new().to_string()
+                } else {
+                    request_id.clone()
+                };
                 let context = OperationLogContext {
                     user_id,
                     username,
@@ -137,10 +144,12 @@ where
                     created_at: start_time,
                 };
 
-                global::send_dyn_event(
+                global::send_outbox_event(
                     SystemEvent::AuditOperationLoggedEvent.as_ref(),
-                    Box::new(context),
-                );
+                    dedup_key,
+                    context,
+                )
+                .await;
 
                 Ok(Response::from_parts(
                     response_parts,
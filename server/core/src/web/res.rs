@@ -15,6 +15,9 @@ pub struct Res<T> {
     pub data: Option<T>,
     pub msg: String,
     pub success: bool,
+    /// 错误文案的 i18n 键，仅目录内的错误码返回
+    #[serde(rename = "i18nKey", skip_serializing_if = "Option::is_none")]
+    pub i18n_key: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -25,6 +28,7 @@ impl<T: Serialize> Res<T> {
             data: Some(data),
             msg: "success".to_string(),
             success: true,
+            i18n_key: None,
         }
     }
 
@@ -34,6 +38,7 @@ impl<T: Serialize> Res<T> {
             data: Some(data),
             msg: msg.to_string(),
             success: true,
+            i18n_key: None,
         }
     }
 
@@ -43,6 +48,7 @@ impl<T: Serialize> Res<T> {
             data: None,
             msg: msg.to_string(),
             success: false,
+            i18n_key: None,
         }
     }
 
@@ -52,15 +58,22 @@ impl<T: Serialize> Res<T> {
             data: None,
             msg: msg.to_string(),
             success: true,
+            i18n_key: None,
         }
     }
 
+    pub fn with_i18n_key(mut self, key: Option<&str>) -> Self {
+        self.i18n_key = key.map(ToString::to_string);
+        self
+    }
+
     pub fn new_data(data: T) -> Self {
         Self {
             code: StatusCode::OK.as_u16(),
             data: Some(data),
             msg: "success".to_string(),
             success: true,
+            i18n_key: None,
         }
     }
 }
@@ -12,12 +12,14 @@ use http::Method;
 use jsonwebtoken::{DecodingKey, EncodingKey, Validation};
 use mongodb::Client as MongoClient;
 use once_cell::sync::Lazy;
-use redis::{cluster::ClusterClient, Client};
+use redis::{cluster::ClusterClient, Client, Cmd, FromRedisValue, RedisResult, ScriptInvocation};
 use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tokio::sync::{mpsc, Mutex, OnceCell, RwLock};
+use sol_spl_token::RpcPool;
+use tokio::sync::{mpsc, oneshot, Mutex, OnceCell, RwLock};
 
-use crate::project_info;
+use crate::{project_error, project_info};
 
 //*****************************************************************************
 // 全局配置
@@ -54,6 +56,39 @@ pub enum RedisConnection {
     Cluster(Arc<ClusterClient>),
 }
 
+impl RedisConnection {
+    /// 执行命令，按单机或集群模式获取连接
+    pub async fn query<T: FromRedisValue>(&self, cmd: &Cmd) -> RedisResult<T> {
+        match self {
+            RedisConnection::Single(client) => {
+                let mut conn = client.get_multiplexed_async_connection().await?;
+                cmd.query_async(&mut conn).await
+            },
+            RedisConnection::Cluster(client) => {
+                let mut conn = client.get_async_connection().await?;
+                cmd.query_async(&mut conn).await
+            },
+        }
+    }
+
+    /// 执行 Lua 脚本，按单机或集群模式获取连接
+    pub async fn invoke<T: FromRedisValue>(
+        &self,
+        invocation: &ScriptInvocation<'_>,
+    ) -> RedisResult<T> {
+        match self {
+            RedisConnection::Single(client) => {
+                let mut conn = client.get_multiplexed_async_connection().await?;
+                invocation.invoke_async(&mut conn).await
+            },
+            RedisConnection::Cluster(client) => {
+                let mut conn = client.get_async_connection().await?;
+                invocation.invoke_async(&mut conn).await
+            },
+        }
+    }
+}
+
 pub static GLOBAL_PRIMARY_REDIS: Lazy<RwLock<Option<RedisConnection>>> =
     Lazy::new(|| RwLock::new(None));
 
@@ -78,6 +113,18 @@ pub static GLOBAL_PRIMARY_S3: Lazy<RwLock<Option<Arc<S3Client>>>> = Lazy::new(||
 pub static GLOBAL_S3_POOL: Lazy<RwLock<HashMap<String, Arc<S3Client>>>> =
     Lazy::new(|| RwLock::new(HashMap::new()));
 
+//*****************************************************************************
+// Solana RPC 端点池
+//*****************************************************************************
+
+// 主网络的 RPC 端点池
+pub static GLOBAL_PRIMARY_SOLANA: Lazy<RwLock<Option<Arc<RpcPool>>>> =
+    Lazy::new(|| RwLock::new(None));
+
+// 按实例名称区分的 RPC 端点池
+pub static GLOBAL_SOLANA_POOL: Lazy<RwLock<HashMap<String, Arc<RpcPool>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
 //*****************************************************************************
 // JWT 密钥和验证
 //*****************************************************************************
@@ -129,6 +176,11 @@ type DynListener = (
     String,
     Box<dyn Fn(mpsc::UnboundedReceiver<Box<dyn Any + Send>>) -> Pin<Box<DynFuture>>>,
 );
+type OutboxFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+type OutboxWriter = Box<dyn Fn(&'static str, String, Value) -> OutboxFuture + Send + Sync>;
+
+/// 事件发件箱写入器，由服务层在启动时注册
+static OUTBOX_WRITER: OnceCell<OutboxWriter> = OnceCell::const_new();
 
 /// 获取字符串事件发送器
 #[inline]
@@ -177,6 +229,53 @@ pub async fn register_event_listeners(
     }
 }
 
+/// 需要监听器确认处理结果的事件，由事件发件箱中继投递
+///
+/// 中继收到确认后才把事件标记为已发布，监听器未确认即退出时事件会被重新投递
+pub struct AckedEvent {
+    event: Box<dyn Any + Send>,
+    ack: oneshot::Sender<Result<(), String>>,
+}
+
+impl AckedEvent {
+    pub fn new(event: Box<dyn Any + Send>) -> (Self, oneshot::Receiver<Result<(), String>>) {
+        let (ack, rx) = oneshot::channel();
+        (Self { event, ack }, rx)
+    }
+}
+
+/// 监听器对事件的确认，普通事件的确认为空操作
+pub struct EventAck(Option<oneshot::Sender<Result<(), String>>>);
+
+impl EventAck {
+    /// 确认事件已处理
+    pub fn ok(self) {
+        self.done(Ok::<(), String>(()));
+    }
+
+    /// 回报处理结果，失败时中继稍后重新投递
+    pub fn done<E: std::fmt::Debug>(self, result: Result<(), E>) {
+        if let Some(ack) = self.0 {
+            let _ = ack.send(result.map_err(|e| format!("{:?}", e)));
+        }
+    }
+}
+
+/// 取出监听器收到的事件及其确认
+pub fn open_event(event: Box<dyn Any + Send>) -> (Box<dyn Any + Send>, EventAck) {
+    match event.downcast::<AckedEvent>() {
+        Ok(acked) => (acked.event, EventAck(Some(acked.ack))),
+        Err(event) => (event, EventAck(None)),
+    }
+}
+
+/// 注册事件发件箱写入器，参数依次为事件名、去重键和事件内容
+pub fn register_outbox_writer(writer: OutboxWriter) {
+    if OUTBOX_WRITER.set(writer).is_err() {
+        project_error!("Outbox writer is already registered");
+    }
+}
+
 //*****************************************************************************
 // 路由信息收集
 //*****************************************************************************
@@ -218,7 +317,7 @@ pub async fn clear_routes() {
 // 操作日志
 //*****************************************************************************
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OperationLogContext {
     pub user_id: Option<String>,
     pub username: Option<String>,
@@ -276,3 +375,24 @@ pub fn send_dyn_event(event_name: &'static str, event: Box<dyn Any + Send>) {
         }
     });
 }
+
+/// 通过事件发件箱发送动态类型事件
+///
+/// 事件先写入发件箱，再由中继投递给同名监听器，写入后进程退出也不会丢失；
+/// 同一事件名下相同去重键的事件只会写入一次。未注册写入器或写入失败时退化为 [`send_dyn_event`]
+pub async fn send_outbox_event<T>(event_name: &'static str, dedup_key: String, event: T)
+where
+    T: Serialize + Any + Send,
+{
+    if let Some(writer) = OUTBOX_WRITER.get() {
+        let written = match serde_json::to_value(&event) {
+            Ok(payload) => writer(event_name, dedup_key, payload).await,
+            Err(e) => Err(e.to_string()),
+        };
+        match written {
+            Ok(()) => return,
+            Err(e) => project_error!("Failed to write {} event to outbox: {}", event_name, e),
+        }
+    }
+    send_dyn_event(event_name, Box::new(event));
+}
@@ -252,6 +252,7 @@ mod tests {
                 access_key_id: "test_key".to_string(),
                 secret_access_key: "test_secret".to_string(),
                 endpoint: Some("http://localhost:4566".to_string()),
+                bucket: None,
             },
         };
 
@@ -3,8 +3,9 @@ use server_global::global;
 
 pub async fn initialize_event_channel() {
     use server_service::admin::{
-        api_key_validate_listener, auth_login_listener, jwt_created_listener,
-        sys_operation_log_listener,
+        api_key_validate_listener, auth_login_listener, auto_convert_job_listener,
+        jwt_created_listener, payout_batch_listener, sys_operation_log_listener,
+        webhook_event_listener,
     };
 
     global::register_event_listeners(
@@ -22,6 +23,18 @@ pub async fn initialize_event_channel() {
                 SystemEvent::AuthApiKeyValidatedEvent.to_string(),
                 Box::new(|rx| Box::pin(api_key_validate_listener(rx))),
             ),
+            (
+                SystemEvent::AutoConvertJobQueuedEvent.to_string(),
+                Box::new(|rx| Box::pin(auto_convert_job_listener(rx))),
+            ),
+            (
+                SystemEvent::PayoutBatchQueuedEvent.to_string(),
+                Box::new(|rx| Box::pin(payout_batch_listener(rx))),
+            ),
+            (
+                SystemEvent::WebhookEventQueuedEvent.to_string(),
+                Box::new(|rx| Box::pin(webhook_event_listener(rx))),
+            ),
         ],
     )
     .await;
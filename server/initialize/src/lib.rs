@@ -11,10 +11,17 @@ pub use ip2region_initialization::init_xdb;
 pub use jwt_initialization::initialize_keys_and_validation;
 pub use log_tracing_init::initialize_log_tracing;
 pub use mongo_initialization::{init_mongo_pools, init_primary_mongo};
+pub use outbox_relay_initialization::initialize_outbox_relay;
+pub use payment_invoice_checker_initialization::initialize_payment_invoice_checker;
 pub use redis_initialization::{init_primary_redis, init_redis_pools};
 pub use router_initialization::initialize_admin_router;
 pub use server_global::{project_error, project_info};
+pub use scheduler_initialization::initialize_scheduler;
 pub use server_initialization::get_server_address;
+pub use solana_initialization::{
+    get_solana_pool_connection, init_primary_solana, init_solana_pools, restore_system_keypair,
+};
+pub use webhook_dispatcher_initialization::initialize_webhook_dispatcher;
 
 mod access_key_initialization;
 mod aws_s3_initialization;
@@ -26,9 +33,14 @@ mod ip2region_initialization;
 mod jwt_initialization;
 mod log_tracing_init;
 mod mongo_initialization;
+mod outbox_relay_initialization;
+mod payment_invoice_checker_initialization;
 mod redis_initialization;
 mod router_initialization;
+mod scheduler_initialization;
 mod server_initialization;
+mod solana_initialization;
+mod webhook_dispatcher_initialization;
 
 // TODO: axum_test_helpers不兼容axum 0.8.x
 // #[cfg(test)]
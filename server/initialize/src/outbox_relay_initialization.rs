@@ -0,0 +1,14 @@
+use std::time::Duration;
+
+use server_global::project_info;
+use server_service::admin::SysOutboxService;
+
+/// 到期重试的扫描间隔，新事件提交后立即唤醒中继
+const RELAY_INTERVAL: Duration = Duration::from_secs(5);
+
+pub async fn initialize_outbox_relay() {
+    SysOutboxService::register_writer();
+    SysOutboxService::spawn_relay(RELAY_INTERVAL);
+
+    project_info!("Outbox relay started")
+}
@@ -0,0 +1,13 @@
+use std::time::Duration;
+
+use server_global::project_info;
+use server_service::admin::SysPaymentInvoiceService;
+
+/// 待付款收款单的链上检查间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+pub async fn initialize_payment_invoice_checker() {
+    SysPaymentInvoiceService::spawn_checker(CHECK_INTERVAL);
+
+    project_info!("Payment invoice checker started")
+}
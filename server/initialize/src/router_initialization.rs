@@ -10,19 +10,28 @@ use server_core::sign::{
     api_key_middleware, protect_route, ApiKeySource, ApiKeyValidation, ComplexApiKeyConfig,
     SimpleApiKeyConfig, ValidatorType,
 };
-use server_core::web::{RequestId, RequestIdLayer};
+use server_core::web::{error_code::CustodyErrorCode, RequestId, RequestIdLayer};
 use server_global::global::{clear_routes, get_collected_routes, get_config};
 use server_middleware::jwt_auth_middleware;
 use server_router::admin::{
-    SysAccessKeyRouter, SysAuthenticationRouter, SysDomainRouter, SysEndpointRouter,
-    SysLoginLogRouter, SysMenuRouter, SysOperationLogRouter, SysOrganizationRouter, SysRoleRouter,
-    SysSandboxRouter, SysUserRouter,
+    SysAccessKeyRouter, SysAssetRouter, SysAuthenticationRouter, SysAutoConvertRouter,
+    SysCustodyHoldRouter, SysDomainRouter, SysEndpointRouter, SysJobRouter, SysKeyRotationRouter,
+    SysKeystoreRouter, SysLoginLogRouter, SysLookupTableRouter, SysMenuRouter,
+    SysMintAdminRouter, SysOperationLogRouter, SysOrganizationRouter, SysPaymentInvoiceRouter,
+    SysPayoutRouter, SysRentReclamationRouter, SysReservesRouter, SysRoleRouter,
+    SysSandboxRouter, SysSolanaRouter, SysStakeRouter, SysUserRouter, SysWebhookRouter,
+    SysWithdrawalFeeRouter,
 };
 use server_service::{
     admin::{
-        SysAccessKeyService, SysAuthService, SysAuthorizationService, SysDomainService,
-        SysEndpointService, SysLoginLogService, SysMenuService, SysOperationLogService,
-        SysOrganizationService, SysRoleService, SysUserService, TEndpointService,
+        SysAccessKeyService, SysAssetService, SysAuthService, SysAuthorizationService,
+        SysAutoConvertService, SysCustodyHoldService, SysDomainService, SysEndpointService,
+        SysJobService,
+        SysKeyRotationService, SysKeystoreService, SysLoginLogService, SysLookupTableService,
+        SysMenuService, SysMintAdminService, SysOperationLogService, SysOrganizationService,
+        SysPaymentInvoiceService, SysPayoutService, SysRentReclamationService,
+        SysReservesService, SysRoleService, SysSolanaService, SysStakeService, SysUserService,
+        SysWebhookService, SysWithdrawalFeeService, TEndpointService,
     },
     SysEndpoint,
 };
@@ -52,7 +61,7 @@ impl Modify for SecurityAddon {
 // Optional, in case the warning persists
 #[derive(OpenApi,ToSchema)]
 #[openapi(
-    components(schemas(ApiDoc)),
+    components(schemas(ApiDoc, CustodyErrorCode)),
     security(
         ("bearer_auth" = ["Bearer"])
     ),
@@ -318,6 +327,146 @@ pub async fn initialize_admin_router() -> Router {
         None
     );
 
+    merge_router!(
+        SysAssetRouter::init_asset_router().await,
+        SysAssetService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysAutoConvertRouter::init_auto_convert_router().await,
+        SysAutoConvertService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysReservesRouter::init_reserves_router().await,
+        SysReservesService,
+        true,
+        true,
+        None
+    );
+    merge_router!(
+        SysReservesRouter::init_protected_reserves_router().await,
+        SysReservesService,
+        false,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysSolanaRouter::init_solana_router().await,
+        SysSolanaService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysRentReclamationRouter::init_rent_reclamation_router().await,
+        SysRentReclamationService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysWithdrawalFeeRouter::init_withdrawal_fee_router().await,
+        SysWithdrawalFeeService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysPayoutRouter::init_payout_router().await,
+        SysPayoutService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysLookupTableRouter::init_lookup_table_router().await,
+        SysLookupTableService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysMintAdminRouter::init_mint_admin_router().await,
+        SysMintAdminService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysPaymentInvoiceRouter::init_payment_invoice_router().await,
+        SysPaymentInvoiceService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysStakeRouter::init_stake_router().await,
+        SysStakeService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysCustodyHoldRouter::init_custody_hold_router().await,
+        SysCustodyHoldService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysKeyRotationRouter::init_key_rotation_router().await,
+        SysKeyRotationService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysKeystoreRouter::init_keystore_router().await,
+        SysKeystoreService,
+        true,
+        true,
+        None
+    );
+    merge_router!(
+        SysWebhookRouter::init_webhook_router().await,
+        SysWebhookService,
+        true,
+        true,
+        None
+    );
+    merge_router!(
+        SysJobRouter::init_job_router().await,
+        SysJobService,
+        true,
+        true,
+        None
+    );
+    merge_router!(
+        SysStakeRouter::init_protected_stake_router().await,
+        SysStakeService,
+        false,
+        true,
+        None
+    );
+
     merge_router!(
         SysOrganizationRouter::init_organization_router().await,
         SysOrganizationService,
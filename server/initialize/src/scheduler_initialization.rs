@@ -0,0 +1,148 @@
+use chrono::{Duration, Local};
+use server_core::web::error::AppError;
+use server_global::{project_error, project_info};
+use server_scheduler::{prune_runs, JobRegistry, Scheduler};
+use server_model::admin::entities::sea_orm_active_enums::RentReclamationStatus;
+use server_service::admin::{
+    SysAuthService, SysAutoConvertService, SysLoginLogService, SysOperationLogService,
+    SysOutboxService, SysPayoutService, SysRentReclamationService, SysReservesService,
+    SysStakeService,
+};
+
+/// 登录日志与操作日志的保留天数
+const LOG_RETENTION_DAYS: i64 = 90;
+
+/// 任务运行记录的保留天数
+const JOB_RUN_RETENTION_DAYS: i64 = 30;
+
+/// 已发布事件在发件箱中的保留天数
+const OUTBOX_RETENTION_DAYS: i64 = 7;
+
+fn registry() -> Result<JobRegistry, AppError> {
+    let mut registry = JobRegistry::new();
+    registry
+        // 同一 epoch 重复拉取不会重复记入，间隔只需短于一个 epoch（约两天）
+        .register(
+            "stake_reward_collection",
+            "拉取上一个 epoch 的质押奖励",
+            "0 0 * * * *",
+            || async {
+                let output = SysStakeService::collect_rewards(None, None).await?;
+                Ok(format!(
+                    "Collected {} rewards for epoch {} across {} accounts: {} lamports",
+                    output.rewards, output.epoch, output.accounts, output.amount
+                ))
+            },
+        )?
+        .register(
+            "log_retention",
+            "清理过期的登录日志与操作日志",
+            "0 30 3 * * *",
+            || async {
+                let before = Local::now().naive_local() - Duration::days(LOG_RETENTION_DAYS);
+                let login_logs = SysLoginLogService::purge_before(before).await?;
+                let operation_logs = SysOperationLogService::purge_before(before).await?;
+                Ok(format!(
+                    "Deleted {} login logs and {} operation logs before {}",
+                    login_logs, operation_logs, before
+                ))
+            },
+        )?
+        .register(
+            "token_cleanup",
+            "清理已过期的登录令牌记录",
+            "0 15 * * * *",
+            || async {
+                let deleted = SysAuthService::purge_expired_tokens().await?;
+                Ok(format!("Deleted {} expired tokens", deleted))
+            },
+        )?
+        .register(
+            "job_run_retention",
+            "清理过期的任务运行记录，并将中断的运行标记为失败",
+            "0 0 4 * * *",
+            || async {
+                let interrupted = Scheduler::global()?.fail_interrupted_runs().await?;
+                let before = Local::now().naive_local() - Duration::days(JOB_RUN_RETENTION_DAYS);
+                let deleted = prune_runs(before).await?;
+                Ok(format!(
+                    "Marked {} interrupted job runs as failed, deleted {} job runs before {}",
+                    interrupted, deleted, before
+                ))
+            },
+        )?
+        // 按各域已启用的资产生成储备快照，用户可据此校验负债证明
+        .register(
+            "reserves_snapshot",
+            "生成各资产的储备与负债快照",
+            "0 0 2 * * *",
+            || async {
+                let snapshots = SysReservesService::run_scheduled().await?;
+                Ok(format!("Took {} reserves snapshots", snapshots.len()))
+            },
+        )?
+        // 执行中断的兑换任务标记为失败，长时间未执行的待处理任务重新入队
+        .register(
+            "auto_convert_recovery",
+            "恢复中断或滞留的自动兑换任务",
+            "0 */5 * * * *",
+            || async {
+                let recovered = SysAutoConvertService::recover_stale_jobs().await?;
+                Ok(format!("Recovered {} stale auto-convert jobs", recovered))
+            },
+        )?
+        // 心跳超时的执行中批次先按链上记录核对，再继续发送未发出的行
+        .register(
+            "payout_resume",
+            "恢复中断的批量付款",
+            "30 */5 * * * *",
+            || async {
+                let resumed = SysPayoutService::resume_stale_batches().await?;
+                Ok(format!("Resumed {} stale payout batches", resumed))
+            },
+        )?
+        // 按各钱包自身的密钥关闭闲置的空 Token 账户，租金退回系统钱包
+        .register(
+            "rent_reclamation",
+            "回收托管钱包空 Token 账户的租金",
+            "0 0 5 * * *",
+            || async {
+                let records = SysRentReclamationService::run_scheduled().await?;
+                let closed: Vec<_> = records
+                    .iter()
+                    .filter(|record| record.status == RentReclamationStatus::Closed)
+                    .collect();
+                Ok(format!(
+                    "Closed {} of {} empty token accounts, reclaimed {} lamports",
+                    closed.len(),
+                    records.len(),
+                    closed.iter().map(|record| record.lamports).sum::<i64>()
+                ))
+            },
+        )?
+        // 死信事件保留，待人工处理
+        .register(
+            "outbox_retention",
+            "清理已发布的发件箱事件",
+            "0 45 4 * * *",
+            || async {
+                let before = Local::now().naive_local() - Duration::days(OUTBOX_RETENTION_DAYS);
+                let deleted = SysOutboxService::purge_published_before(before).await?;
+                Ok(format!("Deleted {} published outbox events before {}", deleted, before))
+            },
+        )?;
+    Ok(registry)
+}
+
+pub async fn initialize_scheduler() {
+    match registry() {
+        Ok(registry) => {
+            let scheduler = Scheduler::start(registry);
+            project_info!(
+                "Job scheduler started on instance {}",
+                scheduler.instance_id()
+            )
+        },
+        Err(e) => project_error!("Failed to start job scheduler: {}", e.message),
+    }
+}
@@ -0,0 +1,88 @@
+use std::{process, sync::Arc};
+
+use server_config::{OptionalConfigs, SolanaConfig, SolanaInstancesConfig};
+use server_global::global::{get_config, GLOBAL_PRIMARY_SOLANA, GLOBAL_SOLANA_POOL};
+use server_service::admin::SysKeyRotationService;
+use sol_spl_token::RpcPool;
+
+use crate::{project_error, project_info};
+
+/// 初始化主网络的 RPC 端点池
+pub async fn init_primary_solana() {
+    if let Some(config) = get_config::<SolanaConfig>().await {
+        match create_rpc_pool(&config) {
+            Ok(pool) => {
+                *GLOBAL_PRIMARY_SOLANA.write().await = Some(pool);
+                project_info!("Primary Solana RPC pool initialized ({})", config.network);
+            },
+            Err(e) => {
+                project_error!("Failed to initialize primary Solana RPC pool: {}", e);
+                process::exit(1);
+            },
+        }
+    }
+}
+
+/// 恢复轮换后的系统钱包密钥
+///
+/// 配置中的系统钱包已被轮换时改用密钥库中的新密钥，恢复失败时退出，避免继续使用已停用的钱包
+pub async fn restore_system_keypair() {
+    if get_config::<SolanaConfig>().await.is_none() {
+        return;
+    }
+    match SysKeyRotationService::restore_system_keypair().await {
+        Ok(Some(address)) => project_info!("Rotated system wallet {} restored", address),
+        Ok(None) => {},
+        Err(e) => {
+            project_error!("Failed to restore rotated system wallet: {}", e.message);
+            process::exit(1);
+        },
+    }
+}
+
+/// 初始化所有命名的 Solana 实例
+pub async fn init_solana_pools() {
+    if let Some(solana_instances_config) =
+        get_config::<OptionalConfigs<SolanaInstancesConfig>>().await
+    {
+        if let Some(solana_instances) = &solana_instances_config.configs {
+            for solana_instance in solana_instances {
+                let _ =
+                    init_solana_connection(&solana_instance.name, &solana_instance.solana).await;
+            }
+        }
+    }
+}
+
+async fn init_solana_connection(name: &str, config: &SolanaConfig) -> Result<(), String> {
+    match create_rpc_pool(config) {
+        Ok(pool) => {
+            GLOBAL_SOLANA_POOL
+                .write()
+                .await
+                .insert(name.to_string(), pool);
+            project_info!(
+                "Solana instance '{}' initialized ({})",
+                name,
+                config.network
+            );
+            Ok(())
+        },
+        Err(e) => {
+            let error_msg = format!("Failed to initialize Solana instance '{}': {}", name, e);
+            project_error!("{}", error_msg);
+            Err(error_msg)
+        },
+    }
+}
+
+fn create_rpc_pool(config: &SolanaConfig) -> Result<Arc<RpcPool>, String> {
+    let pool = Arc::new(RpcPool::from_config(config).map_err(|e| e.to_string())?);
+    pool.spawn_health_checker();
+    Ok(pool)
+}
+
+/// 获取命名的 Solana 实例的 RPC 端点池
+pub async fn get_solana_pool_connection(name: &str) -> Option<Arc<RpcPool>> {
+    GLOBAL_SOLANA_POOL.read().await.get(name).cloned()
+}
@@ -0,0 +1,13 @@
+use std::time::Duration;
+
+use server_global::project_info;
+use server_service::admin::SysWebhookService;
+
+/// 到期重试的扫描间隔，新事件登记后立即发送，不等待扫描
+const DISPATCH_INTERVAL: Duration = Duration::from_secs(10);
+
+pub async fn initialize_webhook_dispatcher() {
+    SysWebhookService::spawn_dispatcher(DISPATCH_INTERVAL);
+
+    project_info!("Webhook dispatcher started")
+}
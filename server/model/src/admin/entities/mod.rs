@@ -5,14 +5,46 @@ pub mod prelude;
 pub mod casbin_rule;
 pub mod sea_orm_active_enums;
 pub mod sys_access_key;
+pub mod sys_asset;
+pub mod sys_auto_convert_job;
+pub mod sys_auto_convert_leg;
+pub mod sys_auto_convert_policy;
+pub mod sys_balance_entry;
+pub mod sys_custody_hold;
+pub mod sys_custody_hold_audit;
+pub mod sys_custody_wallet;
 pub mod sys_domain;
 pub mod sys_endpoint;
+pub mod sys_job_run;
+pub mod sys_key_export;
+pub mod sys_key_export_policy;
+pub mod sys_key_rotation;
+pub mod sys_keystore_audit;
 pub mod sys_login_log;
+pub mod sys_lookup_table;
 pub mod sys_menu;
+pub mod sys_mint_operation;
+pub mod sys_mint_operation_audit;
 pub mod sys_operation_log;
 pub mod sys_organization;
+pub mod sys_outbox;
+pub mod sys_payment_invoice;
+pub mod sys_payout_batch;
+pub mod sys_payout_row;
+pub mod sys_rent_reclamation;
+pub mod sys_reserve_liability;
+pub mod sys_reserve_snapshot;
+pub mod sys_risk_screening;
 pub mod sys_role;
 pub mod sys_role_menu;
+pub mod sys_stake_account;
+pub mod sys_stake_reward;
 pub mod sys_tokens;
 pub mod sys_user;
+pub mod sys_user_balance;
 pub mod sys_user_role;
+pub mod sys_webhook_delivery;
+pub mod sys_webhook_endpoint;
+pub mod sys_withdrawal_fee;
+pub mod sys_withdrawal_fee_record;
+pub mod sys_withdrawal_fee_tier;
@@ -2,10 +2,40 @@
 
 pub use super::{
     casbin_rule::Entity as CasbinRule, sys_access_key::Entity as SysAccessKey,
-    sys_domain::Entity as SysDomain, sys_endpoint::Entity as SysEndpoint,
-    sys_login_log::Entity as SysLoginLog, sys_menu::Entity as SysMenu,
-    sys_operation_log::Entity as SysOperationLog, sys_organization::Entity as SysOrganization,
-    sys_role::Entity as SysRole, sys_role_menu::Entity as SysRoleMenu,
+    sys_asset::Entity as SysAsset,
+    sys_auto_convert_job::Entity as SysAutoConvertJob,
+    sys_auto_convert_leg::Entity as SysAutoConvertLeg,
+    sys_auto_convert_policy::Entity as SysAutoConvertPolicy,
+    sys_balance_entry::Entity as SysBalanceEntry,
+    sys_custody_hold::Entity as SysCustodyHold,
+    sys_custody_hold_audit::Entity as SysCustodyHoldAudit,
+    sys_custody_wallet::Entity as SysCustodyWallet, sys_domain::Entity as SysDomain,
+    sys_endpoint::Entity as SysEndpoint, sys_job_run::Entity as SysJobRun,
+    sys_key_export::Entity as SysKeyExport,
+    sys_key_export_policy::Entity as SysKeyExportPolicy,
+    sys_key_rotation::Entity as SysKeyRotation,
+    sys_keystore_audit::Entity as SysKeystoreAudit,
+    sys_login_log::Entity as SysLoginLog,
+    sys_lookup_table::Entity as SysLookupTable,
+    sys_menu::Entity as SysMenu, sys_mint_operation::Entity as SysMintOperation,
+    sys_mint_operation_audit::Entity as SysMintOperationAudit,
+    sys_operation_log::Entity as SysOperationLog,
+    sys_organization::Entity as SysOrganization, sys_outbox::Entity as SysOutbox,
+    sys_payment_invoice::Entity as SysPaymentInvoice,
+    sys_payout_batch::Entity as SysPayoutBatch, sys_payout_row::Entity as SysPayoutRow,
+    sys_rent_reclamation::Entity as SysRentReclamation,
+    sys_reserve_liability::Entity as SysReserveLiability,
+    sys_reserve_snapshot::Entity as SysReserveSnapshot,
+    sys_risk_screening::Entity as SysRiskScreening, sys_role::Entity as SysRole,
+    sys_role_menu::Entity as SysRoleMenu,
+    sys_stake_account::Entity as SysStakeAccount,
+    sys_stake_reward::Entity as SysStakeReward,
     sys_tokens::Entity as SysTokens, sys_user::Entity as SysUser,
+    sys_user_balance::Entity as SysUserBalance,
     sys_user_role::Entity as SysUserRole,
+    sys_webhook_delivery::Entity as SysWebhookDelivery,
+    sys_webhook_endpoint::Entity as SysWebhookEndpoint,
+    sys_withdrawal_fee::Entity as SysWithdrawalFee,
+    sys_withdrawal_fee_record::Entity as SysWithdrawalFeeRecord,
+    sys_withdrawal_fee_tier::Entity as SysWithdrawalFeeTier,
 };
@@ -28,3 +28,450 @@ pub enum Status {
     #[serde(rename = "enabled")]
     Enabled,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum CustodyWalletType {
+    #[sea_orm(string_value = "user")]
+    #[serde(rename = "user")]
+    User,
+    #[sea_orm(string_value = "system")]
+    #[serde(rename = "system")]
+    System,
+    #[sea_orm(string_value = "hot")]
+    #[serde(rename = "hot")]
+    Hot,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum AutoConvertJobStatus {
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[sea_orm(string_value = "running")]
+    #[serde(rename = "running")]
+    Running,
+    #[sea_orm(string_value = "succeeded")]
+    #[serde(rename = "succeeded")]
+    Succeeded,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+    #[sea_orm(string_value = "skipped")]
+    #[serde(rename = "skipped")]
+    Skipped,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum AutoConvertLegStatus {
+    #[sea_orm(string_value = "dry_run")]
+    #[serde(rename = "dry_run")]
+    DryRun,
+    #[sea_orm(string_value = "landed")]
+    #[serde(rename = "landed")]
+    Landed,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum RentReclaimReason {
+    #[sea_orm(string_value = "unsupported_mint")]
+    #[serde(rename = "unsupported_mint")]
+    UnsupportedMint,
+    #[sea_orm(string_value = "idle")]
+    #[serde(rename = "idle")]
+    Idle,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum RentReclamationStatus {
+    #[sea_orm(string_value = "closed")]
+    #[serde(rename = "closed")]
+    Closed,
+    #[sea_orm(string_value = "dry_run")]
+    #[serde(rename = "dry_run")]
+    DryRun,
+    #[sea_orm(string_value = "skipped")]
+    #[serde(rename = "skipped")]
+    Skipped,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum AssetTokenProgram {
+    #[sea_orm(string_value = "spl_token")]
+    #[serde(rename = "spl_token")]
+    SplToken,
+    #[sea_orm(string_value = "token_2022")]
+    #[serde(rename = "token_2022")]
+    Token2022,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum WithdrawalFeeType {
+    #[sea_orm(string_value = "flat")]
+    #[serde(rename = "flat")]
+    Flat,
+    #[sea_orm(string_value = "percentage")]
+    #[serde(rename = "percentage")]
+    Percentage,
+    #[sea_orm(string_value = "tiered")]
+    #[serde(rename = "tiered")]
+    Tiered,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum PayoutSourceFormat {
+    #[sea_orm(string_value = "csv")]
+    #[serde(rename = "csv")]
+    Csv,
+    #[sea_orm(string_value = "json")]
+    #[serde(rename = "json")]
+    Json,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum PayoutBatchStatus {
+    #[sea_orm(string_value = "pending_approval")]
+    #[serde(rename = "pending_approval")]
+    PendingApproval,
+    #[sea_orm(string_value = "approved")]
+    #[serde(rename = "approved")]
+    Approved,
+    #[sea_orm(string_value = "rejected")]
+    #[serde(rename = "rejected")]
+    Rejected,
+    #[sea_orm(string_value = "executing")]
+    #[serde(rename = "executing")]
+    Executing,
+    #[sea_orm(string_value = "completed")]
+    #[serde(rename = "completed")]
+    Completed,
+    #[sea_orm(string_value = "partially_failed")]
+    #[serde(rename = "partially_failed")]
+    PartiallyFailed,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum PayoutRowStatus {
+    #[sea_orm(string_value = "invalid")]
+    #[serde(rename = "invalid")]
+    Invalid,
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[sea_orm(string_value = "unconfirmed")]
+    #[serde(rename = "unconfirmed")]
+    Unconfirmed,
+    #[sea_orm(string_value = "succeeded")]
+    #[serde(rename = "succeeded")]
+    Succeeded,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum MintOperationType {
+    #[sea_orm(string_value = "mint_to")]
+    #[serde(rename = "mint_to")]
+    MintTo,
+    #[sea_orm(string_value = "burn")]
+    #[serde(rename = "burn")]
+    Burn,
+    #[sea_orm(string_value = "freeze_account")]
+    #[serde(rename = "freeze_account")]
+    FreezeAccount,
+    #[sea_orm(string_value = "thaw_account")]
+    #[serde(rename = "thaw_account")]
+    ThawAccount,
+    #[sea_orm(string_value = "set_authority")]
+    #[serde(rename = "set_authority")]
+    SetAuthority,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum MintAuthorityType {
+    #[sea_orm(string_value = "mint_tokens")]
+    #[serde(rename = "mint_tokens")]
+    MintTokens,
+    #[sea_orm(string_value = "freeze_account")]
+    #[serde(rename = "freeze_account")]
+    FreezeAccount,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum MintOperationStatus {
+    #[sea_orm(string_value = "pending_approval")]
+    #[serde(rename = "pending_approval")]
+    PendingApproval,
+    #[sea_orm(string_value = "approved")]
+    #[serde(rename = "approved")]
+    Approved,
+    #[sea_orm(string_value = "rejected")]
+    #[serde(rename = "rejected")]
+    Rejected,
+    #[sea_orm(string_value = "executing")]
+    #[serde(rename = "executing")]
+    Executing,
+    #[sea_orm(string_value = "executed")]
+    #[serde(rename = "executed")]
+    Executed,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum MintOperationAction {
+    #[sea_orm(string_value = "created")]
+    #[serde(rename = "created")]
+    Created,
+    #[sea_orm(string_value = "approved")]
+    #[serde(rename = "approved")]
+    Approved,
+    #[sea_orm(string_value = "rejected")]
+    #[serde(rename = "rejected")]
+    Rejected,
+    #[sea_orm(string_value = "executed")]
+    #[serde(rename = "executed")]
+    Executed,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum PaymentInvoiceStatus {
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[sea_orm(string_value = "paid")]
+    #[serde(rename = "paid")]
+    Paid,
+    #[sea_orm(string_value = "underpaid")]
+    #[serde(rename = "underpaid")]
+    Underpaid,
+    #[sea_orm(string_value = "expired")]
+    #[serde(rename = "expired")]
+    Expired,
+    #[sea_orm(string_value = "cancelled")]
+    #[serde(rename = "cancelled")]
+    Cancelled,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum StakeAccountStatus {
+    #[sea_orm(string_value = "activating")]
+    #[serde(rename = "activating")]
+    Activating,
+    #[sea_orm(string_value = "active")]
+    #[serde(rename = "active")]
+    Active,
+    #[sea_orm(string_value = "deactivating")]
+    #[serde(rename = "deactivating")]
+    Deactivating,
+    #[sea_orm(string_value = "inactive")]
+    #[serde(rename = "inactive")]
+    Inactive,
+    #[sea_orm(string_value = "withdrawn")]
+    #[serde(rename = "withdrawn")]
+    Withdrawn,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum CustodyHoldSource {
+    #[sea_orm(string_value = "manual")]
+    #[serde(rename = "manual")]
+    Manual,
+    #[sea_orm(string_value = "user_disabled")]
+    #[serde(rename = "user_disabled")]
+    UserDisabled,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum CustodyHoldStatus {
+    #[sea_orm(string_value = "active")]
+    #[serde(rename = "active")]
+    Active,
+    #[sea_orm(string_value = "released")]
+    #[serde(rename = "released")]
+    Released,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum CustodyHoldAction {
+    #[sea_orm(string_value = "placed")]
+    #[serde(rename = "placed")]
+    Placed,
+    #[sea_orm(string_value = "released")]
+    #[serde(rename = "released")]
+    Released,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum KeyRotationStatus {
+    #[sea_orm(string_value = "completed")]
+    #[serde(rename = "completed")]
+    Completed,
+    #[sea_orm(string_value = "partial")]
+    #[serde(rename = "partial")]
+    Partial,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum KeyExportStatus {
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[sea_orm(string_value = "downloaded")]
+    #[serde(rename = "downloaded")]
+    Downloaded,
+    #[sea_orm(string_value = "cancelled")]
+    #[serde(rename = "cancelled")]
+    Cancelled,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum KeystoreAction {
+    #[sea_orm(string_value = "imported")]
+    #[serde(rename = "imported")]
+    Imported,
+    #[sea_orm(string_value = "export_requested")]
+    #[serde(rename = "export_requested")]
+    ExportRequested,
+    #[sea_orm(string_value = "export_downloaded")]
+    #[serde(rename = "export_downloaded")]
+    ExportDownloaded,
+    #[sea_orm(string_value = "export_cancelled")]
+    #[serde(rename = "export_cancelled")]
+    ExportCancelled,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum WebhookEventType {
+    #[sea_orm(string_value = "deposit.detected")]
+    #[serde(rename = "deposit.detected")]
+    DepositDetected,
+    #[sea_orm(string_value = "deposit.confirmed")]
+    #[serde(rename = "deposit.confirmed")]
+    DepositConfirmed,
+    #[sea_orm(string_value = "withdrawal.updated")]
+    #[serde(rename = "withdrawal.updated")]
+    WithdrawalUpdated,
+    #[sea_orm(string_value = "swap.completed")]
+    #[serde(rename = "swap.completed")]
+    SwapCompleted,
+    #[sea_orm(string_value = "sweep.completed")]
+    #[serde(rename = "sweep.completed")]
+    SweepCompleted,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum WebhookDeliveryStatus {
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[sea_orm(string_value = "delivering")]
+    #[serde(rename = "delivering")]
+    Delivering,
+    #[sea_orm(string_value = "delivered")]
+    #[serde(rename = "delivered")]
+    Delivered,
+    #[sea_orm(string_value = "dead_lettered")]
+    #[serde(rename = "dead_lettered")]
+    DeadLettered,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum JobTrigger {
+    #[sea_orm(string_value = "schedule")]
+    #[serde(rename = "schedule")]
+    Schedule,
+    #[sea_orm(string_value = "manual")]
+    #[serde(rename = "manual")]
+    Manual,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum JobRunStatus {
+    #[sea_orm(string_value = "running")]
+    #[serde(rename = "running")]
+    Running,
+    #[sea_orm(string_value = "succeeded")]
+    #[serde(rename = "succeeded")]
+    Succeeded,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum OutboxStatus {
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[sea_orm(string_value = "publishing")]
+    #[serde(rename = "publishing")]
+    Publishing,
+    #[sea_orm(string_value = "published")]
+    #[serde(rename = "published")]
+    Published,
+    #[sea_orm(string_value = "dead_lettered")]
+    #[serde(rename = "dead_lettered")]
+    DeadLettered,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum RiskScreeningDecision {
+    #[sea_orm(string_value = "allow")]
+    #[serde(rename = "allow")]
+    Allow,
+    #[sea_orm(string_value = "manual_review")]
+    #[serde(rename = "manual_review")]
+    ManualReview,
+    #[sea_orm(string_value = "deny")]
+    #[serde(rename = "deny")]
+    Deny,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum BalanceEntryType {
+    #[sea_orm(string_value = "deposit")]
+    #[serde(rename = "deposit")]
+    Deposit,
+    #[sea_orm(string_value = "withdrawal")]
+    #[serde(rename = "withdrawal")]
+    Withdrawal,
+    #[sea_orm(string_value = "withdrawal_reversal")]
+    #[serde(rename = "withdrawal_reversal")]
+    WithdrawalReversal,
+    #[sea_orm(string_value = "convert_out")]
+    #[serde(rename = "convert_out")]
+    ConvertOut,
+    #[sea_orm(string_value = "convert_in")]
+    #[serde(rename = "convert_in")]
+    ConvertIn,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum WithdrawalStatus {
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[sea_orm(string_value = "sent")]
+    #[serde(rename = "sent")]
+    Sent,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
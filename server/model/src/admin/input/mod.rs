@@ -1,23 +1,75 @@
 pub use sys_access_key::{AccessKeyPageRequest, CreateAccessKeyInput};
+pub use sys_asset::{AssetInput, AssetPageRequest, CreateAssetInput, UpdateAssetInput};
 pub use sys_authentication::LoginInput;
+pub use sys_auto_convert::{
+    AutoConvertJobPageRequest, DepositNotificationInput, UpsertAutoConvertPolicyInput,
+};
 pub use sys_authorization::{AssignPermissionDto, AssignRouteDto, AssignUserDto};
+pub use sys_custody_hold::{
+    CustodyHoldPageRequest, PlaceCustodyHoldInput, ReleaseCustodyHoldInput,
+};
 pub use sys_domain::{CreateDomainInput, DomainPageRequest, UpdateDomainInput};
 pub use sys_endpoint::EndpointPageRequest;
+pub use sys_job::JobRunPageRequest;
+pub use sys_key_rotation::{KeyRotationPageRequest, RotateWalletKeyInput};
+pub use sys_keystore::{
+    DownloadKeyExportInput, ImportKeyInput, ImportKeystoreInput, KeyExportPageRequest,
+    RequestKeyExportInput, UpsertKeyExportPolicyInput,
+};
 pub use sys_login_log::LoginLogPageRequest;
+pub use sys_lookup_table::{
+    CreateLookupTableInput, ExtendLookupTableInput, LookupTablePageRequest,
+    UpdateLookupTableStatusInput,
+};
 pub use sys_menu::{CreateMenuInput, UpdateMenuInput};
+pub use sys_mint_admin::{CreateMintOperationInput, MintOperationPageRequest, MintQuery};
 pub use sys_operation_log::OperationLogPageRequest;
 pub use sys_organization::OrganizationPageRequest;
+pub use sys_payment_invoice::{CreatePaymentInvoiceInput, PaymentInvoicePageRequest};
+pub use sys_payout::{PayoutBatchPageRequest, PayoutRowPageRequest};
+pub use sys_rent_reclamation::{RentReclamationPageRequest, RunRentReclamationInput};
+pub use sys_reserves::{
+    CreateReservesSnapshotInput, ReservesPageRequest, ReservesProofQuery, ReservesReportQuery,
+};
 pub use sys_role::{CreateRoleInput, RolePageRequest, UpdateRoleInput};
+pub use sys_stake::{
+    CreateStakeAccountInput, StakeAccountPageRequest, StakeRewardPageRequest,
+    SyncStakeRewardsInput,
+};
 pub use sys_user::{CreateUserInput, UpdateUserInput, UserPageRequest};
+pub use sys_webhook::{
+    CreateWebhookEndpointInput, UpdateWebhookEndpointInput, WebhookDeliveryPageRequest,
+    WebhookEndpointPageRequest,
+};
+pub use sys_withdrawal_fee::{
+    CreateWithdrawalFeeInput, UpdateWithdrawalFeeInput, WithdrawInput, WithdrawalFeeInput,
+    WithdrawalFeePageRequest, WithdrawalFeeQuoteQuery, WithdrawalFeeRecordPageRequest,
+    WithdrawalFeeTierInput,
+};
 
 mod sys_access_key;
+mod sys_asset;
 mod sys_authentication;
 mod sys_authorization;
+mod sys_auto_convert;
+mod sys_custody_hold;
 mod sys_domain;
 mod sys_endpoint;
+mod sys_job;
+mod sys_key_rotation;
+mod sys_keystore;
 mod sys_login_log;
+mod sys_lookup_table;
 mod sys_menu;
+mod sys_mint_admin;
 mod sys_operation_log;
 mod sys_organization;
+mod sys_payment_invoice;
+mod sys_payout;
+mod sys_rent_reclamation;
+mod sys_reserves;
 mod sys_role;
+mod sys_stake;
 mod sys_user;
+mod sys_webhook;
+mod sys_withdrawal_fee;
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::{AssetTokenProgram, Status};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssetPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub keywords: Option<String>,
+}
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetInput {
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Mint must be a base58 encoded public key"
+    ))]
+    pub mint: String,
+    #[validate(length(
+        min = 1,
+        max = 20,
+        message = "Symbol must be between 1 and 20 characters"
+    ))]
+    pub symbol: String,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    #[validate(range(min = 0, max = 18, message = "Decimals must be between 0 and 18"))]
+    pub decimals: i32,
+    pub token_program: AssetTokenProgram,
+    /// 单笔最小提现数量（最小单位）
+    #[validate(range(min = 0, message = "Minimum withdrawal must not be negative"))]
+    pub min_withdrawal: i64,
+    /// 单笔最大提现数量（最小单位）
+    #[validate(range(min = 1, message = "Maximum withdrawal must be positive"))]
+    pub max_withdrawal: i64,
+    /// 提现手续费（最小单位）
+    #[validate(range(min = 0, message = "Withdrawal fee must not be negative"))]
+    pub withdrawal_fee: i64,
+    pub deposit_enabled: bool,
+    pub withdrawal_enabled: bool,
+    pub status: Status,
+}
+
+pub type CreateAssetInput = AssetInput;
+
+#[derive(Deserialize, Validate)]
+pub struct UpdateAssetInput {
+    pub id: String,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub asset: AssetInput,
+}
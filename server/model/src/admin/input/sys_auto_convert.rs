@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::{AutoConvertJobStatus, Status};
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertAutoConvertPolicyInput {
+    /// 触发兑换的入金币种，如 SOL 原生 mint 与 USDC
+    #[validate(length(min = 1, message = "At least one source mint is required"))]
+    pub source_mints: Vec<String>,
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Stablecoin mint must be a base58 encoded public key"
+    ))]
+    pub stablecoin_mint: String,
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Target token mint must be a base58 encoded public key"
+    ))]
+    pub target_token_mint: String,
+    /// 兑换比例（万分比）
+    #[validate(range(min = 1, max = 10000, message = "Convert bps must be between 1 and 10000"))]
+    pub convert_bps: i32,
+    #[validate(range(min = 0, message = "Minimum trade amount must not be negative"))]
+    pub min_trade_amount: i64,
+    /// 最大滑点（万分比）
+    #[validate(range(min = 0, max = 5000, message = "Max slippage bps must be between 0 and 5000"))]
+    pub max_slippage_bps: i32,
+    pub dry_run: bool,
+    #[validate(range(min = 0, max = 10, message = "Max retries must be between 0 and 10"))]
+    pub max_retries: i32,
+    pub status: Status,
+}
+
+/// 入金通知，由入金监听方上报
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositNotificationInput {
+    /// 入金唯一标识，通常为入金交易签名
+    #[validate(length(min = 1, max = 128, message = "Deposit id must not be empty"))]
+    pub deposit_id: String,
+    pub user_id: Option<String>,
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Mint must be a base58 encoded public key"
+    ))]
+    pub mint: String,
+    #[validate(range(min = 1, message = "Amount must be positive"))]
+    pub amount: i64,
+    /// 入金交易中的备注，为空且未指定用户时从链上读取
+    #[validate(length(max = 256, message = "Memo must not exceed 256 characters"))]
+    pub memo: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutoConvertJobPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub status: Option<AutoConvertJobStatus>,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::{CustodyHoldSource, CustodyHoldStatus};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustodyHoldPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub user_id: Option<String>,
+    pub source: Option<CustodyHoldSource>,
+    pub status: Option<CustodyHoldStatus>,
+}
+
+/// 冻结用户的托管钱包
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceCustodyHoldInput {
+    #[validate(length(min = 1, message = "User id must not be empty"))]
+    pub user_id: String,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters"
+    ))]
+    pub reason: String,
+    /// 有效期（小时），为空表示直到人工解除
+    #[validate(range(
+        min = 1,
+        max = 8760,
+        message = "Expires in hours must be between 1 and 8760"
+    ))]
+    pub expires_in_hours: Option<i64>,
+}
+
+/// 解除冻结
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseCustodyHoldInput {
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters"
+    ))]
+    pub reason: String,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+
+use crate::admin::entities::sea_orm_active_enums::{JobRunStatus, JobTrigger};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobRunPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub job_name: Option<String>,
+    pub trigger: Option<JobTrigger>,
+    pub status: Option<JobRunStatus>,
+}
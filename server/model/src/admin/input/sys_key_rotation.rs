@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::KeyRotationStatus;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyRotationPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub wallet_id: Option<String>,
+    pub status: Option<KeyRotationStatus>,
+}
+
+/// 轮换托管钱包的密钥
+///
+/// 旧私钥从密钥库解密后签署迁移交易，新私钥加密落库，不经接口传递；
+/// 钱包已轮换过时继续把剩余资产迁移到上次生成的新钱包
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RotateWalletKeyInput {
+    #[validate(length(min = 1, message = "Wallet id must not be empty"))]
+    pub wallet_id: String,
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::KeyExportStatus;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyExportPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub user_id: Option<String>,
+    pub status: Option<KeyExportStatus>,
+}
+
+/// 私钥导出策略
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertKeyExportPolicyInput {
+    pub allow_export: bool,
+    /// 申请后需等待的小时数
+    #[validate(range(
+        min = 0,
+        max = 720,
+        message = "Cooling hours must be between 0 and 720"
+    ))]
+    pub cooling_hours: i32,
+    /// 冷静期结束后可下载的小时数
+    #[validate(range(
+        min = 1,
+        max = 168,
+        message = "Download window hours must be between 1 and 168"
+    ))]
+    pub download_window_hours: i32,
+}
+
+/// 批量导入已有密钥对
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportKeystoreInput {
+    #[validate(nested)]
+    pub keys: Vec<ImportKeyInput>,
+}
+
+/// 导入的单个密钥对
+///
+/// 地址已登记时只补充密钥，未登记时按 `user_id` 新建托管钱包
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportKeyInput {
+    pub user_id: Option<String>,
+    /// 私钥（base58）
+    #[validate(length(min = 1, message = "Private key must not be empty"))]
+    pub private_key: String,
+}
+
+/// 申请导出自己的托管钱包私钥，需重新输入登录密码
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestKeyExportInput {
+    #[validate(length(min = 1, message = "Password must not be empty"))]
+    pub password: String,
+}
+
+/// 下载导出的私钥
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadKeyExportInput {
+    #[validate(length(min = 1, message = "Password must not be empty"))]
+    pub password: String,
+    /// 申请时返回的一次性下载凭证
+    #[validate(length(min = 1, message = "Download token must not be empty"))]
+    pub token: String,
+    /// 加密导出文件的口令，由用户自行保管
+    #[validate(length(
+        min = 12,
+        max = 256,
+        message = "Passphrase must be between 12 and 256 characters"
+    ))]
+    pub passphrase: String,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::Status;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LookupTablePageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub status: Option<Status>,
+}
+
+/// 创建或扩展查找表
+///
+/// 热钱包、系统钱包、启用资产的 mint 及其关联 Token 账户总会写入，
+/// `addresses` 用于追加其他常用地址
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendLookupTableInput {
+    #[serde(default)]
+    #[validate(length(max = 256, message = "At most 256 addresses can be added"))]
+    pub addresses: Vec<String>,
+}
+
+pub type CreateLookupTableInput = ExtendLookupTableInput;
+
+#[derive(Deserialize, Validate)]
+pub struct UpdateLookupTableStatusInput {
+    pub id: String,
+    pub status: Status,
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::{
+    MintAuthorityType, MintOperationStatus, MintOperationType,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintOperationPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub mint: Option<String>,
+    pub status: Option<MintOperationStatus>,
+}
+
+/// 发起 mint 管理操作
+///
+/// `target` 对增发是接收钱包，对冻结/解冻是 Token 账户，对转移权限是新的权限地址（为空表示放弃权限）
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMintOperationInput {
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Mint must be a base58 encoded public key"
+    ))]
+    pub mint: String,
+    pub operation_type: MintOperationType,
+    pub target: Option<String>,
+    /// 增发或销毁数量（最小单位）
+    #[validate(range(min = 1, message = "Amount must be positive"))]
+    pub amount: Option<i64>,
+    pub authority_type: Option<MintAuthorityType>,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters"
+    ))]
+    pub reason: String,
+    /// 所需审批人数，不含发起人
+    #[validate(range(min = 2, max = 10, message = "Required approvals must be between 2 and 10"))]
+    pub required_approvals: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MintQuery {
+    pub mint: String,
+}
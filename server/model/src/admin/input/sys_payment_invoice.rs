@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::PaymentInvoiceStatus;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentInvoicePageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub user_id: Option<String>,
+    pub status: Option<PaymentInvoiceStatus>,
+}
+
+/// 为用户的托管钱包创建收款单
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePaymentInvoiceInput {
+    #[validate(length(min = 1, message = "User id must not be empty"))]
+    pub user_id: String,
+    /// 收款 mint，为空表示 SOL
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Mint must be a base58 encoded public key"
+    ))]
+    pub mint: Option<String>,
+    /// 应收数量（最小单位）
+    #[validate(range(min = 1, message = "Amount must be positive"))]
+    pub amount: i64,
+    #[validate(length(max = 100, message = "Label must not exceed 100 characters"))]
+    pub label: Option<String>,
+    #[validate(length(max = 200, message = "Message must not exceed 200 characters"))]
+    pub message: Option<String>,
+    /// 有效期（分钟），默认 60
+    #[validate(range(
+        min = 1,
+        max = 10080,
+        message = "Expires in minutes must be between 1 and 10080"
+    ))]
+    pub expires_in_minutes: Option<i64>,
+}
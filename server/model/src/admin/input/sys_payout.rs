@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+
+use crate::admin::entities::sea_orm_active_enums::{PayoutBatchStatus, PayoutRowStatus};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PayoutBatchPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub status: Option<PayoutBatchStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PayoutRowPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub status: Option<PayoutRowStatus>,
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::RentReclamationStatus;
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RunRentReclamationInput {
+    /// 闲置天数，支持的 mint 超过该天数无活动的空账户才会关闭
+    #[validate(range(min = 1, max = 3650, message = "Idle days must be between 1 and 3650"))]
+    pub idle_days: u32,
+    /// 额外保留的 mint，资产登记表、系统配置与自动兑换策略中的 mint 默认保留
+    #[serde(default)]
+    pub supported_mints: Vec<String>,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RentReclamationPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub wallet_address: Option<String>,
+    pub status: Option<RentReclamationStatus>,
+}
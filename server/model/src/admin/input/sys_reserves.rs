@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReservesPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub keywords: Option<String>,
+}
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReservesSnapshotInput {
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Mint must be a base58 encoded public key"
+    ))]
+    pub mint: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservesReportQuery {
+    /// 报告格式: json / csv，默认 json
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservesProofQuery {
+    pub mint: String,
+    /// 为空时取该 mint 最新快照
+    pub snapshot_id: Option<String>,
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::StakeAccountStatus;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeAccountPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub user_id: Option<String>,
+    pub status: Option<StakeAccountStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeRewardPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub user_id: Option<String>,
+    pub stake_account_id: Option<String>,
+    pub epoch: Option<i64>,
+}
+
+/// 从系统钱包出资创建质押账户
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStakeAccountInput {
+    /// 奖励归属的用户，为空表示系统自有质押
+    #[validate(length(min = 1, message = "User id must not be empty"))]
+    pub user_id: Option<String>,
+    /// 验证者投票账户，须在配置的验证者列表中
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Vote account must be a base58 encoded public key"
+    ))]
+    pub vote_account: String,
+    /// 委托数量（lamports），不含租金储备
+    #[validate(range(min = 1, message = "Lamports must be positive"))]
+    pub lamports: i64,
+}
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncStakeRewardsInput {
+    /// 拉取奖励的 epoch，为空时为上一个 epoch
+    #[validate(range(min = 0, message = "Epoch must not be negative"))]
+    pub epoch: Option<i64>,
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::{
+    Status, WebhookDeliveryStatus, WebhookEventType,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEndpointPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub status: Option<Status>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDeliveryPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub endpoint_id: Option<String>,
+    pub event_type: Option<WebhookEventType>,
+    /// 传 `dead_lettered` 查询死信
+    pub status: Option<WebhookDeliveryStatus>,
+}
+
+/// 登记接收通知的端点，签名密钥由服务端生成
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhookEndpointInput {
+    #[validate(url(message = "Url must be a valid URL"))]
+    pub url: String,
+    #[validate(length(min = 1, message = "At least one event type is required"))]
+    pub events: Vec<WebhookEventType>,
+    #[validate(length(max = 500, message = "Description must not exceed 500 characters"))]
+    pub description: Option<String>,
+}
+
+/// 修改端点
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWebhookEndpointInput {
+    #[validate(url(message = "Url must be a valid URL"))]
+    pub url: String,
+    #[validate(length(min = 1, message = "At least one event type is required"))]
+    pub events: Vec<WebhookEventType>,
+    pub status: Status,
+    #[validate(length(max = 500, message = "Description must not exceed 500 characters"))]
+    pub description: Option<String>,
+    /// 重新生成签名密钥，旧密钥立即失效
+    #[serde(default)]
+    pub rotate_secret: bool,
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::{Status, WithdrawalFeeType};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WithdrawalFeePageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub mint: Option<String>,
+}
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalFeeTierInput {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Role code must be between 1 and 50 characters"
+    ))]
+    pub role_code: String,
+    /// 固定费用（最小单位）
+    #[validate(range(min = 0, message = "Flat fee must not be negative"))]
+    pub flat_fee: i64,
+    /// 比例费用（基点）
+    #[validate(range(
+        min = 0,
+        max = 10000,
+        message = "Percentage must be between 0 and 10000 bps"
+    ))]
+    pub percentage_bps: i32,
+}
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalFeeInput {
+    /// 为空时作为域内所有资产的默认规则
+    pub mint: Option<String>,
+    pub fee_type: WithdrawalFeeType,
+    /// 固定费用（最小单位），固定计费与分档计费的基础费率使用
+    #[validate(range(min = 0, message = "Flat fee must not be negative"))]
+    pub flat_fee: i64,
+    /// 比例费用（基点），按比例计费与分档计费的基础费率使用
+    #[validate(range(
+        min = 0,
+        max = 10000,
+        message = "Percentage must be between 0 and 10000 bps"
+    ))]
+    pub percentage_bps: i32,
+    #[validate(range(min = 0, message = "Minimum fee must not be negative"))]
+    pub min_fee: i64,
+    #[validate(range(min = 0, message = "Maximum fee must not be negative"))]
+    pub max_fee: Option<i64>,
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Fee wallet must be a base58 encoded public key"
+    ))]
+    pub fee_wallet: String,
+    pub status: Status,
+    /// 按角色分档的费率，仅分档计费使用
+    #[serde(default)]
+    #[validate(nested)]
+    pub tiers: Vec<WithdrawalFeeTierInput>,
+}
+
+pub type CreateWithdrawalFeeInput = WithdrawalFeeInput;
+
+#[derive(Deserialize, Validate)]
+pub struct UpdateWithdrawalFeeInput {
+    pub id: String,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub fee: WithdrawalFeeInput,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalFeeQuoteQuery {
+    pub mint: String,
+    /// 提现金额（最小单位），手续费从中扣除
+    pub amount: u64,
+}
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawInput {
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Mint must be a base58 encoded public key"
+    ))]
+    pub mint: String,
+    /// 提现金额（最小单位），手续费从中扣除
+    #[validate(range(min = 1, message = "Amount must be positive"))]
+    pub amount: u64,
+    #[validate(length(
+        min = 32,
+        max = 44,
+        message = "Destination must be a base58 encoded public key"
+    ))]
+    pub destination: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalFeeRecordPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub user_id: Option<String>,
+    pub mint: Option<String>,
+}
@@ -1,11 +1,35 @@
 pub use sys_authentication::{AuthOutput, UserInfoOutput, UserRoute};
+pub use sys_custody_hold::CustodyHoldOutput;
 pub use sys_domain::DomainOutput;
 pub use sys_endpoint::EndpointTree;
+pub use sys_job::JobOutput;
+pub use sys_keystore::{ImportKeyResultOutput, KeyExportOutput, KeyExportTicketOutput};
+pub use sys_lookup_table::LookupTableOutput;
 pub use sys_menu::{MenuRoute, MenuTree, RouteMeta};
+pub use sys_mint_admin::{MintHolderCountOutput, MintOperationOutput, MintSupplyOutput};
+pub use sys_payment_invoice::PaymentInvoiceOutput;
+pub use sys_payout::{PayoutBatchOutput, PayoutTotalOutput};
+pub use sys_rent_reclamation::RentReclaimedWalletOutput;
+pub use sys_reserves::{ReservesProofOutput, ReservesProofStep, ReservesReportOutput};
+pub use sys_stake::StakeRewardSyncOutput;
 pub use sys_user::{UserWithDomainAndOrgOutput, UserWithoutPassword};
+pub use sys_webhook::WebhookEndpointOutput;
+pub use sys_withdrawal_fee::{WithdrawalFeeOutput, WithdrawalFeeQuoteOutput};
 
 mod sys_authentication;
+mod sys_custody_hold;
 mod sys_domain;
 mod sys_endpoint;
+mod sys_job;
+mod sys_keystore;
+mod sys_lookup_table;
 mod sys_menu;
+mod sys_mint_admin;
+mod sys_payment_invoice;
+mod sys_payout;
+mod sys_rent_reclamation;
+mod sys_reserves;
+mod sys_stake;
 mod sys_user;
+mod sys_webhook;
+mod sys_withdrawal_fee;
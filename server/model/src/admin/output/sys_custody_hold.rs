@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+use crate::admin::entities::{sys_custody_hold, sys_custody_hold_audit};
+
+/// 冻结详情及审计记录
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustodyHoldOutput {
+    #[serde(flatten)]
+    pub hold: sys_custody_hold::Model,
+    pub audits: Vec<sys_custody_hold_audit::Model>,
+}
@@ -0,0 +1,19 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+use crate::admin::entities::sys_job_run;
+
+/// 已注册的后台任务
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobOutput {
+    pub name: String,
+    pub description: String,
+    /// cron 表达式（含秒）
+    pub schedule: String,
+    pub paused: bool,
+    /// 是否正在某个实例上执行
+    pub running: bool,
+    pub next_run_at: Option<NaiveDateTime>,
+    pub last_run: Option<sys_job_run::Model>,
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::admin::entities::{sys_key_export, sys_keystore_audit};
+
+/// 导出申请详情及审计记录
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyExportOutput {
+    #[serde(flatten)]
+    pub export: sys_key_export::Model,
+    pub audits: Vec<sys_keystore_audit::Model>,
+}
+
+/// 导出申请结果
+///
+/// 下载凭证只在申请时返回一次，库中只保存哈希
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyExportTicketOutput {
+    #[serde(flatten)]
+    pub export: sys_key_export::Model,
+    pub download_token: String,
+}
+
+/// 单个密钥的导入结果
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportKeyResultOutput {
+    /// 密钥对应的地址，私钥无法解析时为空
+    pub address: Option<String>,
+    pub wallet_id: Option<String>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+use crate::admin::entities::sys_lookup_table;
+
+/// 查找表及链上当前包含的地址
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LookupTableOutput {
+    #[serde(flatten)]
+    pub lookup_table: sys_lookup_table::Model,
+    pub addresses: Vec<String>,
+}
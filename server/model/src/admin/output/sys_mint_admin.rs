@@ -0,0 +1,31 @@
+use serde::Serialize;
+
+use crate::admin::entities::{sys_mint_operation, sys_mint_operation_audit};
+
+/// 操作详情及审计记录
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintOperationOutput {
+    #[serde(flatten)]
+    pub operation: sys_mint_operation::Model,
+    pub audits: Vec<sys_mint_operation_audit::Model>,
+}
+
+/// mint 的供应量与权限
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintSupplyOutput {
+    pub mint: String,
+    pub supply: u64,
+    pub decimals: u8,
+    pub mint_authority: Option<String>,
+    pub freeze_authority: Option<String>,
+}
+
+/// mint 的持有人数，按余额大于零的 Token 账户计
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintHolderCountOutput {
+    pub mint: String,
+    pub holders: u64,
+}
@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+use crate::admin::entities::sys_payment_invoice;
+
+/// 收款单及其 Solana Pay 转账请求
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentInvoiceOutput {
+    #[serde(flatten)]
+    pub invoice: sys_payment_invoice::Model,
+    /// `solana:` 转账请求 URL
+    pub url: String,
+    /// URL 的 SVG 二维码
+    pub qr_svg: String,
+}
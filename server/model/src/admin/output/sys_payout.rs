@@ -0,0 +1,22 @@
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+
+use crate::admin::entities::sys_payout_batch;
+
+/// 批次内单个 mint 的发放合计
+#[derive(Clone, Debug, Serialize, FromQueryResult)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutTotalOutput {
+    pub mint: String,
+    pub rows: i64,
+    pub amount: i64,
+}
+
+/// 批次详情及按 mint 汇总的发放数量
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutBatchOutput {
+    #[serde(flatten)]
+    pub batch: sys_payout_batch::Model,
+    pub totals: Vec<PayoutTotalOutput>,
+}
@@ -0,0 +1,11 @@
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+
+/// 单个托管钱包累计回收的租金
+#[derive(Clone, Debug, Serialize, FromQueryResult)]
+#[serde(rename_all = "camelCase")]
+pub struct RentReclaimedWalletOutput {
+    pub wallet_address: String,
+    pub accounts: i64,
+    pub lamports: i64,
+}
@@ -0,0 +1,35 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservesProofStep {
+    pub hash: String,
+    pub sum: u64,
+    pub is_left: bool,
+}
+
+/// 用户包含性证明，叶子盐即快照 ID
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservesProofOutput {
+    pub snapshot_id: String,
+    pub mint: String,
+    pub slot: u64,
+    pub user_id: String,
+    pub amount: u64,
+    pub leaf_index: usize,
+    pub steps: Vec<ReservesProofStep>,
+    pub root_hash: String,
+    pub root_sum: u64,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservesReportOutput {
+    pub format: String,
+    pub content: String,
+    pub signer: String,
+    pub signature: String,
+}
@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+/// 一次奖励拉取的结果
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeRewardSyncOutput {
+    pub epoch: i64,
+    /// 查询的质押账户数
+    pub accounts: u64,
+    /// 新记入的奖励条数
+    pub rewards: u64,
+    /// 新记入的奖励总额（lamports）
+    pub amount: i64,
+}
@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+use crate::admin::entities::sys_webhook_endpoint;
+
+/// 端点信息
+///
+/// 签名密钥只在创建或重新生成时返回一次
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEndpointOutput {
+    #[serde(flatten)]
+    pub endpoint: sys_webhook_endpoint::Model,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+use crate::admin::entities::{sys_withdrawal_fee, sys_withdrawal_fee_tier};
+
+/// 手续费规则及其角色档位
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalFeeOutput {
+    #[serde(flatten)]
+    pub fee: sys_withdrawal_fee::Model,
+    pub tiers: Vec<sys_withdrawal_fee_tier::Model>,
+}
+
+/// 提现前展示给用户的手续费报价
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalFeeQuoteOutput {
+    pub mint: String,
+    pub amount: u64,
+    pub fee: u64,
+    pub net_amount: u64,
+    /// 命中的角色档位
+    pub tier: Option<String>,
+    /// 适用的手续费规则，为空表示使用资产的默认手续费
+    pub fee_id: Option<String>,
+    pub fee_wallet: String,
+}
@@ -1,23 +1,55 @@
 pub use sys_access_key_route::SysAccessKeyRouter;
+pub use sys_asset_route::SysAssetRouter;
 pub use sys_authentication_route::SysAuthenticationRouter;
+pub use sys_auto_convert_route::SysAutoConvertRouter;
+pub use sys_custody_hold_route::SysCustodyHoldRouter;
 pub use sys_domain_route::SysDomainRouter;
 pub use sys_endpoint_route::SysEndpointRouter;
+pub use sys_job_route::SysJobRouter;
+pub use sys_key_rotation_route::SysKeyRotationRouter;
+pub use sys_keystore_route::SysKeystoreRouter;
 pub use sys_login_log_route::SysLoginLogRouter;
+pub use sys_lookup_table_route::SysLookupTableRouter;
 pub use sys_menu_route::SysMenuRouter;
+pub use sys_mint_admin_route::SysMintAdminRouter;
 pub use sys_operation_log_route::SysOperationLogRouter;
 pub use sys_organization_route::SysOrganizationRouter;
+pub use sys_payment_invoice_route::SysPaymentInvoiceRouter;
+pub use sys_payout_route::SysPayoutRouter;
+pub use sys_rent_reclamation_route::SysRentReclamationRouter;
+pub use sys_reserves_route::SysReservesRouter;
 pub use sys_role_route::SysRoleRouter;
 pub use sys_sandbox_route::SysSandboxRouter;
+pub use sys_solana_route::SysSolanaRouter;
+pub use sys_stake_route::SysStakeRouter;
 pub use sys_user_route::SysUserRouter;
+pub use sys_webhook_route::SysWebhookRouter;
+pub use sys_withdrawal_fee_route::SysWithdrawalFeeRouter;
 
 mod sys_access_key_route;
+mod sys_asset_route;
 mod sys_authentication_route;
+mod sys_auto_convert_route;
+mod sys_custody_hold_route;
 mod sys_domain_route;
 mod sys_endpoint_route;
+mod sys_job_route;
+mod sys_key_rotation_route;
+mod sys_keystore_route;
 mod sys_login_log_route;
+mod sys_lookup_table_route;
 mod sys_menu_route;
+mod sys_mint_admin_route;
 mod sys_operation_log_route;
 mod sys_organization_route;
+mod sys_payment_invoice_route;
+mod sys_payout_route;
+mod sys_rent_reclamation_route;
+mod sys_reserves_route;
 mod sys_role_route;
 mod sys_sandbox_route;
+mod sys_solana_route;
+mod sys_stake_route;
 mod sys_user_route;
+mod sys_webhook_route;
+mod sys_withdrawal_fee_route;
@@ -0,0 +1,63 @@
+use axum::{
+    http::Method,
+    routing::{delete, get, post, put},
+    Router,
+};
+use server_api::admin::SysAssetApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysAssetRouter;
+
+impl SysAssetRouter {
+    pub async fn init_asset_router() -> Router {
+        let base_path = "/asset";
+        let service_name = "SysAssetApi";
+
+        let routes = vec![
+            RouteInfo::new(base_path, Method::GET, service_name, "获取资产列表"),
+            RouteInfo::new(base_path, Method::POST, service_name, "创建资产"),
+            RouteInfo::new(
+                &format!("{}/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取资产详情",
+            ),
+            RouteInfo::new(base_path, Method::PUT, service_name, "更新资产"),
+            RouteInfo::new(
+                &format!("{}/:id", base_path),
+                Method::DELETE,
+                service_name,
+                "删除资产",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/icon", base_path),
+                Method::POST,
+                service_name,
+                "上传资产图标",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/icon", base_path),
+                Method::GET,
+                service_name,
+                "获取资产图标访问链接",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/", get(SysAssetApi::get_paginated_assets))
+            .route("/", post(SysAssetApi::create_asset))
+            .route("/{id}", get(SysAssetApi::get_asset))
+            .route("/", put(SysAssetApi::update_asset))
+            .route("/{id}", delete(SysAssetApi::delete_asset))
+            .route(
+                "/{id}/icon",
+                get(SysAssetApi::get_icon_url).post(SysAssetApi::upload_icon),
+            );
+
+        Router::new().nest(base_path, router)
+    }
+}
@@ -0,0 +1,78 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysAutoConvertApi;
+use server_core::web::IdempotencyLayer;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysAutoConvertRouter;
+
+impl SysAutoConvertRouter {
+    pub async fn init_auto_convert_router() -> Router {
+        let base_path = "/auto-convert";
+        let service_name = "SysAutoConvertApi";
+
+        let routes = vec![
+            RouteInfo::new(
+                &format!("{}/policy", base_path),
+                Method::GET,
+                service_name,
+                "获取自动兑换策略",
+            ),
+            RouteInfo::new(
+                &format!("{}/policy", base_path),
+                Method::PUT,
+                service_name,
+                "保存自动兑换策略",
+            ),
+            RouteInfo::new(
+                &format!("{}/deposit", base_path),
+                Method::POST,
+                service_name,
+                "上报入金并触发自动兑换",
+            ),
+            RouteInfo::new(
+                &format!("{}/jobs", base_path),
+                Method::GET,
+                service_name,
+                "获取自动兑换任务列表",
+            ),
+            RouteInfo::new(
+                &format!("{}/jobs/:id/legs", base_path),
+                Method::GET,
+                service_name,
+                "获取自动兑换任务各腿记录",
+            ),
+            RouteInfo::new(
+                &format!("{}/jobs/:id/retry", base_path),
+                Method::POST,
+                service_name,
+                "重试失败的自动兑换任务",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route(
+                "/policy",
+                get(SysAutoConvertApi::get_policy).put(SysAutoConvertApi::upsert_policy),
+            )
+            .route(
+                "/deposit",
+                post(SysAutoConvertApi::notify_deposit).layer(IdempotencyLayer::default()),
+            )
+            .route("/jobs", get(SysAutoConvertApi::get_paginated_jobs))
+            .route("/jobs/{id}/legs", get(SysAutoConvertApi::get_job_legs))
+            .route(
+                "/jobs/{id}/retry",
+                post(SysAutoConvertApi::retry_job).layer(IdempotencyLayer::default()),
+            );
+
+        Router::new().nest(base_path, router)
+    }
+}
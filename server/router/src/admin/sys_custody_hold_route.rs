@@ -0,0 +1,45 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysCustodyHoldApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysCustodyHoldRouter;
+
+impl SysCustodyHoldRouter {
+    pub async fn init_custody_hold_router() -> Router {
+        let base_path = "/custody-hold";
+        let service_name = "SysCustodyHoldApi";
+
+        let routes = vec![
+            RouteInfo::new(base_path, Method::GET, service_name, "获取托管冻结列表"),
+            RouteInfo::new(base_path, Method::POST, service_name, "冻结用户托管钱包"),
+            RouteInfo::new(
+                &format!("{}/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取托管冻结及审计记录",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/release", base_path),
+                Method::POST,
+                service_name,
+                "解除托管冻结",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/", get(SysCustodyHoldApi::get_paginated_holds))
+            .route("/", post(SysCustodyHoldApi::place_hold))
+            .route("/{id}", get(SysCustodyHoldApi::get_hold))
+            .route("/{id}/release", post(SysCustodyHoldApi::release_hold));
+
+        Router::new().nest(base_path, router)
+    }
+}
@@ -0,0 +1,64 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysJobApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysJobRouter;
+
+impl SysJobRouter {
+    pub async fn init_job_router() -> Router {
+        let base_path = "/jobs";
+        let service_name = "SysJobApi";
+
+        let routes = vec![
+            RouteInfo::new(base_path, Method::GET, service_name, "获取后台任务列表"),
+            RouteInfo::new(
+                &format!("{}/:name/trigger", base_path),
+                Method::POST,
+                service_name,
+                "立即执行后台任务",
+            ),
+            RouteInfo::new(
+                &format!("{}/:name/pause", base_path),
+                Method::POST,
+                service_name,
+                "暂停后台任务",
+            ),
+            RouteInfo::new(
+                &format!("{}/:name/resume", base_path),
+                Method::POST,
+                service_name,
+                "恢复后台任务",
+            ),
+            RouteInfo::new(
+                &format!("{}/runs", base_path),
+                Method::GET,
+                service_name,
+                "获取后台任务运行记录",
+            ),
+            RouteInfo::new(
+                &format!("{}/runs/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取后台任务运行详情",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/", get(SysJobApi::list_jobs))
+            .route("/{name}/trigger", post(SysJobApi::trigger_job))
+            .route("/{name}/pause", post(SysJobApi::pause_job))
+            .route("/{name}/resume", post(SysJobApi::resume_job))
+            .route("/runs", get(SysJobApi::get_paginated_runs))
+            .route("/runs/{id}", get(SysJobApi::get_run));
+
+        Router::new().nest(base_path, router)
+    }
+}
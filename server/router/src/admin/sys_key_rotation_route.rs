@@ -0,0 +1,50 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysKeyRotationApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysKeyRotationRouter;
+
+impl SysKeyRotationRouter {
+    pub async fn init_key_rotation_router() -> Router {
+        let base_path = "/key-rotation";
+        let service_name = "SysKeyRotationApi";
+
+        let routes = vec![
+            RouteInfo::new(base_path, Method::GET, service_name, "获取密钥轮换记录列表"),
+            RouteInfo::new(
+                &format!("{}/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取密钥轮换记录",
+            ),
+            RouteInfo::new(
+                &format!("{}/wallet", base_path),
+                Method::POST,
+                service_name,
+                "轮换托管钱包密钥并迁移资产",
+            ),
+            RouteInfo::new(
+                &format!("{}/system", base_path),
+                Method::POST,
+                service_name,
+                "轮换系统钱包密钥并迁移资产",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/", get(SysKeyRotationApi::get_paginated_rotations))
+            .route("/{id}", get(SysKeyRotationApi::get_rotation))
+            .route("/wallet", post(SysKeyRotationApi::rotate_wallet_key))
+            .route("/system", post(SysKeyRotationApi::rotate_system_key));
+
+        Router::new().nest(base_path, router)
+    }
+}
@@ -0,0 +1,90 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysKeystoreApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysKeystoreRouter;
+
+impl SysKeystoreRouter {
+    pub async fn init_keystore_router() -> Router {
+        let base_path = "/keystore";
+        let service_name = "SysKeystoreApi";
+
+        let routes = vec![
+            RouteInfo::new(
+                &format!("{}/policy", base_path),
+                Method::GET,
+                service_name,
+                "获取私钥导出策略",
+            ),
+            RouteInfo::new(
+                &format!("{}/policy", base_path),
+                Method::PUT,
+                service_name,
+                "保存私钥导出策略",
+            ),
+            RouteInfo::new(
+                &format!("{}/import", base_path),
+                Method::POST,
+                service_name,
+                "批量导入密钥对到密钥库",
+            ),
+            RouteInfo::new(
+                &format!("{}/exports", base_path),
+                Method::GET,
+                service_name,
+                "获取私钥导出申请列表",
+            ),
+            RouteInfo::new(
+                &format!("{}/exports/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取私钥导出申请及审计记录",
+            ),
+            RouteInfo::new(
+                &format!("{}/exports", base_path),
+                Method::POST,
+                service_name,
+                "申请导出本人托管钱包私钥",
+            ),
+            RouteInfo::new(
+                &format!("{}/exports/:id/download", base_path),
+                Method::POST,
+                service_name,
+                "下载加密的托管钱包私钥",
+            ),
+            RouteInfo::new(
+                &format!("{}/exports/:id/cancel", base_path),
+                Method::POST,
+                service_name,
+                "取消私钥导出申请",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route(
+                "/policy",
+                get(SysKeystoreApi::get_policy).put(SysKeystoreApi::upsert_policy),
+            )
+            .route("/import", post(SysKeystoreApi::import_keys))
+            .route(
+                "/exports",
+                get(SysKeystoreApi::get_paginated_exports).post(SysKeystoreApi::request_export),
+            )
+            .route("/exports/{id}", get(SysKeystoreApi::get_export))
+            .route(
+                "/exports/{id}/download",
+                post(SysKeystoreApi::download_export),
+            )
+            .route("/exports/{id}/cancel", post(SysKeystoreApi::cancel_export));
+
+        Router::new().nest(base_path, router)
+    }
+}
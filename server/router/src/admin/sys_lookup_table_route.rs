@@ -0,0 +1,47 @@
+use axum::{
+    http::Method,
+    routing::{get, post, put},
+    Router,
+};
+use server_api::admin::SysLookupTableApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysLookupTableRouter;
+
+impl SysLookupTableRouter {
+    pub async fn init_lookup_table_router() -> Router {
+        let base_path = "/lookup-table";
+        let service_name = "SysLookupTableApi";
+
+        let routes = vec![
+            RouteInfo::new(base_path, Method::GET, service_name, "获取地址查找表列表"),
+            RouteInfo::new(base_path, Method::POST, service_name, "创建地址查找表"),
+            RouteInfo::new(base_path, Method::PUT, service_name, "启用或停用地址查找表"),
+            RouteInfo::new(
+                &format!("{}/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取地址查找表及链上地址",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/extend", base_path),
+                Method::POST,
+                service_name,
+                "向地址查找表写入常用地址",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/", get(SysLookupTableApi::get_paginated_lookup_tables))
+            .route("/", post(SysLookupTableApi::create_lookup_table))
+            .route("/", put(SysLookupTableApi::update_lookup_table_status))
+            .route("/{id}", get(SysLookupTableApi::get_lookup_table))
+            .route("/{id}/extend", post(SysLookupTableApi::extend_lookup_table));
+
+        Router::new().nest(base_path, router)
+    }
+}
@@ -0,0 +1,95 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysMintAdminApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysMintAdminRouter;
+
+impl SysMintAdminRouter {
+    pub async fn init_mint_admin_router() -> Router {
+        let base_path = "/mint-admin";
+        let service_name = "SysMintAdminApi";
+
+        let routes = vec![
+            RouteInfo::new(
+                &format!("{}/operations", base_path),
+                Method::GET,
+                service_name,
+                "获取 mint 管理操作列表",
+            ),
+            RouteInfo::new(
+                &format!("{}/operations", base_path),
+                Method::POST,
+                service_name,
+                "发起 mint 管理操作",
+            ),
+            RouteInfo::new(
+                &format!("{}/operations/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取 mint 管理操作及审计记录",
+            ),
+            RouteInfo::new(
+                &format!("{}/operations/:id/approve", base_path),
+                Method::POST,
+                service_name,
+                "审批通过 mint 管理操作",
+            ),
+            RouteInfo::new(
+                &format!("{}/operations/:id/reject", base_path),
+                Method::POST,
+                service_name,
+                "拒绝 mint 管理操作",
+            ),
+            RouteInfo::new(
+                &format!("{}/operations/:id/execute", base_path),
+                Method::POST,
+                service_name,
+                "执行已审批的 mint 管理操作",
+            ),
+            RouteInfo::new(
+                &format!("{}/supply", base_path),
+                Method::GET,
+                service_name,
+                "查询 mint 供应量与权限",
+            ),
+            RouteInfo::new(
+                &format!("{}/holders", base_path),
+                Method::GET,
+                service_name,
+                "查询 mint 持有人数",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route(
+                "/operations",
+                get(SysMintAdminApi::get_paginated_operations),
+            )
+            .route("/operations", post(SysMintAdminApi::create_operation))
+            .route("/operations/{id}", get(SysMintAdminApi::get_operation))
+            .route(
+                "/operations/{id}/approve",
+                post(SysMintAdminApi::approve_operation),
+            )
+            .route(
+                "/operations/{id}/reject",
+                post(SysMintAdminApi::reject_operation),
+            )
+            .route(
+                "/operations/{id}/execute",
+                post(SysMintAdminApi::execute_operation),
+            )
+            .route("/supply", get(SysMintAdminApi::get_supply))
+            .route("/holders", get(SysMintAdminApi::get_holder_count));
+
+        Router::new().nest(base_path, router)
+    }
+}
@@ -0,0 +1,50 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysPaymentInvoiceApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysPaymentInvoiceRouter;
+
+impl SysPaymentInvoiceRouter {
+    pub async fn init_payment_invoice_router() -> Router {
+        let base_path = "/payment-invoice";
+        let service_name = "SysPaymentInvoiceApi";
+
+        let routes = vec![
+            RouteInfo::new(base_path, Method::GET, service_name, "获取收款单列表"),
+            RouteInfo::new(
+                base_path,
+                Method::POST,
+                service_name,
+                "创建 Solana Pay 收款单",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取收款单及付款二维码",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/cancel", base_path),
+                Method::POST,
+                service_name,
+                "取消待付款的收款单",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/", get(SysPaymentInvoiceApi::get_paginated_invoices))
+            .route("/", post(SysPaymentInvoiceApi::create_invoice))
+            .route("/{id}", get(SysPaymentInvoiceApi::get_invoice))
+            .route("/{id}/cancel", post(SysPaymentInvoiceApi::cancel_invoice));
+
+        Router::new().nest(base_path, router)
+    }
+}
@@ -0,0 +1,77 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysPayoutApi;
+use server_core::web::IdempotencyLayer;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysPayoutRouter;
+
+impl SysPayoutRouter {
+    pub async fn init_payout_router() -> Router {
+        let base_path = "/payout";
+        let service_name = "SysPayoutApi";
+
+        let routes = vec![
+            RouteInfo::new(base_path, Method::GET, service_name, "获取批量发放批次列表"),
+            RouteInfo::new(base_path, Method::POST, service_name, "上传批量发放文件"),
+            RouteInfo::new(
+                &format!("{}/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取批量发放批次详情",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/rows", base_path),
+                Method::GET,
+                service_name,
+                "获取批量发放明细",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/approve", base_path),
+                Method::POST,
+                service_name,
+                "批准批量发放批次",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/reject", base_path),
+                Method::POST,
+                service_name,
+                "驳回批量发放批次",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/execute", base_path),
+                Method::POST,
+                service_name,
+                "执行批量发放批次",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/report", base_path),
+                Method::GET,
+                service_name,
+                "获取批量发放结果报告下载链接",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/", get(SysPayoutApi::get_paginated_batches))
+            .route("/", post(SysPayoutApi::create_batch))
+            .route("/{id}", get(SysPayoutApi::get_batch))
+            .route("/{id}/rows", get(SysPayoutApi::get_paginated_rows))
+            .route("/{id}/approve", post(SysPayoutApi::approve_batch))
+            .route("/{id}/reject", post(SysPayoutApi::reject_batch))
+            .route(
+                "/{id}/execute",
+                post(SysPayoutApi::execute_batch).layer(IdempotencyLayer::default()),
+            )
+            .route("/{id}/report", get(SysPayoutApi::get_report_url));
+
+        Router::new().nest(base_path, router)
+    }
+}
@@ -0,0 +1,51 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysRentReclamationApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysRentReclamationRouter;
+
+impl SysRentReclamationRouter {
+    pub async fn init_rent_reclamation_router() -> Router {
+        let base_path = "/rent-reclamation";
+        let service_name = "SysRentReclamationApi";
+
+        let routes = vec![
+            RouteInfo::new(
+                &format!("{}/run", base_path),
+                Method::POST,
+                service_name,
+                "关闭空 Token 账户并回收租金",
+            ),
+            RouteInfo::new(
+                &format!("{}/records", base_path),
+                Method::GET,
+                service_name,
+                "获取租金回收记录",
+            ),
+            RouteInfo::new(
+                &format!("{}/wallets", base_path),
+                Method::GET,
+                service_name,
+                "获取各托管钱包累计回收的租金",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/run", post(SysRentReclamationApi::run_reclamation))
+            .route(
+                "/records",
+                get(SysRentReclamationApi::get_paginated_records),
+            )
+            .route("/wallets", get(SysRentReclamationApi::get_wallet_totals));
+
+        Router::new().nest(base_path, router)
+    }
+}
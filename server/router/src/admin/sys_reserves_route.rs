@@ -0,0 +1,61 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysReservesApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysReservesRouter;
+
+impl SysReservesRouter {
+    pub async fn init_reserves_router() -> Router {
+        let base_path = "/reserves";
+        let service_name = "SysReservesApi";
+
+        let routes = vec![
+            RouteInfo::new(base_path, Method::GET, service_name, "获取储备金快照列表"),
+            RouteInfo::new(
+                &format!("{}/snapshot", base_path),
+                Method::POST,
+                service_name,
+                "生成储备金快照",
+            ),
+            RouteInfo::new(
+                &format!("{}/:id/report", base_path),
+                Method::GET,
+                service_name,
+                "获取签名储备金报告",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/", get(SysReservesApi::get_paginated_snapshots))
+            .route("/snapshot", post(SysReservesApi::take_snapshot))
+            .route("/{id}/report", get(SysReservesApi::get_report));
+
+        Router::new().nest(base_path, router)
+    }
+
+    /// 用户侧接口，仅需登录
+    pub async fn init_protected_reserves_router() -> Router {
+        let base_path = "/reserves";
+        let service_name = "SysReservesApi";
+
+        add_route(RouteInfo::new(
+            &format!("{}/proof", base_path),
+            Method::GET,
+            service_name,
+            "获取当前用户负债包含性证明",
+        ))
+        .await;
+
+        let router = Router::new().route("/proof", get(SysReservesApi::get_proof));
+
+        Router::new().nest(base_path, router)
+    }
+}
@@ -0,0 +1,24 @@
+use axum::{http::Method, routing::get, Router};
+use server_api::admin::SysSolanaApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysSolanaRouter;
+
+impl SysSolanaRouter {
+    pub async fn init_solana_router() -> Router {
+        let base_path = "/solana";
+        let service_name = "SysSolanaApi";
+
+        add_route(RouteInfo::new(
+            &format!("{}/rpc-pool", base_path),
+            Method::GET,
+            service_name,
+            "获取 RPC 端点池指标",
+        ))
+        .await;
+
+        let router = Router::new().route("/rpc-pool", get(SysSolanaApi::get_rpc_pool_metrics));
+
+        Router::new().nest(base_path, router)
+    }
+}
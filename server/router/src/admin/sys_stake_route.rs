@@ -0,0 +1,124 @@
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysStakeApi;
+use server_core::web::IdempotencyLayer;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysStakeRouter;
+
+impl SysStakeRouter {
+    pub async fn init_stake_router() -> Router {
+        let base_path = "/stake";
+        let service_name = "SysStakeApi";
+
+        let routes = vec![
+            RouteInfo::new(
+                &format!("{}/validators", base_path),
+                Method::GET,
+                service_name,
+                "获取可委托的验证者",
+            ),
+            RouteInfo::new(
+                &format!("{}/accounts", base_path),
+                Method::GET,
+                service_name,
+                "获取质押账户列表",
+            ),
+            RouteInfo::new(
+                &format!("{}/accounts", base_path),
+                Method::POST,
+                service_name,
+                "创建质押账户并委托",
+            ),
+            RouteInfo::new(
+                &format!("{}/accounts/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取质押账户并刷新链上状态",
+            ),
+            RouteInfo::new(
+                &format!("{}/accounts/:id/deactivate", base_path),
+                Method::POST,
+                service_name,
+                "解除质押委托",
+            ),
+            RouteInfo::new(
+                &format!("{}/accounts/:id/withdraw", base_path),
+                Method::POST,
+                service_name,
+                "提取已冷却的质押账户",
+            ),
+            RouteInfo::new(
+                &format!("{}/rewards", base_path),
+                Method::GET,
+                service_name,
+                "获取质押奖励记录",
+            ),
+            RouteInfo::new(
+                &format!("{}/rewards/sync", base_path),
+                Method::POST,
+                service_name,
+                "拉取指定 epoch 的质押奖励",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/validators", get(SysStakeApi::get_validators))
+            .route("/accounts", get(SysStakeApi::get_paginated_accounts))
+            .route(
+                "/accounts",
+                post(SysStakeApi::create_account).layer(IdempotencyLayer::default()),
+            )
+            .route("/accounts/{id}", get(SysStakeApi::get_account))
+            .route(
+                "/accounts/{id}/deactivate",
+                post(SysStakeApi::deactivate_account),
+            )
+            .route(
+                "/accounts/{id}/withdraw",
+                post(SysStakeApi::withdraw_account).layer(IdempotencyLayer::default()),
+            )
+            .route("/rewards", get(SysStakeApi::get_paginated_rewards))
+            .route("/rewards/sync", post(SysStakeApi::sync_rewards));
+
+        Router::new().nest(base_path, router)
+    }
+
+    /// 用户侧接口，仅需登录
+    pub async fn init_protected_stake_router() -> Router {
+        let base_path = "/stake";
+        let service_name = "SysStakeApi";
+
+        let routes = vec![
+            RouteInfo::new(
+                &format!("{}/mine", base_path),
+                Method::GET,
+                service_name,
+                "获取当前用户的质押账户",
+            ),
+            RouteInfo::new(
+                &format!("{}/mine/rewards", base_path),
+                Method::GET,
+                service_name,
+                "获取当前用户的质押奖励",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/mine", get(SysStakeApi::get_my_accounts))
+            .route("/mine/rewards", get(SysStakeApi::get_my_rewards));
+
+        Router::new().nest(base_path, router)
+    }
+}
@@ -0,0 +1,79 @@
+use axum::{
+    http::Method,
+    routing::{delete, get, post, put},
+    Router,
+};
+use server_api::admin::SysWebhookApi;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysWebhookRouter;
+
+impl SysWebhookRouter {
+    pub async fn init_webhook_router() -> Router {
+        let base_path = "/webhooks";
+        let service_name = "SysWebhookApi";
+
+        let routes = vec![
+            RouteInfo::new(
+                &format!("{}/endpoints", base_path),
+                Method::GET,
+                service_name,
+                "获取 Webhook 端点列表",
+            ),
+            RouteInfo::new(
+                &format!("{}/endpoints", base_path),
+                Method::POST,
+                service_name,
+                "创建 Webhook 端点",
+            ),
+            RouteInfo::new(
+                &format!("{}/endpoints/:id", base_path),
+                Method::PUT,
+                service_name,
+                "修改 Webhook 端点",
+            ),
+            RouteInfo::new(
+                &format!("{}/endpoints/:id", base_path),
+                Method::DELETE,
+                service_name,
+                "删除 Webhook 端点",
+            ),
+            RouteInfo::new(
+                &format!("{}/deliveries", base_path),
+                Method::GET,
+                service_name,
+                "获取 Webhook 投递记录",
+            ),
+            RouteInfo::new(
+                &format!("{}/deliveries/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取 Webhook 投递详情",
+            ),
+            RouteInfo::new(
+                &format!("{}/deliveries/:id/replay", base_path),
+                Method::POST,
+                service_name,
+                "重新投递 Webhook",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/endpoints", get(SysWebhookApi::get_paginated_endpoints))
+            .route("/endpoints", post(SysWebhookApi::create_endpoint))
+            .route("/endpoints/{id}", put(SysWebhookApi::update_endpoint))
+            .route("/endpoints/{id}", delete(SysWebhookApi::delete_endpoint))
+            .route("/deliveries", get(SysWebhookApi::get_paginated_deliveries))
+            .route("/deliveries/{id}", get(SysWebhookApi::get_delivery))
+            .route(
+                "/deliveries/{id}/replay",
+                post(SysWebhookApi::replay_delivery),
+            );
+
+        Router::new().nest(base_path, router)
+    }
+}
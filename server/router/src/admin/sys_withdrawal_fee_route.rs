@@ -0,0 +1,77 @@
+use axum::{
+    http::Method,
+    routing::{delete, get, post, put},
+    Router,
+};
+use server_api::admin::SysWithdrawalFeeApi;
+use server_core::web::IdempotencyLayer;
+use server_global::global::{add_route, RouteInfo};
+
+pub struct SysWithdrawalFeeRouter;
+
+impl SysWithdrawalFeeRouter {
+    pub async fn init_withdrawal_fee_router() -> Router {
+        let base_path = "/withdrawal-fee";
+        let service_name = "SysWithdrawalFeeApi";
+
+        let routes = vec![
+            RouteInfo::new(
+                base_path,
+                Method::GET,
+                service_name,
+                "获取提现手续费规则列表",
+            ),
+            RouteInfo::new(base_path, Method::POST, service_name, "创建提现手续费规则"),
+            RouteInfo::new(
+                &format!("{}/:id", base_path),
+                Method::GET,
+                service_name,
+                "获取提现手续费规则详情",
+            ),
+            RouteInfo::new(base_path, Method::PUT, service_name, "更新提现手续费规则"),
+            RouteInfo::new(
+                &format!("{}/:id", base_path),
+                Method::DELETE,
+                service_name,
+                "删除提现手续费规则",
+            ),
+            RouteInfo::new(
+                &format!("{}/quote", base_path),
+                Method::GET,
+                service_name,
+                "获取提现手续费报价",
+            ),
+            RouteInfo::new(
+                &format!("{}/withdraw", base_path),
+                Method::POST,
+                service_name,
+                "提现并收取手续费",
+            ),
+            RouteInfo::new(
+                &format!("{}/records", base_path),
+                Method::GET,
+                service_name,
+                "获取手续费收取记录",
+            ),
+        ];
+
+        for route in routes {
+            add_route(route).await;
+        }
+
+        let router = Router::new()
+            .route("/", get(SysWithdrawalFeeApi::get_paginated_fees))
+            .route("/", post(SysWithdrawalFeeApi::create_fee))
+            .route("/", put(SysWithdrawalFeeApi::update_fee))
+            .route("/quote", get(SysWithdrawalFeeApi::quote))
+            .route(
+                "/withdraw",
+                post(SysWithdrawalFeeApi::withdraw).layer(IdempotencyLayer::default()),
+            )
+            .route("/records", get(SysWithdrawalFeeApi::get_paginated_records))
+            .route("/{id}", get(SysWithdrawalFeeApi::get_fee))
+            .route("/{id}", delete(SysWithdrawalFeeApi::delete_fee));
+
+        Router::new().nest(base_path, router)
+    }
+}
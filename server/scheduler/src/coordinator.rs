@@ -0,0 +1,177 @@
+//! 多实例协调：按任务选举主节点、共享暂停状态、运行锁
+//!
+//! 已初始化主 Redis 时状态保存在 Redis，由所有实例共享；否则只在本进程内生效
+
+use std::{
+    collections::HashSet,
+    sync::{LazyLock, Mutex},
+    time::Duration,
+};
+
+use redis::Script;
+use server_core::web::error::AppError;
+use server_global::global::{RedisConnection, GLOBAL_PRIMARY_REDIS};
+
+const KEY_PREFIX: &str = "scheduler";
+
+/// 已是主节点则续期，无主节点则当选
+static ELECT_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+        local current = redis.call('GET', KEYS[1])
+        if current == ARGV[1] then
+            redis.call('PEXPIRE', KEYS[1], ARGV[2])
+            return 1
+        end
+        if not current then
+            redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2])
+            return 1
+        end
+        return 0
+        ",
+    )
+});
+
+/// 只删除自己写入的键
+static RELEASE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+        if redis.call('GET', KEYS[1]) == ARGV[1] then
+            return redis.call('DEL', KEYS[1])
+        end
+        return 0
+        ",
+    )
+});
+
+pub(crate) struct Coordinator {
+    instance_id: String,
+    lease: Duration,
+    paused: Mutex<HashSet<String>>,
+    running: Mutex<HashSet<String>>,
+}
+
+impl Coordinator {
+    pub(crate) fn new(instance_id: String, lease: Duration) -> Self {
+        Self {
+            instance_id,
+            lease,
+            paused: Mutex::new(HashSet::new()),
+            running: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// 竞选任务的主节点，当选或续期成功返回 true
+    ///
+    /// 主节点持续续期，租约过期前其他实例无法当选
+    pub(crate) async fn elect(&self, job: &str) -> Result<bool, AppError> {
+        let Some(redis) = primary_redis().await else {
+            return Ok(true);
+        };
+
+        let mut invocation = ELECT_SCRIPT.prepare_invoke();
+        invocation
+            .key(format!("{}:leader:{{{}}}", KEY_PREFIX, job))
+            .arg(&self.instance_id)
+            .arg(self.lease.as_millis() as u64);
+        let elected: i64 = redis.invoke(&invocation).await.map_err(AppError::from)?;
+        Ok(elected == 1)
+    }
+
+    /// 已暂停的任务
+    pub(crate) async fn paused_jobs(&self) -> Result<HashSet<String>, AppError> {
+        let Some(redis) = primary_redis().await else {
+            return Ok(self
+                .paused
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone());
+        };
+
+        let mut cmd = redis::cmd("SMEMBERS");
+        cmd.arg(format!("{}:paused", KEY_PREFIX));
+        redis.query(&cmd).await.map_err(AppError::from)
+    }
+
+    pub(crate) async fn set_paused(&self, job: &str, paused: bool) -> Result<(), AppError> {
+        let Some(redis) = primary_redis().await else {
+            let mut jobs = self.paused.lock().unwrap_or_else(|e| e.into_inner());
+            if paused {
+                jobs.insert(job.to_string());
+            } else {
+                jobs.remove(job);
+            }
+            return Ok(());
+        };
+
+        let mut cmd = redis::cmd(if paused { "SADD" } else { "SREM" });
+        cmd.arg(format!("{}:paused", KEY_PREFIX)).arg(job);
+        redis.query::<()>(&cmd).await.map_err(AppError::from)
+    }
+
+    /// 获取任务的运行锁，同一任务同一时刻只在一个实例上执行
+    ///
+    /// 返回锁的值，释放时使用；`ttl` 到期后锁自动失效，防止实例退出后任务永远无法执行
+    pub(crate) async fn try_lock_run(
+        &self,
+        job: &str,
+        run_id: &str,
+        ttl: Duration,
+    ) -> Result<Option<String>, AppError> {
+        let token = format!("{}:{}", self.instance_id, run_id);
+        let Some(redis) = primary_redis().await else {
+            let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+            return Ok(running.insert(job.to_string()).then_some(token));
+        };
+
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(run_lock_key(job))
+            .arg(&token)
+            .arg("NX")
+            .arg("PX")
+            .arg(ttl.as_millis() as u64);
+        let locked: Option<String> = redis.query(&cmd).await.map_err(AppError::from)?;
+        Ok(locked.map(|_| token))
+    }
+
+    pub(crate) async fn unlock_run(&self, job: &str, token: &str) -> Result<(), AppError> {
+        let Some(redis) = primary_redis().await else {
+            self.running
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(job);
+            return Ok(());
+        };
+
+        let mut invocation = RELEASE_SCRIPT.prepare_invoke();
+        invocation.key(run_lock_key(job)).arg(token);
+        redis
+            .invoke::<i64>(&invocation)
+            .await
+            .map_err(AppError::from)
+            .map(|_| ())
+    }
+
+    /// 任务是否正在某个实例上执行
+    pub(crate) async fn is_running(&self, job: &str) -> Result<bool, AppError> {
+        let Some(redis) = primary_redis().await else {
+            return Ok(self
+                .running
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .contains(job));
+        };
+
+        let mut cmd = redis::cmd("EXISTS");
+        cmd.arg(run_lock_key(job));
+        redis.query(&cmd).await.map_err(AppError::from)
+    }
+}
+
+fn run_lock_key(job: &str) -> String {
+    format!("{}:running:{{{}}}", KEY_PREFIX, job)
+}
+
+async fn primary_redis() -> Option<RedisConnection> {
+    GLOBAL_PRIMARY_REDIS.read().await.clone()
+}
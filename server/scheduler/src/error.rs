@@ -0,0 +1,41 @@
+use server_core::web::error::{ApiError, AppError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SchedulerError {
+    #[error("Job not found: {0}")]
+    JobNotFound(String),
+    #[error("Invalid cron expression for job {0}: {1}")]
+    InvalidSchedule(String, String),
+    #[error("Job {0} is already running")]
+    JobAlreadyRunning(String),
+    #[error("Scheduler has not been started")]
+    NotStarted,
+    #[error("Job run not found")]
+    RunNotFound,
+}
+
+impl ApiError for SchedulerError {
+    fn code(&self) -> u16 {
+        match self {
+            SchedulerError::JobNotFound(_) => 20001,
+            SchedulerError::InvalidSchedule(..) => 20002,
+            SchedulerError::JobAlreadyRunning(_) => 20003,
+            SchedulerError::NotStarted => 20004,
+            SchedulerError::RunNotFound => 20005,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<SchedulerError> for AppError {
+    fn from(err: SchedulerError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
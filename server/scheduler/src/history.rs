@@ -0,0 +1,149 @@
+//! 任务运行历史，写入 `sys_job_run` 表
+
+use std::sync::Arc;
+
+use chrono::{Local, NaiveDateTime};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnAcquireErr, DatabaseConnection, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, Set,
+};
+use server_core::web::error::AppError;
+use server_global::global::GLOBAL_PRIMARY_DB;
+use server_model::admin::entities::{
+    prelude::SysJobRun,
+    sea_orm_active_enums::{JobRunStatus, JobTrigger},
+    sys_job_run::{ActiveModel as SysJobRunActiveModel, Column as SysJobRunColumn, Model},
+};
+
+/// 输出与错误信息的最大长度，超出部分截断
+const MAX_TEXT_LEN: usize = 4000;
+
+pub(crate) async fn db() -> Result<Arc<DatabaseConnection>, AppError> {
+    GLOBAL_PRIMARY_DB
+        .read()
+        .await
+        .as_ref()
+        .cloned()
+        .ok_or_else(|| AppError::from(DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)))
+}
+
+/// 记录一次开始执行的运行
+pub(crate) async fn start_run(
+    id: &str,
+    job_name: &str,
+    trigger: JobTrigger,
+    instance_id: &str,
+    scheduled_at: Option<NaiveDateTime>,
+    triggered_by: Option<String>,
+) -> Result<Model, AppError> {
+    let db = db().await?;
+    SysJobRunActiveModel {
+        id: Set(id.to_string()),
+        job_name: Set(job_name.to_string()),
+        trigger: Set(trigger),
+        status: Set(JobRunStatus::Running),
+        instance_id: Set(instance_id.to_string()),
+        scheduled_at: Set(scheduled_at),
+        started_at: Set(Local::now().naive_local()),
+        finished_at: Set(None),
+        duration_ms: Set(None),
+        output: Set(None),
+        error: Set(None),
+        triggered_by: Set(triggered_by),
+    }
+    .insert(db.as_ref())
+    .await
+    .map_err(AppError::from)
+}
+
+/// 记录运行结果
+pub(crate) async fn finish_run(
+    run: Model,
+    result: Result<String, String>,
+) -> Result<Model, AppError> {
+    let db = db().await?;
+    let finished_at = Local::now().naive_local();
+    let duration_ms = (finished_at - run.started_at).num_milliseconds();
+
+    let mut active: SysJobRunActiveModel = run.into();
+    active.finished_at = Set(Some(finished_at));
+    active.duration_ms = Set(Some(duration_ms));
+    match result {
+        Ok(output) => {
+            active.status = Set(JobRunStatus::Succeeded);
+            active.output = Set(Some(truncate(output)));
+        },
+        Err(error) => {
+            active.status = Set(JobRunStatus::Failed);
+            active.error = Set(Some(truncate(error)));
+        },
+    }
+    active.update(db.as_ref()).await.map_err(AppError::from)
+}
+
+/// 仍处于运行中的记录
+pub(crate) async fn running_runs() -> Result<Vec<Model>, AppError> {
+    let db = db().await?;
+    SysJobRun::find()
+        .filter(SysJobRunColumn::Status.eq(JobRunStatus::Running))
+        .all(db.as_ref())
+        .await
+        .map_err(AppError::from)
+}
+
+/// 将运行中的记录标记为中断，期间已完成的记录不受影响
+pub(crate) async fn interrupt_run(run: &Model) -> Result<bool, AppError> {
+    let db = db().await?;
+    let finished_at = Local::now().naive_local();
+    SysJobRun::update_many()
+        .col_expr(SysJobRunColumn::Status, Expr::value(JobRunStatus::Failed))
+        .col_expr(SysJobRunColumn::FinishedAt, Expr::value(finished_at))
+        .col_expr(
+            SysJobRunColumn::DurationMs,
+            Expr::value((finished_at - run.started_at).num_milliseconds()),
+        )
+        .col_expr(
+            SysJobRunColumn::Error,
+            Expr::value("Interrupted: the instance stopped before the run finished"),
+        )
+        .filter(SysJobRunColumn::Id.eq(run.id.as_str()))
+        .filter(SysJobRunColumn::Status.eq(JobRunStatus::Running))
+        .exec(db.as_ref())
+        .await
+        .map(|result| result.rows_affected > 0)
+        .map_err(AppError::from)
+}
+
+/// 任务最近一次运行
+pub(crate) async fn last_run(job_name: &str) -> Result<Option<Model>, AppError> {
+    let db = db().await?;
+    SysJobRun::find()
+        .filter(SysJobRunColumn::JobName.eq(job_name))
+        .order_by_desc(SysJobRunColumn::StartedAt)
+        .one(db.as_ref())
+        .await
+        .map_err(AppError::from)
+}
+
+/// 删除 `before` 之前开始的运行记录
+pub async fn prune_runs(before: NaiveDateTime) -> Result<u64, AppError> {
+    let db = db().await?;
+    SysJobRun::delete_many()
+        .filter(SysJobRunColumn::StartedAt.lt(before))
+        .filter(SysJobRunColumn::Status.ne(JobRunStatus::Running))
+        .exec(db.as_ref())
+        .await
+        .map(|result| result.rows_affected)
+        .map_err(AppError::from)
+}
+
+fn truncate(mut text: String) -> String {
+    if text.len() > MAX_TEXT_LEN {
+        let mut end = MAX_TEXT_LEN;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+    }
+    text
+}
@@ -0,0 +1,68 @@
+use std::{future::Future, str::FromStr, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Local};
+use cron::Schedule;
+use server_core::web::error::AppError;
+
+use crate::error::SchedulerError;
+
+/// 后台任务
+///
+/// 返回的字符串作为执行结果摘要记入运行历史
+#[async_trait]
+pub trait Job: Send + Sync {
+    async fn run(&self) -> Result<String, AppError>;
+}
+
+#[async_trait]
+impl<F, Fut> Job for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, AppError>> + Send,
+{
+    async fn run(&self) -> Result<String, AppError> {
+        self().await
+    }
+}
+
+/// 已注册的任务及其执行计划
+pub struct JobDefinition {
+    pub name: String,
+    pub description: String,
+    /// cron 表达式，含秒字段，如 `0 */10 * * * *`
+    pub expression: String,
+    schedule: Schedule,
+    job: Arc<dyn Job>,
+}
+
+impl JobDefinition {
+    pub fn new(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        expression: impl Into<String>,
+        job: impl Job + 'static,
+    ) -> Result<Self, SchedulerError> {
+        let name = name.into();
+        let expression = expression.into();
+        let schedule = Schedule::from_str(&expression)
+            .map_err(|e| SchedulerError::InvalidSchedule(name.clone(), e.to_string()))?;
+
+        Ok(Self {
+            name,
+            description: description.into(),
+            expression,
+            schedule,
+            job: Arc::new(job),
+        })
+    }
+
+    /// `after` 之后的下一次计划执行时间
+    pub fn next_after(&self, after: &DateTime<Local>) -> Option<DateTime<Local>> {
+        self.schedule.after(after).next()
+    }
+
+    pub(crate) fn job(&self) -> Arc<dyn Job> {
+        self.job.clone()
+    }
+}
@@ -0,0 +1,67 @@
+//! 常驻后台循环的主节点选举
+//!
+//! 收款单检查、Webhook 分发、发件箱中继等按固定间隔轮询的循环不经调度器执行，
+//! 每轮开始前通过 [`LeaderElection::is_leader`] 确认本实例是主节点，多实例部署时只有主节点处理
+
+use std::time::Instant;
+
+use server_global::{project_error, project_info};
+use ulid::Ulid;
+
+use crate::{
+    coordinator::Coordinator,
+    scheduler::{LEADER_LEASE, LEADER_RENEW_INTERVAL},
+};
+
+/// 单个后台循环的选举状态
+pub struct LeaderElection {
+    name: String,
+    instance_id: String,
+    coordinator: Coordinator,
+    leader: bool,
+    checked_at: Option<Instant>,
+}
+
+impl LeaderElection {
+    /// `name` 为循环名称，与调度任务共用选举键空间，不能与任务同名
+    pub fn new(name: &str) -> Self {
+        let instance_id = Ulid::new().to_string();
+        Self {
+            name: name.to_string(),
+            coordinator: Coordinator::new(instance_id.clone(), LEADER_LEASE),
+            instance_id,
+            leader: false,
+            checked_at: None,
+        }
+    }
+
+    /// 本实例是否为主节点，到续期间隔时重新竞选或续期
+    ///
+    /// 无法访问 Redis 时按非主节点处理，避免多个实例同时执行
+    pub async fn is_leader(&mut self) -> bool {
+        if self
+            .checked_at
+            .is_some_and(|at| at.elapsed() < LEADER_RENEW_INTERVAL)
+        {
+            return self.leader;
+        }
+
+        let leader = match self.coordinator.elect(&self.name).await {
+            Ok(leader) => leader,
+            Err(e) => {
+                project_error!("Failed to elect leader for {}: {}", self.name, e.message);
+                false
+            },
+        };
+        if leader && !self.leader {
+            project_info!(
+                "Instance {} is now leader for {}",
+                self.instance_id,
+                self.name
+            );
+        }
+        self.leader = leader;
+        self.checked_at = Some(Instant::now());
+        leader
+    }
+}
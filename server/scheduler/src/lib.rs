@@ -0,0 +1,21 @@
+//! 后台任务调度
+//!
+//! 按 cron 表达式（含秒字段）定时执行已注册的任务。多实例部署时通过主 Redis
+//! 为每个任务选举主节点，同一任务只在一个实例上按计划执行；每次运行记录到
+//! `sys_job_run` 表，支持手动触发与暂停。常驻的轮询循环通过 [`LeaderElection`]
+//! 同样只在主节点上执行。
+
+pub use error::SchedulerError;
+pub use history::prune_runs;
+pub use job::{Job, JobDefinition};
+pub use leader::LeaderElection;
+pub use registry::JobRegistry;
+pub use scheduler::Scheduler;
+
+mod coordinator;
+mod error;
+mod history;
+mod job;
+mod leader;
+mod registry;
+mod scheduler;
@@ -0,0 +1,90 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use server_core::web::error::AppError;
+
+use crate::{
+    error::SchedulerError,
+    job::{Job, JobDefinition},
+};
+
+/// 任务注册表，按名称排序
+#[derive(Default)]
+pub struct JobRegistry {
+    jobs: BTreeMap<String, Arc<JobDefinition>>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册任务，同名任务会被覆盖
+    pub fn register(
+        &mut self,
+        name: &str,
+        description: &str,
+        expression: &str,
+        job: impl Job + 'static,
+    ) -> Result<&mut Self, AppError> {
+        let definition = JobDefinition::new(name, description, expression, job)?;
+        self.jobs
+            .insert(definition.name.clone(), Arc::new(definition));
+        Ok(self)
+    }
+
+    pub fn get(&self, name: &str) -> Result<Arc<JobDefinition>, SchedulerError> {
+        self.jobs
+            .get(name)
+            .cloned()
+            .ok_or_else(|| SchedulerError::JobNotFound(name.to_string()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<JobDefinition>> {
+        self.jobs.values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone, Timelike};
+
+    use super::*;
+
+    async fn noop() -> Result<String, AppError> {
+        Ok(String::new())
+    }
+
+    #[test]
+    fn test_register_and_schedule() {
+        let mut registry = JobRegistry::new();
+        registry
+            .register("b", "second", "0 */10 * * * *", noop)
+            .unwrap()
+            .register("a", "first", "0 30 3 * * *", noop)
+            .unwrap();
+
+        let names: Vec<_> = registry.iter().map(|job| job.name.as_str()).collect();
+        assert_eq!(names, ["a", "b"]);
+
+        let after = Local.with_ymd_and_hms(2026, 1, 1, 12, 3, 0).unwrap();
+        let next = registry.get("b").unwrap().next_after(&after).unwrap();
+        assert_eq!((next.hour(), next.minute(), next.second()), (12, 10, 0));
+
+        assert!(matches!(
+            registry.get("missing"),
+            Err(SchedulerError::JobNotFound(_))
+        ));
+        assert_eq!(
+            registry
+                .register("c", "invalid", "every minute", noop)
+                .err()
+                .unwrap()
+                .code,
+            20002
+        );
+    }
+}
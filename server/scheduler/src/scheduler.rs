@@ -0,0 +1,325 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Local, NaiveDateTime};
+use server_core::web::error::AppError;
+use server_global::{project_error, project_info};
+use server_model::admin::{
+    entities::{sea_orm_active_enums::JobTrigger, sys_job_run::Model as SysJobRunModel},
+    output::JobOutput,
+};
+use ulid::Ulid;
+
+use crate::{
+    coordinator::Coordinator, error::SchedulerError, history, job::JobDefinition,
+    registry::JobRegistry,
+};
+
+/// 检查到期任务的间隔，cron 表达式精确到秒
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 主节点租约，主节点退出后其他实例最迟在租约到期后接管
+pub(crate) const LEADER_LEASE: Duration = Duration::from_secs(30);
+
+/// 主节点续期间隔，须明显短于租约
+pub(crate) const LEADER_RENEW_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 运行锁的最长持有时间，执行实例崩溃后到期自动释放
+const RUN_LOCK_TTL: Duration = Duration::from_secs(60 * 60);
+
+static SCHEDULER: OnceLock<Arc<Scheduler>> = OnceLock::new();
+
+struct Leadership {
+    leader: bool,
+    checked_at: Instant,
+}
+
+/// 后台任务调度器
+///
+/// 每个任务单独选举主节点，只有主节点按计划执行；手动触发可在任意实例上执行，
+/// 由运行锁保证同一任务不会并发执行
+pub struct Scheduler {
+    registry: JobRegistry,
+    coordinator: Coordinator,
+    instance_id: String,
+}
+
+impl Scheduler {
+    /// 启动调度器，重复调用返回已启动的实例
+    pub fn start(registry: JobRegistry) -> Arc<Self> {
+        SCHEDULER
+            .get_or_init(|| {
+                let instance_id = Ulid::new().to_string();
+                let scheduler = Arc::new(Self {
+                    registry,
+                    coordinator: Coordinator::new(instance_id.clone(), LEADER_LEASE),
+                    instance_id,
+                });
+                tokio::spawn(scheduler.clone().run_loop());
+                scheduler
+            })
+            .clone()
+    }
+
+    /// 已启动的调度器
+    pub fn global() -> Result<Arc<Self>, SchedulerError> {
+        SCHEDULER.get().cloned().ok_or(SchedulerError::NotStarted)
+    }
+
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    /// 已注册的任务及其状态
+    pub async fn jobs(&self) -> Result<Vec<JobOutput>, AppError> {
+        let paused = self.coordinator.paused_jobs().await?;
+        let now = Local::now();
+
+        let mut jobs = Vec::new();
+        for definition in self.registry.iter() {
+            let paused = paused.contains(&definition.name);
+            jobs.push(JobOutput {
+                name: definition.name.clone(),
+                description: definition.description.clone(),
+                schedule: definition.expression.clone(),
+                paused,
+                running: self.coordinator.is_running(&definition.name).await?,
+                next_run_at: if paused {
+                    None
+                } else {
+                    definition.next_after(&now).map(|at| at.naive_local())
+                },
+                last_run: history::last_run(&definition.name).await?,
+            });
+        }
+        Ok(jobs)
+    }
+
+    /// 立即执行任务，返回刚创建的运行记录，任务在后台继续执行
+    pub async fn trigger(
+        self: &Arc<Self>,
+        name: &str,
+        operator: &str,
+    ) -> Result<SysJobRunModel, AppError> {
+        let definition = self.registry.get(name)?;
+        self.execute(
+            definition,
+            JobTrigger::Manual,
+            None,
+            Some(operator.to_string()),
+        )
+        .await?
+        .ok_or_else(|| SchedulerError::JobAlreadyRunning(name.to_string()).into())
+    }
+
+    /// 暂停任务的计划执行，所有实例生效
+    pub async fn pause(&self, name: &str) -> Result<(), AppError> {
+        self.registry.get(name)?;
+        self.coordinator.set_paused(name, true).await?;
+        project_info!("Job {} paused", name);
+        Ok(())
+    }
+
+    pub async fn resume(&self, name: &str) -> Result<(), AppError> {
+        self.registry.get(name)?;
+        self.coordinator.set_paused(name, false).await?;
+        project_info!("Job {} resumed", name);
+        Ok(())
+    }
+
+    /// 将已没有运行锁的运行中记录标记为失败，返回标记条数
+    ///
+    /// 执行实例在任务完成前退出时记录停留在运行中；运行锁在写入结果之后才释放，
+    /// 锁已释放或到期说明该次运行不会再写入结果
+    pub async fn fail_interrupted_runs(&self) -> Result<u64, AppError> {
+        let mut failed = 0;
+        for run in history::running_runs().await? {
+            if self.coordinator.is_running(&run.job_name).await? {
+                continue;
+            }
+            if history::interrupt_run(&run).await? {
+                project_info!("Job {} run {} marked as interrupted", run.job_name, run.id);
+                failed += 1;
+            }
+        }
+        Ok(failed)
+    }
+
+    async fn run_loop(self: Arc<Self>) {
+        if let Err(e) = self.fail_interrupted_runs().await {
+            project_error!("Failed to clean up interrupted job runs: {}", e.message);
+        }
+
+        let now = Local::now();
+        let mut next_runs: HashMap<String, DateTime<Local>> = self
+            .registry
+            .iter()
+            .filter_map(|definition| {
+                definition
+                    .next_after(&now)
+                    .map(|at| (definition.name.clone(), at))
+            })
+            .collect();
+        let mut leadership: HashMap<String, Leadership> = HashMap::new();
+
+        let mut ticker = tokio::time::interval(TICK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            let now = Local::now();
+
+            for definition in self.registry.iter() {
+                let leader = self
+                    .renew_leadership(&definition.name, &mut leadership)
+                    .await;
+
+                let Some(scheduled_at) = next_runs.get(&definition.name).copied() else {
+                    continue;
+                };
+                if scheduled_at > now {
+                    continue;
+                }
+                // 错过的执行时间不补跑，只执行一次后从当前时间重新计算
+                match definition.next_after(&now) {
+                    Some(next) => next_runs.insert(definition.name.clone(), next),
+                    None => next_runs.remove(&definition.name),
+                };
+
+                if !leader || self.is_paused(&definition.name).await {
+                    continue;
+                }
+
+                let scheduler = self.clone();
+                let definition = definition.clone();
+                tokio::spawn(async move {
+                    let name = definition.name.clone();
+                    let scheduled_at = Some(scheduled_at.naive_local());
+                    match scheduler
+                        .execute(definition, JobTrigger::Schedule, scheduled_at, None)
+                        .await
+                    {
+                        Ok(Some(_)) => {},
+                        Ok(None) => {
+                            project_info!("Skipped job {}: previous run still in progress", name)
+                        },
+                        Err(e) => project_error!("Failed to start job {}: {}", name, e.message),
+                    }
+                });
+            }
+        }
+    }
+
+    async fn renew_leadership(
+        &self,
+        name: &str,
+        leadership: &mut HashMap<String, Leadership>,
+    ) -> bool {
+        if let Some(state) = leadership.get(name) {
+            if state.checked_at.elapsed() < LEADER_RENEW_INTERVAL {
+                return state.leader;
+            }
+        }
+
+        let was_leader = leadership.get(name).is_some_and(|state| state.leader);
+        let leader = match self.coordinator.elect(name).await {
+            Ok(leader) => leader,
+            Err(e) => {
+                project_error!("Failed to elect leader for job {}: {}", name, e.message);
+                false
+            },
+        };
+        if leader && !was_leader {
+            project_info!(
+                "Instance {} is now leader for job {}",
+                self.instance_id,
+                name
+            );
+        }
+        leadership.insert(
+            name.to_string(),
+            Leadership {
+                leader,
+                checked_at: Instant::now(),
+            },
+        );
+        leader
+    }
+
+    async fn is_paused(&self, name: &str) -> bool {
+        match self.coordinator.paused_jobs().await {
+            Ok(paused) => paused.contains(name),
+            Err(e) => {
+                // 无法确认暂停状态时不执行
+                project_error!("Failed to load paused jobs: {}", e.message);
+                true
+            },
+        }
+    }
+
+    /// 取得运行锁后记录运行并在后台执行，任务正在执行时返回 None
+    async fn execute(
+        self: &Arc<Self>,
+        definition: Arc<JobDefinition>,
+        trigger: JobTrigger,
+        scheduled_at: Option<NaiveDateTime>,
+        triggered_by: Option<String>,
+    ) -> Result<Option<SysJobRunModel>, AppError> {
+        let run_id = Ulid::new().to_string();
+        let Some(token) = self
+            .coordinator
+            .try_lock_run(&definition.name, &run_id, RUN_LOCK_TTL)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let run = match history::start_run(
+            &run_id,
+            &definition.name,
+            trigger,
+            &self.instance_id,
+            scheduled_at,
+            triggered_by,
+        )
+        .await
+        {
+            Ok(run) => run,
+            Err(e) => {
+                self.unlock_run(&definition.name, &token).await;
+                return Err(e);
+            },
+        };
+
+        let scheduler = self.clone();
+        let started = run.clone();
+        tokio::spawn(async move {
+            let name = definition.name.clone();
+            // 在独立任务中执行，任务 panic 时同样能记录结果并释放运行锁
+            let job = definition.job();
+            let result = match tokio::spawn(async move { job.run().await }).await {
+                Ok(Ok(output)) => Ok(output),
+                Ok(Err(e)) => Err(e.message),
+                Err(e) => Err(format!("Job panicked: {}", e)),
+            };
+
+            match &result {
+                Ok(_) => project_info!("Job {} run {} succeeded", name, run.id),
+                Err(e) => project_error!("Job {} run {} failed: {}", name, run.id, e),
+            }
+            if let Err(e) = history::finish_run(run, result).await {
+                project_error!("Failed to record result of job {}: {}", name, e.message);
+            }
+            scheduler.unlock_run(&name, &token).await;
+        });
+
+        Ok(Some(started))
+    }
+
+    async fn unlock_run(&self, name: &str, token: &str) {
+        if let Err(e) = self.coordinator.unlock_run(name, token).await {
+            project_error!("Failed to release run lock of job {}: {}", name, e.message);
+        }
+    }
+}
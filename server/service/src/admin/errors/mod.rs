@@ -1,5 +1,19 @@
 pub mod sys_access_key_error;
+pub mod sys_asset_error;
+pub mod sys_auto_convert_error;
+pub mod sys_custody_hold_error;
 pub mod sys_domain_error;
+pub mod sys_key_rotation_error;
+pub mod sys_keystore_error;
+pub mod sys_lookup_table_error;
 pub mod sys_menu_error;
+pub mod sys_mint_admin_error;
+pub mod sys_payment_invoice_error;
+pub mod sys_payout_error;
+pub mod sys_rent_reclamation_error;
+pub mod sys_reserves_error;
 pub mod sys_role_error;
+pub mod sys_stake_error;
 pub mod sys_user_error;
+pub mod sys_webhook_error;
+pub mod sys_withdrawal_fee_error;
@@ -0,0 +1,57 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AssetError {
+    #[error("Asset not found")]
+    AssetNotFound,
+    #[error("Asset with this mint already exists")]
+    DuplicateMint,
+    #[error("Invalid mint address: {0}")]
+    InvalidMint(String),
+    #[error("Minimum withdrawal must not exceed maximum withdrawal")]
+    InvalidWithdrawalLimits,
+    #[error("Unsupported icon type: {0}")]
+    UnsupportedIconType(String),
+    #[error("Icon must not exceed {0} bytes")]
+    IconTooLarge(usize),
+    #[error("Asset has no icon")]
+    IconNotFound,
+    #[error("Object storage is not configured")]
+    StorageNotConfigured,
+    #[error("Object storage error: {0}")]
+    Storage(String),
+}
+
+impl ApiError for AssetError {
+    fn code(&self) -> u16 {
+        match self {
+            AssetError::AssetNotFound => CustodyErrorCode::AssetNotFound,
+            AssetError::DuplicateMint => CustodyErrorCode::DuplicateAsset,
+            AssetError::InvalidMint(_) => CustodyErrorCode::InvalidAddress,
+            AssetError::InvalidWithdrawalLimits => CustodyErrorCode::InvalidWithdrawalLimits,
+            AssetError::UnsupportedIconType(_) => CustodyErrorCode::UnsupportedIconType,
+            AssetError::IconTooLarge(_) => CustodyErrorCode::IconTooLarge,
+            AssetError::IconNotFound => CustodyErrorCode::IconNotFound,
+            AssetError::StorageNotConfigured => CustodyErrorCode::StorageNotConfigured,
+            AssetError::Storage(_) => CustodyErrorCode::StorageFailed,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<AssetError> for AppError {
+    fn from(err: AssetError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AutoConvertError {
+    #[error("Auto-convert policy not found")]
+    PolicyNotFound,
+    #[error("Auto-convert policy is disabled")]
+    PolicyDisabled,
+    #[error("Invalid mint address: {0}")]
+    InvalidMint(String),
+    #[error("Invalid auto-convert policy: {0}")]
+    InvalidPolicy(String),
+    #[error("Auto-convert job not found")]
+    JobNotFound,
+    #[error("Only failed jobs without a landed leg can be retried")]
+    JobNotRetryable,
+    #[error("Deposit already has an auto-convert job")]
+    DuplicateDeposit,
+    #[error("Amount out of range")]
+    AmountOutOfRange,
+}
+
+impl ApiError for AutoConvertError {
+    fn code(&self) -> u16 {
+        match self {
+            AutoConvertError::PolicyNotFound => CustodyErrorCode::PolicyNotFound,
+            AutoConvertError::PolicyDisabled => CustodyErrorCode::PolicyDisabled,
+            AutoConvertError::InvalidMint(_) => CustodyErrorCode::InvalidAddress,
+            AutoConvertError::InvalidPolicy(_) => CustodyErrorCode::InvalidPolicy,
+            AutoConvertError::JobNotFound => CustodyErrorCode::ConvertJobNotFound,
+            AutoConvertError::JobNotRetryable => CustodyErrorCode::ConvertJobNotRetryable,
+            AutoConvertError::DuplicateDeposit => CustodyErrorCode::DuplicateDeposit,
+            AutoConvertError::AmountOutOfRange => CustodyErrorCode::AmountOverflow,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<AutoConvertError> for AppError {
+    fn from(err: AutoConvertError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
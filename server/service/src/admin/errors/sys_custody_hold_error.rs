@@ -0,0 +1,42 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CustodyHoldError {
+    #[error("Custody hold not found")]
+    HoldNotFound,
+    #[error("User not found")]
+    UserNotFound,
+    #[error("Custody hold is already released")]
+    AlreadyReleased,
+    #[error("Custody hold follows the user status and is released by re-enabling the user")]
+    ManagedByUserStatus,
+}
+
+impl ApiError for CustodyHoldError {
+    fn code(&self) -> u16 {
+        match self {
+            CustodyHoldError::HoldNotFound => CustodyErrorCode::HoldNotFound,
+            CustodyHoldError::UserNotFound => CustodyErrorCode::UserNotFound,
+            CustodyHoldError::AlreadyReleased => CustodyErrorCode::HoldAlreadyReleased,
+            CustodyHoldError::ManagedByUserStatus => CustodyErrorCode::HoldManagedByUserStatus,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<CustodyHoldError> for AppError {
+    fn from(err: CustodyHoldError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum KeyRotationError {
+    #[error("Key rotation not found")]
+    RotationNotFound,
+    #[error("Custody wallet not found")]
+    WalletNotFound,
+    #[error("Private key of wallet {0} is not in the keystore")]
+    KeyNotInKeystore(String),
+    #[error("Private key does not belong to wallet {0}")]
+    KeyMismatch(String),
+    #[error("Replacement wallet of retired wallet {0} not found")]
+    ReplacementNotFound(String),
+}
+
+impl ApiError for KeyRotationError {
+    fn code(&self) -> u16 {
+        match self {
+            KeyRotationError::RotationNotFound => CustodyErrorCode::RotationNotFound,
+            KeyRotationError::WalletNotFound => CustodyErrorCode::WalletNotFound,
+            KeyRotationError::KeyNotInKeystore(_) => CustodyErrorCode::KeyNotInKeystore,
+            KeyRotationError::KeyMismatch(_) => CustodyErrorCode::KeyMismatch,
+            KeyRotationError::ReplacementNotFound(_) => CustodyErrorCode::ReplacementWalletNotFound,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<KeyRotationError> for AppError {
+    fn from(err: KeyRotationError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
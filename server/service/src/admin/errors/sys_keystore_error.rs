@@ -0,0 +1,72 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum KeystoreError {
+    #[error("Key export not found")]
+    ExportNotFound,
+    #[error("Private key export is not allowed in this domain")]
+    ExportNotAllowed,
+    #[error("Custody wallet not found")]
+    WalletNotFound,
+    #[error("Private key of wallet {0} is not in the keystore")]
+    KeyNotInKeystore(String),
+    #[error("A key export is already pending")]
+    ExportAlreadyPending,
+    #[error("Key export is no longer pending")]
+    ExportNotPending,
+    #[error("Key export is in its cooling period until {0}")]
+    CoolingPeriod(String),
+    #[error("Key export has expired")]
+    ExportExpired,
+    #[error("Invalid download token")]
+    InvalidToken,
+    #[error("Re-authentication failed")]
+    ReauthenticationFailed,
+    #[error("Keystore passphrase is not configured")]
+    KeystoreNotConfigured,
+    #[error("Key export policy not found")]
+    PolicyNotFound,
+    #[error("Custody key has been exported, the wallet is no longer managed")]
+    KeyExported,
+    #[error("Balance of {0} must be settled before exporting the key")]
+    BalanceNotSettled(String),
+}
+
+impl ApiError for KeystoreError {
+    fn code(&self) -> u16 {
+        match self {
+            KeystoreError::ExportNotFound => CustodyErrorCode::KeyExportNotFound,
+            KeystoreError::ExportNotAllowed => CustodyErrorCode::KeyExportNotAllowed,
+            KeystoreError::WalletNotFound => CustodyErrorCode::WalletNotFound,
+            KeystoreError::KeyNotInKeystore(_) => CustodyErrorCode::KeyNotInKeystore,
+            KeystoreError::ExportAlreadyPending => CustodyErrorCode::KeyExportAlreadyPending,
+            KeystoreError::ExportNotPending => CustodyErrorCode::KeyExportNotPending,
+            KeystoreError::CoolingPeriod(_) => CustodyErrorCode::KeyExportCoolingPeriod,
+            KeystoreError::ExportExpired => CustodyErrorCode::KeyExportExpired,
+            KeystoreError::InvalidToken => CustodyErrorCode::InvalidDownloadToken,
+            KeystoreError::ReauthenticationFailed => CustodyErrorCode::ReauthenticationFailed,
+            KeystoreError::KeystoreNotConfigured => CustodyErrorCode::Misconfigured,
+            KeystoreError::PolicyNotFound => CustodyErrorCode::PolicyNotFound,
+            KeystoreError::KeyExported => CustodyErrorCode::KeyExported,
+            KeystoreError::BalanceNotSettled(_) => CustodyErrorCode::BalanceNotSettled,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<KeystoreError> for AppError {
+    fn from(err: KeystoreError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
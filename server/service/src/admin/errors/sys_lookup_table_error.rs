@@ -0,0 +1,39 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum LookupTableError {
+    #[error("Lookup table not found")]
+    LookupTableNotFound,
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+    #[error("Lookup table is disabled")]
+    LookupTableDisabled,
+}
+
+impl ApiError for LookupTableError {
+    fn code(&self) -> u16 {
+        match self {
+            LookupTableError::LookupTableNotFound => CustodyErrorCode::LookupTableNotFound,
+            LookupTableError::InvalidAddress(_) => CustodyErrorCode::InvalidAddress,
+            LookupTableError::LookupTableDisabled => CustodyErrorCode::LookupTableDisabled,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<LookupTableError> for AppError {
+    fn from(err: LookupTableError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
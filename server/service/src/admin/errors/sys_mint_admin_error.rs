@@ -0,0 +1,48 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MintAdminError {
+    #[error("Mint operation not found")]
+    OperationNotFound,
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+    #[error("Invalid mint operation: {0}")]
+    InvalidOperation(&'static str),
+    #[error("Mint operation is not {0}")]
+    InvalidStatus(&'static str),
+    #[error("Mint operation must be approved by someone other than its initiator")]
+    SelfApproval,
+    #[error("Mint operation has already been reviewed by this user")]
+    AlreadyReviewed,
+}
+
+impl ApiError for MintAdminError {
+    fn code(&self) -> u16 {
+        match self {
+            MintAdminError::OperationNotFound => CustodyErrorCode::MintOperationNotFound,
+            MintAdminError::InvalidAddress(_) => CustodyErrorCode::InvalidAddress,
+            MintAdminError::InvalidOperation(_) => CustodyErrorCode::InvalidMintOperation,
+            MintAdminError::InvalidStatus(_) => CustodyErrorCode::InvalidState,
+            MintAdminError::SelfApproval => CustodyErrorCode::SelfApproval,
+            MintAdminError::AlreadyReviewed => CustodyErrorCode::AlreadyReviewed,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<MintAdminError> for AppError {
+    fn from(err: MintAdminError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PaymentInvoiceError {
+    #[error("Payment invoice not found")]
+    InvoiceNotFound,
+    #[error("User has no enabled custody wallet")]
+    WalletNotFound,
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+    #[error("Payment invoice is not pending")]
+    NotPending,
+}
+
+impl ApiError for PaymentInvoiceError {
+    fn code(&self) -> u16 {
+        match self {
+            PaymentInvoiceError::InvoiceNotFound => CustodyErrorCode::InvoiceNotFound,
+            PaymentInvoiceError::WalletNotFound => CustodyErrorCode::WalletNotFound,
+            PaymentInvoiceError::InvalidAddress(_) => CustodyErrorCode::InvalidAddress,
+            PaymentInvoiceError::NotPending => CustodyErrorCode::InvalidState,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<PaymentInvoiceError> for AppError {
+    fn from(err: PaymentInvoiceError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PayoutError {
+    #[error("Payout batch not found")]
+    BatchNotFound,
+    #[error("Unsupported payout file format: {0}")]
+    UnsupportedFormat(String),
+    #[error("Invalid payout file: {0}")]
+    InvalidFile(String),
+    #[error("Payout file contains no rows")]
+    EmptyFile,
+    #[error("Payout file must not exceed {0} rows")]
+    TooManyRows(usize),
+    #[error("Payout batch is not {0}")]
+    InvalidStatus(&'static str),
+    #[error("Payout batch has {0} invalid rows")]
+    HasInvalidRows(i32),
+    #[error("Payout batch must be approved by someone other than its uploader")]
+    SelfApproval,
+    #[error("Object storage is not configured")]
+    StorageNotConfigured,
+    #[error("Object storage error: {0}")]
+    Storage(String),
+    #[error("Payout report is not available yet")]
+    ReportNotFound,
+    #[error("Mint is not an enabled asset: {0}")]
+    AssetNotEnabled(String),
+    #[error("Invalid payout amount in row {0}")]
+    InvalidAmount(i32),
+}
+
+impl ApiError for PayoutError {
+    fn code(&self) -> u16 {
+        match self {
+            PayoutError::BatchNotFound => CustodyErrorCode::PayoutBatchNotFound,
+            PayoutError::UnsupportedFormat(_) => CustodyErrorCode::InvalidPayoutFile,
+            PayoutError::InvalidFile(_) => CustodyErrorCode::InvalidPayoutFile,
+            PayoutError::EmptyFile => CustodyErrorCode::InvalidPayoutFile,
+            PayoutError::TooManyRows(_) => CustodyErrorCode::InvalidPayoutFile,
+            PayoutError::InvalidStatus(_) => CustodyErrorCode::InvalidState,
+            PayoutError::HasInvalidRows(_) => CustodyErrorCode::PayoutHasInvalidRows,
+            PayoutError::SelfApproval => CustodyErrorCode::SelfApproval,
+            PayoutError::StorageNotConfigured => CustodyErrorCode::StorageNotConfigured,
+            PayoutError::Storage(_) => CustodyErrorCode::StorageFailed,
+            PayoutError::ReportNotFound => CustodyErrorCode::PayoutReportNotFound,
+            PayoutError::AssetNotEnabled(_) => CustodyErrorCode::AssetNotEnabled,
+            PayoutError::InvalidAmount(_) => CustodyErrorCode::InvalidPayoutFile,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<PayoutError> for AppError {
+    fn from(err: PayoutError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RentReclamationError {
+    #[error("Invalid mint address: {0}")]
+    InvalidMint(String),
+    #[error("Invalid wallet address: {0}")]
+    InvalidWalletAddress(String),
+    #[error("Amount out of range")]
+    AmountOutOfRange,
+}
+
+impl ApiError for RentReclamationError {
+    fn code(&self) -> u16 {
+        match self {
+            RentReclamationError::InvalidMint(_) => CustodyErrorCode::InvalidAddress,
+            RentReclamationError::InvalidWalletAddress(_) => CustodyErrorCode::InvalidAddress,
+            RentReclamationError::AmountOutOfRange => CustodyErrorCode::AmountOverflow,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<RentReclamationError> for AppError {
+    fn from(err: RentReclamationError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use sol_spl_token::SolanaError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ReservesError {
+    #[error("Reserves snapshot not found")]
+    SnapshotNotFound,
+    #[error("No liability found for current user in this snapshot")]
+    ProofNotFound,
+    #[error("No enabled custody wallet in this domain")]
+    NoCustodyWallets,
+    #[error("Invalid mint address")]
+    InvalidMint,
+    #[error("Invalid custody wallet address: {0}")]
+    InvalidWalletAddress(String),
+    #[error("Unsupported report format")]
+    InvalidReportFormat,
+    #[error("Amount out of range")]
+    AmountOutOfRange,
+    #[error("Stored liabilities do not match the published root")]
+    RootMismatch,
+    #[error("Chain error: {0}")]
+    Chain(#[from] SolanaError),
+}
+
+impl ApiError for ReservesError {
+    fn code(&self) -> u16 {
+        match self {
+            ReservesError::SnapshotNotFound => CustodyErrorCode::ReservesSnapshotNotFound,
+            ReservesError::ProofNotFound => CustodyErrorCode::ReservesProofNotFound,
+            ReservesError::NoCustodyWallets => CustodyErrorCode::NoCustodyWallets,
+            ReservesError::InvalidMint => CustodyErrorCode::InvalidAddress,
+            ReservesError::InvalidWalletAddress(_) => CustodyErrorCode::InvalidAddress,
+            ReservesError::InvalidReportFormat => CustodyErrorCode::InvalidReportFormat,
+            ReservesError::AmountOutOfRange => CustodyErrorCode::AmountOverflow,
+            ReservesError::RootMismatch => CustodyErrorCode::LiabilityRootMismatch,
+            ReservesError::Chain(e) => CustodyErrorCode::from(e),
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<ReservesError> for AppError {
+    fn from(err: ReservesError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StakeError {
+    #[error("Stake account not found")]
+    StakeAccountNotFound,
+    #[error("Validator is not configured for staking: {0}")]
+    ValidatorNotConfigured(String),
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+    #[error("Stake account is {0}")]
+    InvalidStatus(String),
+    #[error("Custody wallet not found")]
+    WalletNotFound,
+    #[error("Private key of wallet {0} is not in the keystore")]
+    KeyNotInKeystore(String),
+}
+
+impl ApiError for StakeError {
+    fn code(&self) -> u16 {
+        match self {
+            StakeError::StakeAccountNotFound => CustodyErrorCode::StakeAccountNotFound,
+            StakeError::ValidatorNotConfigured(_) => CustodyErrorCode::ValidatorNotConfigured,
+            StakeError::InvalidAddress(_) => CustodyErrorCode::InvalidAddress,
+            StakeError::InvalidStatus(_) => CustodyErrorCode::InvalidState,
+            StakeError::WalletNotFound => CustodyErrorCode::WalletNotFound,
+            StakeError::KeyNotInKeystore(_) => CustodyErrorCode::KeyNotInKeystore,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<StakeError> for AppError {
+    fn from(err: StakeError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
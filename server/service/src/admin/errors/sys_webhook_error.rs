@@ -0,0 +1,39 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    #[error("Webhook endpoint not found")]
+    EndpointNotFound,
+    #[error("Webhook delivery not found")]
+    DeliveryNotFound,
+    #[error("Webhook delivery is still in flight and cannot be replayed")]
+    DeliveryInFlight,
+}
+
+impl ApiError for WebhookError {
+    fn code(&self) -> u16 {
+        match self {
+            WebhookError::EndpointNotFound => CustodyErrorCode::WebhookEndpointNotFound,
+            WebhookError::DeliveryNotFound => CustodyErrorCode::WebhookDeliveryNotFound,
+            WebhookError::DeliveryInFlight => CustodyErrorCode::WebhookDeliveryInFlight,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<WebhookError> for AppError {
+    fn from(err: WebhookError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use server_core::web::{
+    error::{ApiError, AppError},
+    error_code::CustodyErrorCode,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WithdrawalFeeError {
+    #[error("Withdrawal fee schedule not found")]
+    FeeNotFound,
+    #[error("Withdrawal fee schedule for this mint already exists")]
+    DuplicateFee,
+    #[error("Invalid mint address: {0}")]
+    InvalidMint(String),
+    #[error("Invalid fee wallet address: {0}")]
+    InvalidFeeWallet(String),
+    #[error("Minimum fee must not exceed maximum fee")]
+    InvalidFeeLimits,
+    #[error("Duplicate fee tier for role: {0}")]
+    DuplicateTier(String),
+    #[error("Tiered fee schedule requires at least one tier")]
+    TiersRequired,
+    #[error("Withdrawal is disabled for this asset")]
+    WithdrawalDisabled,
+    #[error("Withdrawal amount must be between {0} and {1}")]
+    AmountOutOfRange(i64, i64),
+    #[error("Withdrawal amount does not cover the fee")]
+    AmountBelowFee,
+    #[error("Invalid destination address: {0}")]
+    InvalidDestination(String),
+}
+
+impl ApiError for WithdrawalFeeError {
+    fn code(&self) -> u16 {
+        match self {
+            WithdrawalFeeError::FeeNotFound => CustodyErrorCode::WithdrawalFeeNotFound,
+            WithdrawalFeeError::DuplicateFee => CustodyErrorCode::DuplicateWithdrawalFee,
+            WithdrawalFeeError::InvalidMint(_) => CustodyErrorCode::InvalidAddress,
+            WithdrawalFeeError::InvalidFeeWallet(_) => CustodyErrorCode::InvalidAddress,
+            WithdrawalFeeError::InvalidFeeLimits => CustodyErrorCode::InvalidFeeSchedule,
+            WithdrawalFeeError::DuplicateTier(_) => CustodyErrorCode::InvalidFeeSchedule,
+            WithdrawalFeeError::TiersRequired => CustodyErrorCode::InvalidFeeSchedule,
+            WithdrawalFeeError::WithdrawalDisabled => CustodyErrorCode::WithdrawalDisabled,
+            WithdrawalFeeError::AmountOutOfRange(..) => CustodyErrorCode::AmountOutOfRange,
+            WithdrawalFeeError::AmountBelowFee => CustodyErrorCode::AmountBelowFee,
+            WithdrawalFeeError::InvalidDestination(_) => CustodyErrorCode::InvalidAddress,
+        }
+        .code()
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+impl From<WithdrawalFeeError> for AppError {
+    fn from(err: WithdrawalFeeError) -> Self {
+        AppError {
+            code: err.code(),
+            message: err.message(),
+        }
+    }
+}
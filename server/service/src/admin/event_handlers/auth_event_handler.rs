@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use server_core::web::error::AppError;
 
 use crate::{
@@ -5,6 +6,7 @@ use crate::{
     helper::db_helper,
 };
 
+#[derive(Serialize, Deserialize)]
 pub struct AuthEvent {
     pub user_id: String,
     pub username: String,
@@ -3,47 +3,113 @@ pub use server_model::admin::{
     entities::{
         prelude::{SysDomain, SysEndpoint, SysMenu, SysRole, SysUser},
         sys_access_key::Model as SysAccessKeyModel,
+        sys_asset::Model as SysAssetModel,
+        sys_auto_convert_job::Model as SysAutoConvertJobModel,
+        sys_auto_convert_leg::Model as SysAutoConvertLegModel,
+        sys_auto_convert_policy::Model as SysAutoConvertPolicyModel,
+        sys_custody_hold::Model as SysCustodyHoldModel,
         sys_domain::Model as SysDomainModel,
         sys_endpoint::Model as SysEndpointModel,
+        sys_job_run::Model as SysJobRunModel,
+        sys_key_export::Model as SysKeyExportModel,
+        sys_key_export_policy::Model as SysKeyExportPolicyModel,
+        sys_key_rotation::Model as SysKeyRotationModel,
         sys_login_log::Model as SysLoginLogModel,
+        sys_lookup_table::Model as SysLookupTableModel,
         sys_menu::Model as SysMenuModel,
+        sys_mint_operation::Model as SysMintOperationModel,
         sys_operation_log::Model as SysOperationLogModel,
         sys_organization::Model as SysOrganizationModel,
+        sys_outbox::Model as SysOutboxModel,
+        sys_payment_invoice::Model as SysPaymentInvoiceModel,
+        sys_payout_batch::Model as SysPayoutBatchModel,
+        sys_payout_row::Model as SysPayoutRowModel,
+        sys_rent_reclamation::Model as SysRentReclamationModel,
+        sys_reserve_snapshot::Model as SysReserveSnapshotModel,
         sys_role::Model as SysRoleModel,
+        sys_stake_account::Model as SysStakeAccountModel,
+        sys_stake_reward::Model as SysStakeRewardModel,
+        sys_webhook_delivery::Model as SysWebhookDeliveryModel,
+        sys_webhook_endpoint::Model as SysWebhookEndpointModel,
+        sys_withdrawal_fee::Model as SysWithdrawalFeeModel,
+        sys_withdrawal_fee_record::Model as SysWithdrawalFeeRecordModel,
     },
     input::*,
     output::*,
 };
+pub use sol_spl_token::{keystore::EncryptedKey, rpc_pool::RpcPoolMetrics};
 pub use sys_access_key_service::{
     api_key_validate_listener, SysAccessKeyService, TAccessKeyService,
 };
+pub use sys_asset_service::{SysAssetService, TAssetService};
 pub use sys_auth_service::{
     auth_login_listener, jwt_created_listener, SysAuthService, TAuthService,
 };
+pub use sys_auto_convert_service::{
+    auto_convert_job_listener, SysAutoConvertService, TAutoConvertService,
+};
 pub use sys_authorization_service::{SysAuthorizationService, TAuthorizationService};
+pub use sys_balance_service::SysBalanceService;
+pub use sys_custody_hold_service::{
+    CustodyHoldRegistry, SysCustodyHoldService, TCustodyHoldService,
+};
 pub use sys_domain_service::{SysDomainService, TDomainService};
 pub use sys_endpoint_service::{SysEndpointService, TEndpointService};
+pub use sys_job_service::{SysJobService, TJobService};
+pub use sys_key_rotation_service::{SysKeyRotationService, TKeyRotationService};
+pub use sys_keystore_service::{SysKeystoreService, TKeystoreService};
 pub use sys_login_log_service::{SysLoginLogService, TLoginLogService};
+pub use sys_lookup_table_service::{SysLookupTableService, TLookupTableService};
 pub use sys_menu_service::{SysMenuService, TMenuService};
+pub use sys_mint_admin_service::{SysMintAdminService, TMintAdminService};
 pub use sys_operation_log_service::{
     sys_operation_log_listener, SysOperationLogService, TOperationLogService,
 };
 pub use sys_organization_service::{SysOrganizationService, TOrganizationService};
+pub use sys_outbox_service::SysOutboxService;
+pub use sys_payment_invoice_service::{SysPaymentInvoiceService, TPaymentInvoiceService};
+pub use sys_payout_service::{payout_batch_listener, SysPayoutService, TPayoutService};
+pub use sys_rent_reclamation_service::{SysRentReclamationService, TRentReclamationService};
+pub use sys_reserves_service::{SysReservesService, TReservesService};
+pub use sys_risk_screening_service::RiskScreeningStorage;
 pub use sys_role_service::{SysRoleService, TRoleService};
+pub use sys_solana_service::{SysSolanaService, TSolanaService};
+pub use sys_stake_service::{SysStakeService, TStakeService};
 pub use sys_user_service::{SysUserService, TUserService};
+pub use sys_webhook_service::{webhook_event_listener, SysWebhookService, TWebhookService};
+pub use sys_withdrawal_fee_service::{SysWithdrawalFeeService, TWithdrawalFeeService};
 pub mod dto;
 pub mod errors;
 mod sys_access_key_service;
+mod sys_asset_service;
 mod sys_auth_service;
 mod sys_authorization_service;
+mod sys_auto_convert_service;
+mod sys_balance_service;
+mod sys_custody_hold_service;
 mod sys_domain_service;
 mod sys_endpoint_service;
+mod sys_job_service;
+mod sys_key_rotation_service;
+mod sys_keystore_service;
 mod sys_login_log_service;
+mod sys_lookup_table_service;
 mod sys_menu_service;
+mod sys_mint_admin_service;
 mod sys_operation_log_service;
 mod sys_organization_service;
+mod sys_outbox_service;
+mod sys_payment_invoice_service;
+mod sys_payout_service;
+mod sys_rent_reclamation_service;
+mod sys_reserves_service;
+mod sys_risk_screening_service;
 mod sys_role_service;
+mod sys_solana_service;
+mod sys_stake_service;
 mod sys_user_service;
+mod sys_webhook_service;
+mod sys_withdrawal_fee_service;
 
 mod event_handlers;
 mod events;
@@ -0,0 +1,381 @@
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use aws_sdk_s3::{presigning::PresigningConfig, Client as S3Client};
+use chrono::Local;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_global::project_error;
+use server_model::admin::{
+    entities::{
+        prelude::SysAsset,
+        sea_orm_active_enums::{AssetTokenProgram, Status},
+        sys_asset::{
+            ActiveModel as SysAssetActiveModel, Column as SysAssetColumn, Model as SysAssetModel,
+        },
+    },
+    input::{AssetInput, AssetPageRequest, CreateAssetInput, UpdateAssetInput},
+};
+use sol_spl_token::{Pubkey, TokenProgram};
+use ulid::Ulid;
+
+use crate::helper::{db_helper, s3_helper};
+
+use super::sys_asset_error::AssetError;
+
+/// 图标大小上限
+pub const MAX_ICON_BYTES: usize = 512 * 1024;
+
+/// 图标访问链接有效期
+const ICON_URL_TTL: Duration = Duration::from_secs(3600);
+
+#[async_trait]
+pub trait TAssetService {
+    async fn find_paginated_assets(
+        &self,
+        domain: &str,
+        params: AssetPageRequest,
+    ) -> Result<PaginatedData<SysAssetModel>, AppError>;
+
+    async fn create_asset(
+        &self,
+        domain: &str,
+        input: CreateAssetInput,
+        operator: &str,
+    ) -> Result<SysAssetModel, AppError>;
+
+    async fn get_asset(&self, domain: &str, id: &str) -> Result<SysAssetModel, AppError>;
+
+    async fn update_asset(
+        &self,
+        domain: &str,
+        input: UpdateAssetInput,
+        operator: &str,
+    ) -> Result<SysAssetModel, AppError>;
+
+    async fn delete_asset(&self, domain: &str, id: &str) -> Result<(), AppError>;
+
+    async fn upload_icon(
+        &self,
+        domain: &str,
+        id: &str,
+        content_type: &str,
+        data: Vec<u8>,
+        operator: &str,
+    ) -> Result<SysAssetModel, AppError>;
+
+    async fn get_icon_url(&self, domain: &str, id: &str) -> Result<String, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysAssetService;
+
+/// 图标的 MIME 类型对应的扩展名
+fn icon_extension(content_type: &str) -> Option<&'static str> {
+    match content_type {
+        "image/png" => Some("png"),
+        "image/jpeg" => Some("jpg"),
+        "image/webp" => Some("webp"),
+        "image/svg+xml" => Some("svg"),
+        _ => None,
+    }
+}
+
+fn validate_asset(input: &AssetInput) -> Result<(), AppError> {
+    Pubkey::from_str(&input.mint)
+        .map_err(|_| AppError::from(AssetError::InvalidMint(input.mint.clone())))?;
+
+    if input.min_withdrawal > input.max_withdrawal {
+        return Err(AssetError::InvalidWithdrawalLimits.into());
+    }
+
+    Ok(())
+}
+
+impl SysAssetService {
+    /// 域内已启用的资产，其他托管功能据此判断可操作的 mint
+    pub async fn find_enabled_assets(domain: &str) -> Result<Vec<SysAssetModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysAsset::find()
+            .filter(SysAssetColumn::Domain.eq(domain))
+            .filter(SysAssetColumn::Status.eq(Status::Enabled))
+            .order_by_asc(SysAssetColumn::Symbol)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// 资产登记的 Token 程序与小数位，关联账户与转账指令据此生成
+    pub fn token_program(asset: &SysAssetModel) -> (TokenProgram, u8) {
+        let program = match asset.token_program {
+            AssetTokenProgram::SplToken => TokenProgram::SplToken,
+            AssetTokenProgram::Token2022 => TokenProgram::Token2022,
+        };
+        // 登记时已限制为 0..=18
+        (program, asset.decimals as u8)
+    }
+
+    /// 按 mint 查找域内已启用的资产
+    pub async fn find_enabled_asset_by_mint(
+        domain: &str,
+        mint: &str,
+    ) -> Result<SysAssetModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysAsset::find()
+            .filter(SysAssetColumn::Domain.eq(domain))
+            .filter(SysAssetColumn::Mint.eq(mint))
+            .filter(SysAssetColumn::Status.eq(Status::Enabled))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AssetError::AssetNotFound.into())
+    }
+
+    async fn check_mint_exists(
+        &self,
+        domain: &str,
+        id: Option<&str>,
+        mint: &str,
+    ) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mint_exists = SysAsset::find()
+            .filter(SysAssetColumn::Domain.eq(domain))
+            .filter(SysAssetColumn::Mint.eq(mint))
+            .filter(SysAssetColumn::Id.ne(id.unwrap_or("-1")))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .is_some();
+
+        if mint_exists {
+            return Err(AssetError::DuplicateMint.into());
+        }
+
+        Ok(())
+    }
+
+    async fn storage() -> Result<(Arc<S3Client>, String), AppError> {
+        match (
+            s3_helper::get_primary_s3_client().await,
+            s3_helper::get_default_bucket().await,
+        ) {
+            (Some(client), Some(bucket)) => Ok((client, bucket)),
+            _ => Err(AssetError::StorageNotConfigured.into()),
+        }
+    }
+
+    /// 删除旧图标失败只记录日志，不影响主流程
+    async fn delete_icon_object(key: &str) {
+        let Ok((client, bucket)) = Self::storage().await else {
+            return;
+        };
+        if let Err(e) = client.delete_object().bucket(bucket).key(key).send().await {
+            project_error!("Failed to delete asset icon {}: {}", key, e);
+        }
+    }
+}
+
+#[async_trait]
+impl TAssetService for SysAssetService {
+    async fn find_paginated_assets(
+        &self,
+        domain: &str,
+        params: AssetPageRequest,
+    ) -> Result<PaginatedData<SysAssetModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysAsset::find()
+            .filter(SysAssetColumn::Domain.eq(domain))
+            .order_by_asc(SysAssetColumn::Symbol);
+
+        if let Some(ref keywords) = params.keywords {
+            let condition = Condition::any()
+                .add(SysAssetColumn::Symbol.contains(keywords))
+                .add(SysAssetColumn::Name.contains(keywords))
+                .add(SysAssetColumn::Mint.eq(keywords.as_str()));
+            query = query.filter(condition);
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn create_asset(
+        &self,
+        domain: &str,
+        input: CreateAssetInput,
+        operator: &str,
+    ) -> Result<SysAssetModel, AppError> {
+        validate_asset(&input)?;
+        self.check_mint_exists(domain, None, &input.mint).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let asset = SysAssetActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            mint: Set(input.mint),
+            symbol: Set(input.symbol),
+            name: Set(input.name),
+            decimals: Set(input.decimals),
+            token_program: Set(input.token_program),
+            min_withdrawal: Set(input.min_withdrawal),
+            max_withdrawal: Set(input.max_withdrawal),
+            withdrawal_fee: Set(input.withdrawal_fee),
+            deposit_enabled: Set(input.deposit_enabled),
+            withdrawal_enabled: Set(input.withdrawal_enabled),
+            status: Set(input.status),
+            icon_key: Set(None),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        };
+
+        asset.insert(db.as_ref()).await.map_err(AppError::from)
+    }
+
+    async fn get_asset(&self, domain: &str, id: &str) -> Result<SysAssetModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysAsset::find_by_id(id)
+            .filter(SysAssetColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AssetError::AssetNotFound.into())
+    }
+
+    async fn update_asset(
+        &self,
+        domain: &str,
+        input: UpdateAssetInput,
+        operator: &str,
+    ) -> Result<SysAssetModel, AppError> {
+        validate_asset(&input.asset)?;
+        let existing = self.get_asset(domain, &input.id).await?;
+        self.check_mint_exists(domain, Some(&input.id), &input.asset.mint)
+            .await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let mut asset: SysAssetActiveModel = existing.into();
+        asset.mint = Set(input.asset.mint);
+        asset.symbol = Set(input.asset.symbol);
+        asset.name = Set(input.asset.name);
+        asset.decimals = Set(input.asset.decimals);
+        asset.token_program = Set(input.asset.token_program);
+        asset.min_withdrawal = Set(input.asset.min_withdrawal);
+        asset.max_withdrawal = Set(input.asset.max_withdrawal);
+        asset.withdrawal_fee = Set(input.asset.withdrawal_fee);
+        asset.deposit_enabled = Set(input.asset.deposit_enabled);
+        asset.withdrawal_enabled = Set(input.asset.withdrawal_enabled);
+        asset.status = Set(input.asset.status);
+        asset.updated_at = Set(Some(Local::now().naive_local()));
+        asset.updated_by = Set(Some(operator.to_string()));
+
+        asset.update(db.as_ref()).await.map_err(AppError::from)
+    }
+
+    async fn delete_asset(&self, domain: &str, id: &str) -> Result<(), AppError> {
+        let asset = self.get_asset(domain, id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        SysAsset::delete_by_id(id)
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        if let Some(key) = asset.icon_key {
+            Self::delete_icon_object(&key).await;
+        }
+
+        Ok(())
+    }
+
+    async fn upload_icon(
+        &self,
+        domain: &str,
+        id: &str,
+        content_type: &str,
+        data: Vec<u8>,
+        operator: &str,
+    ) -> Result<SysAssetModel, AppError> {
+        let extension = icon_extension(content_type).ok_or_else(|| {
+            AppError::from(AssetError::UnsupportedIconType(content_type.to_string()))
+        })?;
+        if data.len() > MAX_ICON_BYTES {
+            return Err(AssetError::IconTooLarge(MAX_ICON_BYTES).into());
+        }
+
+        let asset = self.get_asset(domain, id).await?;
+        let (client, bucket) = Self::storage().await?;
+
+        // 每次上传使用新的对象键，避免 CDN 缓存旧图标
+        let key = format!(
+            "assets/{}/{}/{}.{}",
+            domain,
+            asset.id,
+            Ulid::new(),
+            extension
+        );
+        client
+            .put_object()
+            .bucket(bucket)
+            .key(&key)
+            .content_type(content_type)
+            .body(data.into())
+            .send()
+            .await
+            .map_err(|e| AppError::from(AssetError::Storage(e.to_string())))?;
+
+        let previous_key = asset.icon_key.clone();
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysAssetActiveModel = asset.into();
+        active.icon_key = Set(Some(key));
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.updated_by = Set(Some(operator.to_string()));
+        let asset = active.update(db.as_ref()).await.map_err(AppError::from)?;
+
+        if let Some(previous_key) = previous_key {
+            Self::delete_icon_object(&previous_key).await;
+        }
+
+        Ok(asset)
+    }
+
+    async fn get_icon_url(&self, domain: &str, id: &str) -> Result<String, AppError> {
+        let asset = self.get_asset(domain, id).await?;
+        let key = asset
+            .icon_key
+            .ok_or_else(|| AppError::from(AssetError::IconNotFound))?;
+        let (client, bucket) = Self::storage().await?;
+
+        let presigning = PresigningConfig::expires_in(ICON_URL_TTL)
+            .map_err(|e| AppError::from(AssetError::Storage(e.to_string())))?;
+        let request = client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .presigned(presigning)
+            .await
+            .map_err(|e| AppError::from(AssetError::Storage(e.to_string())))?;
+
+        Ok(request.uri().to_string())
+    }
+}
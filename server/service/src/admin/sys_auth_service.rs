@@ -1,10 +1,12 @@
 use std::any::Any;
 
 use async_trait::async_trait;
+use chrono::{Duration, Local};
 use sea_orm::{
     ColumnTrait, DatabaseConnection, EntityTrait, JoinType, QueryFilter, QueryOrder, QuerySelect,
     RelationTrait,
 };
+use server_config::JwtConfig;
 use server_constant::definition::{consts::SystemEvent, Audience};
 use server_core::web::{
     auth::Claims,
@@ -14,12 +16,13 @@ use server_core::web::{
 use server_global::global;
 use server_model::admin::{
     entities::{
-        prelude::{SysRole, SysUser},
+        prelude::{SysRole, SysTokens, SysUser},
         sea_orm_active_enums::Status,
         sys_domain::Column as SysDomainColumn,
         sys_menu::{Column as SysMenuColumn, Entity as SysMenuEntity, Model as SysMenuModel},
         sys_role::{Column as SysRoleColumn, Entity as SysRoleEntity, Relation as SysRoleRelation},
         sys_role_menu::{Column as SysRoleMenuColumn, Entity as SysRoleMenuEntity},
+        sys_tokens::Column as SysTokensColumn,
         sys_user::{Column as SysUserColumn, Relation as SysUserRelation},
         sys_user_role::Relation as SysUserRoleRelation,
     },
@@ -97,6 +100,23 @@ impl SysAuthService {
         }
         None
     }
+
+    /// 删除访问令牌已过期的登录令牌记录，返回删除条数
+    pub async fn purge_expired_tokens() -> Result<u64, AppError> {
+        let jwt_config = global::get_config::<JwtConfig>().await.ok_or_else(|| AppError {
+            code: 500,
+            message: "JWT config is not initialized".to_string(),
+        })?;
+        let expired_before = Local::now().naive_local() - Duration::seconds(jwt_config.expire);
+
+        let db = db_helper::get_db_connection().await?;
+        SysTokens::delete_many()
+            .filter(SysTokensColumn::LoginTime.lt(expired_before))
+            .exec(db.as_ref())
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(AppError::from)
+    }
 }
 
 #[async_trait]
@@ -289,10 +309,12 @@ impl SysAuthService {
             login_type: context.login_type.clone(),
         };
 
-        global::send_dyn_event(
+        global::send_outbox_event(
             SystemEvent::AuthLoggedInEvent.as_ref(),
-            Box::new(auth_event),
-        );
+            context.request_id.clone(),
+            auth_event,
+        )
+        .await;
     }
 
     async fn check_login_security(
@@ -363,10 +385,13 @@ pub async fn auth_login_listener(
     mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
 ) {
     while let Some(event) = rx.recv().await {
+        let (event, ack) = global::open_event(event);
         if let Some(auth_event) = event.downcast_ref::<AuthEvent>() {
-            if let Err(e) = handle_auth_event(auth_event).await {
+            let result = handle_auth_event(auth_event).await;
+            if let Err(ref e) = result {
                 project_error!("Failed to handle AuthEvent: {:?}", e);
             }
+            ack.done(result);
         }
     }
 }
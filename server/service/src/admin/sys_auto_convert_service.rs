@@ -0,0 +1,747 @@
+use std::{any::Any, str::FromStr, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use server_constant::definition::consts::SystemEvent;
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_global::{global, project_error, project_info};
+use server_model::admin::{
+    entities::{
+        prelude::{SysAutoConvertJob, SysAutoConvertLeg, SysAutoConvertPolicy, SysUser},
+        sea_orm_active_enums::{
+            AutoConvertJobStatus, AutoConvertLegStatus, BalanceEntryType, Status,
+            WebhookEventType,
+        },
+        sys_auto_convert_job::{
+            ActiveModel as SysAutoConvertJobActiveModel, Column as SysAutoConvertJobColumn,
+            Model as SysAutoConvertJobModel,
+        },
+        sys_auto_convert_leg::{
+            ActiveModel as SysAutoConvertLegActiveModel, Column as SysAutoConvertLegColumn,
+            Model as SysAutoConvertLegModel,
+        },
+        sys_auto_convert_policy::{
+            ActiveModel as SysAutoConvertPolicyActiveModel, Column as SysAutoConvertPolicyColumn,
+            Model as SysAutoConvertPolicyModel,
+        },
+        sys_user::Column as SysUserColumn,
+    },
+    input::{AutoConvertJobPageRequest, DepositNotificationInput, UpsertAutoConvertPolicyInput},
+};
+use sol_spl_token::{
+    convert::{
+        AutoConvertPolicy, AutoConvertStorage, ConvertDeposit, ConvertLegRecord, ConvertOutcome,
+        LegStatus,
+    },
+    memo::parse_sub_account,
+    AutoConverter, Pubkey, Signature, SolanaError,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::{
+    sys_auto_convert_error::AutoConvertError, SysBalanceService,
+    SysCustodyHoldService, SysOutboxService, SysWebhookService,
+};
+
+/// 重试基础间隔
+const RETRY_BACKOFF: Duration = Duration::from_secs(2);
+
+/// pending 或 running 状态超过该时长（分钟）没有进展的任务视为中断
+const STALE_JOB_MINUTES: i64 = 15;
+
+#[async_trait]
+pub trait TAutoConvertService {
+    async fn get_policy(&self, domain: &str) -> Result<SysAutoConvertPolicyModel, AppError>;
+
+    async fn upsert_policy(
+        &self,
+        domain: &str,
+        input: UpsertAutoConvertPolicyInput,
+        operator: &str,
+    ) -> Result<SysAutoConvertPolicyModel, AppError>;
+
+    async fn notify_deposit(
+        &self,
+        domain: &str,
+        input: DepositNotificationInput,
+    ) -> Result<SysAutoConvertJobModel, AppError>;
+
+    async fn find_paginated_jobs(
+        &self,
+        domain: &str,
+        params: AutoConvertJobPageRequest,
+    ) -> Result<PaginatedData<SysAutoConvertJobModel>, AppError>;
+
+    async fn find_job_legs(
+        &self,
+        domain: &str,
+        job_id: &str,
+    ) -> Result<Vec<SysAutoConvertLegModel>, AppError>;
+
+    async fn retry_job(&self, domain: &str, job_id: &str)
+        -> Result<SysAutoConvertJobModel, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysAutoConvertService;
+
+/// 自动兑换任务入队事件
+#[derive(Serialize, Deserialize)]
+pub struct AutoConvertJobEvent {
+    pub job_id: String,
+}
+
+fn parse_mint(mint: &str) -> Result<Pubkey, AppError> {
+    Pubkey::from_str(mint).map_err(|_| AppError::from(AutoConvertError::InvalidMint(mint.to_string())))
+}
+
+fn to_i64(value: u64) -> Result<i64, AppError> {
+    i64::try_from(value).map_err(|_| AppError::from(AutoConvertError::AmountOutOfRange))
+}
+
+fn to_u64(value: i64) -> Result<u64, AppError> {
+    u64::try_from(value).map_err(|_| AppError::from(AutoConvertError::AmountOutOfRange))
+}
+
+/// 将策略记录转换为链上执行使用的策略
+fn to_convert_policy(
+    policy: &SysAutoConvertPolicyModel,
+    dry_run: bool,
+) -> Result<AutoConvertPolicy, AppError> {
+    let source_mints = policy
+        .source_mints
+        .split(',')
+        .filter(|mint| !mint.is_empty())
+        .map(parse_mint)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let convert_policy = AutoConvertPolicy {
+        source_mints,
+        stablecoin_mint: parse_mint(&policy.stablecoin_mint)?,
+        target_token_mint: parse_mint(&policy.target_token_mint)?,
+        convert_bps: u16::try_from(policy.convert_bps)
+            .map_err(|_| AppError::from(AutoConvertError::InvalidPolicy("convert_bps".to_string())))?,
+        min_trade_amount: to_u64(policy.min_trade_amount)?,
+        max_slippage: f64::from(policy.max_slippage_bps) / 10_000.0,
+        dry_run,
+        max_retries: u32::try_from(policy.max_retries).unwrap_or(0),
+        retry_backoff: RETRY_BACKOFF,
+    };
+
+    convert_policy
+        .validate()
+        .map_err(|e| AppError::from(AutoConvertError::InvalidPolicy(e.to_string())))?;
+
+    Ok(convert_policy)
+}
+
+/// 单腿记录落库
+struct DbAutoConvertStorage;
+
+#[async_trait]
+impl AutoConvertStorage for DbAutoConvertStorage {
+    async fn save_leg(&self, record: &ConvertLegRecord) -> sol_spl_token::error::Result<()> {
+        let to_i64 = |value: u64| {
+            i64::try_from(value).map_err(|_| SolanaError::Other("Amount out of range".to_string()))
+        };
+        let status = match record.status {
+            LegStatus::DryRun => AutoConvertLegStatus::DryRun,
+            LegStatus::Landed => AutoConvertLegStatus::Landed,
+            LegStatus::Failed => AutoConvertLegStatus::Failed,
+        };
+
+        let leg = SysAutoConvertLegActiveModel {
+            id: Set(Ulid::new().to_string()),
+            job_id: Set(record.job_id.clone()),
+            leg_index: Set(record.leg_index as i32),
+            attempt: Set(record.attempt as i32),
+            from_mint: Set(record.from_mint.to_string()),
+            to_mint: Set(record.to_mint.to_string()),
+            from_amount: Set(to_i64(record.from_amount)?),
+            to_amount: Set(to_i64(record.to_amount)?),
+            min_to_amount: Set(to_i64(record.min_to_amount)?),
+            slippage: Set(record.slippage),
+            execution_price: Set(record.execution_price),
+            oracle_price: Set(record.oracle_price),
+            price_impact_bps: Set(record.price_impact_bps.map(|bps| bps as i32)),
+            signature: Set(record.signature.clone()),
+            status: Set(status),
+            error: Set(record.error.clone()),
+            created_at: Set(Local::now().naive_local()),
+        };
+
+        let db = db_helper::get_db_connection()
+            .await
+            .map_err(|e| SolanaError::Other(e.message))?;
+        leg.insert(db.as_ref())
+            .await
+            .map_err(|e| SolanaError::Other(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+impl SysAutoConvertService {
+    async fn find_job(&self, domain: &str, job_id: &str) -> Result<SysAutoConvertJobModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysAutoConvertJob::find_by_id(job_id)
+            .filter(SysAutoConvertJobColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AutoConvertError::JobNotFound.into())
+    }
+
+    /// 在任务所在的事务中写入入队事件，提交后再唤醒中继
+    async fn enqueue<C: ConnectionTrait>(
+        conn: &C,
+        job_id: &str,
+        dedup_key: &str,
+    ) -> Result<(), AppError> {
+        SysOutboxService::enqueue(
+            conn,
+            SystemEvent::AutoConvertJobQueuedEvent,
+            dedup_key,
+            &AutoConvertJobEvent {
+                job_id: job_id.to_string(),
+            },
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// 领取一个待处理的兑换任务，把任务从 pending 置为 running
+    ///
+    /// 只有领取成功的调用者才会执行，重复投递的事件不会导致同一笔入金被兑换两次；
+    /// 任务已被领取或不存在时返回 None
+    pub async fn claim_job(job_id: &str) -> Result<Option<SysAutoConvertJobModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+
+        let claimed = SysAutoConvertJob::update_many()
+            .col_expr(
+                SysAutoConvertJobColumn::Status,
+                Expr::value(AutoConvertJobStatus::Running),
+            )
+            .col_expr(
+                SysAutoConvertJobColumn::Attempts,
+                Expr::col(SysAutoConvertJobColumn::Attempts).add(1),
+            )
+            .col_expr(
+                SysAutoConvertJobColumn::UpdatedAt,
+                Expr::value(Local::now().naive_local()),
+            )
+            .filter(SysAutoConvertJobColumn::Id.eq(job_id))
+            .filter(SysAutoConvertJobColumn::Status.eq(AutoConvertJobStatus::Pending))
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if claimed.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        SysAutoConvertJob::find_by_id(job_id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// 恢复中断的兑换任务，返回处理的任务数
+    ///
+    /// 长时间停在 pending 的任务尚未执行，重新入队；停在 running 的任务执行实例已退出，
+    /// 可能已发出交易，置为 failed 并记录原因，由 [`TAutoConvertService::retry_job`]
+    /// 在确认没有落地的腿后重试
+    pub async fn recover_stale_jobs() -> Result<usize, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let now = Local::now().naive_local();
+        let stale_before = now - chrono::Duration::minutes(STALE_JOB_MINUTES);
+        let stale = Condition::any()
+            .add(SysAutoConvertJobColumn::UpdatedAt.lt(stale_before))
+            .add(
+                Condition::all()
+                    .add(SysAutoConvertJobColumn::UpdatedAt.is_null())
+                    .add(SysAutoConvertJobColumn::CreatedAt.lt(stale_before)),
+            );
+
+        let interrupted = SysAutoConvertJob::update_many()
+            .col_expr(
+                SysAutoConvertJobColumn::Status,
+                Expr::value(AutoConvertJobStatus::Failed),
+            )
+            .col_expr(
+                SysAutoConvertJobColumn::Error,
+                Expr::value("Interrupted while running, check landed legs before retrying"),
+            )
+            .col_expr(SysAutoConvertJobColumn::UpdatedAt, Expr::value(now))
+            .filter(SysAutoConvertJobColumn::Status.eq(AutoConvertJobStatus::Running))
+            .filter(stale.clone())
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .rows_affected as usize;
+        if interrupted > 0 {
+            project_error!("Marked {} interrupted auto-convert jobs as failed", interrupted);
+        }
+
+        let pending = SysAutoConvertJob::find()
+            .filter(SysAutoConvertJobColumn::Status.eq(AutoConvertJobStatus::Pending))
+            .filter(stale.clone())
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        let mut requeued = 0;
+        for job in pending {
+            // 以状态与时间做比较交换，多个实例中只有一个会重新入队
+            let txn = db.begin().await.map_err(AppError::from)?;
+            let touched = SysAutoConvertJob::update_many()
+                .col_expr(SysAutoConvertJobColumn::UpdatedAt, Expr::value(now))
+                .filter(SysAutoConvertJobColumn::Id.eq(job.id.as_str()))
+                .filter(SysAutoConvertJobColumn::Status.eq(AutoConvertJobStatus::Pending))
+                .filter(stale.clone())
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?;
+            if touched.rows_affected == 0 {
+                txn.rollback().await.map_err(AppError::from)?;
+                continue;
+            }
+            let dedup_key = format!("{}:{}", job.id, now.and_utc().timestamp_millis());
+            Self::enqueue(&txn, &job.id, &dedup_key).await?;
+            txn.commit().await.map_err(AppError::from)?;
+            requeued += 1;
+        }
+        if requeued > 0 {
+            project_info!("Requeued {} stale auto-convert jobs", requeued);
+            SysOutboxService::wake_relay();
+        }
+
+        Ok(interrupted + requeued)
+    }
+
+    /// 执行已领取的兑换任务并记录结果
+    pub async fn run_job(job: SysAutoConvertJobModel) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let outcome = Self::execute_job(&job).await;
+
+        // 只结算实际上链的兑换，dry-run 与模拟交易不影响用户余额
+        let settlement = outcome.as_ref().ok().and_then(ConvertOutcome::settlement);
+        let mut active: SysAutoConvertJobActiveModel = job.into();
+        match outcome {
+            Ok(ConvertOutcome::Skipped(reason)) => {
+                active.status = Set(AutoConvertJobStatus::Skipped);
+                active.error = Set(Some(reason));
+            },
+            Ok(ConvertOutcome::Completed { legs, .. }) => {
+                active.status = Set(AutoConvertJobStatus::Succeeded);
+                active.convert_amount = Set(match legs.first() {
+                    Some(leg) => Some(to_i64(leg.from_amount)?),
+                    None => None,
+                });
+                active.error = Set(None);
+            },
+            Err(e) => {
+                active.status = Set(AutoConvertJobStatus::Failed);
+                active.error = Set(Some(e.message));
+            },
+        }
+        active.updated_at = Set(Some(Local::now().naive_local()));
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let job = active.update(&txn).await.map_err(AppError::from)?;
+        if job.status == AutoConvertJobStatus::Succeeded && !job.dry_run {
+            // 兑换结果同步到用户余额：扣减源资产，入账目标资产
+            if let (Some(user_id), Some(settlement)) = (&job.user_id, settlement) {
+                SysBalanceService::debit(
+                    &txn,
+                    &job.domain,
+                    user_id,
+                    &settlement.from_mint.to_string(),
+                    to_i64(settlement.from_amount)?,
+                    BalanceEntryType::ConvertOut,
+                    &job.id,
+                )
+                .await?;
+                SysBalanceService::credit(
+                    &txn,
+                    &job.domain,
+                    user_id,
+                    &settlement.to_mint.to_string(),
+                    to_i64(settlement.to_amount)?,
+                    BalanceEntryType::ConvertIn,
+                    &job.id,
+                )
+                .await?;
+            }
+            SysWebhookService::notify(&txn, &job.domain, WebhookEventType::SwapCompleted, &job)
+                .await?;
+        }
+        txn.commit().await.map_err(AppError::from)?;
+        SysOutboxService::wake_relay();
+
+        Ok(())
+    }
+
+    async fn execute_job(job: &SysAutoConvertJobModel) -> Result<ConvertOutcome, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let policy = SysAutoConvertPolicy::find_by_id(job.policy_id.as_str())
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(AutoConvertError::PolicyNotFound))?;
+        if policy.status != Status::Enabled {
+            return Err(AutoConvertError::PolicyDisabled.into());
+        }
+        // 入金照常入账，被冻结用户的入金不做兑换
+        if let Some(ref user_id) = job.user_id {
+            SysCustodyHoldService::ensure_not_held(&job.domain, user_id).await?;
+        }
+
+        let convert_policy = to_convert_policy(&policy, job.dry_run)?;
+        let deposit = ConvertDeposit {
+            deposit_id: job.deposit_id.clone(),
+            mint: parse_mint(&job.source_mint)?,
+            amount: to_u64(job.deposit_amount)?,
+        };
+
+        // 入金归集在系统钱包，兑换由系统钱包执行
+        let keypair = solana_helper::get_system_keypair().await?;
+        let swap_manager = solana_helper::get_swap_manager().await?;
+        let converter = AutoConverter::new(Arc::new(swap_manager))
+            .with_storage(Arc::new(DbAutoConvertStorage));
+
+        converter
+            .run(&job.id, &convert_policy, &keypair, &deposit)
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// 按入金备注路由到子账户，备注中的子账户标识即域内用户 ID
+    ///
+    /// 未上报备注时从入金交易中读取；读取失败或没有匹配的用户时返回 None，
+    /// 入金照常记录，只是不归属到子账户
+    async fn route_deposit(
+        domain: &str,
+        deposit_id: &str,
+        memo: Option<String>,
+    ) -> Result<Option<String>, AppError> {
+        let memos = match memo {
+            Some(memo) => vec![memo],
+            None => match Signature::from_str(deposit_id) {
+                Ok(signature) => {
+                    let fetched = match solana_helper::get_memo_reader().await {
+                        Ok(reader) => reader.fetch_memos(&signature).map_err(AppError::from),
+                        Err(e) => Err(e),
+                    };
+                    fetched.unwrap_or_else(|e| {
+                        project_error!("Failed to read memos of deposit {}: {}", deposit_id, e.message);
+                        Vec::new()
+                    })
+                },
+                Err(_) => Vec::new(),
+            },
+        };
+
+        let db = db_helper::get_db_connection().await?;
+        for sub_account in memos.iter().filter_map(|memo| parse_sub_account(memo)) {
+            let exists = SysUser::find_by_id(sub_account.as_str())
+                .filter(SysUserColumn::Domain.eq(domain))
+                .count(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+            if exists > 0 {
+                project_info!("Deposit {} routed to sub-account {}", deposit_id, sub_account);
+                return Ok(Some(sub_account));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl TAutoConvertService for SysAutoConvertService {
+    async fn get_policy(&self, domain: &str) -> Result<SysAutoConvertPolicyModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysAutoConvertPolicy::find()
+            .filter(SysAutoConvertPolicyColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AutoConvertError::PolicyNotFound.into())
+    }
+
+    async fn upsert_policy(
+        &self,
+        domain: &str,
+        input: UpsertAutoConvertPolicyInput,
+        operator: &str,
+    ) -> Result<SysAutoConvertPolicyModel, AppError> {
+        for mint in &input.source_mints {
+            parse_mint(mint)?;
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let existing = SysAutoConvertPolicy::find()
+            .filter(SysAutoConvertPolicyColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let now = Local::now().naive_local();
+        let policy = SysAutoConvertPolicyModel {
+            id: Ulid::new().to_string(),
+            domain: domain.to_string(),
+            source_mints: input.source_mints.join(","),
+            stablecoin_mint: input.stablecoin_mint,
+            target_token_mint: input.target_token_mint,
+            convert_bps: input.convert_bps,
+            min_trade_amount: input.min_trade_amount,
+            max_slippage_bps: input.max_slippage_bps,
+            dry_run: input.dry_run,
+            max_retries: input.max_retries,
+            status: input.status,
+            created_at: now,
+            created_by: operator.to_string(),
+            updated_at: None,
+            updated_by: None,
+        };
+
+        // 保存前按执行时的规则校验一遍
+        to_convert_policy(&policy, policy.dry_run)?;
+
+        let result = match existing {
+            Some(existing) => {
+                let active: SysAutoConvertPolicyActiveModel = existing.into();
+                SysAutoConvertPolicyActiveModel {
+                    source_mints: Set(policy.source_mints),
+                    stablecoin_mint: Set(policy.stablecoin_mint),
+                    target_token_mint: Set(policy.target_token_mint),
+                    convert_bps: Set(policy.convert_bps),
+                    min_trade_amount: Set(policy.min_trade_amount),
+                    max_slippage_bps: Set(policy.max_slippage_bps),
+                    dry_run: Set(policy.dry_run),
+                    max_retries: Set(policy.max_retries),
+                    status: Set(policy.status),
+                    updated_at: Set(Some(now)),
+                    updated_by: Set(Some(operator.to_string())),
+                    ..active
+                }
+                .update(db.as_ref())
+                .await
+            },
+            None => SysAutoConvertPolicyActiveModel::from(policy)
+                .reset_all()
+                .insert(db.as_ref())
+                .await,
+        }
+        .map_err(AppError::from)?;
+
+        Ok(result)
+    }
+
+    async fn notify_deposit(
+        &self,
+        domain: &str,
+        input: DepositNotificationInput,
+    ) -> Result<SysAutoConvertJobModel, AppError> {
+        parse_mint(&input.mint)?;
+        let policy = self.get_policy(domain).await?;
+        if policy.status != Status::Enabled {
+            return Err(AutoConvertError::PolicyDisabled.into());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let duplicate = SysAutoConvertJob::find()
+            .filter(SysAutoConvertJobColumn::Domain.eq(domain))
+            .filter(SysAutoConvertJobColumn::DepositId.eq(input.deposit_id.as_str()))
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if duplicate > 0 {
+            return Err(AutoConvertError::DuplicateDeposit.into());
+        }
+
+        let user_id = match input.user_id {
+            Some(user_id) => Some(user_id),
+            None => Self::route_deposit(domain, &input.deposit_id, input.memo).await?,
+        };
+
+        let job = SysAutoConvertJobActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            policy_id: Set(policy.id),
+            deposit_id: Set(input.deposit_id),
+            user_id: Set(user_id),
+            source_mint: Set(input.mint),
+            deposit_amount: Set(input.amount),
+            convert_amount: Set(None),
+            status: Set(AutoConvertJobStatus::Pending),
+            dry_run: Set(policy.dry_run),
+            attempts: Set(0),
+            error: Set(None),
+            created_at: Set(Local::now().naive_local()),
+            updated_at: Set(None),
+        };
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let job = job.insert(&txn).await.map_err(AppError::from)?;
+        if let Some(ref user_id) = job.user_id {
+            SysBalanceService::credit(
+                &txn,
+                domain,
+                user_id,
+                &job.source_mint,
+                job.deposit_amount,
+                BalanceEntryType::Deposit,
+                &job.deposit_id,
+            )
+            .await?;
+        }
+        Self::enqueue(&txn, &job.id, &job.id).await?;
+        SysWebhookService::notify(
+            &txn,
+            domain,
+            WebhookEventType::DepositDetected,
+            json!({
+                "depositId": job.deposit_id,
+                "userId": job.user_id,
+                "mint": job.source_mint,
+                "amount": job.deposit_amount,
+            }),
+        )
+        .await?;
+        txn.commit().await.map_err(AppError::from)?;
+        SysOutboxService::wake_relay();
+
+        Ok(job)
+    }
+
+    async fn find_paginated_jobs(
+        &self,
+        domain: &str,
+        params: AutoConvertJobPageRequest,
+    ) -> Result<PaginatedData<SysAutoConvertJobModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysAutoConvertJob::find()
+            .filter(SysAutoConvertJobColumn::Domain.eq(domain))
+            .order_by_desc(SysAutoConvertJobColumn::CreatedAt);
+
+        if let Some(status) = params.status {
+            query = query.filter(SysAutoConvertJobColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn find_job_legs(
+        &self,
+        domain: &str,
+        job_id: &str,
+    ) -> Result<Vec<SysAutoConvertLegModel>, AppError> {
+        let job = self.find_job(domain, job_id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        SysAutoConvertLeg::find()
+            .filter(SysAutoConvertLegColumn::JobId.eq(job.id))
+            .order_by_asc(SysAutoConvertLegColumn::LegIndex)
+            .order_by_asc(SysAutoConvertLegColumn::Attempt)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn retry_job(
+        &self,
+        domain: &str,
+        job_id: &str,
+    ) -> Result<SysAutoConvertJobModel, AppError> {
+        let job = self.find_job(domain, job_id).await?;
+        if job.status != AutoConvertJobStatus::Failed {
+            return Err(AutoConvertError::JobNotRetryable.into());
+        }
+
+        // 已有落地的腿时资金已经换出，整单重跑会重复兑换，需人工处理
+        let db = db_helper::get_db_connection().await?;
+        let landed = SysAutoConvertLeg::find()
+            .filter(SysAutoConvertLegColumn::JobId.eq(job.id.as_str()))
+            .filter(SysAutoConvertLegColumn::Status.eq(AutoConvertLegStatus::Landed))
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if landed > 0 {
+            return Err(AutoConvertError::JobNotRetryable.into());
+        }
+
+        let retried_at = Local::now().naive_local();
+        let mut active: SysAutoConvertJobActiveModel = job.into();
+        active.status = Set(AutoConvertJobStatus::Pending);
+        active.error = Set(None);
+        active.updated_at = Set(Some(retried_at));
+
+        // 每次重试都需要重新入队，去重键带上重试时间
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let job = active.update(&txn).await.map_err(AppError::from)?;
+        let dedup_key = format!("{}:{}", job.id, retried_at.and_utc().timestamp_millis());
+        Self::enqueue(&txn, &job.id, &dedup_key).await?;
+        txn.commit().await.map_err(AppError::from)?;
+        SysOutboxService::wake_relay();
+
+        Ok(job)
+    }
+}
+
+#[instrument(skip(rx))]
+pub async fn auto_convert_job_listener(
+    mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
+) {
+    while let Some(event) = rx.recv().await {
+        let (event, ack) = global::open_event(event);
+        if let Some(job_event) = event.downcast_ref::<AutoConvertJobEvent>() {
+            let job_id = job_event.job_id.clone();
+            // 领取后任务状态已落库，即可确认事件；单个任务可能等待链上确认，不阻塞后续任务
+            match SysAutoConvertService::claim_job(&job_id).await {
+                Ok(Some(job)) => {
+                    ack.ok();
+                    tokio::spawn(async move {
+                        project_info!("Running auto-convert job {}", job_id);
+                        if let Err(e) = SysAutoConvertService::run_job(job).await {
+                            project_error!("Failed to run auto-convert job {}: {:?}", job_id, e);
+                        }
+                    });
+                },
+                Ok(None) => ack.ok(),
+                Err(e) => {
+                    project_error!("Failed to claim auto-convert job {}: {:?}", job_id, e);
+                    ack.done(Err(e));
+                },
+            }
+        } else {
+            project_error!("Received unknown event type in auto-convert listener");
+        }
+    }
+}
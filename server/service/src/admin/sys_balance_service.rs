@@ -0,0 +1,183 @@
+use chrono::Local;
+use sea_orm::{
+    sea_query::{Expr, OnConflict},
+    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use server_core::web::error::AppError;
+use server_model::admin::entities::{
+    prelude::{SysBalanceEntry, SysUserBalance},
+    sea_orm_active_enums::BalanceEntryType,
+    sys_balance_entry::{
+        ActiveModel as SysBalanceEntryActiveModel, Column as SysBalanceEntryColumn,
+    },
+    sys_user_balance::{
+        ActiveModel as SysUserBalanceActiveModel, Column as SysUserBalanceColumn,
+        Model as SysUserBalanceModel,
+    },
+};
+use sol_spl_token::SolanaError;
+use ulid::Ulid;
+
+use crate::helper::db_helper;
+
+/// 用户余额账本
+///
+/// 余额表保存每个用户每种资产的可用余额，流水表逐笔记录变动。
+/// 同一业务引用的同类变动只记一次，重复调用不会重复入账或扣减；
+/// 调用方在业务记录所在的事务中记账，二者同时提交或回滚
+pub struct SysBalanceService;
+
+impl SysBalanceService {
+    /// 入账，返回是否实际记账，业务引用已记过时返回 false
+    pub async fn credit<C: ConnectionTrait>(
+        conn: &C,
+        domain: &str,
+        user_id: &str,
+        mint: &str,
+        amount: i64,
+        entry_type: BalanceEntryType,
+        reference: &str,
+    ) -> Result<bool, AppError> {
+        Self::apply(conn, domain, user_id, mint, amount, entry_type, reference).await
+    }
+
+    /// 扣减，余额不足时返回错误且不做任何变动
+    pub async fn debit<C: ConnectionTrait>(
+        conn: &C,
+        domain: &str,
+        user_id: &str,
+        mint: &str,
+        amount: i64,
+        entry_type: BalanceEntryType,
+        reference: &str,
+    ) -> Result<bool, AppError> {
+        Self::apply(conn, domain, user_id, mint, -amount, entry_type, reference).await
+    }
+
+    /// 域内某资产余额为正的用户，作为储备金证明的用户负债
+    pub async fn find_liabilities(
+        domain: &str,
+        mint: &str,
+    ) -> Result<Vec<SysUserBalanceModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysUserBalance::find()
+            .filter(SysUserBalanceColumn::Domain.eq(domain))
+            .filter(SysUserBalanceColumn::Mint.eq(mint))
+            .filter(SysUserBalanceColumn::Balance.gt(0))
+            .order_by_asc(SysUserBalanceColumn::UserId)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// 用户的全部余额行，在事务中调用时锁定，提交前其他变动需等待
+    pub async fn lock_user_balances<C: ConnectionTrait>(
+        conn: &C,
+        domain: &str,
+        user_id: &str,
+    ) -> Result<Vec<SysUserBalanceModel>, AppError> {
+        SysUserBalance::find()
+            .filter(SysUserBalanceColumn::Domain.eq(domain))
+            .filter(SysUserBalanceColumn::UserId.eq(user_id))
+            .order_by_asc(SysUserBalanceColumn::Mint)
+            .lock_exclusive()
+            .all(conn)
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn apply<C: ConnectionTrait>(
+        conn: &C,
+        domain: &str,
+        user_id: &str,
+        mint: &str,
+        delta: i64,
+        entry_type: BalanceEntryType,
+        reference: &str,
+    ) -> Result<bool, AppError> {
+        let recorded = SysBalanceEntry::find()
+            .filter(SysBalanceEntryColumn::Domain.eq(domain))
+            .filter(SysBalanceEntryColumn::EntryType.eq(entry_type))
+            .filter(SysBalanceEntryColumn::Reference.eq(reference))
+            .count(conn)
+            .await
+            .map_err(AppError::from)?;
+        if recorded > 0 {
+            return Ok(false);
+        }
+
+        let now = Local::now().naive_local();
+        // 首次变动时创建余额行
+        SysUserBalance::insert(SysUserBalanceActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            user_id: Set(user_id.to_string()),
+            mint: Set(mint.to_string()),
+            balance: Set(0),
+            created_at: Set(now),
+            updated_at: Set(None),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                SysUserBalanceColumn::Domain,
+                SysUserBalanceColumn::UserId,
+                SysUserBalanceColumn::Mint,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(conn)
+        .await
+        .map_err(AppError::from)?;
+
+        // 扣减以余额充足为条件，并发扣减不会把余额扣成负数
+        let mut update = SysUserBalance::update_many()
+            .col_expr(
+                SysUserBalanceColumn::Balance,
+                Expr::col(SysUserBalanceColumn::Balance).add(delta),
+            )
+            .col_expr(SysUserBalanceColumn::UpdatedAt, Expr::value(now))
+            .filter(SysUserBalanceColumn::Domain.eq(domain))
+            .filter(SysUserBalanceColumn::UserId.eq(user_id))
+            .filter(SysUserBalanceColumn::Mint.eq(mint));
+        if delta < 0 {
+            update = update.filter(SysUserBalanceColumn::Balance.gte(-delta));
+        }
+        let updated = update.exec(conn).await.map_err(AppError::from)?;
+        if updated.rows_affected == 0 {
+            return Err(SolanaError::InsufficientBalance(format!(
+                "Balance of {} is below {}",
+                mint, -delta
+            ))
+            .into());
+        }
+
+        let balance = SysUserBalance::find()
+            .filter(SysUserBalanceColumn::Domain.eq(domain))
+            .filter(SysUserBalanceColumn::UserId.eq(user_id))
+            .filter(SysUserBalanceColumn::Mint.eq(mint))
+            .one(conn)
+            .await
+            .map_err(AppError::from)?
+            .map(|balance| balance.balance)
+            .unwrap_or_default();
+
+        SysBalanceEntry::insert(SysBalanceEntryActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            user_id: Set(user_id.to_string()),
+            mint: Set(mint.to_string()),
+            entry_type: Set(entry_type),
+            amount: Set(delta),
+            balance_after: Set(balance),
+            reference: Set(reference.to_string()),
+            created_at: Set(now),
+        })
+        .exec_without_returning(conn)
+        .await
+        .map_err(AppError::from)?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,388 @@
+use async_trait::async_trait;
+use chrono::{Duration, Local};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, EntityTrait, IntoActiveModel,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_model::admin::{
+    entities::{
+        prelude::{SysCustodyHold, SysCustodyHoldAudit, SysCustodyWallet, SysUser},
+        sea_orm_active_enums::{CustodyHoldAction, CustodyHoldSource, CustodyHoldStatus, Status},
+        sys_custody_hold::{
+            ActiveModel as SysCustodyHoldActiveModel, Column as SysCustodyHoldColumn,
+            Model as SysCustodyHoldModel,
+        },
+        sys_custody_hold_audit::{
+            ActiveModel as SysCustodyHoldAuditActiveModel, Column as SysCustodyHoldAuditColumn,
+        },
+        sys_custody_wallet::Column as SysCustodyWalletColumn,
+        sys_user::{Column as SysUserColumn, Model as SysUserModel},
+    },
+    input::{CustodyHoldPageRequest, PlaceCustodyHoldInput, ReleaseCustodyHoldInput},
+    output::CustodyHoldOutput,
+};
+use sol_spl_token::{hold::Hold, HoldRegistry, Pubkey, SolanaError};
+use ulid::Ulid;
+
+use crate::helper::db_helper;
+
+use super::sys_custody_hold_error::CustodyHoldError;
+
+/// 用户状态变更触发的冻结与解除记在系统名下
+const SYSTEM_OPERATOR: &str = "system";
+
+#[async_trait]
+pub trait TCustodyHoldService {
+    async fn find_paginated_holds(
+        &self,
+        domain: &str,
+        params: CustodyHoldPageRequest,
+    ) -> Result<PaginatedData<SysCustodyHoldModel>, AppError>;
+
+    async fn place_hold(
+        &self,
+        domain: &str,
+        input: PlaceCustodyHoldInput,
+        operator: &str,
+    ) -> Result<SysCustodyHoldModel, AppError>;
+
+    async fn get_hold(&self, domain: &str, id: &str) -> Result<CustodyHoldOutput, AppError>;
+
+    async fn release_hold(
+        &self,
+        domain: &str,
+        id: &str,
+        input: ReleaseCustodyHoldInput,
+        operator: &str,
+    ) -> Result<SysCustodyHoldModel, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysCustodyHoldService;
+
+impl SysCustodyHoldService {
+    async fn find_hold(&self, domain: &str, id: &str) -> Result<SysCustodyHoldModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysCustodyHold::find_by_id(id)
+            .filter(SysCustodyHoldColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| CustodyHoldError::HoldNotFound.into())
+    }
+
+    async fn audit<C: ConnectionTrait>(
+        conn: &C,
+        hold: &SysCustodyHoldModel,
+        action: CustodyHoldAction,
+        operator: &str,
+        detail: Option<String>,
+    ) -> Result<(), AppError> {
+        SysCustodyHoldAuditActiveModel {
+            id: Set(Ulid::new().to_string()),
+            hold_id: Set(hold.id.clone()),
+            domain: Set(hold.domain.clone()),
+            action: Set(action),
+            operator: Set(operator.to_string()),
+            detail: Set(detail),
+            created_at: Set(Local::now().naive_local()),
+        }
+        .insert(conn)
+        .await
+        .map_err(AppError::from)?;
+        Ok(())
+    }
+
+    async fn insert_hold<C: ConnectionTrait>(
+        conn: &C,
+        hold: SysCustodyHoldActiveModel,
+        operator: &str,
+    ) -> Result<SysCustodyHoldModel, AppError> {
+        let hold = hold.insert(conn).await.map_err(AppError::from)?;
+        Self::audit(
+            conn,
+            &hold,
+            CustodyHoldAction::Placed,
+            operator,
+            Some(hold.reason.clone()),
+        )
+        .await?;
+        Ok(hold)
+    }
+
+    async fn release<C: ConnectionTrait>(
+        conn: &C,
+        hold: SysCustodyHoldModel,
+        reason: String,
+        operator: &str,
+    ) -> Result<SysCustodyHoldModel, AppError> {
+        let mut active = hold.into_active_model();
+        active.status = Set(CustodyHoldStatus::Released);
+        active.released_at = Set(Some(Local::now().naive_local()));
+        active.released_by = Set(Some(operator.to_string()));
+        active.release_reason = Set(Some(reason.clone()));
+        let released = active.update(conn).await.map_err(AppError::from)?;
+
+        Self::audit(
+            conn,
+            &released,
+            CustodyHoldAction::Released,
+            operator,
+            Some(reason),
+        )
+        .await?;
+        Ok(released)
+    }
+
+    /// 用户当前生效的冻结：未停用的用户看未解除且未到期的冻结记录，停用或封禁的用户一律冻结
+    async fn find_user_hold<C: ConnectionTrait>(
+        conn: &C,
+        domain: &str,
+        user_id: &str,
+    ) -> Result<Option<Hold>, AppError> {
+        let user = SysUser::find_by_id(user_id)
+            .filter(SysUserColumn::Domain.eq(domain))
+            .one(conn)
+            .await
+            .map_err(AppError::from)?;
+        if user.is_some_and(|user| user.status != Status::Enabled) {
+            return Ok(Some(Hold {
+                reason: "user is not enabled".to_string(),
+                expires_at: None,
+            }));
+        }
+
+        let now = Local::now().naive_local();
+        let hold = SysCustodyHold::find()
+            .filter(SysCustodyHoldColumn::Domain.eq(domain))
+            .filter(SysCustodyHoldColumn::UserId.eq(user_id))
+            .filter(SysCustodyHoldColumn::Status.eq(CustodyHoldStatus::Active))
+            .filter(
+                Condition::any()
+                    .add(SysCustodyHoldColumn::ExpiresAt.is_null())
+                    .add(SysCustodyHoldColumn::ExpiresAt.gt(now)),
+            )
+            .order_by_asc(SysCustodyHoldColumn::CreatedAt)
+            .one(conn)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(hold.map(|hold| Hold {
+            reason: hold.reason,
+            expires_at: hold
+                .expires_at
+                .and_then(|expires_at| expires_at.and_local_timezone(Local).earliest())
+                .map(|expires_at| expires_at.timestamp()),
+        }))
+    }
+
+    /// 用户的托管资金被冻结时拒绝转出、兑换与质押，入金不受影响
+    pub async fn ensure_not_held(domain: &str, user_id: &str) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        match Self::find_user_hold(db.as_ref(), domain, user_id).await? {
+            Some(hold) => {
+                Err(SolanaError::AccountOnHold(format!("user {}: {}", user_id, hold.reason)).into())
+            },
+            None => Ok(()),
+        }
+    }
+
+    /// 随用户状态同步冻结：停用或封禁时冻结，重新启用时解除
+    pub(crate) async fn sync_user_status<C: ConnectionTrait>(
+        conn: &C,
+        user: &SysUserModel,
+    ) -> Result<(), AppError> {
+        let holds = SysCustodyHold::find()
+            .filter(SysCustodyHoldColumn::Domain.eq(&user.domain))
+            .filter(SysCustodyHoldColumn::UserId.eq(&user.id))
+            .filter(SysCustodyHoldColumn::Source.eq(CustodyHoldSource::UserDisabled))
+            .filter(SysCustodyHoldColumn::Status.eq(CustodyHoldStatus::Active))
+            .all(conn)
+            .await
+            .map_err(AppError::from)?;
+
+        if user.status == Status::Enabled {
+            for hold in holds {
+                Self::release(conn, hold, "user enabled".to_string(), SYSTEM_OPERATOR).await?;
+            }
+        } else if holds.is_empty() {
+            let hold = SysCustodyHoldActiveModel {
+                id: Set(Ulid::new().to_string()),
+                domain: Set(user.domain.clone()),
+                user_id: Set(user.id.clone()),
+                source: Set(CustodyHoldSource::UserDisabled),
+                reason: Set("user is not enabled".to_string()),
+                status: Set(CustodyHoldStatus::Active),
+                expires_at: Set(None),
+                created_at: Set(Local::now().naive_local()),
+                created_by: Set(SYSTEM_OPERATOR.to_string()),
+                ..Default::default()
+            };
+            Self::insert_hold(conn, hold, SYSTEM_OPERATOR).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TCustodyHoldService for SysCustodyHoldService {
+    async fn find_paginated_holds(
+        &self,
+        domain: &str,
+        params: CustodyHoldPageRequest,
+    ) -> Result<PaginatedData<SysCustodyHoldModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysCustodyHold::find()
+            .filter(SysCustodyHoldColumn::Domain.eq(domain))
+            .order_by_desc(SysCustodyHoldColumn::CreatedAt);
+
+        if let Some(user_id) = params.user_id {
+            query = query.filter(SysCustodyHoldColumn::UserId.eq(user_id));
+        }
+        if let Some(source) = params.source {
+            query = query.filter(SysCustodyHoldColumn::Source.eq(source));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysCustodyHoldColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn place_hold(
+        &self,
+        domain: &str,
+        input: PlaceCustodyHoldInput,
+        operator: &str,
+    ) -> Result<SysCustodyHoldModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let exists = SysUser::find_by_id(input.user_id.as_str())
+            .filter(SysUserColumn::Domain.eq(domain))
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if exists == 0 {
+            return Err(CustodyHoldError::UserNotFound.into());
+        }
+
+        let now = Local::now().naive_local();
+        let hold = SysCustodyHoldActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            user_id: Set(input.user_id),
+            source: Set(CustodyHoldSource::Manual),
+            reason: Set(input.reason),
+            status: Set(CustodyHoldStatus::Active),
+            expires_at: Set(input
+                .expires_in_hours
+                .map(|hours| now + Duration::hours(hours))),
+            created_at: Set(now),
+            created_by: Set(operator.to_string()),
+            ..Default::default()
+        };
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let hold = Self::insert_hold(&txn, hold, operator).await?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(hold)
+    }
+
+    async fn get_hold(&self, domain: &str, id: &str) -> Result<CustodyHoldOutput, AppError> {
+        let hold = self.find_hold(domain, id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let audits = SysCustodyHoldAudit::find()
+            .filter(SysCustodyHoldAuditColumn::HoldId.eq(&hold.id))
+            .order_by_asc(SysCustodyHoldAuditColumn::CreatedAt)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(CustodyHoldOutput { hold, audits })
+    }
+
+    async fn release_hold(
+        &self,
+        domain: &str,
+        id: &str,
+        input: ReleaseCustodyHoldInput,
+        operator: &str,
+    ) -> Result<SysCustodyHoldModel, AppError> {
+        let hold = self.find_hold(domain, id).await?;
+        if hold.status != CustodyHoldStatus::Active {
+            return Err(CustodyHoldError::AlreadyReleased.into());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        if hold.source == CustodyHoldSource::UserDisabled {
+            let enabled = SysUser::find_by_id(hold.user_id.as_str())
+                .filter(SysUserColumn::Status.eq(Status::Enabled))
+                .count(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+            if enabled == 0 {
+                return Err(CustodyHoldError::ManagedByUserStatus.into());
+            }
+        }
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let released = Self::release(&txn, hold, input.reason, operator).await?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(released)
+    }
+}
+
+/// 按托管钱包地址查询所属用户的冻结，供转出与兑换在签名前检查
+///
+/// 系统钱包等不属于任何用户的地址不受冻结限制
+pub struct CustodyHoldRegistry;
+
+#[async_trait]
+impl HoldRegistry for CustodyHoldRegistry {
+    async fn find_hold(&self, wallet: &Pubkey) -> sol_spl_token::error::Result<Option<Hold>> {
+        let lookup = async {
+            let db = db_helper::get_db_connection().await?;
+            let wallet = SysCustodyWallet::find()
+                .filter(SysCustodyWalletColumn::Address.eq(wallet.to_string()))
+                .one(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+
+            match wallet {
+                Some(wallet) => match wallet.user_id {
+                    Some(user_id) => {
+                        SysCustodyHoldService::find_user_hold(db.as_ref(), &wallet.domain, &user_id)
+                            .await
+                    },
+                    None => Ok(None),
+                },
+                None => Ok(None),
+            }
+        };
+
+        lookup
+            .await
+            .map_err(|e: AppError| SolanaError::Other(format!("Hold lookup failed: {}", e.message)))
+    }
+}
@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_model::admin::{
+    entities::{
+        prelude::SysJobRun,
+        sys_job_run::{Column as SysJobRunColumn, Model as SysJobRunModel},
+    },
+    input::JobRunPageRequest,
+    output::JobOutput,
+};
+use server_scheduler::{Scheduler, SchedulerError};
+
+use crate::helper::db_helper;
+
+#[async_trait]
+pub trait TJobService {
+    async fn list_jobs(&self) -> Result<Vec<JobOutput>, AppError>;
+
+    async fn trigger_job(&self, name: &str, operator: &str) -> Result<SysJobRunModel, AppError>;
+
+    async fn pause_job(&self, name: &str) -> Result<(), AppError>;
+
+    async fn resume_job(&self, name: &str) -> Result<(), AppError>;
+
+    async fn find_paginated_runs(
+        &self,
+        params: JobRunPageRequest,
+    ) -> Result<PaginatedData<SysJobRunModel>, AppError>;
+
+    async fn get_run(&self, id: &str) -> Result<SysJobRunModel, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysJobService;
+
+#[async_trait]
+impl TJobService for SysJobService {
+    async fn list_jobs(&self) -> Result<Vec<JobOutput>, AppError> {
+        Scheduler::global()?.jobs().await
+    }
+
+    async fn trigger_job(&self, name: &str, operator: &str) -> Result<SysJobRunModel, AppError> {
+        Scheduler::global()?.trigger(name, operator).await
+    }
+
+    async fn pause_job(&self, name: &str) -> Result<(), AppError> {
+        Scheduler::global()?.pause(name).await
+    }
+
+    async fn resume_job(&self, name: &str) -> Result<(), AppError> {
+        Scheduler::global()?.resume(name).await
+    }
+
+    async fn find_paginated_runs(
+        &self,
+        params: JobRunPageRequest,
+    ) -> Result<PaginatedData<SysJobRunModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysJobRun::find().order_by_desc(SysJobRunColumn::StartedAt);
+
+        if let Some(job_name) = params.job_name {
+            query = query.filter(SysJobRunColumn::JobName.eq(job_name));
+        }
+        if let Some(trigger) = params.trigger {
+            query = query.filter(SysJobRunColumn::Trigger.eq(trigger));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysJobRunColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn get_run(&self, id: &str) -> Result<SysJobRunModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysJobRun::find_by_id(id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| SchedulerError::RunNotFound.into())
+    }
+}
@@ -0,0 +1,411 @@
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, PaginatorTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_global::{project_error, project_info};
+use server_model::admin::{
+    entities::{
+        prelude::{SysCustodyWallet, SysKeyRotation},
+        sea_orm_active_enums::{CustodyWalletType, KeyRotationStatus, Status},
+        sys_custody_wallet::{
+            ActiveModel as SysCustodyWalletActiveModel, Column as SysCustodyWalletColumn,
+            Model as SysCustodyWalletModel,
+        },
+        sys_key_rotation::{
+            ActiveModel as SysKeyRotationActiveModel, Column as SysKeyRotationColumn,
+            Model as SysKeyRotationModel,
+        },
+    },
+    input::{KeyRotationPageRequest, RotateWalletKeyInput},
+};
+use sol_spl_token::{rotation::MigrationReport, Keypair, Pubkey, Signer, SolanaError};
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::sys_key_rotation_error::KeyRotationError;
+
+#[async_trait]
+pub trait TKeyRotationService {
+    async fn find_paginated_rotations(
+        &self,
+        domain: &str,
+        params: KeyRotationPageRequest,
+    ) -> Result<PaginatedData<SysKeyRotationModel>, AppError>;
+
+    async fn get_rotation(&self, domain: &str, id: &str) -> Result<SysKeyRotationModel, AppError>;
+
+    async fn rotate_wallet_key(
+        &self,
+        domain: &str,
+        input: RotateWalletKeyInput,
+        operator: &str,
+    ) -> Result<SysKeyRotationModel, AppError>;
+
+    async fn rotate_system_key(
+        &self,
+        domain: &str,
+        operator: &str,
+    ) -> Result<SysKeyRotationModel, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysKeyRotationService;
+
+/// 资产迁移的目标钱包
+enum RotationTarget {
+    /// 新生成的钱包
+    Fresh(Keypair),
+
+    /// 已轮换过的钱包，继续迁移到上次生成的新钱包
+    Existing(SysCustodyWalletModel),
+}
+
+/// 沿 replaced_by 查找最新钱包时最多跟随的轮换次数，防止数据异常时循环
+const MAX_ROTATION_CHAIN: usize = 64;
+
+fn to_i64(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+impl SysKeyRotationService {
+    async fn find_wallet(domain: &str, id: &str) -> Result<SysCustodyWalletModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysCustodyWallet::find_by_id(id)
+            .filter(SysCustodyWalletColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| KeyRotationError::WalletNotFound.into())
+    }
+
+    /// 未轮换过的钱包生成新密钥，已轮换过的钱包迁移到原来的新钱包
+    async fn target_for(wallet: &SysCustodyWalletModel) -> Result<RotationTarget, AppError> {
+        if wallet.retired_at.is_none() {
+            return Ok(RotationTarget::Fresh(Keypair::new()));
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let replacement = match wallet.replaced_by.as_deref() {
+            Some(id) => SysCustodyWallet::find_by_id(id)
+                .one(db.as_ref())
+                .await
+                .map_err(AppError::from)?,
+            None => None,
+        };
+
+        replacement
+            .map(RotationTarget::Existing)
+            .ok_or_else(|| KeyRotationError::ReplacementNotFound(wallet.address.clone()).into())
+    }
+
+    /// 解密托管钱包落库的私钥，并确认与钱包地址一致
+    async fn open_wallet_key(wallet: &SysCustodyWalletModel) -> Result<Keypair, AppError> {
+        let encrypted_key = wallet
+            .encrypted_key
+            .as_deref()
+            .ok_or_else(|| KeyRotationError::KeyNotInKeystore(wallet.address.clone()))?;
+        let keypair = solana_helper::open_custody_key(encrypted_key).await?;
+        if keypair.pubkey().to_string() != wallet.address {
+            return Err(KeyRotationError::KeyMismatch(wallet.address.clone()).into());
+        }
+        Ok(keypair)
+    }
+
+    /// 登记新钱包并停用旧钱包，新私钥加密后随钱包一起落库
+    ///
+    /// 在迁移资产之前提交，迁移中途失败时新私钥也不会丢失；
+    /// 系统钱包尚未登记时补登一条已停用的旧钱包记录，启动时据此找到新钱包
+    async fn register_replacement(
+        domain: &str,
+        wallet: Option<&SysCustodyWalletModel>,
+        old_address: &Pubkey,
+        keypair: &Keypair,
+        operator: &str,
+    ) -> Result<SysCustodyWalletModel, AppError> {
+        let encrypted_key = solana_helper::seal_custody_key(keypair).await?;
+        let (wallet_domain, user_id, wallet_type) = match wallet {
+            Some(wallet) => (
+                wallet.domain.clone(),
+                wallet.user_id.clone(),
+                wallet.wallet_type,
+            ),
+            None => (domain.to_string(), None, CustodyWalletType::System),
+        };
+
+        let now = Local::now().naive_local();
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let created = SysCustodyWalletActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(wallet_domain.clone()),
+            user_id: Set(user_id),
+            address: Set(keypair.pubkey().to_string()),
+            wallet_type: Set(wallet_type),
+            status: Set(Status::Enabled),
+            created_at: Set(now),
+            created_by: Set(operator.to_string()),
+            encrypted_key: Set(Some(encrypted_key)),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(AppError::from)?;
+
+        match wallet.cloned() {
+            Some(wallet) => {
+                let mut retired = wallet.into_active_model();
+                retired.status = Set(Status::Disabled);
+                retired.retired_at = Set(Some(now));
+                retired.replaced_by = Set(Some(created.id.clone()));
+                retired.updated_at = Set(Some(now));
+                retired.updated_by = Set(Some(operator.to_string()));
+                retired.update(&txn).await.map_err(AppError::from)?;
+            },
+            None => {
+                SysCustodyWalletActiveModel {
+                    id: Set(Ulid::new().to_string()),
+                    domain: Set(wallet_domain),
+                    user_id: Set(None),
+                    address: Set(old_address.to_string()),
+                    wallet_type: Set(CustodyWalletType::System),
+                    status: Set(Status::Disabled),
+                    created_at: Set(now),
+                    created_by: Set(operator.to_string()),
+                    retired_at: Set(Some(now)),
+                    replaced_by: Set(Some(created.id.clone())),
+                    ..Default::default()
+                }
+                .insert(&txn)
+                .await
+                .map_err(AppError::from)?;
+            },
+        }
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(created)
+    }
+
+    /// 登记新钱包、迁移资产并记录本次轮换
+    ///
+    /// 新钱包在迁移前登记，迁移没有任何交易成功时再次轮换会继续迁移到该钱包
+    async fn rotate(
+        domain: &str,
+        wallet: Option<SysCustodyWalletModel>,
+        old_keypair: &Keypair,
+        target: RotationTarget,
+        payer: &Keypair,
+        operator: &str,
+    ) -> Result<SysKeyRotationModel, AppError> {
+        let (replacement, new_keypair) = match target {
+            RotationTarget::Existing(replacement) => (replacement, None),
+            RotationTarget::Fresh(keypair) => {
+                let created = Self::register_replacement(
+                    domain,
+                    wallet.as_ref(),
+                    &old_keypair.pubkey(),
+                    &keypair,
+                    operator,
+                )
+                .await?;
+                (created, Some(keypair))
+            },
+        };
+        let new_address = replacement
+            .address
+            .parse::<Pubkey>()
+            .map_err(|_| KeyRotationError::ReplacementNotFound(replacement.address.clone()))?;
+
+        // 轮换的是当前系统钱包时，后续交易立即改用新密钥；重启后由 restore_system_keypair 恢复
+        if let Some(ref keypair) = new_keypair {
+            let system_keypair = solana_helper::get_system_keypair().await?;
+            if system_keypair.pubkey() == old_keypair.pubkey() {
+                if solana_helper::replace_system_keypair(keypair).await {
+                    project_info!("System wallet rotated to {}", keypair.pubkey());
+                } else {
+                    project_error!(
+                        "System wallet rotated to {} but the running config could not be updated",
+                        keypair.pubkey()
+                    );
+                }
+            }
+        }
+
+        let rotator = solana_helper::get_key_rotator().await?;
+        let report = rotator
+            .migrate(old_keypair, &new_address, payer)
+            .await
+            .unwrap_or_else(|e| MigrationReport {
+                error: Some(e.to_string()),
+                ..Default::default()
+            });
+        let status = if report.signatures.is_empty() && report.error.is_some() {
+            KeyRotationStatus::Failed
+        } else if report.is_complete() {
+            KeyRotationStatus::Completed
+        } else {
+            KeyRotationStatus::Partial
+        };
+
+        let db = db_helper::get_db_connection().await?;
+        SysKeyRotationActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            wallet_id: Set(wallet.as_ref().map(|wallet| wallet.id.clone())),
+            wallet_type: Set(wallet
+                .as_ref()
+                .map(|wallet| wallet.wallet_type)
+                .unwrap_or(CustodyWalletType::System)),
+            user_id: Set(wallet.as_ref().and_then(|wallet| wallet.user_id.clone())),
+            old_address: Set(old_keypair.pubkey().to_string()),
+            new_address: Set(new_address.to_string()),
+            new_wallet_id: Set(Some(replacement.id)),
+            status: Set(status),
+            migrated_lamports: Set(to_i64(report.migrated_lamports)),
+            migrated_token_accounts: Set(report.migrated_token_accounts.len() as i32),
+            skipped_token_accounts: Set(report.skipped_token_accounts.len() as i32),
+            report: Set(serde_json::to_value(&report).unwrap_or_default()),
+            error: Set(report.error.clone()),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+        }
+        .insert(db.as_ref())
+        .await
+        .map_err(AppError::from)
+    }
+
+    /// 启动时恢复轮换后的系统钱包密钥
+    ///
+    /// 配置中的系统钱包已被轮换时，沿 replaced_by 找到最新的钱包，从密钥库解密其私钥并替换运行中的配置；
+    /// 未轮换过时返回 None
+    pub async fn restore_system_keypair() -> Result<Option<Pubkey>, AppError> {
+        let configured = solana_helper::get_system_keypair().await?;
+        let db = db_helper::get_db_connection().await?;
+
+        let mut latest = None;
+        let mut address = configured.pubkey().to_string();
+        for _ in 0..MAX_ROTATION_CHAIN {
+            let wallet = SysCustodyWallet::find()
+                .filter(SysCustodyWalletColumn::Address.eq(address.as_str()))
+                .one(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+            let Some(wallet) = wallet.filter(|wallet| wallet.retired_at.is_some()) else {
+                break;
+            };
+            let replacement = match wallet.replaced_by.as_deref() {
+                Some(id) => SysCustodyWallet::find_by_id(id)
+                    .one(db.as_ref())
+                    .await
+                    .map_err(AppError::from)?,
+                None => None,
+            }
+            .ok_or_else(|| KeyRotationError::ReplacementNotFound(wallet.address.clone()))?;
+            address = replacement.address.clone();
+            latest = Some(replacement);
+        }
+
+        let Some(wallet) = latest else {
+            return Ok(None);
+        };
+        let keypair = Self::open_wallet_key(&wallet).await?;
+        if !solana_helper::replace_system_keypair(&keypair).await {
+            return Err(AppError::from(SolanaError::ConfigError(
+                "Solana config is not loaded".to_string(),
+            )));
+        }
+        Ok(Some(keypair.pubkey()))
+    }
+}
+
+#[async_trait]
+impl TKeyRotationService for SysKeyRotationService {
+    async fn find_paginated_rotations(
+        &self,
+        domain: &str,
+        params: KeyRotationPageRequest,
+    ) -> Result<PaginatedData<SysKeyRotationModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysKeyRotation::find()
+            .filter(SysKeyRotationColumn::Domain.eq(domain))
+            .order_by_desc(SysKeyRotationColumn::CreatedAt);
+
+        if let Some(wallet_id) = params.wallet_id {
+            query = query.filter(SysKeyRotationColumn::WalletId.eq(wallet_id));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysKeyRotationColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn get_rotation(&self, domain: &str, id: &str) -> Result<SysKeyRotationModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysKeyRotation::find_by_id(id)
+            .filter(SysKeyRotationColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| KeyRotationError::RotationNotFound.into())
+    }
+
+    async fn rotate_wallet_key(
+        &self,
+        domain: &str,
+        input: RotateWalletKeyInput,
+        operator: &str,
+    ) -> Result<SysKeyRotationModel, AppError> {
+        let wallet = Self::find_wallet(domain, &input.wallet_id).await?;
+        let old_keypair = Self::open_wallet_key(&wallet).await?;
+
+        // 手续费与新关联账户的租金由系统钱包支付，旧钱包的 SOL 可以全部转出
+        let payer = solana_helper::get_system_keypair().await?;
+        let target = Self::target_for(&wallet).await?;
+
+        Self::rotate(domain, Some(wallet), &old_keypair, target, &payer, operator).await
+    }
+
+    async fn rotate_system_key(
+        &self,
+        domain: &str,
+        operator: &str,
+    ) -> Result<SysKeyRotationModel, AppError> {
+        let old_keypair = solana_helper::get_system_keypair().await?;
+
+        // 系统钱包不分域，按地址查找登记记录
+        let db = db_helper::get_db_connection().await?;
+        let wallet = SysCustodyWallet::find()
+            .filter(SysCustodyWalletColumn::Address.eq(old_keypair.pubkey().to_string()))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        let target = match &wallet {
+            Some(wallet) => Self::target_for(wallet).await?,
+            None => RotationTarget::Fresh(Keypair::new()),
+        };
+
+        // 系统钱包自己支付手续费
+        Self::rotate(domain, wallet, &old_keypair, target, &old_keypair, operator).await
+    }
+}
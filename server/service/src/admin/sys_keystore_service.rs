@@ -0,0 +1,687 @@
+use async_trait::async_trait;
+use chrono::{Duration, Local};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel, PaginatorTrait,
+    QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_model::admin::{
+    entities::{
+        prelude::{SysCustodyWallet, SysKeyExport, SysKeyExportPolicy, SysKeystoreAudit, SysUser},
+        sea_orm_active_enums::{CustodyWalletType, KeyExportStatus, KeystoreAction, Status},
+        sys_custody_wallet::{
+            ActiveModel as SysCustodyWalletActiveModel, Column as SysCustodyWalletColumn,
+            Model as SysCustodyWalletModel,
+        },
+        sys_key_export::{
+            ActiveModel as SysKeyExportActiveModel, Column as SysKeyExportColumn,
+            Model as SysKeyExportModel,
+        },
+        sys_key_export_policy::{
+            ActiveModel as SysKeyExportPolicyActiveModel, Column as SysKeyExportPolicyColumn,
+            Model as SysKeyExportPolicyModel,
+        },
+        sys_keystore_audit::{
+            ActiveModel as SysKeystoreAuditActiveModel, Column as SysKeystoreAuditColumn,
+        },
+        sys_user::Column as SysUserColumn,
+        sys_user_balance::Model as SysUserBalanceModel,
+    },
+    input::{
+        DownloadKeyExportInput, ImportKeyInput, ImportKeystoreInput, KeyExportPageRequest,
+        RequestKeyExportInput, UpsertKeyExportPolicyInput,
+    },
+    output::{ImportKeyResultOutput, KeyExportOutput, KeyExportTicketOutput},
+};
+use server_utils::SecureUtil;
+use sol_spl_token::{
+    config::keypair_from_base58,
+    keystore::{self, EncryptedKey},
+    Signer,
+};
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::{sys_keystore_error::KeystoreError, SysBalanceService, SysCustodyHoldService};
+
+#[async_trait]
+pub trait TKeystoreService {
+    async fn get_policy(&self, domain: &str) -> Result<SysKeyExportPolicyModel, AppError>;
+
+    async fn upsert_policy(
+        &self,
+        domain: &str,
+        input: UpsertKeyExportPolicyInput,
+        operator: &str,
+    ) -> Result<SysKeyExportPolicyModel, AppError>;
+
+    async fn import_keys(
+        &self,
+        domain: &str,
+        input: ImportKeystoreInput,
+        operator: &str,
+    ) -> Result<Vec<ImportKeyResultOutput>, AppError>;
+
+    async fn find_paginated_exports(
+        &self,
+        domain: &str,
+        params: KeyExportPageRequest,
+    ) -> Result<PaginatedData<SysKeyExportModel>, AppError>;
+
+    async fn get_export(&self, domain: &str, id: &str) -> Result<KeyExportOutput, AppError>;
+
+    async fn request_export(
+        &self,
+        domain: &str,
+        user_id: &str,
+        input: RequestKeyExportInput,
+    ) -> Result<KeyExportTicketOutput, AppError>;
+
+    async fn download_export(
+        &self,
+        domain: &str,
+        user_id: &str,
+        id: &str,
+        input: DownloadKeyExportInput,
+    ) -> Result<EncryptedKey, AppError>;
+
+    async fn cancel_export(
+        &self,
+        domain: &str,
+        user_id: &str,
+        id: &str,
+    ) -> Result<SysKeyExportModel, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysKeystoreService;
+
+impl SysKeystoreService {
+    async fn audit<C: ConnectionTrait>(
+        conn: &C,
+        wallet: &SysCustodyWalletModel,
+        export_id: Option<String>,
+        action: KeystoreAction,
+        operator: &str,
+        detail: Option<String>,
+    ) -> Result<(), AppError> {
+        SysKeystoreAuditActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(wallet.domain.clone()),
+            wallet_id: Set(wallet.id.clone()),
+            export_id: Set(export_id),
+            action: Set(action),
+            operator: Set(operator.to_string()),
+            detail: Set(detail),
+            created_at: Set(Local::now().naive_local()),
+        }
+        .insert(conn)
+        .await
+        .map_err(AppError::from)?;
+        Ok(())
+    }
+
+    /// 敏感操作前重新校验登录密码
+    async fn reauthenticate(domain: &str, user_id: &str, password: &str) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let user = SysUser::find_by_id(user_id)
+            .filter(SysUserColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(KeystoreError::ReauthenticationFailed))?;
+
+        match SecureUtil::verify_password(password.as_bytes(), &user.password) {
+            Ok(true) => Ok(()),
+            _ => Err(KeystoreError::ReauthenticationFailed.into()),
+        }
+    }
+
+    /// 私钥已交给用户的钱包不再由托管管理，拒绝账本提现等依赖托管钱包的操作
+    pub async fn ensure_not_exported(domain: &str, user_id: &str) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let exports = SysKeyExport::find()
+            .filter(SysKeyExportColumn::Domain.eq(domain))
+            .filter(SysKeyExportColumn::UserId.eq(user_id))
+            .filter(SysKeyExportColumn::Status.eq(KeyExportStatus::Downloaded))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        ensure_no_downloaded_export(&exports).map_err(AppError::from)
+    }
+
+    async fn find_user_wallet(
+        domain: &str,
+        user_id: &str,
+    ) -> Result<SysCustodyWalletModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysCustodyWallet::find()
+            .filter(SysCustodyWalletColumn::Domain.eq(domain))
+            .filter(SysCustodyWalletColumn::UserId.eq(user_id))
+            .filter(SysCustodyWalletColumn::WalletType.eq(CustodyWalletType::User))
+            .filter(SysCustodyWalletColumn::Status.eq(Status::Enabled))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| KeystoreError::WalletNotFound.into())
+    }
+
+    async fn find_user_export(
+        domain: &str,
+        user_id: &str,
+        id: &str,
+    ) -> Result<SysKeyExportModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysKeyExport::find_by_id(id)
+            .filter(SysKeyExportColumn::Domain.eq(domain))
+            .filter(SysKeyExportColumn::UserId.eq(user_id))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| KeystoreError::ExportNotFound.into())
+    }
+
+    /// 把单个密钥对写入密钥库，地址未登记时新建托管钱包
+    async fn import_key(
+        domain: &str,
+        input: ImportKeyInput,
+        operator: &str,
+    ) -> Result<SysCustodyWalletModel, AppError> {
+        let keypair = keypair_from_base58(&input.private_key).map_err(AppError::from)?;
+        let address = keypair.pubkey().to_string();
+        let encrypted_key = solana_helper::seal_custody_key(&keypair).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let existing = SysCustodyWallet::find()
+            .filter(SysCustodyWalletColumn::Address.eq(address.as_str()))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if existing
+            .as_ref()
+            .is_some_and(|wallet| wallet.domain != domain)
+        {
+            return Err(KeystoreError::WalletNotFound.into());
+        }
+
+        let now = Local::now().naive_local();
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let wallet = match existing {
+            Some(wallet) => {
+                let mut active = wallet.into_active_model();
+                active.encrypted_key = Set(Some(encrypted_key));
+                active.updated_at = Set(Some(now));
+                active.updated_by = Set(Some(operator.to_string()));
+                active.update(&txn).await.map_err(AppError::from)?
+            },
+            None => SysCustodyWalletActiveModel {
+                id: Set(Ulid::new().to_string()),
+                domain: Set(domain.to_string()),
+                wallet_type: Set(if input.user_id.is_some() {
+                    CustodyWalletType::User
+                } else {
+                    CustodyWalletType::Hot
+                }),
+                user_id: Set(input.user_id),
+                address: Set(address),
+                status: Set(Status::Enabled),
+                encrypted_key: Set(Some(encrypted_key)),
+                created_at: Set(now),
+                created_by: Set(operator.to_string()),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await
+            .map_err(AppError::from)?,
+        };
+
+        Self::audit(
+            &txn,
+            &wallet,
+            None,
+            KeystoreAction::Imported,
+            operator,
+            None,
+        )
+        .await?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(wallet)
+    }
+}
+
+#[async_trait]
+impl TKeystoreService for SysKeystoreService {
+    async fn get_policy(&self, domain: &str) -> Result<SysKeyExportPolicyModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysKeyExportPolicy::find()
+            .filter(SysKeyExportPolicyColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| KeystoreError::PolicyNotFound.into())
+    }
+
+    async fn upsert_policy(
+        &self,
+        domain: &str,
+        input: UpsertKeyExportPolicyInput,
+        operator: &str,
+    ) -> Result<SysKeyExportPolicyModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let existing = SysKeyExportPolicy::find()
+            .filter(SysKeyExportPolicyColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let now = Local::now().naive_local();
+        let result = match existing {
+            Some(existing) => {
+                let active: SysKeyExportPolicyActiveModel = existing.into();
+                SysKeyExportPolicyActiveModel {
+                    allow_export: Set(input.allow_export),
+                    cooling_hours: Set(input.cooling_hours),
+                    download_window_hours: Set(input.download_window_hours),
+                    updated_at: Set(Some(now)),
+                    updated_by: Set(Some(operator.to_string())),
+                    ..active
+                }
+                .update(db.as_ref())
+                .await
+            },
+            None => {
+                SysKeyExportPolicyActiveModel {
+                    id: Set(Ulid::new().to_string()),
+                    domain: Set(domain.to_string()),
+                    allow_export: Set(input.allow_export),
+                    cooling_hours: Set(input.cooling_hours),
+                    download_window_hours: Set(input.download_window_hours),
+                    created_at: Set(now),
+                    created_by: Set(operator.to_string()),
+                    ..Default::default()
+                }
+                .insert(db.as_ref())
+                .await
+            },
+        }
+        .map_err(AppError::from)?;
+
+        Ok(result)
+    }
+
+    async fn import_keys(
+        &self,
+        domain: &str,
+        input: ImportKeystoreInput,
+        operator: &str,
+    ) -> Result<Vec<ImportKeyResultOutput>, AppError> {
+        let mut results = Vec::with_capacity(input.keys.len());
+
+        // 逐个导入，单个失败不影响其他密钥
+        for key in input.keys {
+            let address = keypair_from_base58(&key.private_key)
+                .ok()
+                .map(|keypair| keypair.pubkey().to_string());
+
+            results.push(match Self::import_key(domain, key, operator).await {
+                Ok(wallet) => ImportKeyResultOutput {
+                    address,
+                    wallet_id: Some(wallet.id),
+                    error: None,
+                },
+                Err(e) => ImportKeyResultOutput {
+                    address,
+                    wallet_id: None,
+                    error: Some(e.message),
+                },
+            });
+        }
+
+        Ok(results)
+    }
+
+    async fn find_paginated_exports(
+        &self,
+        domain: &str,
+        params: KeyExportPageRequest,
+    ) -> Result<PaginatedData<SysKeyExportModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysKeyExport::find()
+            .filter(SysKeyExportColumn::Domain.eq(domain))
+            .order_by_desc(SysKeyExportColumn::CreatedAt);
+
+        if let Some(user_id) = params.user_id {
+            query = query.filter(SysKeyExportColumn::UserId.eq(user_id));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysKeyExportColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn get_export(&self, domain: &str, id: &str) -> Result<KeyExportOutput, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let export = SysKeyExport::find_by_id(id)
+            .filter(SysKeyExportColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(KeystoreError::ExportNotFound))?;
+
+        let audits = SysKeystoreAudit::find()
+            .filter(SysKeystoreAuditColumn::ExportId.eq(&export.id))
+            .order_by_asc(SysKeystoreAuditColumn::CreatedAt)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(KeyExportOutput { export, audits })
+    }
+
+    async fn request_export(
+        &self,
+        domain: &str,
+        user_id: &str,
+        input: RequestKeyExportInput,
+    ) -> Result<KeyExportTicketOutput, AppError> {
+        Self::reauthenticate(domain, user_id, &input.password).await?;
+        SysCustodyHoldService::ensure_not_held(domain, user_id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let policy = SysKeyExportPolicy::find()
+            .filter(SysKeyExportPolicyColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .filter(|policy| policy.allow_export)
+            .ok_or_else(|| AppError::from(KeystoreError::ExportNotAllowed))?;
+
+        let wallet = Self::find_user_wallet(domain, user_id).await?;
+        if wallet.encrypted_key.is_none() {
+            return Err(KeystoreError::KeyNotInKeystore(wallet.address).into());
+        }
+        // 提前拒绝未结清的申请，下载时在同一事务中再次校验
+        ensure_settled(&SysBalanceService::lock_user_balances(db.as_ref(), domain, user_id).await?)?;
+
+        let now = Local::now().naive_local();
+        let pending = SysKeyExport::find()
+            .filter(SysKeyExportColumn::Domain.eq(domain))
+            .filter(SysKeyExportColumn::UserId.eq(user_id))
+            .filter(SysKeyExportColumn::Status.eq(KeyExportStatus::Pending))
+            .filter(SysKeyExportColumn::ExpiresAt.gt(now))
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if pending > 0 {
+            return Err(KeystoreError::ExportAlreadyPending.into());
+        }
+
+        let token = keystore::generate_token().map_err(AppError::from)?;
+        let token_hash = SecureUtil::hash_password(token.as_bytes()).map_err(|e| AppError {
+            code: 500,
+            message: e.to_string(),
+        })?;
+        let available_at = now + Duration::hours(policy.cooling_hours.into());
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let export = SysKeyExportActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            user_id: Set(user_id.to_string()),
+            wallet_id: Set(wallet.id.clone()),
+            address: Set(wallet.address.clone()),
+            status: Set(KeyExportStatus::Pending),
+            token_hash: Set(token_hash),
+            available_at: Set(available_at),
+            expires_at: Set(available_at + Duration::hours(policy.download_window_hours.into())),
+            created_at: Set(now),
+            created_by: Set(user_id.to_string()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(AppError::from)?;
+        Self::audit(
+            &txn,
+            &wallet,
+            Some(export.id.clone()),
+            KeystoreAction::ExportRequested,
+            user_id,
+            None,
+        )
+        .await?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(KeyExportTicketOutput {
+            export,
+            download_token: token,
+        })
+    }
+
+    async fn download_export(
+        &self,
+        domain: &str,
+        user_id: &str,
+        id: &str,
+        input: DownloadKeyExportInput,
+    ) -> Result<EncryptedKey, AppError> {
+        let export = Self::find_user_export(domain, user_id, id).await?;
+        if export.status != KeyExportStatus::Pending {
+            return Err(KeystoreError::ExportNotPending.into());
+        }
+
+        let now = Local::now().naive_local();
+        if now < export.available_at {
+            return Err(KeystoreError::CoolingPeriod(
+                export.available_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            )
+            .into());
+        }
+        if now >= export.expires_at {
+            return Err(KeystoreError::ExportExpired.into());
+        }
+
+        Self::reauthenticate(domain, user_id, &input.password).await?;
+        SysCustodyHoldService::ensure_not_held(domain, user_id).await?;
+        if !matches!(
+            SecureUtil::verify_password(input.token.as_bytes(), &export.token_hash),
+            Ok(true)
+        ) {
+            return Err(KeystoreError::InvalidToken.into());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let wallet = SysCustodyWallet::find_by_id(export.wallet_id.as_str())
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(KeystoreError::WalletNotFound))?;
+        let encrypted_key = wallet
+            .encrypted_key
+            .as_deref()
+            .ok_or_else(|| KeystoreError::KeyNotInKeystore(wallet.address.clone()))?;
+
+        let keypair = solana_helper::open_custody_key(encrypted_key).await?;
+        let exported = solana_helper::seal_keypair(&keypair, &input.passphrase).await?;
+
+        // 只有仍处于待下载状态时才标记成功，并发下载只有一个能拿到私钥；
+        // 余额须已结清，否则用户可凭私钥转走链上资产后再从账本提现
+        let txn = db.begin().await.map_err(AppError::from)?;
+        ensure_settled(&SysBalanceService::lock_user_balances(&txn, domain, user_id).await?)?;
+        let updated = SysKeyExport::update_many()
+            .col_expr(
+                SysKeyExportColumn::Status,
+                KeyExportStatus::Downloaded.into(),
+            )
+            .col_expr(SysKeyExportColumn::DownloadedAt, Some(now).into())
+            .col_expr(SysKeyExportColumn::UpdatedAt, Some(now).into())
+            .col_expr(
+                SysKeyExportColumn::UpdatedBy,
+                Some(user_id.to_string()).into(),
+            )
+            .filter(SysKeyExportColumn::Id.eq(export.id.as_str()))
+            .filter(SysKeyExportColumn::Status.eq(KeyExportStatus::Pending))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        if updated.rows_affected == 0 {
+            return Err(KeystoreError::ExportNotPending.into());
+        }
+        // 私钥交给用户后钱包停用并移出密钥库，不再用于签名、收款或归集
+        let mut retired = wallet.clone().into_active_model();
+        retired.status = Set(Status::Disabled);
+        retired.encrypted_key = Set(None);
+        retired.retired_at = Set(Some(now));
+        retired.updated_at = Set(Some(now));
+        retired.updated_by = Set(Some(user_id.to_string()));
+        retired.update(&txn).await.map_err(AppError::from)?;
+        Self::audit(
+            &txn,
+            &wallet,
+            Some(export.id),
+            KeystoreAction::ExportDownloaded,
+            user_id,
+            None,
+        )
+        .await?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(exported)
+    }
+
+    async fn cancel_export(
+        &self,
+        domain: &str,
+        user_id: &str,
+        id: &str,
+    ) -> Result<SysKeyExportModel, AppError> {
+        let export = Self::find_user_export(domain, user_id, id).await?;
+        if export.status != KeyExportStatus::Pending {
+            return Err(KeystoreError::ExportNotPending.into());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let wallet = SysCustodyWallet::find_by_id(export.wallet_id.as_str())
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(KeystoreError::WalletNotFound))?;
+
+        let now = Local::now().naive_local();
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let mut active = export.into_active_model();
+        active.status = Set(KeyExportStatus::Cancelled);
+        active.updated_at = Set(Some(now));
+        active.updated_by = Set(Some(user_id.to_string()));
+        let export = active.update(&txn).await.map_err(AppError::from)?;
+        Self::audit(
+            &txn,
+            &wallet,
+            Some(export.id.clone()),
+            KeystoreAction::ExportCancelled,
+            user_id,
+            None,
+        )
+        .await?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(export)
+    }
+}
+
+/// 导出私钥前用户所有资产的账本余额须为零
+fn ensure_settled(balances: &[SysUserBalanceModel]) -> Result<(), KeystoreError> {
+    match balances.iter().find(|balance| balance.balance != 0) {
+        Some(balance) => Err(KeystoreError::BalanceNotSettled(balance.mint.clone())),
+        None => Ok(()),
+    }
+}
+
+fn ensure_no_downloaded_export(exports: &[SysKeyExportModel]) -> Result<(), KeystoreError> {
+    if exports
+        .iter()
+        .any(|export| export.status == KeyExportStatus::Downloaded)
+    {
+        return Err(KeystoreError::KeyExported);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+
+    fn export(status: KeyExportStatus) -> SysKeyExportModel {
+        SysKeyExportModel {
+            id: "export-1".to_string(),
+            domain: "built-in".to_string(),
+            user_id: "user-1".to_string(),
+            wallet_id: "wallet-1".to_string(),
+            address: "address".to_string(),
+            status,
+            token_hash: String::new(),
+            available_at: NaiveDateTime::default(),
+            expires_at: NaiveDateTime::default(),
+            downloaded_at: None,
+            created_at: NaiveDateTime::default(),
+            created_by: "user-1".to_string(),
+            updated_at: None,
+            updated_by: None,
+        }
+    }
+
+    fn balance(mint: &str, amount: i64) -> SysUserBalanceModel {
+        SysUserBalanceModel {
+            id: mint.to_string(),
+            domain: "built-in".to_string(),
+            user_id: "user-1".to_string(),
+            mint: mint.to_string(),
+            balance: amount,
+            created_at: NaiveDateTime::default(),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn withdrawal_is_rejected_after_export() {
+        let pending = [export(KeyExportStatus::Pending), export(KeyExportStatus::Cancelled)];
+        assert!(ensure_no_downloaded_export(&pending).is_ok());
+
+        let downloaded = [export(KeyExportStatus::Cancelled), export(KeyExportStatus::Downloaded)];
+        assert!(matches!(
+            ensure_no_downloaded_export(&downloaded),
+            Err(KeystoreError::KeyExported)
+        ));
+    }
+
+    #[test]
+    fn export_requires_settled_balances() {
+        assert!(ensure_settled(&[balance("mint-a", 0), balance("mint-b", 0)]).is_ok());
+        assert!(matches!(
+            ensure_settled(&[balance("mint-a", 0), balance("mint-b", 5)]),
+            Err(KeystoreError::BalanceNotSettled(mint)) if mint == "mint-b"
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use sea_orm::{ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
 use server_core::web::{error::AppError, page::PaginatedData};
 use server_model::admin::{
@@ -21,6 +22,19 @@ pub trait TLoginLogService {
 
 pub struct SysLoginLogService;
 
+impl SysLoginLogService {
+    /// 删除早于 `before` 的登录日志，返回删除条数
+    pub async fn purge_before(before: NaiveDateTime) -> Result<u64, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysLoginLog::delete_many()
+            .filter(SysLoginLogColumn::CreatedAt.lt(before))
+            .exec(db.as_ref())
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(AppError::from)
+    }
+}
+
 #[async_trait]
 impl TLoginLogService for SysLoginLogService {
     async fn find_paginated_login_logs(
@@ -0,0 +1,307 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+};
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_global::project_error;
+use server_model::admin::{
+    entities::{
+        prelude::{SysCustodyWallet, SysLookupTable},
+        sea_orm_active_enums::{CustodyWalletType, Status},
+        sys_custody_wallet::Column as SysCustodyWalletColumn,
+        sys_lookup_table::{
+            ActiveModel as SysLookupTableActiveModel, Column as SysLookupTableColumn,
+            Model as SysLookupTableModel,
+        },
+    },
+    input::{
+        CreateLookupTableInput, ExtendLookupTableInput, LookupTablePageRequest,
+        UpdateLookupTableStatusInput,
+    },
+    output::LookupTableOutput,
+};
+use sol_spl_token::{
+    lookup_table::{custody_addresses, AddressLookupTableAccount},
+    Keypair, Pubkey, Signer,
+};
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::{sys_lookup_table_error::LookupTableError, SysAssetService};
+
+#[async_trait]
+pub trait TLookupTableService {
+    async fn find_paginated_lookup_tables(
+        &self,
+        domain: &str,
+        params: LookupTablePageRequest,
+    ) -> Result<PaginatedData<SysLookupTableModel>, AppError>;
+
+    async fn create_lookup_table(
+        &self,
+        domain: &str,
+        input: CreateLookupTableInput,
+        operator: &str,
+    ) -> Result<SysLookupTableModel, AppError>;
+
+    async fn get_lookup_table(&self, domain: &str, id: &str)
+        -> Result<LookupTableOutput, AppError>;
+
+    async fn extend_lookup_table(
+        &self,
+        domain: &str,
+        id: &str,
+        input: ExtendLookupTableInput,
+        operator: &str,
+    ) -> Result<SysLookupTableModel, AppError>;
+
+    async fn update_lookup_table_status(
+        &self,
+        domain: &str,
+        input: UpdateLookupTableStatusInput,
+        operator: &str,
+    ) -> Result<SysLookupTableModel, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysLookupTableService;
+
+fn parse_address(address: &str) -> Result<Pubkey, AppError> {
+    Pubkey::from_str(address)
+        .map_err(|_| AppError::from(LookupTableError::InvalidAddress(address.to_string())))
+}
+
+impl SysLookupTableService {
+    async fn find_lookup_table(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysLookupTableModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysLookupTable::find_by_id(id)
+            .filter(SysLookupTableColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(LookupTableError::LookupTableNotFound))
+    }
+
+    /// 域内交易常用的地址：系统钱包与已启用的热钱包、启用资产的 mint 及对应的关联 Token 账户，
+    /// 再加上调用方追加的地址
+    async fn common_addresses(
+        domain: &str,
+        payer: &Keypair,
+        extra_addresses: &[String],
+    ) -> Result<Vec<Pubkey>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let hot_wallets = SysCustodyWallet::find()
+            .filter(SysCustodyWalletColumn::Domain.eq(domain))
+            .filter(SysCustodyWalletColumn::WalletType.eq(CustodyWalletType::Hot))
+            .filter(SysCustodyWalletColumn::Status.eq(Status::Enabled))
+            .order_by_asc(SysCustodyWalletColumn::Address)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let mut wallets = vec![payer.pubkey()];
+        for wallet in hot_wallets {
+            wallets.push(parse_address(&wallet.address)?);
+        }
+
+        let mints = SysAssetService::find_enabled_assets(domain)
+            .await?
+            .iter()
+            .map(|asset| parse_address(&asset.mint))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut addresses = custody_addresses(&wallets, &mints);
+        for address in extra_addresses {
+            let address = parse_address(address)?;
+            if !addresses.contains(&address) {
+                addresses.push(address);
+            }
+        }
+
+        Ok(addresses)
+    }
+
+    /// 把常用地址写入链上查找表，并记录表中当前的地址数量
+    async fn sync_addresses(
+        table: SysLookupTableModel,
+        payer: &Keypair,
+        extra_addresses: &[String],
+        operator: &str,
+    ) -> Result<SysLookupTableModel, AppError> {
+        let address = parse_address(&table.address)?;
+        let addresses = Self::common_addresses(&table.domain, payer, extra_addresses).await?;
+
+        let manager = solana_helper::get_lookup_table_manager().await?;
+        manager
+            .extend_lookup_table(payer, &address, &addresses)
+            .await?;
+        let account = manager.fetch_lookup_table(&address)?;
+
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysLookupTableActiveModel = table.into();
+        active.address_count = Set(account.addresses.len() as i32);
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.updated_by = Set(Some(operator.to_string()));
+        active.update(db.as_ref()).await.map_err(AppError::from)
+    }
+
+    /// 读取域内启用的查找表，供版本化交易引用
+    ///
+    /// 读取失败时返回空列表，调用方回退为旧版交易
+    pub async fn load_enabled_lookup_tables(
+        domain: &str,
+    ) -> Result<Vec<AddressLookupTableAccount>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let tables = SysLookupTable::find()
+            .filter(SysLookupTableColumn::Domain.eq(domain))
+            .filter(SysLookupTableColumn::Status.eq(Status::Enabled))
+            .order_by_asc(SysLookupTableColumn::CreatedAt)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if tables.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let addresses = tables
+            .iter()
+            .map(|table| parse_address(&table.address))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let manager = solana_helper::get_lookup_table_manager().await?;
+        match manager.fetch_lookup_tables(&addresses) {
+            Ok(accounts) => Ok(accounts),
+            Err(e) => {
+                project_error!("Failed to load lookup tables for domain {}: {}", domain, e);
+                Ok(Vec::new())
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl TLookupTableService for SysLookupTableService {
+    async fn find_paginated_lookup_tables(
+        &self,
+        domain: &str,
+        params: LookupTablePageRequest,
+    ) -> Result<PaginatedData<SysLookupTableModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysLookupTable::find()
+            .filter(SysLookupTableColumn::Domain.eq(domain))
+            .order_by_desc(SysLookupTableColumn::CreatedAt);
+
+        if let Some(status) = params.status {
+            query = query.filter(SysLookupTableColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn create_lookup_table(
+        &self,
+        domain: &str,
+        input: CreateLookupTableInput,
+        operator: &str,
+    ) -> Result<SysLookupTableModel, AppError> {
+        // 查找表由系统钱包创建、付费并管理
+        let payer = solana_helper::get_system_keypair().await?;
+        let manager = solana_helper::get_lookup_table_manager().await?;
+        let (address, _) = manager.create_lookup_table(&payer).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let table = SysLookupTableActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            address: Set(address.to_string()),
+            authority: Set(payer.pubkey().to_string()),
+            address_count: Set(0),
+            status: Set(Status::Enabled),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        }
+        .insert(db.as_ref())
+        .await
+        .map_err(AppError::from)?;
+
+        Self::sync_addresses(table, &payer, &input.addresses, operator).await
+    }
+
+    async fn get_lookup_table(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<LookupTableOutput, AppError> {
+        let lookup_table = self.find_lookup_table(domain, id).await?;
+        let manager = solana_helper::get_lookup_table_manager().await?;
+        let account = manager.fetch_lookup_table(&parse_address(&lookup_table.address)?)?;
+
+        Ok(LookupTableOutput {
+            lookup_table,
+            addresses: account
+                .addresses
+                .iter()
+                .map(|address| address.to_string())
+                .collect(),
+        })
+    }
+
+    async fn extend_lookup_table(
+        &self,
+        domain: &str,
+        id: &str,
+        input: ExtendLookupTableInput,
+        operator: &str,
+    ) -> Result<SysLookupTableModel, AppError> {
+        let table = self.find_lookup_table(domain, id).await?;
+        if table.status != Status::Enabled {
+            return Err(LookupTableError::LookupTableDisabled.into());
+        }
+
+        let payer = solana_helper::get_system_keypair().await?;
+        Self::sync_addresses(table, &payer, &input.addresses, operator).await
+    }
+
+    async fn update_lookup_table_status(
+        &self,
+        domain: &str,
+        input: UpdateLookupTableStatusInput,
+        operator: &str,
+    ) -> Result<SysLookupTableModel, AppError> {
+        let table = self.find_lookup_table(domain, &input.id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysLookupTableActiveModel = table.into();
+        active.status = Set(input.status);
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.updated_by = Set(Some(operator.to_string()));
+        active.update(db.as_ref()).await.map_err(AppError::from)
+    }
+}
@@ -0,0 +1,536 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_model::admin::{
+    entities::{
+        prelude::{SysMintOperation, SysMintOperationAudit},
+        sea_orm_active_enums::{
+            MintAuthorityType, MintOperationAction, MintOperationStatus, MintOperationType,
+        },
+        sys_mint_operation::{
+            ActiveModel as SysMintOperationActiveModel, Column as SysMintOperationColumn,
+            Model as SysMintOperationModel,
+        },
+        sys_mint_operation_audit::{
+            ActiveModel as SysMintOperationAuditActiveModel, Column as SysMintOperationAuditColumn,
+        },
+    },
+    input::{CreateMintOperationInput, MintOperationPageRequest},
+    output::{MintHolderCountOutput, MintOperationOutput, MintSupplyOutput},
+};
+use sol_spl_token::{
+    mint_admin::{MintAuthority, MintOperation},
+    Pubkey, Signer,
+};
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::{sys_mint_admin_error::MintAdminError, SysAssetService};
+
+/// 未指定时所需的审批人数
+const DEFAULT_REQUIRED_APPROVALS: i32 = 2;
+
+#[async_trait]
+pub trait TMintAdminService {
+    async fn find_paginated_operations(
+        &self,
+        domain: &str,
+        params: MintOperationPageRequest,
+    ) -> Result<PaginatedData<SysMintOperationModel>, AppError>;
+
+    async fn create_operation(
+        &self,
+        domain: &str,
+        input: CreateMintOperationInput,
+        operator: &str,
+    ) -> Result<SysMintOperationModel, AppError>;
+
+    async fn get_operation(&self, domain: &str, id: &str) -> Result<MintOperationOutput, AppError>;
+
+    async fn approve_operation(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysMintOperationModel, AppError>;
+
+    async fn reject_operation(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysMintOperationModel, AppError>;
+
+    async fn execute_operation(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysMintOperationModel, AppError>;
+
+    async fn get_supply(&self, domain: &str, mint: &str) -> Result<MintSupplyOutput, AppError>;
+
+    async fn get_holder_count(
+        &self,
+        domain: &str,
+        mint: &str,
+    ) -> Result<MintHolderCountOutput, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysMintAdminService;
+
+fn parse_address(address: &str) -> Result<Pubkey, AppError> {
+    Pubkey::from_str(address)
+        .map_err(|_| AppError::from(MintAdminError::InvalidAddress(address.to_string())))
+}
+
+/// 由记录中的字段还原链上操作，创建时也用它校验各操作必填的字段
+fn build_operation(
+    operation_type: MintOperationType,
+    target: Option<&str>,
+    amount: Option<i64>,
+    authority_type: Option<MintAuthorityType>,
+) -> Result<MintOperation, AppError> {
+    let amount = || {
+        amount
+            .and_then(|amount| u64::try_from(amount).ok())
+            .filter(|amount| *amount > 0)
+            .ok_or_else(|| AppError::from(MintAdminError::InvalidOperation("amount is required")))
+    };
+    let required_target = || {
+        target
+            .ok_or_else(|| AppError::from(MintAdminError::InvalidOperation("target is required")))
+            .and_then(parse_address)
+    };
+
+    Ok(match operation_type {
+        MintOperationType::MintTo => MintOperation::MintTo {
+            recipient: required_target()?,
+            amount: amount()?,
+        },
+        MintOperationType::Burn => MintOperation::Burn { amount: amount()? },
+        MintOperationType::FreezeAccount => MintOperation::FreezeAccount {
+            account: required_target()?,
+        },
+        MintOperationType::ThawAccount => MintOperation::ThawAccount {
+            account: required_target()?,
+        },
+        MintOperationType::SetAuthority => MintOperation::SetAuthority {
+            authority: match authority_type {
+                Some(MintAuthorityType::MintTokens) => MintAuthority::MintTokens,
+                Some(MintAuthorityType::FreezeAccount) => MintAuthority::FreezeAccount,
+                None => {
+                    return Err(
+                        MintAdminError::InvalidOperation("authority type is required").into(),
+                    )
+                },
+            },
+            new_authority: target.map(parse_address).transpose()?,
+        },
+    })
+}
+
+fn to_operation(operation: &SysMintOperationModel) -> Result<MintOperation, AppError> {
+    build_operation(
+        operation.operation_type,
+        operation.target.as_deref(),
+        operation.amount,
+        operation.authority_type,
+    )
+}
+
+impl SysMintAdminService {
+    async fn find_operation(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysMintOperationModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysMintOperation::find_by_id(id)
+            .filter(SysMintOperationColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| MintAdminError::OperationNotFound.into())
+    }
+
+    async fn audit<C: ConnectionTrait>(
+        conn: &C,
+        operation: &SysMintOperationModel,
+        action: MintOperationAction,
+        operator: &str,
+        detail: Option<String>,
+    ) -> Result<(), AppError> {
+        SysMintOperationAuditActiveModel {
+            id: Set(Ulid::new().to_string()),
+            operation_id: Set(operation.id.clone()),
+            domain: Set(operation.domain.clone()),
+            action: Set(action),
+            operator: Set(operator.to_string()),
+            detail: Set(detail),
+            created_at: Set(Local::now().naive_local()),
+        }
+        .insert(conn)
+        .await
+        .map_err(AppError::from)?;
+        Ok(())
+    }
+
+    /// 待审批的操作只能由发起人以外、尚未审批过的人审批
+    async fn check_reviewer(
+        &self,
+        operation: &SysMintOperationModel,
+        operator: &str,
+    ) -> Result<(), AppError> {
+        if operation.status != MintOperationStatus::PendingApproval {
+            return Err(MintAdminError::InvalidStatus("pending approval").into());
+        }
+        if operation.created_by == operator {
+            return Err(MintAdminError::SelfApproval.into());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let reviewed = SysMintOperationAudit::find()
+            .filter(SysMintOperationAuditColumn::OperationId.eq(&operation.id))
+            .filter(SysMintOperationAuditColumn::Operator.eq(operator))
+            .filter(
+                SysMintOperationAuditColumn::Action
+                    .is_in([MintOperationAction::Approved, MintOperationAction::Rejected]),
+            )
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if reviewed > 0 {
+            return Err(MintAdminError::AlreadyReviewed.into());
+        }
+
+        Ok(())
+    }
+
+    /// 审批人数加一，达到所需人数时转为 approved；
+    /// 两步都以状态为条件更新，并发审批不会丢失计数
+    async fn approve_in_transaction(
+        &self,
+        txn: &DatabaseTransaction,
+        operation: &SysMintOperationModel,
+        operator: &str,
+    ) -> Result<(), AppError> {
+        let now = Local::now().naive_local();
+        let counted = SysMintOperation::update_many()
+            .col_expr(
+                SysMintOperationColumn::Approvals,
+                Expr::col(SysMintOperationColumn::Approvals).add(1),
+            )
+            .col_expr(SysMintOperationColumn::UpdatedAt, Expr::value(now))
+            .col_expr(SysMintOperationColumn::UpdatedBy, Expr::value(operator))
+            .filter(SysMintOperationColumn::Id.eq(&operation.id))
+            .filter(SysMintOperationColumn::Status.eq(MintOperationStatus::PendingApproval))
+            .exec(txn)
+            .await
+            .map_err(AppError::from)?;
+        if counted.rows_affected == 0 {
+            return Err(MintAdminError::InvalidStatus("pending approval").into());
+        }
+
+        SysMintOperation::update_many()
+            .col_expr(
+                SysMintOperationColumn::Status,
+                Expr::value(MintOperationStatus::Approved),
+            )
+            .filter(SysMintOperationColumn::Id.eq(&operation.id))
+            .filter(SysMintOperationColumn::Status.eq(MintOperationStatus::PendingApproval))
+            .filter(
+                Expr::col(SysMintOperationColumn::Approvals)
+                    .gte(Expr::col(SysMintOperationColumn::RequiredApprovals)),
+            )
+            .exec(txn)
+            .await
+            .map_err(AppError::from)?;
+
+        Self::audit(
+            txn,
+            operation,
+            MintOperationAction::Approved,
+            operator,
+            None,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl TMintAdminService for SysMintAdminService {
+    async fn find_paginated_operations(
+        &self,
+        domain: &str,
+        params: MintOperationPageRequest,
+    ) -> Result<PaginatedData<SysMintOperationModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysMintOperation::find()
+            .filter(SysMintOperationColumn::Domain.eq(domain))
+            .order_by_desc(SysMintOperationColumn::CreatedAt);
+
+        if let Some(mint) = params.mint {
+            query = query.filter(SysMintOperationColumn::Mint.eq(mint));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysMintOperationColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn create_operation(
+        &self,
+        domain: &str,
+        input: CreateMintOperationInput,
+        operator: &str,
+    ) -> Result<SysMintOperationModel, AppError> {
+        SysAssetService::find_enabled_asset_by_mint(domain, &input.mint).await?;
+        let mint = parse_address(&input.mint)?;
+        let operation = build_operation(
+            input.operation_type,
+            input.target.as_deref(),
+            input.amount,
+            input.authority_type,
+        )?;
+
+        // 系统钱包没有所需权限的操作即使通过审批也无法执行，发起时就拒绝
+        let payer = solana_helper::get_system_keypair().await?;
+        solana_helper::get_mint_admin().await?.check_authority(
+            &payer.pubkey(),
+            &mint,
+            &operation,
+        )?;
+
+        let model = SysMintOperationActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            mint: Set(input.mint),
+            operation_type: Set(input.operation_type),
+            target: Set(input.target),
+            amount: Set(input.amount),
+            authority_type: Set(input.authority_type),
+            reason: Set(input.reason),
+            status: Set(MintOperationStatus::PendingApproval),
+            required_approvals: Set(input
+                .required_approvals
+                .unwrap_or(DEFAULT_REQUIRED_APPROVALS)),
+            approvals: Set(0),
+            signature: Set(None),
+            error: Set(None),
+            executed_by: Set(None),
+            executed_at: Set(None),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        };
+
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let created = match model.insert(&txn).await {
+            Ok(created) => created,
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                return Err(e.into());
+            },
+        };
+        if let Err(e) =
+            Self::audit(&txn, &created, MintOperationAction::Created, operator, None).await
+        {
+            txn.rollback().await.map_err(AppError::from)?;
+            return Err(e);
+        }
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(created)
+    }
+
+    async fn get_operation(&self, domain: &str, id: &str) -> Result<MintOperationOutput, AppError> {
+        let operation = self.find_operation(domain, id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let audits = SysMintOperationAudit::find()
+            .filter(SysMintOperationAuditColumn::OperationId.eq(&operation.id))
+            .order_by_asc(SysMintOperationAuditColumn::CreatedAt)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(MintOperationOutput { operation, audits })
+    }
+
+    async fn approve_operation(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysMintOperationModel, AppError> {
+        let operation = self.find_operation(domain, id).await?;
+        self.check_reviewer(&operation, operator).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        match self
+            .approve_in_transaction(&txn, &operation, operator)
+            .await
+        {
+            Ok(()) => txn.commit().await.map_err(AppError::from)?,
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                return Err(e);
+            },
+        }
+
+        self.find_operation(domain, id).await
+    }
+
+    async fn reject_operation(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysMintOperationModel, AppError> {
+        let operation = self.find_operation(domain, id).await?;
+        self.check_reviewer(&operation, operator).await?;
+
+        // 任一审批人拒绝即终止
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysMintOperationActiveModel = operation.clone().into();
+        active.status = Set(MintOperationStatus::Rejected);
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.updated_by = Set(Some(operator.to_string()));
+        let rejected = active.update(db.as_ref()).await.map_err(AppError::from)?;
+
+        Self::audit(
+            db.as_ref(),
+            &rejected,
+            MintOperationAction::Rejected,
+            operator,
+            None,
+        )
+        .await?;
+
+        Ok(rejected)
+    }
+
+    async fn execute_operation(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysMintOperationModel, AppError> {
+        let operation = self.find_operation(domain, id).await?;
+
+        // 先把状态从 approved 置为 executing，重复提交的执行请求不会发送第二笔交易
+        let db = db_helper::get_db_connection().await?;
+        let claimed = SysMintOperation::update_many()
+            .col_expr(
+                SysMintOperationColumn::Status,
+                Expr::value(MintOperationStatus::Executing),
+            )
+            .filter(SysMintOperationColumn::Id.eq(&operation.id))
+            .filter(SysMintOperationColumn::Status.eq(MintOperationStatus::Approved))
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if claimed.rows_affected == 0 {
+            return Err(MintAdminError::InvalidStatus("approved").into());
+        }
+
+        let result = async {
+            let mint = parse_address(&operation.mint)?;
+            let instruction = to_operation(&operation)?;
+            let payer = solana_helper::get_system_keypair().await?;
+            solana_helper::get_mint_admin()
+                .await?
+                .execute(&payer, &mint, &instruction)
+                .await
+                .map_err(AppError::from)
+        }
+        .await;
+
+        let now = Local::now().naive_local();
+        let mut active: SysMintOperationActiveModel = operation.into();
+        let (action, detail) = match result {
+            Ok(signature) => {
+                active.status = Set(MintOperationStatus::Executed);
+                active.signature = Set(Some(signature.to_string()));
+                (MintOperationAction::Executed, signature.to_string())
+            },
+            Err(e) => {
+                active.status = Set(MintOperationStatus::Failed);
+                active.error = Set(Some(e.message.clone()));
+                (MintOperationAction::Failed, e.message)
+            },
+        };
+        active.executed_by = Set(Some(operator.to_string()));
+        active.executed_at = Set(Some(now));
+        active.updated_at = Set(Some(now));
+        active.updated_by = Set(Some(operator.to_string()));
+        let executed = active.update(db.as_ref()).await.map_err(AppError::from)?;
+
+        Self::audit(db.as_ref(), &executed, action, operator, Some(detail)).await?;
+
+        Ok(executed)
+    }
+
+    async fn get_supply(&self, domain: &str, mint: &str) -> Result<MintSupplyOutput, AppError> {
+        SysAssetService::find_enabled_asset_by_mint(domain, mint).await?;
+        let supply = solana_helper::get_mint_admin()
+            .await?
+            .get_supply(&parse_address(mint)?)?;
+
+        Ok(MintSupplyOutput {
+            mint: mint.to_string(),
+            supply: supply.supply,
+            decimals: supply.decimals,
+            mint_authority: supply.mint_authority.map(|authority| authority.to_string()),
+            freeze_authority: supply
+                .freeze_authority
+                .map(|authority| authority.to_string()),
+        })
+    }
+
+    async fn get_holder_count(
+        &self,
+        domain: &str,
+        mint: &str,
+    ) -> Result<MintHolderCountOutput, AppError> {
+        SysAssetService::find_enabled_asset_by_mint(domain, mint).await?;
+        let holders = solana_helper::get_mint_admin()
+            .await?
+            .get_holder_count(&parse_address(mint)?)?;
+
+        Ok(MintHolderCountOutput {
+            mint: mint.to_string(),
+            holders,
+        })
+    }
+}
@@ -1,12 +1,16 @@
 use std::any::Any;
 
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
     Set,
 };
 use server_core::web::{error::AppError, page::PaginatedData};
-use server_global::{global::OperationLogContext, project_error};
+use server_global::{
+    global::{self, OperationLogContext},
+    project_error,
+};
 use server_model::admin::{
     entities::{
         prelude::SysOperationLog,
@@ -34,6 +38,19 @@ pub trait TOperationLogService {
 
 pub struct SysOperationLogService;
 
+impl SysOperationLogService {
+    /// 删除早于 `before` 的操作日志，返回删除条数
+    pub async fn purge_before(before: NaiveDateTime) -> Result<u64, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysOperationLog::delete_many()
+            .filter(SysOperationLogColumn::CreatedAt.lt(before))
+            .exec(db.as_ref())
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(AppError::from)
+    }
+}
+
 #[async_trait]
 impl TOperationLogService for SysOperationLogService {
     async fn find_paginated_operation_logs(
@@ -110,12 +127,14 @@ pub async fn sys_operation_log_listener(
     mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
 ) {
     while let Some(event) = rx.recv().await {
+        let (event, ack) = global::open_event(event);
         if let Some(operation_log_context) = event.downcast_ref::<OperationLogContext>() {
-            if let Err(e) =
-                SysOperationLogService::handle_operation_log_event(operation_log_context).await
-            {
+            let result =
+                SysOperationLogService::handle_operation_log_event(operation_log_context).await;
+            if let Err(ref e) = result {
                 project_error!("Failed to handle operation log event: {:?}", e);
             }
+            ack.done(result);
         } else {
             project_error!("Received unknown event type in operation log listener");
         }
@@ -0,0 +1,340 @@
+use std::{any::Any, str::FromStr, sync::LazyLock, time::Duration};
+
+use chrono::{Local, NaiveDateTime};
+use sea_orm::{
+    sea_query::OnConflict, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait,
+    IntoActiveModel, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value as JsonValue;
+use server_config::OutboxConfig;
+use server_constant::definition::consts::SystemEvent;
+use server_core::web::error::AppError;
+use server_global::{
+    global::{self, AckedEvent, OperationLogContext},
+    project_error,
+};
+use server_model::admin::entities::{
+    prelude::SysOutbox,
+    sea_orm_active_enums::OutboxStatus,
+    sys_outbox::{
+        ActiveModel as SysOutboxActiveModel, Column as SysOutboxColumn, Model as SysOutboxModel,
+    },
+};
+use server_scheduler::LeaderElection;
+use tokio::{sync::Notify, task::JoinHandle};
+use ulid::Ulid;
+
+use super::{
+    event_handlers::auth_event_handler::AuthEvent, sys_auto_convert_service::AutoConvertJobEvent,
+    sys_payout_service::PayoutBatchEvent, sys_webhook_service::WebhookEvent,
+};
+use crate::helper::{
+    db_helper, redis_helper,
+    retry_helper::{truncate, RetryPolicy, RetryQueue},
+};
+
+/// 每轮最多发布的事件数
+const RELAY_BATCH_SIZE: u64 = 100;
+
+/// 最多尝试 10 次，首次失败 5 秒后重试，之后每次翻倍，最长间隔 10 分钟；
+/// 领取后 1 分钟内未完成视为发布中断，重新发布
+const RETRY_POLICY: RetryPolicy = RetryPolicy {
+    max_attempts: 10,
+    base_delay: Duration::from_secs(5),
+    max_delay: Duration::from_secs(10 * 60),
+    lease: Duration::from_secs(60),
+};
+
+/// 等待监听器确认的时间，须短于领取租约，超时视为失败稍后重试
+const ACK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 记录的错误信息的最大长度
+const MAX_ERROR_LEN: usize = 1000;
+
+/// 新事件提交后唤醒中继，不必等待下一轮扫描
+static RELAY_WAKEUP: LazyLock<Notify> = LazyLock::new(Notify::new);
+
+/// 事件发件箱
+///
+/// 事件与业务数据在同一个事务中写入 `sys_outbox`，由中继投递给进程内监听器，
+/// 按配置同时发布到 Redis Stream。发布成功后才标记为已发布，因此同一事件可能被投递多次，
+/// 消费方需按去重键或业务状态保证幂等
+pub struct SysOutboxService;
+
+impl RetryQueue for SysOutbox {
+    const ID: Self::Column = SysOutboxColumn::Id;
+    const STATUS: Self::Column = SysOutboxColumn::Status;
+    const ATTEMPTS: Self::Column = SysOutboxColumn::Attempts;
+    const NEXT_ATTEMPT_AT: Self::Column = SysOutboxColumn::NextAttemptAt;
+    const UPDATED_AT: Self::Column = SysOutboxColumn::UpdatedAt;
+}
+
+fn boxed<T: DeserializeOwned + Any + Send>(
+    payload: JsonValue,
+) -> Result<Box<dyn Any + Send>, String> {
+    serde_json::from_value::<T>(payload)
+        .map(|event| Box::new(event) as Box<dyn Any + Send>)
+        .map_err(|e| e.to_string())
+}
+
+/// 还原为监听器接收的事件类型
+fn decode(event_type: &str, payload: JsonValue) -> Result<Box<dyn Any + Send>, String> {
+    let event = SystemEvent::from_str(event_type)
+        .map_err(|_| format!("Unknown event type: {}", event_type))?;
+    match event {
+        SystemEvent::AuthLoggedInEvent => boxed::<AuthEvent>(payload),
+        SystemEvent::AuditOperationLoggedEvent => boxed::<OperationLogContext>(payload),
+        SystemEvent::AutoConvertJobQueuedEvent => boxed::<AutoConvertJobEvent>(payload),
+        SystemEvent::PayoutBatchQueuedEvent => boxed::<PayoutBatchEvent>(payload),
+        SystemEvent::WebhookEventQueuedEvent => boxed::<WebhookEvent>(payload),
+        SystemEvent::AuthApiKeyValidatedEvent => Err(format!(
+            "{} is not delivered through the outbox",
+            event_type
+        )),
+    }
+}
+
+impl SysOutboxService {
+    /// 写入一条事件，通常在业务事务中调用
+    ///
+    /// 去重键在同一事件类型内唯一，已存在时不再写入，返回是否写入。
+    /// 事务提交后调用 [`Self::wake_relay`] 立即发布，否则等待下一轮扫描
+    pub async fn enqueue<C: ConnectionTrait>(
+        conn: &C,
+        event: SystemEvent,
+        dedup_key: &str,
+        payload: &impl Serialize,
+    ) -> Result<bool, AppError> {
+        let payload = serde_json::to_value(payload).map_err(|e| AppError {
+            code: 500,
+            message: format!("Failed to serialize {} event: {}", event, e),
+        })?;
+        Self::insert(conn, event.as_ref(), dedup_key, payload).await
+    }
+
+    async fn insert<C: ConnectionTrait>(
+        conn: &C,
+        event_type: &str,
+        dedup_key: &str,
+        payload: JsonValue,
+    ) -> Result<bool, AppError> {
+        let now = Local::now().naive_local();
+        let entry = SysOutboxActiveModel {
+            id: Set(Ulid::new().to_string()),
+            event_type: Set(event_type.to_string()),
+            dedup_key: Set(format!("{}:{}", event_type, dedup_key)),
+            payload: Set(payload),
+            status: Set(OutboxStatus::Pending),
+            attempts: Set(0),
+            next_attempt_at: Set(now),
+            created_at: Set(now),
+            ..Default::default()
+        };
+
+        let inserted = SysOutbox::insert(entry)
+            .on_conflict(
+                OnConflict::column(SysOutboxColumn::DedupKey)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(conn)
+            .await
+            .map_err(AppError::from)?;
+        Ok(inserted > 0)
+    }
+
+    /// 唤醒中继发布新提交的事件
+    pub fn wake_relay() {
+        RELAY_WAKEUP.notify_one();
+    }
+
+    /// 注册为全局发件箱写入器，供没有业务事务的调用方通过 [`global::send_outbox_event`] 使用
+    pub fn register_writer() {
+        global::register_outbox_writer(Box::new(|event_type, dedup_key, payload| {
+            Box::pin(async move {
+                let db = db_helper::get_db_connection()
+                    .await
+                    .map_err(|e| e.message)?;
+                Self::insert(db.as_ref(), event_type, &dedup_key, payload)
+                    .await
+                    .map_err(|e| e.message)?;
+                Self::wake_relay();
+                Ok(())
+            })
+        }));
+    }
+
+    /// 领取并发布一条事件
+    ///
+    /// 以事件当前的状态和下次发布时间为条件领取，多个实例同时处理时只有一个会发布
+    async fn relay(entry: SysOutboxModel) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let claimed = RETRY_POLICY
+            .claim::<SysOutbox, _, _>(
+                db.as_ref(),
+                &entry.id,
+                entry.status,
+                entry.next_attempt_at,
+                OutboxStatus::Publishing,
+                Local::now().naive_local(),
+            )
+            .await?;
+        if !claimed {
+            return Ok(());
+        }
+
+        let attempts = entry.attempts + 1;
+        let result = Self::publish(&entry).await;
+
+        let now = Local::now().naive_local();
+        let mut active = entry.into_active_model();
+        active.attempts = Set(attempts);
+        active.updated_at = Set(Some(now));
+        match result {
+            Ok(()) => {
+                active.status = Set(OutboxStatus::Published);
+                active.last_error = Set(None);
+                active.published_at = Set(Some(now));
+            },
+            Err(error) => {
+                active.last_error = Set(Some(truncate(error, MAX_ERROR_LEN)));
+                if RETRY_POLICY.exhausted(attempts) {
+                    active.status = Set(OutboxStatus::DeadLettered);
+                } else {
+                    active.status = Set(OutboxStatus::Pending);
+                    active.next_attempt_at = Set(RETRY_POLICY.next_attempt_at(now, attempts));
+                }
+            },
+        }
+        let entry = active.update(db.as_ref()).await.map_err(AppError::from)?;
+
+        if entry.status == OutboxStatus::DeadLettered {
+            project_error!(
+                "Outbox event {} ({}) dead-lettered after {} attempts: {:?}",
+                entry.id,
+                entry.event_type,
+                attempts,
+                entry.last_error
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 先发布到 Redis Stream 再投递给进程内监听器，任一失败都整体重试
+    ///
+    /// Redis 确认写入且监听器确认处理后才算发布成功，进程在两者之间退出时事件会被重新投递
+    async fn publish(entry: &SysOutboxModel) -> Result<(), String> {
+        let event = decode(&entry.event_type, entry.payload.clone())?;
+        let sender = global::get_dyn_sender(&entry.event_type)
+            .await
+            .ok_or_else(|| format!("No listener registered for {}", entry.event_type))?;
+
+        if let Some(config) = global::get_config::<OutboxConfig>().await {
+            if config.redis_stream_enabled {
+                Self::publish_to_stream(&config, entry)
+                    .await
+                    .map_err(|e| format!("Failed to publish to Redis stream: {}", e))?;
+            }
+        }
+
+        let (event, ack) = AckedEvent::new(event);
+        sender
+            .send(Box::new(event))
+            .map_err(|_| format!("Listener for {} has stopped", entry.event_type))?;
+        match tokio::time::timeout(ACK_TIMEOUT, ack).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(format!(
+                "Listener for {} dropped the event without acknowledging",
+                entry.event_type
+            )),
+            Err(_) => Err(format!(
+                "Listener for {} did not acknowledge within {:?}",
+                entry.event_type, ACK_TIMEOUT
+            )),
+        }
+    }
+
+    async fn publish_to_stream(
+        config: &OutboxConfig,
+        entry: &SysOutboxModel,
+    ) -> Result<(), redis::RedisError> {
+        let redis = redis_helper::primary_redis().await?;
+
+        let mut cmd = redis::cmd("XADD");
+        cmd.arg(format!(
+            "{}:{}",
+            config.redis_stream_prefix, entry.event_type
+        ))
+        .arg("MAXLEN")
+        .arg("~")
+        .arg(config.redis_stream_max_len)
+        .arg("*")
+        .arg("id")
+        .arg(&entry.id)
+        .arg("dedup_key")
+        .arg(&entry.dedup_key)
+        .arg("event_type")
+        .arg(&entry.event_type)
+        .arg("payload")
+        .arg(entry.payload.to_string())
+        .arg("created_at")
+        .arg(entry.created_at.to_string());
+
+        redis.query::<String>(&cmd).await.map(|_| ())
+    }
+
+    /// 发布一轮到期的事件，包括新写入的、等待重试的和租约已过期的
+    pub async fn relay_due() -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let entries = SysOutbox::find()
+            .filter(
+                SysOutboxColumn::Status.is_in([OutboxStatus::Pending, OutboxStatus::Publishing]),
+            )
+            .filter(SysOutboxColumn::NextAttemptAt.lte(Local::now().naive_local()))
+            .order_by_asc(SysOutboxColumn::NextAttemptAt)
+            .limit(RELAY_BATCH_SIZE)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        for entry in entries {
+            let id = entry.id.clone();
+            if let Err(e) = Self::relay(entry).await {
+                project_error!("Failed to relay outbox event {}: {}", id, e.message);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 启动中继，被唤醒或到达扫描间隔时发布到期的事件
+    ///
+    /// 多实例部署时只有选举出的主节点发布，其他实例提交的事件最迟在一个扫描间隔后发布
+    pub fn spawn_relay(interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut election = LeaderElection::new("outbox_relay");
+            loop {
+                if election.is_leader().await {
+                    if let Err(e) = Self::relay_due().await {
+                        project_error!("Failed to relay outbox events: {}", e.message);
+                    }
+                }
+                let _ = tokio::time::timeout(interval, RELAY_WAKEUP.notified()).await;
+            }
+        })
+    }
+
+    /// 删除 `before` 之前已发布的事件，返回删除条数
+    pub async fn purge_published_before(before: NaiveDateTime) -> Result<u64, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysOutbox::delete_many()
+            .filter(SysOutboxColumn::Status.eq(OutboxStatus::Published))
+            .filter(SysOutboxColumn::PublishedAt.lt(before))
+            .exec(db.as_ref())
+            .await
+            .map(|result| result.rows_affected)
+            .map_err(AppError::from)
+    }
+}
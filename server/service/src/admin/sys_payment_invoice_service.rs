@@ -0,0 +1,381 @@
+use std::{str::FromStr, time::Duration};
+
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use serde_json::json;
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_global::{project_error, project_info};
+use server_scheduler::LeaderElection;
+use server_model::admin::{
+    entities::{
+        prelude::{SysCustodyWallet, SysPaymentInvoice},
+        sea_orm_active_enums::{
+            CustodyWalletType, PaymentInvoiceStatus, Status, WebhookEventType,
+        },
+        sys_custody_wallet::Column as SysCustodyWalletColumn,
+        sys_payment_invoice::{
+            ActiveModel as SysPaymentInvoiceActiveModel, Column as SysPaymentInvoiceColumn,
+            Model as SysPaymentInvoiceModel,
+        },
+    },
+    input::{CreatePaymentInvoiceInput, PaymentInvoicePageRequest},
+    output::PaymentInvoiceOutput,
+};
+use sol_spl_token::{
+    memo::reference_for,
+    solana_pay::{qr_svg, TransferRequest},
+    Pubkey,
+};
+use tokio::task::JoinHandle;
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::{
+    sys_payment_invoice_error::PaymentInvoiceError, SysAssetService, SysOutboxService,
+    SysWebhookService,
+};
+
+/// SOL 的小数位数
+const SOL_DECIMALS: i32 = 9;
+
+/// 未指定时收款单的有效期（分钟）
+const DEFAULT_EXPIRES_IN_MINUTES: i64 = 60;
+
+/// 每轮检查的待付款收款单数量上限
+const CHECK_BATCH_SIZE: u64 = 100;
+
+#[async_trait]
+pub trait TPaymentInvoiceService {
+    async fn find_paginated_invoices(
+        &self,
+        domain: &str,
+        params: PaymentInvoicePageRequest,
+    ) -> Result<PaginatedData<SysPaymentInvoiceModel>, AppError>;
+
+    async fn create_invoice(
+        &self,
+        domain: &str,
+        input: CreatePaymentInvoiceInput,
+        operator: &str,
+    ) -> Result<PaymentInvoiceOutput, AppError>;
+
+    async fn get_invoice(&self, domain: &str, id: &str) -> Result<PaymentInvoiceOutput, AppError>;
+
+    async fn cancel_invoice(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysPaymentInvoiceModel, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysPaymentInvoiceService;
+
+fn parse_address(address: &str) -> Result<Pubkey, AppError> {
+    Pubkey::from_str(address)
+        .map_err(|_| AppError::from(PaymentInvoiceError::InvalidAddress(address.to_string())))
+}
+
+/// 生成收款单的 Solana Pay 转账请求与二维码
+fn to_output(invoice: SysPaymentInvoiceModel) -> Result<PaymentInvoiceOutput, AppError> {
+    let request = TransferRequest {
+        recipient: parse_address(&invoice.recipient)?,
+        amount: u64::try_from(invoice.amount).ok(),
+        decimals: u8::try_from(invoice.decimals).unwrap_or_default(),
+        spl_token: invoice.mint.as_deref().map(parse_address).transpose()?,
+        references: vec![parse_address(&invoice.reference)?],
+        label: invoice.label.clone(),
+        message: invoice.message.clone(),
+        memo: None,
+    };
+    let url = request.to_url();
+    let qr_svg = qr_svg(&url)?;
+
+    Ok(PaymentInvoiceOutput {
+        invoice,
+        url,
+        qr_svg,
+    })
+}
+
+impl SysPaymentInvoiceService {
+    async fn find_invoice(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysPaymentInvoiceModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysPaymentInvoice::find_by_id(id)
+            .filter(SysPaymentInvoiceColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| PaymentInvoiceError::InvoiceNotFound.into())
+    }
+
+    /// 按引用公钥查找付款交易，找到时按实收数量标记为已付或少付
+    ///
+    /// 返回是否找到付款
+    async fn check_invoice(invoice: &SysPaymentInvoiceModel) -> Result<bool, AppError> {
+        let checker = solana_helper::get_payment_checker().await?;
+        let Some(signature) = checker.find_payment(&parse_address(&invoice.reference)?)? else {
+            return Ok(false);
+        };
+
+        let recipient = parse_address(&invoice.recipient)?;
+        let mint = invoice.mint.as_deref().map(parse_address).transpose()?;
+        let received = checker.received_amount(&signature, &recipient, mint.as_ref())?;
+        let status = if received >= invoice.amount as u64 {
+            PaymentInvoiceStatus::Paid
+        } else {
+            PaymentInvoiceStatus::Underpaid
+        };
+
+        // 以待付款为条件更新，取消与付款同时发生时以先写入的为准
+        let now = Local::now().naive_local();
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let updated = SysPaymentInvoice::update_many()
+            .col_expr(SysPaymentInvoiceColumn::Status, Expr::value(status))
+            .col_expr(
+                SysPaymentInvoiceColumn::Signature,
+                Expr::value(signature.to_string()),
+            )
+            .col_expr(
+                SysPaymentInvoiceColumn::ReceivedAmount,
+                Expr::value(i64::try_from(received).unwrap_or(i64::MAX)),
+            )
+            .col_expr(SysPaymentInvoiceColumn::PaidAt, Expr::value(now))
+            .col_expr(SysPaymentInvoiceColumn::UpdatedAt, Expr::value(now))
+            .filter(SysPaymentInvoiceColumn::Id.eq(&invoice.id))
+            .filter(SysPaymentInvoiceColumn::Status.eq(PaymentInvoiceStatus::Pending))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        if updated.rows_affected > 0 {
+            SysWebhookService::notify(
+                &txn,
+                &invoice.domain,
+                WebhookEventType::DepositConfirmed,
+                json!({
+                    "invoiceId": invoice.id,
+                    "reference": invoice.reference,
+                    "recipient": invoice.recipient,
+                    "mint": invoice.mint,
+                    "amount": invoice.amount,
+                    "receivedAmount": received,
+                    "signature": signature.to_string(),
+                    "status": status,
+                }),
+            )
+            .await?;
+        }
+        txn.commit().await.map_err(AppError::from)?;
+        SysOutboxService::wake_relay();
+
+        project_info!(
+            "Payment invoice {} {:?} by {}: received {}",
+            invoice.id,
+            status,
+            signature,
+            received
+        );
+        Ok(true)
+    }
+
+    /// 检查一轮待付款的收款单
+    ///
+    /// 已过期但尚未检查的收款单也会先查一次链上，过期前付款的不会被误标为过期
+    pub async fn check_pending_invoices() -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let invoices = SysPaymentInvoice::find()
+            .filter(SysPaymentInvoiceColumn::Status.eq(PaymentInvoiceStatus::Pending))
+            .order_by_asc(SysPaymentInvoiceColumn::ExpiresAt)
+            .limit(CHECK_BATCH_SIZE)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let now = Local::now().naive_local();
+        let mut expired = Vec::new();
+        for invoice in invoices {
+            match Self::check_invoice(&invoice).await {
+                Ok(true) => {},
+                Ok(false) if invoice.expires_at <= now => expired.push(invoice.id),
+                Ok(false) => {},
+                Err(e) => project_error!(
+                    "Failed to check payment invoice {}: {}",
+                    invoice.id,
+                    e.message
+                ),
+            }
+        }
+
+        if !expired.is_empty() {
+            SysPaymentInvoice::update_many()
+                .col_expr(
+                    SysPaymentInvoiceColumn::Status,
+                    Expr::value(PaymentInvoiceStatus::Expired),
+                )
+                .col_expr(SysPaymentInvoiceColumn::UpdatedAt, Expr::value(now))
+                .filter(SysPaymentInvoiceColumn::Id.is_in(expired))
+                .filter(SysPaymentInvoiceColumn::Status.eq(PaymentInvoiceStatus::Pending))
+                .exec(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+        }
+
+        Ok(())
+    }
+
+    /// 启动后台任务，按固定间隔检查待付款的收款单
+    ///
+    /// 多实例部署时只有选举出的主节点检查
+    pub fn spawn_checker(interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut election = LeaderElection::new("payment_invoice_checker");
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if !election.is_leader().await {
+                    continue;
+                }
+                if let Err(e) = Self::check_pending_invoices().await {
+                    project_error!("Failed to check payment invoices: {}", e.message);
+                }
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl TPaymentInvoiceService for SysPaymentInvoiceService {
+    async fn find_paginated_invoices(
+        &self,
+        domain: &str,
+        params: PaymentInvoicePageRequest,
+    ) -> Result<PaginatedData<SysPaymentInvoiceModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysPaymentInvoice::find()
+            .filter(SysPaymentInvoiceColumn::Domain.eq(domain))
+            .order_by_desc(SysPaymentInvoiceColumn::CreatedAt);
+
+        if let Some(user_id) = params.user_id {
+            query = query.filter(SysPaymentInvoiceColumn::UserId.eq(user_id));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysPaymentInvoiceColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn create_invoice(
+        &self,
+        domain: &str,
+        input: CreatePaymentInvoiceInput,
+        operator: &str,
+    ) -> Result<PaymentInvoiceOutput, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let wallet = SysCustodyWallet::find()
+            .filter(SysCustodyWalletColumn::Domain.eq(domain))
+            .filter(SysCustodyWalletColumn::UserId.eq(input.user_id.as_str()))
+            .filter(SysCustodyWalletColumn::WalletType.eq(CustodyWalletType::User))
+            .filter(SysCustodyWalletColumn::Status.eq(Status::Enabled))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(PaymentInvoiceError::WalletNotFound))?;
+
+        let decimals = match &input.mint {
+            Some(mint) => {
+                SysAssetService::find_enabled_asset_by_mint(domain, mint)
+                    .await?
+                    .decimals
+            },
+            None => SOL_DECIMALS,
+        };
+
+        // 引用公钥由收款单 ID 派生，与转出交易的标记方式一致
+        let id = Ulid::new().to_string();
+        let now = Local::now().naive_local();
+        let expires_in = chrono::Duration::minutes(
+            input
+                .expires_in_minutes
+                .unwrap_or(DEFAULT_EXPIRES_IN_MINUTES),
+        );
+        let invoice = SysPaymentInvoiceActiveModel {
+            id: Set(id.clone()),
+            domain: Set(domain.to_string()),
+            user_id: Set(input.user_id),
+            recipient: Set(wallet.address),
+            mint: Set(input.mint),
+            amount: Set(input.amount),
+            decimals: Set(decimals),
+            reference: Set(reference_for(&id).to_string()),
+            label: Set(input.label),
+            message: Set(input.message),
+            status: Set(PaymentInvoiceStatus::Pending),
+            signature: Set(None),
+            received_amount: Set(None),
+            expires_at: Set(now + expires_in),
+            paid_at: Set(None),
+            created_at: Set(now),
+            created_by: Set(operator.to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        }
+        .insert(db.as_ref())
+        .await
+        .map_err(AppError::from)?;
+
+        to_output(invoice)
+    }
+
+    async fn get_invoice(&self, domain: &str, id: &str) -> Result<PaymentInvoiceOutput, AppError> {
+        let invoice = self.find_invoice(domain, id).await?;
+        to_output(invoice)
+    }
+
+    async fn cancel_invoice(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysPaymentInvoiceModel, AppError> {
+        let invoice = self.find_invoice(domain, id).await?;
+        if invoice.status != PaymentInvoiceStatus::Pending {
+            return Err(PaymentInvoiceError::NotPending.into());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysPaymentInvoiceActiveModel = invoice.into();
+        active.status = Set(PaymentInvoiceStatus::Cancelled);
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.updated_by = Set(Some(operator.to_string()));
+        active.update(db.as_ref()).await.map_err(AppError::from)
+    }
+}
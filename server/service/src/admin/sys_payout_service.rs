@@ -0,0 +1,1022 @@
+use std::{any::Any, collections::HashMap, str::FromStr, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use aws_sdk_s3::{presigning::PresigningConfig, Client as S3Client};
+use chrono::Local;
+use sea_orm::{
+    prelude::Expr, sea_query::Alias, ActiveEnum, ActiveModelTrait, ColumnTrait,
+    DatabaseTransaction, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+    TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use server_constant::definition::consts::SystemEvent;
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_global::{global, project_error, project_info};
+use server_model::admin::{
+    entities::{
+        prelude::{SysPayoutBatch, SysPayoutRow},
+        sea_orm_active_enums::{
+            PayoutBatchStatus, PayoutRowStatus, PayoutSourceFormat, WebhookEventType,
+        },
+        sys_asset::Model as SysAssetModel,
+        sys_payout_batch::{
+            ActiveModel as SysPayoutBatchActiveModel, Column as SysPayoutBatchColumn,
+            Model as SysPayoutBatchModel,
+        },
+        sys_payout_row::{
+            ActiveModel as SysPayoutRowActiveModel, Column as SysPayoutRowColumn,
+            Model as SysPayoutRowModel,
+        },
+    },
+    input::{PayoutBatchPageRequest, PayoutRowPageRequest},
+    output::{PayoutBatchOutput, PayoutTotalOutput},
+};
+use sol_spl_token::{
+    payout::{estimate_network_fee, PayoutExecutor, PayoutTransfer},
+    memo::reference_for,
+    Pubkey, Signer, TransferTag,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use crate::helper::{db_helper, s3_helper, solana_helper};
+
+use super::{
+    sys_payout_error::PayoutError, SysAssetService, SysLookupTableService, SysOutboxService,
+    SysWebhookService,
+};
+
+/// 单个批次的最大行数
+pub const MAX_PAYOUT_ROWS: usize = 10_000;
+
+/// 单条 INSERT 语句写入的行数，避免超出数据库参数数量上限
+const INSERT_CHUNK_SIZE: usize = 1_000;
+
+/// executing 批次超过该时长（分钟）没有进展即视为执行实例已退出，需长于交易区块哈希的有效期
+const STALE_BATCH_MINUTES: i64 = 10;
+
+/// 报告下载链接有效期
+const REPORT_URL_TTL: Duration = Duration::from_secs(3600);
+
+#[async_trait]
+pub trait TPayoutService {
+    async fn find_paginated_batches(
+        &self,
+        domain: &str,
+        params: PayoutBatchPageRequest,
+    ) -> Result<PaginatedData<SysPayoutBatchModel>, AppError>;
+
+    async fn create_batch(
+        &self,
+        domain: &str,
+        name: Option<String>,
+        file_name: &str,
+        data: Vec<u8>,
+        operator: &str,
+    ) -> Result<PayoutBatchOutput, AppError>;
+
+    async fn get_batch(&self, domain: &str, id: &str) -> Result<PayoutBatchOutput, AppError>;
+
+    async fn find_paginated_rows(
+        &self,
+        domain: &str,
+        id: &str,
+        params: PayoutRowPageRequest,
+    ) -> Result<PaginatedData<SysPayoutRowModel>, AppError>;
+
+    async fn approve_batch(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysPayoutBatchModel, AppError>;
+
+    async fn reject_batch(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysPayoutBatchModel, AppError>;
+
+    async fn execute_batch(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysPayoutBatchModel, AppError>;
+
+    async fn get_report_url(&self, domain: &str, id: &str) -> Result<String, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysPayoutService;
+
+/// 批量发放批次入队事件
+#[derive(Serialize, Deserialize)]
+pub struct PayoutBatchEvent {
+    pub batch_id: String,
+}
+
+/// 上传文件中的一行，校验前保持原始文本
+struct RawPayoutRow {
+    address: String,
+    mint: String,
+    amount: String,
+}
+
+#[derive(Deserialize)]
+struct JsonPayoutRow {
+    #[serde(default)]
+    address: String,
+    #[serde(default)]
+    mint: String,
+    /// 兼容数字与字符串两种写法
+    #[serde(default)]
+    amount: serde_json::Value,
+}
+
+fn source_format(file_name: &str) -> Result<PayoutSourceFormat, AppError> {
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "csv" => Ok(PayoutSourceFormat::Csv),
+        "json" => Ok(PayoutSourceFormat::Json),
+        _ => Err(PayoutError::UnsupportedFormat(file_name.to_string()).into()),
+    }
+}
+
+/// 每行 `address,mint,amount`，首行为表头时跳过，空行忽略
+fn parse_csv(data: &[u8]) -> Result<Vec<RawPayoutRow>, AppError> {
+    let text = std::str::from_utf8(data)
+        .map_err(|e| AppError::from(PayoutError::InvalidFile(e.to_string())))?;
+
+    let mut lines = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .peekable();
+    if lines
+        .peek()
+        .is_some_and(|line| line.to_ascii_lowercase().starts_with("address"))
+    {
+        lines.next();
+    }
+
+    Ok(lines
+        .map(|line| {
+            let mut columns = line.split(',').map(|column| column.trim().to_string());
+            RawPayoutRow {
+                address: columns.next().unwrap_or_default(),
+                mint: columns.next().unwrap_or_default(),
+                amount: columns.next().unwrap_or_default(),
+            }
+        })
+        .collect())
+}
+
+fn parse_json(data: &[u8]) -> Result<Vec<RawPayoutRow>, AppError> {
+    let rows: Vec<JsonPayoutRow> = serde_json::from_slice(data)
+        .map_err(|e| AppError::from(PayoutError::InvalidFile(e.to_string())))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| RawPayoutRow {
+            address: row.address,
+            mint: row.mint,
+            amount: match row.amount {
+                serde_json::Value::String(amount) => amount,
+                amount => amount.to_string(),
+            },
+        })
+        .collect())
+}
+
+/// 校验单行，失败时返回写入行记录的原因
+///
+/// `id` 为该行记录的 ID，作为转账备注写入链上
+fn validate_row(
+    id: &str,
+    row: &RawPayoutRow,
+    enabled_assets: &HashMap<String, SysAssetModel>,
+) -> Result<PayoutTransfer, String> {
+    let recipient =
+        Pubkey::from_str(&row.address).map_err(|_| format!("Invalid address: {}", row.address))?;
+    let mint = Pubkey::from_str(&row.mint).map_err(|_| format!("Invalid mint: {}", row.mint))?;
+    let asset = enabled_assets
+        .get(&row.mint)
+        .ok_or_else(|| format!("Mint is not an enabled asset: {}", row.mint))?;
+    let (token_program, decimals) = SysAssetService::token_program(asset);
+
+    let amount = row
+        .amount
+        .parse::<u64>()
+        .ok()
+        .filter(|amount| *amount > 0 && i64::try_from(*amount).is_ok())
+        .ok_or_else(|| format!("Invalid amount: {}", row.amount))?;
+
+    Ok(PayoutTransfer {
+        recipient,
+        mint,
+        token_program,
+        decimals,
+        amount,
+        tag: Some(TransferTag::new(id)),
+    })
+}
+
+/// 报告字段包含逗号、引号或换行时加引号转义
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn build_report(rows: &[SysPayoutRowModel]) -> String {
+    let mut report = String::from("row,address,mint,amount,status,signature,error\n");
+    for row in rows {
+        report.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            row.row_index,
+            csv_field(&row.address),
+            csv_field(&row.mint),
+            row.amount,
+            row.status.to_value(),
+            row.signature.as_deref().unwrap_or_default(),
+            csv_field(row.error.as_deref().unwrap_or_default()),
+        ));
+    }
+    report
+}
+
+impl SysPayoutService {
+    async fn storage() -> Result<(Arc<S3Client>, String), AppError> {
+        match (
+            s3_helper::get_primary_s3_client().await,
+            s3_helper::get_default_bucket().await,
+        ) {
+            (Some(client), Some(bucket)) => Ok((client, bucket)),
+            _ => Err(PayoutError::StorageNotConfigured.into()),
+        }
+    }
+
+    async fn put_object(key: &str, content_type: &str, data: Vec<u8>) -> Result<(), AppError> {
+        let (client, bucket) = Self::storage().await?;
+        client
+            .put_object()
+            .bucket(bucket)
+            .key(key)
+            .content_type(content_type)
+            .body(data.into())
+            .send()
+            .await
+            .map_err(|e| AppError::from(PayoutError::Storage(e.to_string())))?;
+        Ok(())
+    }
+
+    async fn find_batch(&self, domain: &str, id: &str) -> Result<SysPayoutBatchModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysPayoutBatch::find_by_id(id)
+            .filter(SysPayoutBatchColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| PayoutError::BatchNotFound.into())
+    }
+
+    /// 按 mint 汇总批次中通过校验的行
+    async fn find_totals(batch_id: &str) -> Result<Vec<PayoutTotalOutput>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysPayoutRow::find()
+            .select_only()
+            .column(SysPayoutRowColumn::Mint)
+            .column_as(SysPayoutRowColumn::Id.count(), "rows")
+            .column_as(
+                SysPayoutRowColumn::Amount
+                    .sum()
+                    .cast_as(Alias::new("bigint")),
+                "amount",
+            )
+            .filter(SysPayoutRowColumn::BatchId.eq(batch_id))
+            .filter(SysPayoutRowColumn::Status.ne(PayoutRowStatus::Invalid))
+            .group_by(SysPayoutRowColumn::Mint)
+            .order_by_asc(SysPayoutRowColumn::Mint)
+            .into_model::<PayoutTotalOutput>()
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn save_batch_in_transaction(
+        &self,
+        txn: &DatabaseTransaction,
+        batch: SysPayoutBatchActiveModel,
+        rows: Vec<SysPayoutRowActiveModel>,
+    ) -> Result<SysPayoutBatchModel, AppError> {
+        let batch = batch.insert(txn).await.map_err(AppError::from)?;
+
+        let mut rows = rows.into_iter().peekable();
+        while rows.peek().is_some() {
+            SysPayoutRow::insert_many(rows.by_ref().take(INSERT_CHUNK_SIZE))
+                .exec(txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+
+        Ok(batch)
+    }
+
+    /// 把批次从 pending_approval 推进到新状态，同时校验审批人
+    async fn review_batch(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+        status: PayoutBatchStatus,
+    ) -> Result<SysPayoutBatchModel, AppError> {
+        let batch = self.find_batch(domain, id).await?;
+        if batch.status != PayoutBatchStatus::PendingApproval {
+            return Err(PayoutError::InvalidStatus("pending approval").into());
+        }
+        if batch.created_by == operator {
+            return Err(PayoutError::SelfApproval.into());
+        }
+        if status == PayoutBatchStatus::Approved && batch.invalid_rows > 0 {
+            return Err(PayoutError::HasInvalidRows(batch.invalid_rows).into());
+        }
+
+        let now = Local::now().naive_local();
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysPayoutBatchActiveModel = batch.into();
+        active.status = Set(status);
+        active.approved_by = Set(Some(operator.to_string()));
+        active.approved_at = Set(Some(now));
+        active.updated_at = Set(Some(now));
+        active.updated_by = Set(Some(operator.to_string()));
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let batch = active.update(&txn).await.map_err(AppError::from)?;
+        Self::notify_batch(&txn, &batch, &[]).await?;
+        txn.commit().await.map_err(AppError::from)?;
+        SysOutboxService::wake_relay();
+
+        Ok(batch)
+    }
+
+    /// 通知批次状态变化，执行结束时附带各行的发放结果
+    async fn notify_batch(
+        txn: &DatabaseTransaction,
+        batch: &SysPayoutBatchModel,
+        rows: &[SysPayoutRowModel],
+    ) -> Result<(), AppError> {
+        SysWebhookService::notify(
+            txn,
+            &batch.domain,
+            WebhookEventType::WithdrawalUpdated,
+            json!({
+                "kind": "payout_batch",
+                "status": batch.status,
+                "batch": batch,
+                "rows": rows,
+            }),
+        )
+        .await
+    }
+
+    /// 批次只会被成功领取执行一次，以批次 ID 去重
+    async fn enqueue(batch_id: String) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysOutboxService::enqueue(
+            db.as_ref(),
+            SystemEvent::PayoutBatchQueuedEvent,
+            &batch_id,
+            &PayoutBatchEvent {
+                batch_id: batch_id.clone(),
+            },
+        )
+        .await?;
+        SysOutboxService::wake_relay();
+        Ok(())
+    }
+
+    /// 领取一个已入队的批次，把批次从 approved 置为 executing
+    ///
+    /// 只有领取成功的调用者才会执行，批次已被领取或不存在时返回 None
+    pub async fn claim_batch(batch_id: &str) -> Result<Option<SysPayoutBatchModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+
+        let claimed = SysPayoutBatch::update_many()
+            .col_expr(
+                SysPayoutBatchColumn::Status,
+                Expr::value(PayoutBatchStatus::Executing),
+            )
+            .col_expr(
+                SysPayoutBatchColumn::UpdatedAt,
+                Expr::value(Local::now().naive_local()),
+            )
+            .filter(SysPayoutBatchColumn::Id.eq(batch_id))
+            .filter(SysPayoutBatchColumn::Status.eq(PayoutBatchStatus::Approved))
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if claimed.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        SysPayoutBatch::find_by_id(batch_id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// 执行已领取的批次
+    ///
+    /// 每发送一笔交易就更新其中各行的状态并刷新批次的 updated_at，中途失败时已发放的行不会被重复发放。
+    /// 结果未知的行记为 unconfirmed，批次保持 executing，由 [`Self::resume_stale_batches`] 核对后收尾
+    pub async fn run_batch(batch: SysPayoutBatchModel) -> Result<(), AppError> {
+        let batch_id = batch.id.as_str();
+        if let Err(e) = Self::send_rows(&batch.domain, batch_id).await {
+            project_error!("Failed to execute payout batch {}: {:?}", batch_id, e);
+            // 剩余待发送的行不再发送；已发出但未及记录的行按引用核对
+            Self::reconcile_rows(
+                batch_id,
+                vec![PayoutRowStatus::Pending],
+                PayoutRowStatus::Failed,
+                Some(e.message),
+            )
+            .await?;
+        }
+
+        Self::finish_batch(batch).await
+    }
+
+    /// 按各行状态收尾批次：生成报告、更新批次状态并发送通知
+    ///
+    /// 仍有结果未知的行时只刷新 updated_at，批次保持 executing
+    async fn finish_batch(batch: SysPayoutBatchModel) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let batch_id = batch.id.as_str();
+        let rows = SysPayoutRow::find()
+            .filter(SysPayoutRowColumn::BatchId.eq(batch_id))
+            .order_by_asc(SysPayoutRowColumn::RowIndex)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let count = |status: PayoutRowStatus| rows.iter().filter(|row| row.status == status).count();
+        let unsettled = count(PayoutRowStatus::Pending) + count(PayoutRowStatus::Unconfirmed);
+        if unsettled > 0 {
+            project_info!(
+                "Payout batch {} has {} unsettled rows, waiting for reconciliation",
+                batch_id,
+                unsettled
+            );
+            return Self::touch_batch(batch_id).await;
+        }
+        let status = match (count(PayoutRowStatus::Succeeded), count(PayoutRowStatus::Failed)) {
+            (_, 0) => PayoutBatchStatus::Completed,
+            (0, _) => PayoutBatchStatus::Failed,
+            _ => PayoutBatchStatus::PartiallyFailed,
+        };
+
+        let report_key = format!("payouts/{}/{}/report.csv", batch.domain, batch.id);
+        let report_key =
+            match Self::put_object(&report_key, "text/csv", build_report(&rows).into_bytes()).await
+            {
+                Ok(()) => Some(report_key),
+                Err(e) => {
+                    project_error!("Failed to upload payout report {}: {:?}", batch_id, e);
+                    None
+                },
+            };
+
+        let now = Local::now().naive_local();
+        let mut active: SysPayoutBatchActiveModel = batch.into();
+        active.status = Set(status);
+        active.report_key = Set(report_key);
+        active.executed_at = Set(Some(now));
+        active.updated_at = Set(Some(now));
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let batch = active.update(&txn).await.map_err(AppError::from)?;
+        Self::notify_batch(&txn, &batch, &rows).await?;
+        txn.commit().await.map_err(AppError::from)?;
+        SysOutboxService::wake_relay();
+
+        Ok(())
+    }
+
+    /// 刷新执行中批次的 updated_at，表明执行实例仍在推进
+    async fn touch_batch(batch_id: &str) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysPayoutBatch::update_many()
+            .col_expr(
+                SysPayoutBatchColumn::UpdatedAt,
+                Expr::value(Local::now().naive_local()),
+            )
+            .filter(SysPayoutBatchColumn::Id.eq(batch_id))
+            .filter(SysPayoutBatchColumn::Status.eq(PayoutBatchStatus::Executing))
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        Ok(())
+    }
+
+    /// 接管执行中断的批次：状态为 executing 且超过 [`STALE_BATCH_MINUTES`] 没有进展
+    ///
+    /// 超过时限后，中断前发出的交易所用区块哈希已过期，不会再上链。
+    /// 待发送与结果未知的行先按引用核对，已上链的记为成功，其余重新发送。返回接管的批次数
+    pub async fn resume_stale_batches() -> Result<usize, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let stale_before =
+            Local::now().naive_local() - chrono::Duration::minutes(STALE_BATCH_MINUTES);
+        let batches = SysPayoutBatch::find()
+            .filter(SysPayoutBatchColumn::Status.eq(PayoutBatchStatus::Executing))
+            .filter(SysPayoutBatchColumn::UpdatedAt.lt(stale_before))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let mut resumed = 0;
+        for batch in batches {
+            // 以 updated_at 做比较交换，多个实例中只有一个能接管
+            let claimed = SysPayoutBatch::update_many()
+                .col_expr(
+                    SysPayoutBatchColumn::UpdatedAt,
+                    Expr::value(Local::now().naive_local()),
+                )
+                .filter(SysPayoutBatchColumn::Id.eq(batch.id.as_str()))
+                .filter(SysPayoutBatchColumn::Status.eq(PayoutBatchStatus::Executing))
+                .filter(SysPayoutBatchColumn::UpdatedAt.lt(stale_before))
+                .exec(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+            if claimed.rows_affected == 0 {
+                continue;
+            }
+
+            resumed += 1;
+            let batch_id = batch.id.clone();
+            tokio::spawn(async move {
+                project_info!("Resuming payout batch {}", batch_id);
+                let resumed = match Self::reconcile_rows(
+                    &batch_id,
+                    vec![PayoutRowStatus::Pending, PayoutRowStatus::Unconfirmed],
+                    PayoutRowStatus::Pending,
+                    None,
+                )
+                .await
+                {
+                    Ok(()) => Self::run_batch(batch).await,
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = resumed {
+                    project_error!("Failed to resume payout batch {}: {:?}", batch_id, e);
+                }
+            });
+        }
+
+        Ok(resumed)
+    }
+
+    /// 按引用核对指定状态的行：已成功上链的记为成功并补记签名，其余置为 `unsent`
+    async fn reconcile_rows(
+        batch_id: &str,
+        statuses: Vec<PayoutRowStatus>,
+        unsent: PayoutRowStatus,
+        error: Option<String>,
+    ) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let rows = SysPayoutRow::find()
+            .filter(SysPayoutRowColumn::BatchId.eq(batch_id))
+            .filter(SysPayoutRowColumn::Status.is_in(statuses))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let reader = solana_helper::get_memo_reader().await?;
+        for row in rows {
+            let landed = reader.find_landed_by_reference(&reference_for(&row.id))?;
+            let (status, signature, error) = match landed {
+                Some(signature) => (PayoutRowStatus::Succeeded, Some(signature.to_string()), None),
+                None if row.status == unsent && error.is_none() => continue,
+                None => (unsent, None, error.clone()),
+            };
+
+            SysPayoutRow::update_many()
+                .col_expr(SysPayoutRowColumn::Status, Expr::value(status))
+                .col_expr(SysPayoutRowColumn::Signature, Expr::value(signature))
+                .col_expr(SysPayoutRowColumn::Error, Expr::value(error))
+                .col_expr(
+                    SysPayoutRowColumn::UpdatedAt,
+                    Expr::value(Local::now().naive_local()),
+                )
+                .filter(SysPayoutRowColumn::Id.eq(row.id))
+                .filter(SysPayoutRowColumn::Status.eq(row.status))
+                .exec(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+        }
+
+        Ok(())
+    }
+
+    /// 域内有启用的地址查找表时以 v0 交易发送，否则使用旧版交易
+    async fn executor(domain: &str) -> Result<PayoutExecutor, AppError> {
+        let lookup_tables = SysLookupTableService::load_enabled_lookup_tables(domain).await?;
+        Ok(solana_helper::get_payout_executor()
+            .await?
+            .with_lookup_tables(lookup_tables))
+    }
+
+    /// 域内已启用的资产，按 mint 索引
+    async fn enabled_assets(domain: &str) -> Result<HashMap<String, SysAssetModel>, AppError> {
+        Ok(SysAssetService::find_enabled_assets(domain)
+            .await?
+            .into_iter()
+            .map(|asset| (asset.mint.clone(), asset))
+            .collect())
+    }
+
+    /// 批次中待发送的行及对应的转账，两者按下标一一对应
+    async fn pending_transfers(
+        domain: &str,
+        batch_id: &str,
+    ) -> Result<(Vec<SysPayoutRowModel>, Vec<PayoutTransfer>), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let rows = SysPayoutRow::find()
+            .filter(SysPayoutRowColumn::BatchId.eq(batch_id))
+            .filter(SysPayoutRowColumn::Status.eq(PayoutRowStatus::Pending))
+            .order_by_asc(SysPayoutRowColumn::RowIndex)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let enabled_assets = Self::enabled_assets(domain).await?;
+        let transfers = rows
+            .iter()
+            .map(|row| {
+                let asset = enabled_assets
+                    .get(&row.mint)
+                    .ok_or_else(|| AppError::from(PayoutError::AssetNotEnabled(row.mint.clone())))?;
+                let (token_program, decimals) = SysAssetService::token_program(asset);
+                Ok(PayoutTransfer {
+                    recipient: Pubkey::from_str(&row.address).map_err(|_| {
+                        AppError::from(PayoutError::InvalidFile(row.address.clone()))
+                    })?,
+                    mint: Pubkey::from_str(&row.mint)
+                        .map_err(|_| AppError::from(PayoutError::InvalidFile(row.mint.clone())))?,
+                    token_program,
+                    decimals,
+                    amount: u64::try_from(row.amount)
+                        .map_err(|_| AppError::from(PayoutError::InvalidAmount(row.row_index)))?,
+                    tag: Some(TransferTag::new(&row.id)),
+                })
+            })
+            .collect::<Result<Vec<_>, AppError>>()?;
+
+        Ok((rows, transfers))
+    }
+
+    /// 检查系统钱包余额足以发放批次中全部待发送的行
+    async fn check_funds(domain: &str, batch_id: &str) -> Result<(), AppError> {
+        let (_, transfers) = Self::pending_transfers(domain, batch_id).await?;
+        let executor = Self::executor(domain).await?;
+        let payer = solana_helper::get_system_keypair().await?.pubkey();
+        let packs = executor.pack(&payer, &transfers)?;
+        executor.check_funds(&payer, &transfers, &packs).await?;
+        Ok(())
+    }
+
+    /// 逐笔发送待发放的行，发送前检查余额足以覆盖全部待发送的行
+    async fn send_rows(domain: &str, batch_id: &str) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let (rows, transfers) = Self::pending_transfers(domain, batch_id).await?;
+
+        let executor = Self::executor(domain).await?;
+        let payer = solana_helper::get_system_keypair().await?;
+        let packs = executor.pack(&payer.pubkey(), &transfers)?;
+        executor
+            .check_funds(&payer.pubkey(), &transfers, &packs)
+            .await?;
+
+        for pack in packs {
+            let result = executor.execute_pack(&payer, &transfers, &pack).await?;
+            let ids: Vec<String> = result
+                .rows
+                .iter()
+                .map(|&index| rows[index].id.clone())
+                .collect();
+            // 结果未知的交易可能已经上链，不能记为失败
+            let status = if result.outcome_unknown {
+                PayoutRowStatus::Unconfirmed
+            } else if result.signature.is_some() {
+                PayoutRowStatus::Succeeded
+            } else {
+                PayoutRowStatus::Failed
+            };
+
+            SysPayoutRow::update_many()
+                .col_expr(SysPayoutRowColumn::Status, Expr::value(status))
+                .col_expr(
+                    SysPayoutRowColumn::Signature,
+                    Expr::value(result.signature.map(|signature| signature.to_string())),
+                )
+                .col_expr(SysPayoutRowColumn::Error, Expr::value(result.error))
+                .col_expr(
+                    SysPayoutRowColumn::UpdatedAt,
+                    Expr::value(Local::now().naive_local()),
+                )
+                .filter(SysPayoutRowColumn::Id.is_in(ids))
+                .exec(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+            Self::touch_batch(batch_id).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TPayoutService for SysPayoutService {
+    async fn find_paginated_batches(
+        &self,
+        domain: &str,
+        params: PayoutBatchPageRequest,
+    ) -> Result<PaginatedData<SysPayoutBatchModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysPayoutBatch::find()
+            .filter(SysPayoutBatchColumn::Domain.eq(domain))
+            .order_by_desc(SysPayoutBatchColumn::CreatedAt);
+
+        if let Some(status) = params.status {
+            query = query.filter(SysPayoutBatchColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn create_batch(
+        &self,
+        domain: &str,
+        name: Option<String>,
+        file_name: &str,
+        data: Vec<u8>,
+        operator: &str,
+    ) -> Result<PayoutBatchOutput, AppError> {
+        let format = source_format(file_name)?;
+        let raw_rows = match format {
+            PayoutSourceFormat::Csv => parse_csv(&data)?,
+            PayoutSourceFormat::Json => parse_json(&data)?,
+        };
+        if raw_rows.is_empty() {
+            return Err(PayoutError::EmptyFile.into());
+        }
+        if raw_rows.len() > MAX_PAYOUT_ROWS {
+            return Err(PayoutError::TooManyRows(MAX_PAYOUT_ROWS).into());
+        }
+
+        let enabled_assets = Self::enabled_assets(domain).await?;
+
+        let batch_id = Ulid::new().to_string();
+        let mut transfers = Vec::with_capacity(raw_rows.len());
+        let mut rows = Vec::with_capacity(raw_rows.len());
+        for (index, raw) in raw_rows.into_iter().enumerate() {
+            let id = Ulid::new().to_string();
+            let validated = validate_row(&id, &raw, &enabled_assets);
+            let (amount, status, error) = match validated {
+                Ok(transfer) => {
+                    let amount = transfer.amount as i64;
+                    transfers.push(transfer);
+                    (amount, PayoutRowStatus::Pending, None)
+                },
+                Err(reason) => (0, PayoutRowStatus::Invalid, Some(reason)),
+            };
+            rows.push(SysPayoutRowActiveModel {
+                id: Set(id),
+                batch_id: Set(batch_id.clone()),
+                row_index: Set(index as i32 + 1),
+                address: Set(raw.address),
+                mint: Set(raw.mint),
+                amount: Set(amount),
+                status: Set(status),
+                error: Set(error),
+                signature: Set(None),
+                updated_at: Set(None),
+            });
+        }
+
+        // 有无效行的批次不会被批准，无需估算交易笔数
+        let invalid_rows = (rows.len() - transfers.len()) as i32;
+        let packs = if invalid_rows == 0 {
+            let payer = solana_helper::get_system_keypair().await?;
+            Self::executor(domain)
+                .await?
+                .pack(&payer.pubkey(), &transfers)?
+        } else {
+            Vec::new()
+        };
+
+        let (extension, content_type) = match format {
+            PayoutSourceFormat::Csv => ("csv", "text/csv"),
+            PayoutSourceFormat::Json => ("json", "application/json"),
+        };
+        let source_key = format!("payouts/{}/{}/source.{}", domain, batch_id, extension);
+        Self::put_object(&source_key, content_type, data).await?;
+
+        let batch = SysPayoutBatchActiveModel {
+            id: Set(batch_id),
+            domain: Set(domain.to_string()),
+            name: Set(name
+                .filter(|name| !name.trim().is_empty())
+                .unwrap_or_else(|| file_name.to_string())),
+            source_key: Set(source_key),
+            source_format: Set(format),
+            status: Set(PayoutBatchStatus::PendingApproval),
+            total_rows: Set(rows.len() as i32),
+            invalid_rows: Set(invalid_rows),
+            transaction_count: Set(packs.len() as i32),
+            estimated_fee_lamports: Set(estimate_network_fee(&packs) as i64),
+            report_key: Set(None),
+            approved_by: Set(None),
+            approved_at: Set(None),
+            executed_at: Set(None),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        };
+
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let batch = match self.save_batch_in_transaction(&txn, batch, rows).await {
+            Ok(batch) => {
+                txn.commit().await.map_err(AppError::from)?;
+                batch
+            },
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                return Err(e);
+            },
+        };
+
+        let totals = Self::find_totals(&batch.id).await?;
+        Ok(PayoutBatchOutput { batch, totals })
+    }
+
+    async fn get_batch(&self, domain: &str, id: &str) -> Result<PayoutBatchOutput, AppError> {
+        let batch = self.find_batch(domain, id).await?;
+        let totals = Self::find_totals(&batch.id).await?;
+        Ok(PayoutBatchOutput { batch, totals })
+    }
+
+    async fn find_paginated_rows(
+        &self,
+        domain: &str,
+        id: &str,
+        params: PayoutRowPageRequest,
+    ) -> Result<PaginatedData<SysPayoutRowModel>, AppError> {
+        let batch = self.find_batch(domain, id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysPayoutRow::find()
+            .filter(SysPayoutRowColumn::BatchId.eq(batch.id))
+            .order_by_asc(SysPayoutRowColumn::RowIndex);
+
+        if let Some(status) = params.status {
+            query = query.filter(SysPayoutRowColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn approve_batch(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysPayoutBatchModel, AppError> {
+        self.review_batch(domain, id, operator, PayoutBatchStatus::Approved)
+            .await
+    }
+
+    async fn reject_batch(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysPayoutBatchModel, AppError> {
+        self.review_batch(domain, id, operator, PayoutBatchStatus::Rejected)
+            .await
+    }
+
+    async fn execute_batch(
+        &self,
+        domain: &str,
+        id: &str,
+        _operator: &str,
+    ) -> Result<SysPayoutBatchModel, AppError> {
+        let batch = self.find_batch(domain, id).await?;
+        if batch.status != PayoutBatchStatus::Approved {
+            return Err(PayoutError::InvalidStatus("approved").into());
+        }
+        Self::check_funds(domain, &batch.id).await?;
+
+        Self::enqueue(batch.id.clone()).await?;
+
+        Ok(batch)
+    }
+
+    async fn get_report_url(&self, domain: &str, id: &str) -> Result<String, AppError> {
+        let batch = self.find_batch(domain, id).await?;
+        let key = batch
+            .report_key
+            .ok_or_else(|| AppError::from(PayoutError::ReportNotFound))?;
+        let (client, bucket) = Self::storage().await?;
+
+        let presigning = PresigningConfig::expires_in(REPORT_URL_TTL)
+            .map_err(|e| AppError::from(PayoutError::Storage(e.to_string())))?;
+        let request = client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .presigned(presigning)
+            .await
+            .map_err(|e| AppError::from(PayoutError::Storage(e.to_string())))?;
+
+        Ok(request.uri().to_string())
+    }
+}
+
+#[instrument(skip(rx))]
+pub async fn payout_batch_listener(
+    mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
+) {
+    while let Some(event) = rx.recv().await {
+        let (event, ack) = global::open_event(event);
+        if let Some(batch_event) = event.downcast_ref::<PayoutBatchEvent>() {
+            let batch_id = batch_event.batch_id.clone();
+            // 领取后批次状态已落库，即可确认事件；大批次需要发送很多笔交易，不阻塞后续批次
+            match SysPayoutService::claim_batch(&batch_id).await {
+                Ok(Some(batch)) => {
+                    ack.ok();
+                    tokio::spawn(async move {
+                        project_info!("Running payout batch {}", batch_id);
+                        if let Err(e) = SysPayoutService::run_batch(batch).await {
+                            project_error!("Failed to run payout batch {}: {:?}", batch_id, e);
+                        }
+                    });
+                },
+                Ok(None) => ack.ok(),
+                Err(e) => {
+                    project_error!("Failed to claim payout batch {}: {:?}", batch_id, e);
+                    ack.done(Err(e));
+                },
+            }
+        } else {
+            project_error!("Received unknown event type in payout listener");
+        }
+    }
+}
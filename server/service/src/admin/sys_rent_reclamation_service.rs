@@ -0,0 +1,354 @@
+use std::{collections::HashSet, str::FromStr};
+
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    sea_query::Alias, ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+use serde_json::json;
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_global::project_error;
+use server_model::admin::{
+    entities::{
+        prelude::{SysAutoConvertPolicy, SysCustodyWallet, SysRentReclamation},
+        sea_orm_active_enums::{
+            RentReclaimReason, RentReclamationStatus, Status, WebhookEventType,
+        },
+        sys_auto_convert_policy::Column as SysAutoConvertPolicyColumn,
+        sys_custody_wallet::Column as SysCustodyWalletColumn,
+        sys_rent_reclamation::{
+            ActiveModel as SysRentReclamationActiveModel, Column as SysRentReclamationColumn,
+            Model as SysRentReclamationModel,
+        },
+    },
+    input::{RentReclamationPageRequest, RunRentReclamationInput},
+    output::RentReclaimedWalletOutput,
+};
+use sol_spl_token::{
+    reclaim::{ReclaimPolicy, ReclaimReason, ReclaimStatus},
+    Keypair, Pubkey,
+};
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::{
+    sys_rent_reclamation_error::RentReclamationError, SysAssetService, SysOutboxService,
+    SysWebhookService,
+};
+
+/// 定时回收使用的闲置天数
+const SCHEDULED_IDLE_DAYS: u32 = 90;
+
+/// 定时回收记录的操作人
+const SYSTEM_OPERATOR: &str = "system";
+
+#[async_trait]
+pub trait TRentReclamationService {
+    async fn run_reclamation(
+        &self,
+        domain: &str,
+        input: RunRentReclamationInput,
+        operator: &str,
+    ) -> Result<Vec<SysRentReclamationModel>, AppError>;
+
+    async fn find_paginated_records(
+        &self,
+        domain: &str,
+        params: RentReclamationPageRequest,
+    ) -> Result<PaginatedData<SysRentReclamationModel>, AppError>;
+
+    async fn find_wallet_totals(
+        &self,
+        domain: &str,
+    ) -> Result<Vec<RentReclaimedWalletOutput>, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysRentReclamationService;
+
+fn parse_mint(mint: &str) -> Result<Pubkey, AppError> {
+    Pubkey::from_str(mint)
+        .map_err(|_| AppError::from(RentReclamationError::InvalidMint(mint.to_string())))
+}
+
+impl SysRentReclamationService {
+    /// 仍在使用的 mint：域内已启用的资产、系统配置的稳定币与目标代币、
+    /// 自动兑换策略涉及的 mint，以及调用方额外指定的 mint
+    async fn supported_mints(
+        domain: &str,
+        extra_mints: &[String],
+    ) -> Result<HashSet<Pubkey>, AppError> {
+        let mut mints = extra_mints
+            .iter()
+            .map(|mint| parse_mint(mint))
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        for asset in SysAssetService::find_enabled_assets(domain).await? {
+            mints.insert(parse_mint(&asset.mint)?);
+        }
+
+        let config = solana_helper::get_solana_config().await?;
+        for mint in [&config.default_stablecoin_mint, &config.target_token_mint] {
+            if let Ok(mint) = Pubkey::from_str(mint) {
+                mints.insert(mint);
+            }
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let policies = SysAutoConvertPolicy::find()
+            .filter(SysAutoConvertPolicyColumn::Domain.eq(domain))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        for policy in policies {
+            let policy_mints = policy.source_mints.split(',').chain([
+                policy.stablecoin_mint.as_str(),
+                policy.target_token_mint.as_str(),
+            ]);
+            mints.extend(policy_mints.filter_map(|mint| Pubkey::from_str(mint).ok()));
+        }
+
+        Ok(mints)
+    }
+
+    /// 回收域内全部启用托管钱包的空 Token 账户并记录结果
+    async fn reclaim_domain(
+        domain: &str,
+        policy: &ReclaimPolicy,
+        operator: &str,
+    ) -> Result<Vec<SysRentReclamationModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let wallets = SysCustodyWallet::find()
+            .filter(SysCustodyWalletColumn::Domain.eq(domain))
+            .filter(SysCustodyWalletColumn::Status.eq(Status::Enabled))
+            .order_by_asc(SysCustodyWalletColumn::Address)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        // 租金退回系统钱包，手续费也由系统钱包支付；
+        // 关闭权限由钱包自身或系统钱包持有，两者都取不到密钥的账户记为跳过
+        let reclaimer = solana_helper::get_rent_reclaimer().await?;
+        let payer = solana_helper::get_system_keypair().await?;
+        let now = Local::now().timestamp();
+
+        let mut records = Vec::new();
+        for wallet in wallets {
+            let owner = Pubkey::from_str(&wallet.address).map_err(|_| {
+                AppError::from(RentReclamationError::InvalidWalletAddress(
+                    wallet.address.clone(),
+                ))
+            })?;
+
+            // 托管钱包私钥加密存放在密钥库中，未托管私钥的钱包只能关闭系统钱包有权限的账户
+            let custody_key = match wallet.encrypted_key.as_deref() {
+                Some(encrypted_key) => match solana_helper::open_custody_key(encrypted_key).await {
+                    Ok(keypair) => Some(keypair),
+                    Err(e) => {
+                        project_error!(
+                            "Failed to open custody key of wallet {}: {}",
+                            wallet.address,
+                            e.message
+                        );
+                        continue;
+                    },
+                },
+                None => None,
+            };
+            let signers: Vec<&Keypair> = custody_key.iter().chain([&payer]).collect();
+
+            // 单个钱包读取失败或正在转出时跳过，不影响其余钱包，下一轮再回收
+            let outcomes = match reclaimer
+                .reclaim_wallet(&owner, policy, &signers, &payer, now)
+                .await
+            {
+                Ok(outcomes) => outcomes,
+                Err(e) => {
+                    project_error!(
+                        "Failed to reclaim rent for wallet {}: {}",
+                        wallet.address,
+                        e
+                    );
+                    continue;
+                },
+            };
+
+            for outcome in outcomes {
+                let record = SysRentReclamationActiveModel {
+                    id: Set(Ulid::new().to_string()),
+                    domain: Set(domain.to_string()),
+                    wallet_id: Set(wallet.id.clone()),
+                    wallet_address: Set(wallet.address.clone()),
+                    token_account: Set(outcome.account.address.to_string()),
+                    mint: Set(outcome.account.mint.to_string()),
+                    reason: Set(match outcome.reason {
+                        ReclaimReason::UnsupportedMint => RentReclaimReason::UnsupportedMint,
+                        ReclaimReason::Idle => RentReclaimReason::Idle,
+                    }),
+                    status: Set(match outcome.status {
+                        ReclaimStatus::Closed => RentReclamationStatus::Closed,
+                        ReclaimStatus::DryRun => RentReclamationStatus::DryRun,
+                        ReclaimStatus::Skipped => RentReclamationStatus::Skipped,
+                        ReclaimStatus::Failed => RentReclamationStatus::Failed,
+                    }),
+                    lamports: Set(i64::try_from(outcome.reclaimed_lamports)
+                        .map_err(|_| AppError::from(RentReclamationError::AmountOutOfRange))?),
+                    signature: Set(outcome.signature.map(|signature| signature.to_string())),
+                    error: Set(outcome.error),
+                    created_at: Set(Local::now().naive_local()),
+                    created_by: Set(operator.to_string()),
+                }
+                .insert(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+
+                records.push(record);
+            }
+        }
+
+        let closed: Vec<_> = records
+            .iter()
+            .filter(|record| record.status == RentReclamationStatus::Closed)
+            .collect();
+        SysWebhookService::notify(
+            db.as_ref(),
+            domain,
+            WebhookEventType::SweepCompleted,
+            json!({
+                "dryRun": policy.dry_run,
+                "accounts": records.len(),
+                "closedAccounts": closed.len(),
+                "reclaimedLamports": closed.iter().map(|record| record.lamports).sum::<i64>(),
+                "failedAccounts": records
+                    .iter()
+                    .filter(|record| record.status == RentReclamationStatus::Failed)
+                    .count(),
+            }),
+        )
+        .await?;
+        SysOutboxService::wake_relay();
+
+        Ok(records)
+    }
+
+    /// 定时回收：对存在启用托管钱包的每个域按默认闲置天数回收，单个域失败不影响其余域
+    pub async fn run_scheduled() -> Result<Vec<SysRentReclamationModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let domains: Vec<String> = SysCustodyWallet::find()
+            .select_only()
+            .column(SysCustodyWalletColumn::Domain)
+            .distinct()
+            .filter(SysCustodyWalletColumn::Status.eq(Status::Enabled))
+            .into_tuple()
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let mut records = Vec::new();
+        for domain in domains {
+            let reclaimed = match Self::supported_mints(&domain, &[]).await {
+                Ok(supported_mints) => {
+                    let policy = ReclaimPolicy {
+                        supported_mints,
+                        idle_days: SCHEDULED_IDLE_DAYS,
+                        dry_run: false,
+                    };
+                    Self::reclaim_domain(&domain, &policy, SYSTEM_OPERATOR).await
+                },
+                Err(e) => Err(e),
+            };
+            match reclaimed {
+                Ok(domain_records) => records.extend(domain_records),
+                Err(e) => project_error!(
+                    "Failed to reclaim rent for domain {}: {}",
+                    domain,
+                    e.message
+                ),
+            }
+        }
+
+        Ok(records)
+    }
+}
+
+#[async_trait]
+impl TRentReclamationService for SysRentReclamationService {
+    async fn run_reclamation(
+        &self,
+        domain: &str,
+        input: RunRentReclamationInput,
+        operator: &str,
+    ) -> Result<Vec<SysRentReclamationModel>, AppError> {
+        let policy = ReclaimPolicy {
+            supported_mints: Self::supported_mints(domain, &input.supported_mints).await?,
+            idle_days: input.idle_days,
+            dry_run: input.dry_run,
+        };
+
+        Self::reclaim_domain(domain, &policy, operator).await
+    }
+
+    async fn find_paginated_records(
+        &self,
+        domain: &str,
+        params: RentReclamationPageRequest,
+    ) -> Result<PaginatedData<SysRentReclamationModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysRentReclamation::find()
+            .filter(SysRentReclamationColumn::Domain.eq(domain))
+            .order_by_desc(SysRentReclamationColumn::CreatedAt);
+
+        if let Some(wallet_address) = params.wallet_address {
+            query = query.filter(SysRentReclamationColumn::WalletAddress.eq(wallet_address));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysRentReclamationColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn find_wallet_totals(
+        &self,
+        domain: &str,
+    ) -> Result<Vec<RentReclaimedWalletOutput>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysRentReclamation::find()
+            .select_only()
+            .column(SysRentReclamationColumn::WalletAddress)
+            .column_as(SysRentReclamationColumn::Id.count(), "accounts")
+            .column_as(
+                SysRentReclamationColumn::Lamports
+                    .sum()
+                    .cast_as(Alias::new("bigint")),
+                "lamports",
+            )
+            .filter(SysRentReclamationColumn::Domain.eq(domain))
+            .filter(SysRentReclamationColumn::Status.eq(RentReclamationStatus::Closed))
+            .group_by(SysRentReclamationColumn::WalletAddress)
+            .order_by_asc(SysRentReclamationColumn::WalletAddress)
+            .into_model::<RentReclaimedWalletOutput>()
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+}
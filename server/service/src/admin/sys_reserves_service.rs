@@ -0,0 +1,423 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, Select, Set, TransactionTrait,
+};
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_global::project_error;
+use server_model::admin::{
+    entities::{
+        prelude::{SysAsset, SysCustodyWallet, SysReserveLiability, SysReserveSnapshot},
+        sea_orm_active_enums::Status,
+        sys_asset::Column as SysAssetColumn,
+        sys_custody_wallet::Column as SysCustodyWalletColumn,
+        sys_reserve_liability::{
+            ActiveModel as SysReserveLiabilityActiveModel, Column as SysReserveLiabilityColumn,
+        },
+        sys_reserve_snapshot::{
+            ActiveModel as SysReserveSnapshotActiveModel, Column as SysReserveSnapshotColumn,
+            Model as SysReserveSnapshotModel,
+        },
+    },
+    input::{
+        CreateReservesSnapshotInput, ReservesPageRequest, ReservesProofQuery, ReservesReportQuery,
+    },
+    output::{ReservesProofOutput, ReservesProofStep, ReservesReportOutput},
+};
+use sol_spl_token::{
+    reserves::{Liability, LiabilityTree, ReportFormat, SignedReport},
+    Pubkey, ReservesManager,
+};
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::{sys_reserves_error::ReservesError, SysAssetService, SysBalanceService};
+
+/// 单条 INSERT 写入的负债明细数，避免超出数据库参数上限
+const LIABILITY_INSERT_BATCH: usize = 1000;
+
+/// 定时快照的操作人
+const SYSTEM_OPERATOR: &str = "system";
+
+#[async_trait]
+pub trait TReservesService {
+    async fn find_paginated_snapshots(
+        &self,
+        domain: &str,
+        params: ReservesPageRequest,
+    ) -> Result<PaginatedData<SysReserveSnapshotModel>, AppError>;
+
+    async fn take_snapshot(
+        &self,
+        domain: &str,
+        input: CreateReservesSnapshotInput,
+        operator: &str,
+    ) -> Result<SysReserveSnapshotModel, AppError>;
+
+    async fn get_report(
+        &self,
+        domain: &str,
+        id: &str,
+        query: ReservesReportQuery,
+    ) -> Result<ReservesReportOutput, AppError>;
+
+    async fn get_user_proof(
+        &self,
+        domain: &str,
+        user_id: &str,
+        query: ReservesProofQuery,
+    ) -> Result<ReservesProofOutput, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysReservesService;
+
+/// 按 id 查找快照，限定在当前域内
+fn find_snapshot(domain: &str, id: &str) -> Select<SysReserveSnapshot> {
+    SysReserveSnapshot::find_by_id(id).filter(SysReserveSnapshotColumn::Domain.eq(domain))
+}
+
+fn to_i64(value: u64) -> Result<i64, AppError> {
+    i64::try_from(value).map_err(|_| AppError::from(ReservesError::AmountOutOfRange))
+}
+
+fn to_u64(value: i64) -> Result<u64, AppError> {
+    u64::try_from(value).map_err(|_| AppError::from(ReservesError::AmountOutOfRange))
+}
+
+impl SysReservesService {
+    /// 为各域已启用的资产生成储备快照，供定时任务调用
+    ///
+    /// 单个资产失败时记录日志并继续，返回成功生成的快照
+    pub async fn run_scheduled() -> Result<Vec<SysReserveSnapshotModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let assets = SysAsset::find()
+            .filter(SysAssetColumn::Status.eq(Status::Enabled))
+            .order_by_asc(SysAssetColumn::Domain)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let mut snapshots = Vec::new();
+        for asset in assets {
+            let input = CreateReservesSnapshotInput {
+                mint: asset.mint.clone(),
+            };
+            match SysReservesService
+                .take_snapshot(&asset.domain, input, SYSTEM_OPERATOR)
+                .await
+            {
+                Ok(snapshot) => snapshots.push(snapshot),
+                Err(e) => project_error!(
+                    "Failed to take reserves snapshot of {} in domain {}: {}",
+                    asset.mint,
+                    asset.domain,
+                    e.message
+                ),
+            }
+        }
+        Ok(snapshots)
+    }
+
+    async fn save_snapshot_in_transaction(
+        &self,
+        txn: &DatabaseTransaction,
+        snapshot: SysReserveSnapshotActiveModel,
+        liabilities: Vec<SysReserveLiabilityActiveModel>,
+    ) -> Result<SysReserveSnapshotModel, AppError> {
+        let result = snapshot.insert(txn).await.map_err(AppError::from)?;
+
+        for batch in liabilities.chunks(LIABILITY_INSERT_BATCH) {
+            SysReserveLiability::insert_many(batch.to_vec())
+                .exec(txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+
+        Ok(result)
+    }
+
+    async fn find_snapshot_for_proof(
+        &self,
+        domain: &str,
+        query: &ReservesProofQuery,
+    ) -> Result<SysReserveSnapshotModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut select = SysReserveSnapshot::find()
+            .filter(SysReserveSnapshotColumn::Domain.eq(domain))
+            .filter(SysReserveSnapshotColumn::Mint.eq(query.mint.as_str()));
+
+        if let Some(ref snapshot_id) = query.snapshot_id {
+            select = select.filter(SysReserveSnapshotColumn::Id.eq(snapshot_id.as_str()));
+        }
+
+        select
+            .order_by_desc(SysReserveSnapshotColumn::CreatedAt)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(ReservesError::SnapshotNotFound))
+    }
+}
+
+#[async_trait]
+impl TReservesService for SysReservesService {
+    async fn find_paginated_snapshots(
+        &self,
+        domain: &str,
+        params: ReservesPageRequest,
+    ) -> Result<PaginatedData<SysReserveSnapshotModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysReserveSnapshot::find()
+            .filter(SysReserveSnapshotColumn::Domain.eq(domain))
+            .order_by_desc(SysReserveSnapshotColumn::CreatedAt);
+
+        if let Some(ref keywords) = params.keywords {
+            let condition = Condition::any().add(SysReserveSnapshotColumn::Mint.contains(keywords));
+            query = query.filter(condition);
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn take_snapshot(
+        &self,
+        domain: &str,
+        input: CreateReservesSnapshotInput,
+        operator: &str,
+    ) -> Result<SysReserveSnapshotModel, AppError> {
+        let mint = Pubkey::from_str(&input.mint)
+            .map_err(|_| AppError::from(ReservesError::InvalidMint))?;
+        let asset = SysAssetService::find_enabled_asset_by_mint(domain, &input.mint).await?;
+        let (token_program, _) = SysAssetService::token_program(&asset);
+
+        let db = db_helper::get_db_connection().await?;
+        let wallets = SysCustodyWallet::find()
+            .filter(SysCustodyWalletColumn::Domain.eq(domain))
+            .filter(SysCustodyWalletColumn::Status.eq(Status::Enabled))
+            .order_by_asc(SysCustodyWalletColumn::Address)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        if wallets.is_empty() {
+            return Err(ReservesError::NoCustodyWallets.into());
+        }
+
+        let addresses = wallets
+            .iter()
+            .map(|wallet| {
+                Pubkey::from_str(&wallet.address)
+                    .map_err(|_| AppError::from(ReservesError::InvalidWalletAddress(wallet.address.clone())))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let manager = ReservesManager::new(solana_helper::get_token_manager().await?);
+        let (slot, balances) = manager
+            .read_balances(&mint, token_program, &addresses)
+            .await?;
+
+        // 用户负债取自内部账本的用户余额，与链上储备独立核对
+        let liabilities = SysBalanceService::find_liabilities(domain, &input.mint)
+            .await?
+            .into_iter()
+            .map(|balance| {
+                u64::try_from(balance.balance)
+                    .map(|amount| Liability { user_id: balance.user_id, amount })
+                    .map_err(|_| AppError::from(ReservesError::AmountOutOfRange))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let snapshot_id = Ulid::new().to_string();
+        let (snapshot, tree) =
+            ReservesManager::build_snapshot(&snapshot_id, &mint, slot, balances, liabilities)
+                .map_err(|e| AppError::from(ReservesError::Chain(e)))?;
+
+        let keypair = solana_helper::get_system_keypair().await?;
+        let json = snapshot
+            .to_json()
+            .map_err(|e| AppError::from(ReservesError::Chain(e)))?;
+        let json_report = SignedReport::sign(&keypair, ReportFormat::Json, json);
+        let csv_report = SignedReport::sign(&keypair, ReportFormat::Csv, snapshot.to_csv());
+
+        let snapshot_model = SysReserveSnapshotActiveModel {
+            id: Set(snapshot_id.clone()),
+            domain: Set(domain.to_string()),
+            mint: Set(snapshot.mint.clone()),
+            slot: Set(to_i64(snapshot.slot)?),
+            total_reserves: Set(to_i64(snapshot.total_reserves)?),
+            total_liabilities: Set(to_i64(snapshot.total_liabilities)?),
+            liabilities_root: Set(snapshot.liabilities_root.clone()),
+            leaf_count: Set(snapshot.leaf_count as i32),
+            report_json: Set(json_report.content),
+            report_json_signature: Set(json_report.signature),
+            report_csv: Set(csv_report.content),
+            report_csv_signature: Set(csv_report.signature),
+            signer: Set(json_report.signer),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+        };
+
+        let liability_models = tree
+            .liabilities()
+            .iter()
+            .enumerate()
+            .map(|(index, liability)| {
+                Ok(SysReserveLiabilityActiveModel {
+                    id: Set(Ulid::new().to_string()),
+                    snapshot_id: Set(snapshot_id.clone()),
+                    user_id: Set(liability.user_id.clone()),
+                    leaf_index: Set(index as i32),
+                    amount: Set(to_i64(liability.amount)?),
+                })
+            })
+            .collect::<Result<Vec<_>, AppError>>()?;
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let result = match self
+            .save_snapshot_in_transaction(&txn, snapshot_model, liability_models)
+            .await
+        {
+            Ok(result) => {
+                txn.commit().await.map_err(AppError::from)?;
+                result
+            },
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                return Err(e);
+            },
+        };
+
+        Ok(result)
+    }
+
+    async fn get_report(
+        &self,
+        domain: &str,
+        id: &str,
+        query: ReservesReportQuery,
+    ) -> Result<ReservesReportOutput, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let snapshot = find_snapshot(domain, id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(ReservesError::SnapshotNotFound))?;
+
+        let (format, content, signature) = match query.format.as_deref().unwrap_or("json") {
+            "json" => ("json", snapshot.report_json, snapshot.report_json_signature),
+            "csv" => ("csv", snapshot.report_csv, snapshot.report_csv_signature),
+            _ => return Err(ReservesError::InvalidReportFormat.into()),
+        };
+
+        Ok(ReservesReportOutput {
+            format: format.to_string(),
+            content,
+            signer: snapshot.signer,
+            signature,
+        })
+    }
+
+    async fn get_user_proof(
+        &self,
+        domain: &str,
+        user_id: &str,
+        query: ReservesProofQuery,
+    ) -> Result<ReservesProofOutput, AppError> {
+        let snapshot = self.find_snapshot_for_proof(domain, &query).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let rows = SysReserveLiability::find()
+            .filter(SysReserveLiabilityColumn::SnapshotId.eq(snapshot.id.as_str()))
+            .order_by_asc(SysReserveLiabilityColumn::LeafIndex)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let liabilities = rows
+            .into_iter()
+            .map(|row| {
+                Ok(Liability {
+                    user_id: row.user_id,
+                    amount: to_u64(row.amount)?,
+                })
+            })
+            .collect::<Result<Vec<_>, AppError>>()?;
+
+        // 以快照 ID 为盐重建负债树，根必须与已公开的根一致
+        let tree = LiabilityTree::build(&snapshot.id, liabilities)
+            .map_err(|e| AppError::from(ReservesError::Chain(e)))?;
+        if tree.root().hash.to_string() != snapshot.liabilities_root {
+            return Err(ReservesError::RootMismatch.into());
+        }
+
+        let proof = tree
+            .proof(user_id)
+            .ok_or_else(|| AppError::from(ReservesError::ProofNotFound))?;
+
+        Ok(ReservesProofOutput {
+            snapshot_id: snapshot.id,
+            mint: snapshot.mint,
+            slot: to_u64(snapshot.slot)?,
+            user_id: proof.user_id,
+            amount: proof.amount,
+            leaf_index: proof.leaf_index,
+            steps: proof
+                .steps
+                .into_iter()
+                .map(|step| ReservesProofStep {
+                    hash: step.hash,
+                    sum: step.sum,
+                    is_left: step.is_left,
+                })
+                .collect(),
+            root_hash: proof.root_hash,
+            root_sum: proof.root_sum,
+            created_at: snapshot.created_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{DbBackend, QueryTrait};
+
+    use super::*;
+
+    #[test]
+    fn report_lookup_is_scoped_to_domain() {
+        let sql = find_snapshot("tenant-a", "snapshot-1")
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(sql.contains(r#""id" = 'snapshot-1'"#));
+        assert!(sql.contains(r#""domain" = 'tenant-a'"#));
+
+        let other = find_snapshot("tenant-b", "snapshot-1")
+            .build(DbBackend::Postgres)
+            .to_string();
+        assert!(other.contains(r#""domain" = 'tenant-b'"#));
+        assert!(!other.contains("tenant-a"));
+    }
+}
@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{ActiveModelTrait, Set};
+use server_core::web::error::AppError;
+use server_model::admin::entities::{
+    sea_orm_active_enums::RiskScreeningDecision,
+    sys_risk_screening::ActiveModel as SysRiskScreeningActiveModel,
+};
+use sol_spl_token::{
+    risk::{ScreeningDecision, ScreeningRequest, ScreeningResult, ScreeningStorage},
+    SolanaError,
+};
+use ulid::Ulid;
+
+use crate::helper::db_helper;
+
+/// 把每次转出前的筛查结论写入 `sys_risk_screening`，放行的记录同样保存以便审计
+///
+/// 写入失败时返回错误，风险闸门据此拒绝转出，不留下无记录的转出
+pub struct RiskScreeningStorage;
+
+#[async_trait]
+impl ScreeningStorage for RiskScreeningStorage {
+    async fn save_screening_result(
+        &self,
+        request: &ScreeningRequest,
+        result: &ScreeningResult,
+    ) -> sol_spl_token::error::Result<()> {
+        let save = async {
+            let db = db_helper::get_db_connection().await?;
+            SysRiskScreeningActiveModel {
+                id: Set(Ulid::new().to_string()),
+                from_address: Set(request.from.to_string()),
+                to_address: Set(request.to.to_string()),
+                mint: Set(request.token_mint.map(|mint| mint.to_string())),
+                amount: Set(i64::try_from(request.amount).unwrap_or(i64::MAX)),
+                reference: Set(request.reference.clone()),
+                decision: Set(match result.decision {
+                    ScreeningDecision::Allow => RiskScreeningDecision::Allow,
+                    ScreeningDecision::ManualReview => RiskScreeningDecision::ManualReview,
+                    ScreeningDecision::Deny => RiskScreeningDecision::Deny,
+                }),
+                reasons: Set(serde_json::json!(result.reasons)),
+                provider: Set(result.provider.clone()),
+                created_at: Set(Local::now().naive_local()),
+            }
+            .insert(db.as_ref())
+            .await
+            .map_err(AppError::from)
+        };
+
+        save.await.map(|_| ()).map_err(|e: AppError| {
+            SolanaError::RiskScreeningError(format!("Failed to save screening: {}", e.message))
+        })
+    }
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use server_core::web::error::AppError;
+use sol_spl_token::rpc_pool::RpcPoolMetrics;
+
+use crate::helper::solana_helper;
+
+#[async_trait]
+pub trait TSolanaService {
+    async fn get_rpc_pool_metrics(&self) -> Result<RpcPoolMetrics, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysSolanaService;
+
+#[async_trait]
+impl TSolanaService for SysSolanaService {
+    async fn get_rpc_pool_metrics(&self) -> Result<RpcPoolMetrics, AppError> {
+        Ok(solana_helper::get_rpc_pool().await?.metrics())
+    }
+}
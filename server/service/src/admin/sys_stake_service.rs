@@ -0,0 +1,541 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    sea_query::{Expr, OnConflict},
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_global::project_error;
+use server_model::admin::{
+    entities::{
+        prelude::{SysCustodyWallet, SysStakeAccount, SysStakeReward},
+        sea_orm_active_enums::{CustodyWalletType, StakeAccountStatus, Status},
+        sys_custody_wallet::{Column as SysCustodyWalletColumn, Model as SysCustodyWalletModel},
+        sys_stake_account::{
+            ActiveModel as SysStakeAccountActiveModel, Column as SysStakeAccountColumn,
+            Model as SysStakeAccountModel,
+        },
+        sys_stake_reward::{
+            ActiveModel as SysStakeRewardActiveModel, Column as SysStakeRewardColumn,
+            Model as SysStakeRewardModel,
+        },
+    },
+    input::{
+        CreateStakeAccountInput, StakeAccountPageRequest, StakeRewardPageRequest,
+        SyncStakeRewardsInput,
+    },
+    output::StakeRewardSyncOutput,
+};
+use sol_spl_token::{
+    stake::{EpochReward, StakeStatus},
+    Keypair, Pubkey, Signer,
+};
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::{sys_stake_error::StakeError, SysCustodyHoldService};
+
+/// 单次奖励查询包含的质押账户数
+const REWARD_QUERY_CHUNK: usize = 100;
+
+#[async_trait]
+pub trait TStakeService {
+    async fn list_validators(&self) -> Result<Vec<String>, AppError>;
+
+    async fn find_paginated_accounts(
+        &self,
+        domain: &str,
+        params: StakeAccountPageRequest,
+    ) -> Result<PaginatedData<SysStakeAccountModel>, AppError>;
+
+    async fn find_user_accounts(
+        &self,
+        domain: &str,
+        user_id: &str,
+    ) -> Result<Vec<SysStakeAccountModel>, AppError>;
+
+    async fn create_stake_account(
+        &self,
+        domain: &str,
+        input: CreateStakeAccountInput,
+        operator: &str,
+    ) -> Result<SysStakeAccountModel, AppError>;
+
+    async fn get_stake_account(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysStakeAccountModel, AppError>;
+
+    async fn deactivate_stake_account(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysStakeAccountModel, AppError>;
+
+    async fn withdraw_stake_account(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysStakeAccountModel, AppError>;
+
+    async fn find_paginated_rewards(
+        &self,
+        domain: &str,
+        params: StakeRewardPageRequest,
+    ) -> Result<PaginatedData<SysStakeRewardModel>, AppError>;
+
+    async fn sync_rewards(
+        &self,
+        domain: &str,
+        input: SyncStakeRewardsInput,
+    ) -> Result<StakeRewardSyncOutput, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysStakeService;
+
+fn parse_address(address: &str) -> Result<Pubkey, AppError> {
+    Pubkey::from_str(address)
+        .map_err(|_| AppError::from(StakeError::InvalidAddress(address.to_string())))
+}
+
+fn to_account_status(status: StakeStatus) -> StakeAccountStatus {
+    match status {
+        StakeStatus::Activating => StakeAccountStatus::Activating,
+        StakeStatus::Active => StakeAccountStatus::Active,
+        StakeStatus::Deactivating => StakeAccountStatus::Deactivating,
+        StakeStatus::Initialized | StakeStatus::Inactive => StakeAccountStatus::Inactive,
+    }
+}
+
+fn invalid_status(account: &SysStakeAccountModel) -> AppError {
+    StakeError::InvalidStatus(format!("{:?}", account.status).to_lowercase()).into()
+}
+
+impl SysStakeService {
+    async fn find_account(&self, domain: &str, id: &str) -> Result<SysStakeAccountModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysStakeAccount::find_by_id(id)
+            .filter(SysStakeAccountColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| StakeError::StakeAccountNotFound.into())
+    }
+
+    /// 质押出资钱包：指定用户时为该用户的托管钱包，否则为系统钱包
+    async fn funder_keypair(domain: &str, user_id: Option<&str>) -> Result<Keypair, AppError> {
+        let Some(user_id) = user_id else {
+            return solana_helper::get_system_keypair().await;
+        };
+
+        let db = db_helper::get_db_connection().await?;
+        let wallet = SysCustodyWallet::find()
+            .filter(SysCustodyWalletColumn::Domain.eq(domain))
+            .filter(SysCustodyWalletColumn::UserId.eq(user_id))
+            .filter(SysCustodyWalletColumn::WalletType.eq(CustodyWalletType::User))
+            .filter(SysCustodyWalletColumn::Status.eq(Status::Enabled))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(StakeError::WalletNotFound))?;
+        Self::open_wallet_key(&wallet).await
+    }
+
+    /// 质押账户的授权密钥即出资钱包的密钥，托管钱包轮换后仍按原地址查找
+    async fn authority_keypair(domain: &str, funder: &str) -> Result<Keypair, AppError> {
+        let system_keypair = solana_helper::get_system_keypair().await?;
+        if system_keypair.pubkey().to_string() == funder {
+            return Ok(system_keypair);
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let wallet = SysCustodyWallet::find()
+            .filter(SysCustodyWalletColumn::Domain.eq(domain))
+            .filter(SysCustodyWalletColumn::Address.eq(funder))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(StakeError::WalletNotFound))?;
+        Self::open_wallet_key(&wallet).await
+    }
+
+    async fn open_wallet_key(wallet: &SysCustodyWalletModel) -> Result<Keypair, AppError> {
+        let encrypted_key = wallet
+            .encrypted_key
+            .as_deref()
+            .ok_or_else(|| StakeError::KeyNotInKeystore(wallet.address.clone()))?;
+        solana_helper::open_custody_key(encrypted_key).await
+    }
+
+    /// 按链上状态更新记录，已提取的账户不再查询
+    async fn refresh_status(
+        account: SysStakeAccountModel,
+    ) -> Result<SysStakeAccountModel, AppError> {
+        if account.status == StakeAccountStatus::Withdrawn {
+            return Ok(account);
+        }
+
+        let manager = solana_helper::get_stake_manager().await?;
+        let info = manager.get_stake_account(&parse_address(&account.address)?)?;
+        let status = to_account_status(info.status);
+        if status == account.status {
+            return Ok(account);
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysStakeAccountActiveModel = account.into();
+        active.status = Set(status);
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.update(db.as_ref()).await.map_err(AppError::from)
+    }
+
+    /// 记入一条奖励并累加到质押账户，该 epoch 已记入时返回 false
+    async fn record_reward(
+        txn: &DatabaseTransaction,
+        account: &SysStakeAccountModel,
+        reward: &EpochReward,
+    ) -> Result<bool, AppError> {
+        let epoch = reward.epoch as i64;
+        let amount = i64::try_from(reward.amount).unwrap_or(i64::MAX);
+        let inserted = SysStakeReward::insert(SysStakeRewardActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(account.domain.clone()),
+            stake_account_id: Set(account.id.clone()),
+            user_id: Set(account.user_id.clone()),
+            address: Set(account.address.clone()),
+            epoch: Set(epoch),
+            amount: Set(amount),
+            post_balance: Set(i64::try_from(reward.post_balance).unwrap_or(i64::MAX)),
+            commission: Set(reward.commission.map(i32::from)),
+            created_at: Set(Local::now().naive_local()),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                SysStakeRewardColumn::StakeAccountId,
+                SysStakeRewardColumn::Epoch,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(txn)
+        .await
+        .map_err(AppError::from)?;
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        SysStakeAccount::update_many()
+            .col_expr(
+                SysStakeAccountColumn::TotalRewards,
+                Expr::col(SysStakeAccountColumn::TotalRewards).add(amount),
+            )
+            .col_expr(
+                SysStakeAccountColumn::LastRewardEpoch,
+                Expr::value(Some(epoch)),
+            )
+            .filter(SysStakeAccountColumn::Id.eq(&account.id))
+            .exec(txn)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(true)
+    }
+
+    /// 拉取 `epoch`（为空时为上一个 epoch）的质押奖励并记到各账户的用户名下
+    ///
+    /// `domain` 为空时处理所有域；同时刷新各账户的委托状态
+    pub async fn collect_rewards(
+        domain: Option<&str>,
+        epoch: Option<u64>,
+    ) -> Result<StakeRewardSyncOutput, AppError> {
+        let manager = solana_helper::get_stake_manager().await?;
+        let epoch = match epoch {
+            Some(epoch) => epoch,
+            None => manager.current_epoch()?.saturating_sub(1),
+        };
+
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysStakeAccount::find()
+            .filter(SysStakeAccountColumn::Status.ne(StakeAccountStatus::Withdrawn));
+        if let Some(domain) = domain {
+            query = query.filter(SysStakeAccountColumn::Domain.eq(domain));
+        }
+        let accounts = query.all(db.as_ref()).await.map_err(AppError::from)?;
+
+        let mut output = StakeRewardSyncOutput {
+            epoch: epoch as i64,
+            accounts: accounts.len() as u64,
+            rewards: 0,
+            amount: 0,
+        };
+        for chunk in accounts.chunks(REWARD_QUERY_CHUNK) {
+            let addresses = chunk
+                .iter()
+                .map(|account| parse_address(&account.address))
+                .collect::<Result<Vec<_>, _>>()?;
+            let rewards = manager.get_epoch_rewards(&addresses, Some(epoch))?;
+
+            let txn = db.begin().await.map_err(AppError::from)?;
+            for (account, reward) in chunk.iter().zip(&rewards) {
+                let Some(reward) = reward else {
+                    continue;
+                };
+                match Self::record_reward(&txn, account, reward).await {
+                    Ok(true) => {
+                        output.rewards += 1;
+                        output.amount += reward.amount as i64;
+                    },
+                    Ok(false) => {},
+                    Err(e) => {
+                        txn.rollback().await.map_err(AppError::from)?;
+                        return Err(e);
+                    },
+                }
+            }
+            txn.commit().await.map_err(AppError::from)?;
+        }
+
+        for account in accounts {
+            let id = account.id.clone();
+            if let Err(e) = Self::refresh_status(account).await {
+                project_error!("Failed to refresh stake account {}: {}", id, e.message);
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+#[async_trait]
+impl TStakeService for SysStakeService {
+    async fn list_validators(&self) -> Result<Vec<String>, AppError> {
+        let config = solana_helper::get_solana_config().await?;
+        Ok(config
+            .get_stake_validators()?
+            .iter()
+            .map(Pubkey::to_string)
+            .collect())
+    }
+
+    async fn find_paginated_accounts(
+        &self,
+        domain: &str,
+        params: StakeAccountPageRequest,
+    ) -> Result<PaginatedData<SysStakeAccountModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysStakeAccount::find()
+            .filter(SysStakeAccountColumn::Domain.eq(domain))
+            .order_by_desc(SysStakeAccountColumn::CreatedAt);
+
+        if let Some(user_id) = params.user_id {
+            query = query.filter(SysStakeAccountColumn::UserId.eq(user_id));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysStakeAccountColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn find_user_accounts(
+        &self,
+        domain: &str,
+        user_id: &str,
+    ) -> Result<Vec<SysStakeAccountModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysStakeAccount::find()
+            .filter(SysStakeAccountColumn::Domain.eq(domain))
+            .filter(SysStakeAccountColumn::UserId.eq(user_id))
+            .order_by_desc(SysStakeAccountColumn::CreatedAt)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn create_stake_account(
+        &self,
+        domain: &str,
+        input: CreateStakeAccountInput,
+        operator: &str,
+    ) -> Result<SysStakeAccountModel, AppError> {
+        let vote_account = parse_address(&input.vote_account)?;
+        let config = solana_helper::get_solana_config().await?;
+        if !config.get_stake_validators()?.contains(&vote_account) {
+            return Err(StakeError::ValidatorNotConfigured(input.vote_account).into());
+        }
+        if let Some(ref user_id) = input.user_id {
+            SysCustodyHoldService::ensure_not_held(domain, user_id).await?;
+        }
+
+        // 用户的质押由其托管钱包出资并签名，账户授权归该钱包；未指定用户时由系统钱包出资
+        let manager = solana_helper::get_stake_manager().await?;
+        let funder = Self::funder_keypair(domain, input.user_id.as_deref()).await?;
+        let (address, signature) = manager
+            .create_and_delegate(&funder, &vote_account, input.lamports as u64)
+            .await?;
+
+        let db = db_helper::get_db_connection().await?;
+        SysStakeAccountActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            user_id: Set(input.user_id),
+            funder: Set(funder.pubkey().to_string()),
+            address: Set(address.to_string()),
+            vote_account: Set(input.vote_account),
+            lamports: Set(input.lamports),
+            status: Set(StakeAccountStatus::Activating),
+            total_rewards: Set(0),
+            last_reward_epoch: Set(None),
+            withdrawn_lamports: Set(None),
+            create_signature: Set(signature.to_string()),
+            deactivate_signature: Set(None),
+            withdraw_signature: Set(None),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        }
+        .insert(db.as_ref())
+        .await
+        .map_err(AppError::from)
+    }
+
+    async fn get_stake_account(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysStakeAccountModel, AppError> {
+        let account = self.find_account(domain, id).await?;
+        Self::refresh_status(account).await
+    }
+
+    async fn deactivate_stake_account(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysStakeAccountModel, AppError> {
+        let account = self.find_account(domain, id).await?;
+        if !matches!(
+            account.status,
+            StakeAccountStatus::Activating | StakeAccountStatus::Active
+        ) {
+            return Err(invalid_status(&account));
+        }
+
+        let manager = solana_helper::get_stake_manager().await?;
+        let authority = Self::authority_keypair(domain, &account.funder).await?;
+        let stake_account = parse_address(&account.address)?;
+        let signature = manager.deactivate(&authority, &stake_account).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysStakeAccountActiveModel = account.into();
+        active.status = Set(StakeAccountStatus::Deactivating);
+        active.deactivate_signature = Set(Some(signature.to_string()));
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.updated_by = Set(Some(operator.to_string()));
+        active.update(db.as_ref()).await.map_err(AppError::from)
+    }
+
+    async fn withdraw_stake_account(
+        &self,
+        domain: &str,
+        id: &str,
+        operator: &str,
+    ) -> Result<SysStakeAccountModel, AppError> {
+        let account = self.find_account(domain, id).await?;
+        if account.status == StakeAccountStatus::Withdrawn {
+            return Err(invalid_status(&account));
+        }
+
+        // 冷却是否完成以链上状态为准
+        let manager = solana_helper::get_stake_manager().await?;
+        let authority = Self::authority_keypair(domain, &account.funder).await?;
+        let stake_account = parse_address(&account.address)?;
+        let (lamports, signature) = manager
+            .withdraw(&authority, &stake_account, &parse_address(&account.funder)?)
+            .await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysStakeAccountActiveModel = account.into();
+        active.status = Set(StakeAccountStatus::Withdrawn);
+        active.withdrawn_lamports = Set(Some(i64::try_from(lamports).unwrap_or(i64::MAX)));
+        active.withdraw_signature = Set(Some(signature.to_string()));
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.updated_by = Set(Some(operator.to_string()));
+        active.update(db.as_ref()).await.map_err(AppError::from)
+    }
+
+    async fn find_paginated_rewards(
+        &self,
+        domain: &str,
+        params: StakeRewardPageRequest,
+    ) -> Result<PaginatedData<SysStakeRewardModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysStakeReward::find()
+            .filter(SysStakeRewardColumn::Domain.eq(domain))
+            .order_by_desc(SysStakeRewardColumn::Epoch);
+
+        if let Some(user_id) = params.user_id {
+            query = query.filter(SysStakeRewardColumn::UserId.eq(user_id));
+        }
+        if let Some(stake_account_id) = params.stake_account_id {
+            query = query.filter(SysStakeRewardColumn::StakeAccountId.eq(stake_account_id));
+        }
+        if let Some(epoch) = params.epoch {
+            query = query.filter(SysStakeRewardColumn::Epoch.eq(epoch));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn sync_rewards(
+        &self,
+        domain: &str,
+        input: SyncStakeRewardsInput,
+    ) -> Result<StakeRewardSyncOutput, AppError> {
+        Self::collect_rewards(Some(domain), input.epoch.map(|epoch| epoch as u64)).await
+    }
+}
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use chrono::Local;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, EntityTrait, IntoActiveModel, PaginatorTrait,
-    QueryFilter, Set,
+    QueryFilter, Set, TransactionTrait,
 };
 use server_core::web::{error::AppError, page::PaginatedData};
 use server_model::admin::{
@@ -18,7 +18,7 @@ use server_model::admin::{
 use server_utils::SecureUtil;
 use ulid::Ulid;
 
-use super::sys_user_error::UserError;
+use super::{sys_user_error::UserError, SysCustodyHoldService};
 use crate::helper::db_helper;
 
 #[async_trait]
@@ -159,8 +159,12 @@ impl TUserService for SysUserService {
         user.phone_number = Set(input.user.phone_number);
         user.status = Set(input.user.status);
 
+        // 停用或封禁用户时同步冻结其托管资金，重新启用时解除
         let db = db_helper::get_db_connection().await?;
-        let updated_user = user.update(db.as_ref()).await.map_err(AppError::from)?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let updated_user = user.update(&txn).await.map_err(AppError::from)?;
+        SysCustodyHoldService::sync_user_status(&txn, &updated_user).await?;
+        txn.commit().await.map_err(AppError::from)?;
         Ok(UserWithoutPassword::from(updated_user))
     }
 
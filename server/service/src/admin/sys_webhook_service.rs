@@ -0,0 +1,649 @@
+use std::{any::Any, sync::OnceLock, time::Duration};
+
+use async_trait::async_trait;
+use chrono::{Local, NaiveDateTime};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use server_constant::definition::consts::SystemEvent;
+use server_core::{
+    sign::SignatureAlgorithm,
+    web::{error::AppError, page::PaginatedData},
+};
+use server_global::{global, project_error, project_info};
+use server_scheduler::LeaderElection;
+use server_model::admin::{
+    entities::{
+        prelude::{SysWebhookDelivery, SysWebhookEndpoint},
+        sea_orm_active_enums::{Status, WebhookDeliveryStatus, WebhookEventType},
+        sys_webhook_delivery::{
+            ActiveModel as SysWebhookDeliveryActiveModel, Column as SysWebhookDeliveryColumn,
+            Model as SysWebhookDeliveryModel,
+        },
+        sys_webhook_endpoint::{
+            ActiveModel as SysWebhookEndpointActiveModel, Column as SysWebhookEndpointColumn,
+            Model as SysWebhookEndpointModel,
+        },
+    },
+    input::{
+        CreateWebhookEndpointInput, UpdateWebhookEndpointInput, WebhookDeliveryPageRequest,
+        WebhookEndpointPageRequest,
+    },
+    output::WebhookEndpointOutput,
+};
+use sol_spl_token::keystore;
+use tokio::task::JoinHandle;
+use ulid::Ulid;
+
+use crate::helper::{
+    db_helper,
+    retry_helper::{truncate, RetryPolicy, RetryQueue},
+};
+
+use super::{sys_webhook_error::WebhookError, SysOutboxService};
+
+/// 每轮最多处理的到期投递数
+const DISPATCH_BATCH_SIZE: u64 = 50;
+
+/// 最多尝试 8 次，首次失败 30 秒后重试，之后每次翻倍，最长间隔 6 小时；
+/// 领取后 2 分钟内未完成视为投递中断，重新投递
+const RETRY_POLICY: RetryPolicy = RetryPolicy {
+    max_attempts: 8,
+    base_delay: Duration::from_secs(30),
+    max_delay: Duration::from_secs(6 * 60 * 60),
+    lease: Duration::from_secs(120),
+};
+
+/// 单次请求超时
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 记录的响应内容或错误信息的最大长度
+const MAX_ERROR_LEN: usize = 1000;
+
+#[async_trait]
+pub trait TWebhookService {
+    async fn find_paginated_endpoints(
+        &self,
+        domain: &str,
+        params: WebhookEndpointPageRequest,
+    ) -> Result<PaginatedData<SysWebhookEndpointModel>, AppError>;
+
+    async fn create_endpoint(
+        &self,
+        domain: &str,
+        input: CreateWebhookEndpointInput,
+        operator: &str,
+    ) -> Result<WebhookEndpointOutput, AppError>;
+
+    async fn update_endpoint(
+        &self,
+        domain: &str,
+        id: &str,
+        input: UpdateWebhookEndpointInput,
+        operator: &str,
+    ) -> Result<WebhookEndpointOutput, AppError>;
+
+    async fn delete_endpoint(&self, domain: &str, id: &str) -> Result<(), AppError>;
+
+    async fn find_paginated_deliveries(
+        &self,
+        domain: &str,
+        params: WebhookDeliveryPageRequest,
+    ) -> Result<PaginatedData<SysWebhookDeliveryModel>, AppError>;
+
+    async fn get_delivery(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysWebhookDeliveryModel, AppError>;
+
+    async fn replay_delivery(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysWebhookDeliveryModel, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysWebhookService;
+
+/// Webhook 事件入队事件，由监听器为订阅了该事件的端点登记投递
+#[derive(Serialize, Deserialize)]
+pub struct WebhookEvent {
+    pub event_id: String,
+    pub domain: String,
+    pub event_type: WebhookEventType,
+    pub data: JsonValue,
+    pub created_at: NaiveDateTime,
+}
+
+fn http_client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default()
+    })
+}
+
+fn generate_secret() -> Result<String, AppError> {
+    keystore::generate_token().map_err(AppError::from)
+}
+
+impl RetryQueue for SysWebhookDelivery {
+    const ID: Self::Column = SysWebhookDeliveryColumn::Id;
+    const STATUS: Self::Column = SysWebhookDeliveryColumn::Status;
+    const ATTEMPTS: Self::Column = SysWebhookDeliveryColumn::Attempts;
+    const NEXT_ATTEMPT_AT: Self::Column = SysWebhookDeliveryColumn::NextAttemptAt;
+    const UPDATED_AT: Self::Column = SysWebhookDeliveryColumn::UpdatedAt;
+}
+
+fn subscribes(endpoint: &SysWebhookEndpointModel, event_type: WebhookEventType) -> bool {
+    serde_json::from_value::<Vec<WebhookEventType>>(endpoint.events.clone())
+        .map(|events| events.contains(&event_type))
+        .unwrap_or(false)
+}
+
+impl SysWebhookService {
+    async fn find_endpoint(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysWebhookEndpointModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysWebhookEndpoint::find_by_id(id)
+            .filter(SysWebhookEndpointColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| WebhookError::EndpointNotFound.into())
+    }
+
+    /// 为订阅了该事件的每个启用端点登记一条投递并立即尝试发送
+    ///
+    /// 同一事件的各条投递共享事件 ID，接收方可据此去重；
+    /// 事件被重复投递时跳过已登记过的端点
+    async fn publish(event: &WebhookEvent) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let endpoints: Vec<_> = SysWebhookEndpoint::find()
+            .filter(SysWebhookEndpointColumn::Domain.eq(event.domain.as_str()))
+            .filter(SysWebhookEndpointColumn::Status.eq(Status::Enabled))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .filter(|endpoint| subscribes(endpoint, event.event_type))
+            .collect();
+        if endpoints.is_empty() {
+            return Ok(());
+        }
+
+        let payload = json!({
+            "id": event.event_id,
+            "type": event.event_type,
+            "domain": event.domain,
+            "createdAt": event.created_at,
+            "data": event.data,
+        });
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let registered: Vec<String> = SysWebhookDelivery::find()
+            .select_only()
+            .column(SysWebhookDeliveryColumn::EndpointId)
+            .filter(SysWebhookDeliveryColumn::EventId.eq(event.event_id.as_str()))
+            .into_tuple()
+            .all(&txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let now = Local::now().naive_local();
+        let mut deliveries = Vec::new();
+        for endpoint in endpoints {
+            if registered.contains(&endpoint.id) {
+                continue;
+            }
+            let delivery = SysWebhookDeliveryActiveModel {
+                id: Set(Ulid::new().to_string()),
+                domain: Set(event.domain.clone()),
+                endpoint_id: Set(endpoint.id),
+                event_id: Set(event.event_id.clone()),
+                event_type: Set(event.event_type),
+                payload: Set(payload.clone()),
+                status: Set(WebhookDeliveryStatus::Pending),
+                attempts: Set(0),
+                next_attempt_at: Set(now),
+                created_at: Set(now),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await
+            .map_err(AppError::from)?;
+            deliveries.push(delivery);
+        }
+        txn.commit().await.map_err(AppError::from)?;
+
+        for delivery in deliveries {
+            tokio::spawn(async move {
+                if let Err(e) = Self::dispatch(delivery).await {
+                    project_error!("Failed to dispatch webhook: {}", e.message);
+                }
+            });
+        }
+
+        Ok(())
+    }
+
+    /// 发布事件，写入事件发件箱，由中继投递后为各订阅端点登记投递
+    ///
+    /// 在业务事务中调用时与业务数据一同提交，事务提交后调用 [`SysOutboxService::wake_relay`]
+    /// 立即投递，否则等待中继下一轮扫描
+    pub async fn notify<C: ConnectionTrait>(
+        conn: &C,
+        domain: &str,
+        event_type: WebhookEventType,
+        data: impl Serialize,
+    ) -> Result<(), AppError> {
+        let data = serde_json::to_value(data).map_err(|e| AppError {
+            code: 500,
+            message: format!("Failed to serialize {:?} webhook: {}", event_type, e),
+        })?;
+
+        let event = WebhookEvent {
+            event_id: Ulid::new().to_string(),
+            domain: domain.to_string(),
+            event_type,
+            data,
+            created_at: Local::now().naive_local(),
+        };
+        SysOutboxService::enqueue(
+            conn,
+            SystemEvent::WebhookEventQueuedEvent,
+            &event.event_id,
+            &event,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// 领取并发送一条投递
+    ///
+    /// 以投递当前的状态和下次投递时间为条件领取，多个实例同时处理时只有一个会发送
+    async fn dispatch(delivery: SysWebhookDeliveryModel) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let claimed = RETRY_POLICY
+            .claim::<SysWebhookDelivery, _, _>(
+                db.as_ref(),
+                &delivery.id,
+                delivery.status,
+                delivery.next_attempt_at,
+                WebhookDeliveryStatus::Delivering,
+                Local::now().naive_local(),
+            )
+            .await?;
+        if !claimed {
+            return Ok(());
+        }
+
+        let attempts = delivery.attempts + 1;
+        let endpoint = SysWebhookEndpoint::find_by_id(&delivery.endpoint_id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        let result = match endpoint {
+            Some(endpoint) if endpoint.status == Status::Enabled => {
+                Self::send(&endpoint, &delivery).await
+            },
+            Some(_) => Err((None, "Webhook endpoint is disabled".to_string())),
+            None => Err((None, WebhookError::EndpointNotFound.to_string())),
+        };
+
+        let now = Local::now().naive_local();
+        let mut active = delivery.into_active_model();
+        active.attempts = Set(attempts);
+        active.updated_at = Set(Some(now));
+        match result {
+            Ok(response_status) => {
+                active.status = Set(WebhookDeliveryStatus::Delivered);
+                active.response_status = Set(Some(response_status));
+                active.last_error = Set(None);
+                active.delivered_at = Set(Some(now));
+            },
+            Err((response_status, error)) => {
+                active.response_status = Set(response_status);
+                active.last_error = Set(Some(truncate(error, MAX_ERROR_LEN)));
+                if RETRY_POLICY.exhausted(attempts) {
+                    active.status = Set(WebhookDeliveryStatus::DeadLettered);
+                    active.dead_lettered_at = Set(Some(now));
+                } else {
+                    active.status = Set(WebhookDeliveryStatus::Pending);
+                    active.next_attempt_at = Set(RETRY_POLICY.next_attempt_at(now, attempts));
+                }
+            },
+        }
+        let delivery = active.update(db.as_ref()).await.map_err(AppError::from)?;
+
+        if delivery.status == WebhookDeliveryStatus::DeadLettered {
+            project_error!(
+                "Webhook delivery {} dead-lettered after {} attempts: {:?}",
+                delivery.id,
+                attempts,
+                delivery.last_error
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 签名并发送，返回 2xx 以外的响应或请求错误时视为失败
+    ///
+    /// 签名串为 `{timestamp}.{body}`，与请求验签共用 [`SignatureAlgorithm::HmacSha256`]
+    async fn send(
+        endpoint: &SysWebhookEndpointModel,
+        delivery: &SysWebhookDeliveryModel,
+    ) -> Result<i32, (Option<i32>, String)> {
+        let body = delivery.payload.to_string();
+        let timestamp = Local::now().timestamp_millis().to_string();
+        let signature = SignatureAlgorithm::HmacSha256
+            .sign(&format!("{}.{}", timestamp, body), &endpoint.secret);
+
+        let response = http_client()
+            .post(&endpoint.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("X-Webhook-Id", &delivery.event_id)
+            .header("X-Webhook-Delivery", &delivery.id)
+            .header(
+                "X-Webhook-Event",
+                delivery.payload["type"].as_str().unwrap_or_default(),
+            )
+            .header("X-Webhook-Timestamp", &timestamp)
+            .header("X-Webhook-Signature", signature)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| (None, e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(i32::from(status.as_u16()));
+        }
+
+        let text = response.text().await.unwrap_or_default();
+        Err((
+            Some(i32::from(status.as_u16())),
+            format!("Endpoint returned {}: {}", status, text),
+        ))
+    }
+
+    /// 发送一轮到期的投递，包括等待重试的和租约已过期的
+    pub async fn dispatch_due() -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let deliveries = SysWebhookDelivery::find()
+            .filter(SysWebhookDeliveryColumn::Status.is_in([
+                WebhookDeliveryStatus::Pending,
+                WebhookDeliveryStatus::Delivering,
+            ]))
+            .filter(SysWebhookDeliveryColumn::NextAttemptAt.lte(Local::now().naive_local()))
+            .order_by_asc(SysWebhookDeliveryColumn::NextAttemptAt)
+            .limit(DISPATCH_BATCH_SIZE)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        for delivery in deliveries {
+            let id = delivery.id.clone();
+            if let Err(e) = Self::dispatch(delivery).await {
+                project_error!("Failed to dispatch webhook delivery {}: {}", id, e.message);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 启动后台任务，按固定间隔重试到期的投递
+    ///
+    /// 多实例部署时只有选举出的主节点扫描
+    pub fn spawn_dispatcher(interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut election = LeaderElection::new("webhook_dispatcher");
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if !election.is_leader().await {
+                    continue;
+                }
+                if let Err(e) = Self::dispatch_due().await {
+                    project_error!("Failed to dispatch webhooks: {}", e.message);
+                }
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl TWebhookService for SysWebhookService {
+    async fn find_paginated_endpoints(
+        &self,
+        domain: &str,
+        params: WebhookEndpointPageRequest,
+    ) -> Result<PaginatedData<SysWebhookEndpointModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysWebhookEndpoint::find()
+            .filter(SysWebhookEndpointColumn::Domain.eq(domain))
+            .order_by_desc(SysWebhookEndpointColumn::CreatedAt);
+
+        if let Some(status) = params.status {
+            query = query.filter(SysWebhookEndpointColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn create_endpoint(
+        &self,
+        domain: &str,
+        input: CreateWebhookEndpointInput,
+        operator: &str,
+    ) -> Result<WebhookEndpointOutput, AppError> {
+        let secret = generate_secret()?;
+        let db = db_helper::get_db_connection().await?;
+        let endpoint = SysWebhookEndpointActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            url: Set(input.url),
+            secret: Set(secret.clone()),
+            events: Set(json!(input.events)),
+            status: Set(Status::Enabled),
+            description: Set(input.description),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+            ..Default::default()
+        }
+        .insert(db.as_ref())
+        .await
+        .map_err(AppError::from)?;
+
+        project_info!("Webhook endpoint {} created in {}", endpoint.id, domain);
+
+        Ok(WebhookEndpointOutput {
+            endpoint,
+            secret: Some(secret),
+        })
+    }
+
+    async fn update_endpoint(
+        &self,
+        domain: &str,
+        id: &str,
+        input: UpdateWebhookEndpointInput,
+        operator: &str,
+    ) -> Result<WebhookEndpointOutput, AppError> {
+        let endpoint = self.find_endpoint(domain, id).await?;
+        let secret = if input.rotate_secret {
+            Some(generate_secret()?)
+        } else {
+            None
+        };
+
+        let db = db_helper::get_db_connection().await?;
+        let mut active = endpoint.into_active_model();
+        active.url = Set(input.url);
+        active.events = Set(json!(input.events));
+        active.status = Set(input.status);
+        active.description = Set(input.description);
+        if let Some(ref secret) = secret {
+            active.secret = Set(secret.clone());
+        }
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.updated_by = Set(Some(operator.to_string()));
+        let endpoint = active.update(db.as_ref()).await.map_err(AppError::from)?;
+
+        Ok(WebhookEndpointOutput { endpoint, secret })
+    }
+
+    async fn delete_endpoint(&self, domain: &str, id: &str) -> Result<(), AppError> {
+        let endpoint = self.find_endpoint(domain, id).await?;
+        let db = db_helper::get_db_connection().await?;
+
+        // 投递记录保留，未完成的投递在下次发送时因端点不存在而失败并最终转入死信
+        SysWebhookEndpoint::delete_by_id(endpoint.id)
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
+
+    async fn find_paginated_deliveries(
+        &self,
+        domain: &str,
+        params: WebhookDeliveryPageRequest,
+    ) -> Result<PaginatedData<SysWebhookDeliveryModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysWebhookDelivery::find()
+            .filter(SysWebhookDeliveryColumn::Domain.eq(domain))
+            .order_by_desc(SysWebhookDeliveryColumn::CreatedAt);
+
+        if let Some(endpoint_id) = params.endpoint_id {
+            query = query.filter(SysWebhookDeliveryColumn::EndpointId.eq(endpoint_id));
+        }
+        if let Some(event_type) = params.event_type {
+            query = query.filter(SysWebhookDeliveryColumn::EventType.eq(event_type));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysWebhookDeliveryColumn::Status.eq(status));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn get_delivery(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysWebhookDeliveryModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysWebhookDelivery::find_by_id(id)
+            .filter(SysWebhookDeliveryColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| WebhookError::DeliveryNotFound.into())
+    }
+
+    /// 重新投递死信或已送达的投递，尝试次数清零
+    ///
+    /// 事件 ID 与内容不变，接收方按事件 ID 去重即可
+    async fn replay_delivery(
+        &self,
+        domain: &str,
+        id: &str,
+    ) -> Result<SysWebhookDeliveryModel, AppError> {
+        let delivery = self.get_delivery(domain, id).await?;
+        if matches!(
+            delivery.status,
+            WebhookDeliveryStatus::Pending | WebhookDeliveryStatus::Delivering
+        ) {
+            return Err(WebhookError::DeliveryInFlight.into());
+        }
+
+        let now = Local::now().naive_local();
+        let db = db_helper::get_db_connection().await?;
+        let mut active = delivery.into_active_model();
+        active.status = Set(WebhookDeliveryStatus::Pending);
+        active.attempts = Set(0);
+        active.next_attempt_at = Set(now);
+        active.dead_lettered_at = Set(None);
+        active.updated_at = Set(Some(now));
+        let delivery = active.update(db.as_ref()).await.map_err(AppError::from)?;
+
+        let pending = delivery.clone();
+        tokio::spawn(async move {
+            if let Err(e) = Self::dispatch(pending).await {
+                project_error!("Failed to replay webhook delivery: {}", e.message);
+            }
+        });
+
+        Ok(delivery)
+    }
+}
+
+pub async fn webhook_event_listener(
+    mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
+) {
+    while let Some(event) = rx.recv().await {
+        let (event, ack) = global::open_event(event);
+        if let Some(webhook_event) = event.downcast_ref::<WebhookEvent>() {
+            let result = SysWebhookService::publish(webhook_event).await;
+            if let Err(ref e) = result {
+                project_error!(
+                    "Failed to publish {:?} webhook {} in {}: {}",
+                    webhook_event.event_type,
+                    webhook_event.event_id,
+                    webhook_event.domain,
+                    e.message
+                );
+            }
+            ack.done(result);
+        } else {
+            project_error!("Received unknown event type in webhook listener");
+        }
+    }
+}
@@ -0,0 +1,658 @@
+use std::{collections::HashSet, str::FromStr};
+
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use serde_json::json;
+use server_core::web::{error::AppError, page::PaginatedData};
+use server_model::admin::{
+    entities::{
+        prelude::{SysWithdrawalFee, SysWithdrawalFeeRecord, SysWithdrawalFeeTier},
+        sea_orm_active_enums::{
+            BalanceEntryType, Status, WebhookEventType, WithdrawalFeeType, WithdrawalStatus,
+        },
+        sys_withdrawal_fee::{
+            ActiveModel as SysWithdrawalFeeActiveModel, Column as SysWithdrawalFeeColumn,
+            Model as SysWithdrawalFeeModel,
+        },
+        sys_withdrawal_fee_record::{
+            ActiveModel as SysWithdrawalFeeRecordActiveModel,
+            Column as SysWithdrawalFeeRecordColumn, Model as SysWithdrawalFeeRecordModel,
+        },
+        sys_withdrawal_fee_tier::{
+            ActiveModel as SysWithdrawalFeeTierActiveModel, Column as SysWithdrawalFeeTierColumn,
+            Model as SysWithdrawalFeeTierModel,
+        },
+    },
+    input::{
+        CreateWithdrawalFeeInput, UpdateWithdrawalFeeInput, WithdrawInput, WithdrawalFeeInput,
+        WithdrawalFeePageRequest, WithdrawalFeeQuoteQuery, WithdrawalFeeRecordPageRequest,
+        WithdrawalFeeTierInput,
+    },
+    output::{WithdrawalFeeOutput, WithdrawalFeeQuoteOutput},
+};
+use sol_spl_token::{
+    fee::{FeeKind, FeeRate, FeeTier},
+    FeeSchedule, Pubkey, Signer, TransferTag,
+};
+use ulid::Ulid;
+
+use crate::helper::{db_helper, solana_helper};
+
+use super::{
+    sys_withdrawal_fee_error::WithdrawalFeeError, SysAssetService, SysBalanceService,
+    SysCustodyHoldService, SysKeystoreService, SysOutboxService, SysWebhookService,
+};
+
+#[async_trait]
+pub trait TWithdrawalFeeService {
+    async fn find_paginated_fees(
+        &self,
+        domain: &str,
+        params: WithdrawalFeePageRequest,
+    ) -> Result<PaginatedData<SysWithdrawalFeeModel>, AppError>;
+
+    async fn create_fee(
+        &self,
+        domain: &str,
+        input: CreateWithdrawalFeeInput,
+        operator: &str,
+    ) -> Result<WithdrawalFeeOutput, AppError>;
+
+    async fn get_fee(&self, domain: &str, id: &str) -> Result<WithdrawalFeeOutput, AppError>;
+
+    async fn update_fee(
+        &self,
+        domain: &str,
+        input: UpdateWithdrawalFeeInput,
+        operator: &str,
+    ) -> Result<WithdrawalFeeOutput, AppError>;
+
+    async fn delete_fee(&self, domain: &str, id: &str) -> Result<(), AppError>;
+
+    async fn quote(
+        &self,
+        domain: &str,
+        roles: &[String],
+        query: WithdrawalFeeQuoteQuery,
+    ) -> Result<WithdrawalFeeQuoteOutput, AppError>;
+
+    async fn withdraw(
+        &self,
+        domain: &str,
+        user_id: &str,
+        roles: &[String],
+        input: WithdrawInput,
+        operator: &str,
+    ) -> Result<SysWithdrawalFeeRecordModel, AppError>;
+
+    async fn find_paginated_records(
+        &self,
+        domain: &str,
+        params: WithdrawalFeeRecordPageRequest,
+    ) -> Result<PaginatedData<SysWithdrawalFeeRecordModel>, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysWithdrawalFeeService;
+
+/// 适用于某次提现的手续费规则
+struct ResolvedSchedule {
+    schedule: FeeSchedule,
+    fee_id: Option<String>,
+    fee_wallet: String,
+}
+
+/// 金额字段均已校验为非负数
+fn to_u64(value: i64) -> u64 {
+    u64::try_from(value).unwrap_or_default()
+}
+
+fn to_rate(flat_fee: i64, percentage_bps: i32) -> FeeRate {
+    FeeRate {
+        flat: to_u64(flat_fee),
+        bps: u16::try_from(percentage_bps).unwrap_or_default(),
+    }
+}
+
+fn validate_fee(input: &WithdrawalFeeInput) -> Result<(), AppError> {
+    if let Some(ref mint) = input.mint {
+        Pubkey::from_str(mint)
+            .map_err(|_| AppError::from(WithdrawalFeeError::InvalidMint(mint.clone())))?;
+    }
+    Pubkey::from_str(&input.fee_wallet).map_err(|_| {
+        AppError::from(WithdrawalFeeError::InvalidFeeWallet(
+            input.fee_wallet.clone(),
+        ))
+    })?;
+
+    if input.max_fee.is_some_and(|max_fee| input.min_fee > max_fee) {
+        return Err(WithdrawalFeeError::InvalidFeeLimits.into());
+    }
+
+    if input.fee_type == WithdrawalFeeType::Tiered && input.tiers.is_empty() {
+        return Err(WithdrawalFeeError::TiersRequired.into());
+    }
+
+    let mut roles = HashSet::new();
+    for tier in &input.tiers {
+        if !roles.insert(tier.role_code.as_str()) {
+            return Err(WithdrawalFeeError::DuplicateTier(tier.role_code.clone()).into());
+        }
+    }
+
+    Ok(())
+}
+
+impl SysWithdrawalFeeService {
+    async fn check_fee_exists(
+        &self,
+        domain: &str,
+        id: Option<&str>,
+        mint: Option<&str>,
+    ) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let query = SysWithdrawalFee::find()
+            .filter(SysWithdrawalFeeColumn::Domain.eq(domain))
+            .filter(SysWithdrawalFeeColumn::Id.ne(id.unwrap_or("-1")));
+        let query = match mint {
+            Some(mint) => query.filter(SysWithdrawalFeeColumn::Mint.eq(mint)),
+            None => query.filter(SysWithdrawalFeeColumn::Mint.is_null()),
+        };
+
+        if query
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .is_some()
+        {
+            return Err(WithdrawalFeeError::DuplicateFee.into());
+        }
+
+        Ok(())
+    }
+
+    async fn find_fee(&self, domain: &str, id: &str) -> Result<SysWithdrawalFeeModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysWithdrawalFee::find_by_id(id)
+            .filter(SysWithdrawalFeeColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| WithdrawalFeeError::FeeNotFound.into())
+    }
+
+    async fn find_tiers(fee_id: &str) -> Result<Vec<SysWithdrawalFeeTierModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysWithdrawalFeeTier::find()
+            .filter(SysWithdrawalFeeTierColumn::FeeId.eq(fee_id))
+            .order_by_asc(SysWithdrawalFeeTierColumn::RoleCode)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// 规则与档位整体保存，档位每次全量替换
+    async fn save_fee_in_transaction(
+        &self,
+        txn: &DatabaseTransaction,
+        fee: SysWithdrawalFeeActiveModel,
+        tiers: Vec<WithdrawalFeeTierInput>,
+    ) -> Result<WithdrawalFeeOutput, AppError> {
+        let fee = fee.save(txn).await.map_err(AppError::from)?;
+        let fee_id = fee.id.clone().unwrap();
+
+        SysWithdrawalFeeTier::delete_many()
+            .filter(SysWithdrawalFeeTierColumn::FeeId.eq(&fee_id))
+            .exec(txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let mut saved_tiers = Vec::with_capacity(tiers.len());
+        for tier in tiers {
+            let tier = SysWithdrawalFeeTierActiveModel {
+                id: Set(Ulid::new().to_string()),
+                fee_id: Set(fee_id.clone()),
+                role_code: Set(tier.role_code),
+                flat_fee: Set(tier.flat_fee),
+                percentage_bps: Set(tier.percentage_bps),
+            }
+            .insert(txn)
+            .await
+            .map_err(AppError::from)?;
+            saved_tiers.push(tier);
+        }
+
+        let fee = SysWithdrawalFee::find_by_id(fee_id)
+            .one(txn)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(WithdrawalFeeError::FeeNotFound))?;
+
+        Ok(WithdrawalFeeOutput {
+            fee,
+            tiers: saved_tiers,
+        })
+    }
+
+    async fn save_fee(
+        &self,
+        fee: SysWithdrawalFeeActiveModel,
+        tiers: Vec<WithdrawalFeeTierInput>,
+    ) -> Result<WithdrawalFeeOutput, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        match self.save_fee_in_transaction(&txn, fee, tiers).await {
+            Ok(result) => {
+                txn.commit().await.map_err(AppError::from)?;
+                Ok(result)
+            },
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                Err(e)
+            },
+        }
+    }
+
+    /// 优先使用该资产的规则，其次是域内默认规则，都没有时按资产登记的固定手续费收取，
+    /// 此时手续费留在系统钱包
+    async fn resolve_schedule(
+        domain: &str,
+        mint: &str,
+        default_fee: i64,
+    ) -> Result<ResolvedSchedule, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let fees = SysWithdrawalFee::find()
+            .filter(SysWithdrawalFeeColumn::Domain.eq(domain))
+            .filter(SysWithdrawalFeeColumn::Status.eq(Status::Enabled))
+            .filter(
+                SysWithdrawalFeeColumn::Mint
+                    .eq(mint)
+                    .or(SysWithdrawalFeeColumn::Mint.is_null()),
+            )
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let Some(fee) = fees
+            .iter()
+            .find(|fee| fee.mint.is_some())
+            .or_else(|| fees.first())
+        else {
+            let system_wallet = solana_helper::get_system_keypair().await?.pubkey();
+            return Ok(ResolvedSchedule {
+                schedule: FeeSchedule::flat(to_u64(default_fee)),
+                fee_id: None,
+                fee_wallet: system_wallet.to_string(),
+            });
+        };
+
+        let tiers = Self::find_tiers(&fee.id)
+            .await?
+            .into_iter()
+            .map(|tier| FeeTier {
+                role: tier.role_code,
+                rate: to_rate(tier.flat_fee, tier.percentage_bps),
+            })
+            .collect();
+
+        Ok(ResolvedSchedule {
+            schedule: FeeSchedule {
+                kind: match fee.fee_type {
+                    WithdrawalFeeType::Flat => FeeKind::Flat,
+                    WithdrawalFeeType::Percentage => FeeKind::Percentage,
+                    WithdrawalFeeType::Tiered => FeeKind::Tiered,
+                },
+                rate: to_rate(fee.flat_fee, fee.percentage_bps),
+                tiers,
+                min_fee: to_u64(fee.min_fee),
+                max_fee: fee.max_fee.map(to_u64),
+            },
+            fee_id: Some(fee.id.clone()),
+            fee_wallet: fee.fee_wallet.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl TWithdrawalFeeService for SysWithdrawalFeeService {
+    async fn find_paginated_fees(
+        &self,
+        domain: &str,
+        params: WithdrawalFeePageRequest,
+    ) -> Result<PaginatedData<SysWithdrawalFeeModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysWithdrawalFee::find()
+            .filter(SysWithdrawalFeeColumn::Domain.eq(domain))
+            .order_by_desc(SysWithdrawalFeeColumn::CreatedAt);
+
+        if let Some(mint) = params.mint {
+            query = query.filter(SysWithdrawalFeeColumn::Mint.eq(mint));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+
+    async fn create_fee(
+        &self,
+        domain: &str,
+        input: CreateWithdrawalFeeInput,
+        operator: &str,
+    ) -> Result<WithdrawalFeeOutput, AppError> {
+        validate_fee(&input)?;
+        self.check_fee_exists(domain, None, input.mint.as_deref())
+            .await?;
+
+        let fee = SysWithdrawalFeeActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            mint: Set(input.mint),
+            fee_type: Set(input.fee_type),
+            flat_fee: Set(input.flat_fee),
+            percentage_bps: Set(input.percentage_bps),
+            min_fee: Set(input.min_fee),
+            max_fee: Set(input.max_fee),
+            fee_wallet: Set(input.fee_wallet),
+            status: Set(input.status),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        };
+
+        self.save_fee(fee, input.tiers).await
+    }
+
+    async fn get_fee(&self, domain: &str, id: &str) -> Result<WithdrawalFeeOutput, AppError> {
+        let fee = self.find_fee(domain, id).await?;
+        let tiers = Self::find_tiers(&fee.id).await?;
+        Ok(WithdrawalFeeOutput { fee, tiers })
+    }
+
+    async fn update_fee(
+        &self,
+        domain: &str,
+        input: UpdateWithdrawalFeeInput,
+        operator: &str,
+    ) -> Result<WithdrawalFeeOutput, AppError> {
+        validate_fee(&input.fee)?;
+        let existing = self.find_fee(domain, &input.id).await?;
+        self.check_fee_exists(domain, Some(&input.id), input.fee.mint.as_deref())
+            .await?;
+
+        let mut fee: SysWithdrawalFeeActiveModel = existing.into();
+        fee.mint = Set(input.fee.mint);
+        fee.fee_type = Set(input.fee.fee_type);
+        fee.flat_fee = Set(input.fee.flat_fee);
+        fee.percentage_bps = Set(input.fee.percentage_bps);
+        fee.min_fee = Set(input.fee.min_fee);
+        fee.max_fee = Set(input.fee.max_fee);
+        fee.fee_wallet = Set(input.fee.fee_wallet);
+        fee.status = Set(input.fee.status);
+        fee.updated_at = Set(Some(Local::now().naive_local()));
+        fee.updated_by = Set(Some(operator.to_string()));
+
+        self.save_fee(fee, input.fee.tiers).await
+    }
+
+    async fn delete_fee(&self, domain: &str, id: &str) -> Result<(), AppError> {
+        self.find_fee(domain, id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let result = async {
+            SysWithdrawalFeeTier::delete_many()
+                .filter(SysWithdrawalFeeTierColumn::FeeId.eq(id))
+                .exec(&txn)
+                .await?;
+            SysWithdrawalFee::delete_by_id(id).exec(&txn).await
+        }
+        .await;
+
+        match result {
+            Ok(_) => txn.commit().await.map_err(AppError::from),
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                Err(AppError::from(e))
+            },
+        }
+    }
+
+    async fn quote(
+        &self,
+        domain: &str,
+        roles: &[String],
+        query: WithdrawalFeeQuoteQuery,
+    ) -> Result<WithdrawalFeeQuoteOutput, AppError> {
+        let asset = SysAssetService::find_enabled_asset_by_mint(domain, &query.mint).await?;
+        if !asset.withdrawal_enabled {
+            return Err(WithdrawalFeeError::WithdrawalDisabled.into());
+        }
+
+        let out_of_range =
+            || WithdrawalFeeError::AmountOutOfRange(asset.min_withdrawal, asset.max_withdrawal);
+        let amount = i64::try_from(query.amount).map_err(|_| AppError::from(out_of_range()))?;
+        if amount < asset.min_withdrawal || amount > asset.max_withdrawal {
+            return Err(out_of_range().into());
+        }
+
+        let resolved = Self::resolve_schedule(domain, &asset.mint, asset.withdrawal_fee).await?;
+        let quote = resolved
+            .schedule
+            .quote(query.amount, roles)
+            .ok_or_else(|| AppError::from(WithdrawalFeeError::AmountBelowFee))?;
+
+        Ok(WithdrawalFeeQuoteOutput {
+            mint: asset.mint,
+            amount: quote.amount,
+            fee: quote.fee,
+            net_amount: quote.net_amount,
+            tier: quote.tier,
+            fee_id: resolved.fee_id,
+            fee_wallet: resolved.fee_wallet,
+        })
+    }
+
+    async fn withdraw(
+        &self,
+        domain: &str,
+        user_id: &str,
+        roles: &[String],
+        input: WithdrawInput,
+        operator: &str,
+    ) -> Result<SysWithdrawalFeeRecordModel, AppError> {
+        // 转出由系统钱包签名支付，用户资金按余额账本扣减，冻结按提现用户判断
+        SysCustodyHoldService::ensure_not_held(domain, user_id).await?;
+        // 私钥已导出的用户可以直接动用链上资产，不能再从账本提现
+        SysKeystoreService::ensure_not_exported(domain, user_id).await?;
+
+        let destination = Pubkey::from_str(&input.destination).map_err(|_| {
+            AppError::from(WithdrawalFeeError::InvalidDestination(
+                input.destination.clone(),
+            ))
+        })?;
+
+        // 以提交时的规则重新计算，避免使用用户看到的过期报价
+        let quote = self
+            .quote(
+                domain,
+                roles,
+                WithdrawalFeeQuoteQuery {
+                    mint: input.mint,
+                    amount: input.amount,
+                },
+            )
+            .await?;
+        let mint = Pubkey::from_str(&quote.mint)
+            .map_err(|_| AppError::from(WithdrawalFeeError::InvalidMint(quote.mint.clone())))?;
+        let fee_wallet = Pubkey::from_str(&quote.fee_wallet).map_err(|_| {
+            AppError::from(WithdrawalFeeError::InvalidFeeWallet(
+                quote.fee_wallet.clone(),
+            ))
+        })?;
+
+        let asset = SysAssetService::find_enabled_asset_by_mint(domain, &quote.mint).await?;
+        let (token_program, decimals) = SysAssetService::token_program(&asset);
+
+        // 记录 ID 先生成，作为余额流水引用与链上备注
+        let id = Ulid::new().to_string();
+
+        // 发送前在同一事务中扣减用户余额并落库待发送记录，余额不足时不发送；
+        // 金额在报价阶段已限制在资产的提现上限内
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        SysBalanceService::debit(
+            &txn,
+            domain,
+            user_id,
+            &quote.mint,
+            quote.amount as i64,
+            BalanceEntryType::Withdrawal,
+            &id,
+        )
+        .await?;
+        let record = SysWithdrawalFeeRecordActiveModel {
+            id: Set(id.clone()),
+            domain: Set(domain.to_string()),
+            user_id: Set(user_id.to_string()),
+            mint: Set(quote.mint),
+            destination: Set(input.destination),
+            amount: Set(quote.amount as i64),
+            fee: Set(quote.fee as i64),
+            net_amount: Set(quote.net_amount as i64),
+            fee_wallet: Set(quote.fee_wallet),
+            fee_id: Set(quote.fee_id),
+            tier: Set(quote.tier),
+            signature: Set(None),
+            status: Set(WithdrawalStatus::Pending),
+            error: Set(None),
+            created_at: Set(Local::now().naive_local()),
+            created_by: Set(operator.to_string()),
+            updated_at: Set(None),
+        }
+        .insert(&txn)
+        .await
+        .map_err(AppError::from)?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        let manager = solana_helper::get_token_manager().await?;
+        let system_keypair = solana_helper::get_system_keypair().await?;
+        let result = manager
+            .transfer_token_with_fee(
+                &system_keypair,
+                &destination,
+                &fee_wallet,
+                &mint,
+                token_program,
+                decimals,
+                quote.net_amount,
+                quote.fee,
+                Some(&TransferTag::new(&id)),
+            )
+            .await;
+
+        let mut active: SysWithdrawalFeeRecordActiveModel = record.into();
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        let reverse = match &result {
+            Ok(signature) => {
+                active.status = Set(WithdrawalStatus::Sent);
+                active.signature = Set(Some(signature.clone()));
+                false
+            },
+            // 交易可能已上链，保持待发送并保留扣款，按备注核对链上状态后再处理
+            Err(e) if e.is_outcome_unknown() => {
+                active.error = Set(Some(e.to_string()));
+                false
+            },
+            Err(e) => {
+                active.status = Set(WithdrawalStatus::Failed);
+                active.error = Set(Some(e.to_string()));
+                true
+            },
+        };
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let record = active.update(&txn).await.map_err(AppError::from)?;
+        if reverse {
+            SysBalanceService::credit(
+                &txn,
+                domain,
+                user_id,
+                &record.mint,
+                record.amount,
+                BalanceEntryType::WithdrawalReversal,
+                &record.id,
+            )
+            .await?;
+        }
+        SysWebhookService::notify(
+            &txn,
+            domain,
+            WebhookEventType::WithdrawalUpdated,
+            json!({ "kind": "withdrawal", "status": record.status, "withdrawal": record }),
+        )
+        .await?;
+        txn.commit().await.map_err(AppError::from)?;
+        SysOutboxService::wake_relay();
+
+        result.map_err(AppError::from)?;
+        Ok(record)
+    }
+
+    async fn find_paginated_records(
+        &self,
+        domain: &str,
+        params: WithdrawalFeeRecordPageRequest,
+    ) -> Result<PaginatedData<SysWithdrawalFeeRecordModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysWithdrawalFeeRecord::find()
+            .filter(SysWithdrawalFeeRecordColumn::Domain.eq(domain))
+            .order_by_desc(SysWithdrawalFeeRecordColumn::CreatedAt);
+
+        if let Some(user_id) = params.user_id {
+            query = query.filter(SysWithdrawalFeeRecordColumn::UserId.eq(user_id));
+        }
+        if let Some(mint) = params.mint {
+            query = query.filter(SysWithdrawalFeeRecordColumn::Mint.eq(mint));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PaginatedData {
+            current: params.page_details.current,
+            size: params.page_details.size,
+            total,
+            records,
+        })
+    }
+}
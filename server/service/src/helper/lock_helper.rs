@@ -0,0 +1,182 @@
+use std::{
+    sync::{Arc, LazyLock},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use redis::{FromRedisValue, RedisError, Script};
+use server_global::global::GLOBAL_PRIMARY_REDIS;
+use sol_spl_token::{
+    keystore::generate_token,
+    lock::{InMemoryDistributedLock, LockGuard},
+    DistributedLock, SolanaError,
+};
+
+use super::redis_helper;
+
+/// 未配置 Redis 时使用的进程内锁，单实例部署下同样能串行化同一钱包的交易
+static MEMORY_LOCK: LazyLock<Arc<InMemoryDistributedLock>> =
+    LazyLock::new(|| Arc::new(InMemoryDistributedLock::new()));
+
+/// 加锁并分配 fencing token，锁与计数器使用同一个 hash tag，集群模式下位于同一个槽
+static ACQUIRE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+        if redis.call('SET', KEYS[1], ARGV[1], 'NX', 'PX', ARGV[2]) then
+            local token = redis.call('INCR', KEYS[2])
+            redis.call('SET', KEYS[1], ARGV[1] .. ':' .. token, 'PX', ARGV[2])
+            return token
+        end
+        return false
+        ",
+    )
+});
+
+/// 只释放自己持有的锁
+static RELEASE_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+        if redis.call('GET', KEYS[1]) == ARGV[1] then
+            return redis.call('DEL', KEYS[1])
+        end
+        return 0
+        ",
+    )
+});
+
+/// 只续期自己持有的锁
+static EXTEND_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+        if redis.call('GET', KEYS[1]) == ARGV[1] then
+            return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+        end
+        return 0
+        ",
+    )
+});
+
+/// 基于主 Redis 的分布式锁，支持单机与集群模式
+///
+/// 锁的值为 `{owner}:{fencing_token}`，fencing token 存放在独立的计数器中，释放锁后继续递增
+#[derive(Clone)]
+pub struct RedisDistributedLock {
+    prefix: String,
+}
+
+impl RedisDistributedLock {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    fn lock_key(&self, key: &str) -> String {
+        format!("{}:{{{}}}", self.prefix, key)
+    }
+
+    fn fencing_key(&self, key: &str) -> String {
+        format!("{}:{{{}}}:fence", self.prefix, key)
+    }
+
+    async fn invoke<T: FromRedisValue>(
+        script: &Script,
+        keys: &[String],
+        args: &[String],
+    ) -> Result<T, RedisError> {
+        let redis = redis_helper::primary_redis().await?;
+
+        let mut invocation = script.prepare_invoke();
+        for key in keys {
+            invocation.key(key);
+        }
+        for arg in args {
+            invocation.arg(arg);
+        }
+
+        redis.invoke(&invocation).await
+    }
+
+    async fn get(key: &str) -> Result<Option<String>, RedisError> {
+        let redis = redis_helper::primary_redis().await?;
+
+        let mut cmd = redis::cmd("GET");
+        cmd.arg(key);
+        redis.query(&cmd).await
+    }
+}
+
+impl Default for RedisDistributedLock {
+    fn default() -> Self {
+        Self::new("lock")
+    }
+}
+
+fn lock_error(e: RedisError) -> SolanaError {
+    SolanaError::LockError(e.to_string())
+}
+
+fn lock_value(guard: &LockGuard) -> String {
+    format!("{}:{}", guard.owner, guard.fencing_token)
+}
+
+#[async_trait]
+impl DistributedLock for RedisDistributedLock {
+    async fn try_acquire(
+        &self,
+        key: &str,
+        ttl: Duration,
+    ) -> sol_spl_token::error::Result<Option<LockGuard>> {
+        let owner = generate_token()?;
+        let fencing_token: Option<u64> = Self::invoke(
+            &ACQUIRE_SCRIPT,
+            &[self.lock_key(key), self.fencing_key(key)],
+            &[owner.clone(), ttl.as_millis().max(1).to_string()],
+        )
+        .await
+        .map_err(lock_error)?;
+
+        Ok(fencing_token.map(|fencing_token| LockGuard {
+            key: key.to_string(),
+            owner,
+            fencing_token,
+        }))
+    }
+
+    async fn is_held(&self, guard: &LockGuard) -> sol_spl_token::error::Result<bool> {
+        let value = Self::get(&self.lock_key(&guard.key))
+            .await
+            .map_err(lock_error)?;
+        Ok(value.is_some_and(|value| value == lock_value(guard)))
+    }
+
+    async fn extend(&self, guard: &LockGuard, ttl: Duration) -> sol_spl_token::error::Result<bool> {
+        let extended: i64 = Self::invoke(
+            &EXTEND_SCRIPT,
+            &[self.lock_key(&guard.key)],
+            &[lock_value(guard), ttl.as_millis().max(1).to_string()],
+        )
+        .await
+        .map_err(lock_error)?;
+        Ok(extended == 1)
+    }
+
+    async fn release(&self, guard: &LockGuard) -> sol_spl_token::error::Result<()> {
+        Self::invoke::<i64>(
+            &RELEASE_SCRIPT,
+            &[self.lock_key(&guard.key)],
+            &[lock_value(guard)],
+        )
+        .await
+        .map(|_| ())
+        .map_err(lock_error)
+    }
+}
+
+/// 获取分布式锁，已初始化主 Redis 时使用 Redis，否则使用进程内锁
+pub async fn get_distributed_lock() -> Arc<dyn DistributedLock> {
+    if GLOBAL_PRIMARY_REDIS.read().await.is_some() {
+        return Arc::new(RedisDistributedLock::default());
+    }
+    MEMORY_LOCK.clone()
+}
@@ -1,3 +1,7 @@
 pub mod db_helper;
+pub mod lock_helper;
 pub mod mongo_helper;
 pub mod redis_helper;
+pub mod retry_helper;
+pub mod s3_helper;
+pub mod solana_helper;
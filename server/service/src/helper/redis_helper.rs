@@ -12,6 +12,15 @@ pub enum RedisSource {
     Named(String),
 }
 
+/// 获取主Redis，单机与集群模式通过 [`RedisConnection::query`] 和 [`RedisConnection::invoke`] 统一执行命令
+pub async fn primary_redis() -> Result<RedisConnection, RedisError> {
+    GLOBAL_PRIMARY_REDIS
+        .read()
+        .await
+        .clone()
+        .ok_or_else(|| RedisError::from((ErrorKind::IoError, "Primary Redis not initialized")))
+}
+
 /// 获取Redis连接
 pub async fn get_redis_connection(source: RedisSource) -> Result<MultiplexedConnection, AppError> {
     match source {
@@ -0,0 +1,94 @@
+//! 后台投递队列的公共逻辑：按租约领取、失败后指数退避重试、超过次数转入死信
+//!
+//! Webhook 投递与事件发件箱共用，各自的表通过 [`RetryQueue`] 指明相关列
+
+use std::time::Duration;
+
+use chrono::NaiveDateTime;
+use sea_orm::{sea_query::Expr, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, Value};
+use server_core::web::error::AppError;
+
+/// 按 [`RetryPolicy`] 投递的队列表
+pub trait RetryQueue: EntityTrait {
+    const ID: Self::Column;
+    const STATUS: Self::Column;
+    const ATTEMPTS: Self::Column;
+    const NEXT_ATTEMPT_AT: Self::Column;
+    const UPDATED_AT: Self::Column;
+}
+
+/// 重试策略
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// 超过该次数仍失败的条目转入死信
+    pub max_attempts: i32,
+    /// 首次重试的等待时间，之后每次翻倍
+    pub base_delay: Duration,
+    /// 重试等待时间上限
+    pub max_delay: Duration,
+    /// 条目被领取后的租约，进程在处理中退出时租约到期后重新处理
+    pub lease: Duration,
+}
+
+impl RetryPolicy {
+    /// 第 `attempts` 次失败后的重试等待时间
+    pub fn retry_delay(&self, attempts: i32) -> Duration {
+        let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+        self.base_delay
+            .saturating_mul(2u32.pow(exponent))
+            .min(self.max_delay)
+    }
+
+    /// 第 `attempts` 次失败后的下次处理时间
+    pub fn next_attempt_at(&self, now: NaiveDateTime, attempts: i32) -> NaiveDateTime {
+        after(now, self.retry_delay(attempts))
+    }
+
+    /// 已用完重试次数，应转入死信
+    pub fn exhausted(&self, attempts: i32) -> bool {
+        attempts >= self.max_attempts
+    }
+
+    /// 领取一个条目：置为处理中、尝试次数加一，并把下次处理时间推后一个租约
+    ///
+    /// 以条目读取时的状态和下次处理时间为条件，多个实例同时处理时只有一个领取成功
+    pub async fn claim<E, C, S>(
+        &self,
+        conn: &C,
+        id: &str,
+        status: S,
+        next_attempt_at: NaiveDateTime,
+        in_flight: S,
+        now: NaiveDateTime,
+    ) -> Result<bool, AppError>
+    where
+        E: RetryQueue,
+        C: ConnectionTrait,
+        S: Into<Value>,
+    {
+        let claimed = E::update_many()
+            .col_expr(E::STATUS, Expr::value(in_flight))
+            .col_expr(E::ATTEMPTS, Expr::col(E::ATTEMPTS).add(1))
+            .col_expr(E::NEXT_ATTEMPT_AT, Expr::value(after(now, self.lease)))
+            .col_expr(E::UPDATED_AT, Expr::value(now))
+            .filter(E::ID.eq(id))
+            .filter(E::STATUS.eq(status))
+            .filter(E::NEXT_ATTEMPT_AT.eq(next_attempt_at))
+            .exec(conn)
+            .await
+            .map_err(AppError::from)?;
+        Ok(claimed.rows_affected > 0)
+    }
+}
+
+pub fn after(now: NaiveDateTime, delay: Duration) -> NaiveDateTime {
+    now + chrono::Duration::from_std(delay).unwrap_or_default()
+}
+
+/// 截断到 `max_len` 个字符，用于记录错误信息
+pub fn truncate(message: String, max_len: usize) -> String {
+    match message.char_indices().nth(max_len) {
+        Some((index, _)) => message[..index].to_string(),
+        None => message,
+    }
+}
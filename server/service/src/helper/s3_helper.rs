@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use aws_sdk_s3::Client as S3Client;
+use server_config::S3Config;
+use server_global::global::{self, GLOBAL_PRIMARY_S3};
+
+/// 获取主 S3 客户端，未配置 S3 时返回 None
+pub async fn get_primary_s3_client() -> Option<Arc<S3Client>> {
+    GLOBAL_PRIMARY_S3.read().await.clone()
+}
+
+/// 获取主 S3 配置的默认存储桶
+pub async fn get_default_bucket() -> Option<String> {
+    global::get_config::<S3Config>()
+        .await
+        .and_then(|config| config.bucket.clone())
+}
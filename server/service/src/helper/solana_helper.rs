@@ -0,0 +1,271 @@
+use std::{sync::Arc, time::Duration};
+
+use server_config::RiskConfig;
+use server_core::web::error::AppError;
+use server_global::global::{self, GLOBAL_PRIMARY_SOLANA};
+use sol_spl_token::{
+    config::keypair_from_base58,
+    keystore::{self, EncryptedKey},
+    risk::{CompositeScreener, DenyListScreener, HttpRiskScreener},
+    KeyRotator, Keypair, LookupTableManager, MemoReader, MintAdmin, PaymentChecker, PayoutExecutor,
+    RentReclaimer, RiskGate, RiskScreener, RpcPool, SolanaConfig, SolanaError, StakeManager,
+    SwapManager, TokenManager,
+};
+use tokio::sync::OnceCell;
+
+use super::lock_helper;
+use crate::admin::{sys_keystore_error::KeystoreError, CustodyHoldRegistry, RiskScreeningStorage};
+
+/// 转出前的风险闸门，首次使用时按 `risk` 配置创建
+static RISK_GATE: OnceCell<Arc<RiskGate>> = OnceCell::const_new();
+
+/// 获取 Solana 配置
+///
+/// 读取全局配置中的 `solana` 段，YAML/TOML/JSON 与 `APP_SOLANA_` 环境变量均合并到该段
+pub async fn get_solana_config() -> Result<SolanaConfig, AppError> {
+    global::get_config::<SolanaConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .ok_or_else(|| {
+            AppError::from(SolanaError::ConfigError(
+                "Solana is not configured".to_string(),
+            ))
+        })
+}
+
+/// 获取主网络的 RPC 端点池
+///
+/// 启动时由初始化流程创建，未初始化时按当前配置创建并启动健康检查
+pub async fn get_rpc_pool() -> Result<Arc<RpcPool>, AppError> {
+    if let Some(pool) = GLOBAL_PRIMARY_SOLANA.read().await.clone() {
+        return Ok(pool);
+    }
+
+    let mut primary = GLOBAL_PRIMARY_SOLANA.write().await;
+    if let Some(pool) = primary.clone() {
+        return Ok(pool);
+    }
+    let config = get_solana_config().await?;
+    let pool = Arc::new(RpcPool::from_config(&config)?);
+    pool.spawn_health_checker();
+    *primary = Some(pool.clone());
+    Ok(pool)
+}
+
+/// 获取转出前的风险闸门
+///
+/// 组合本地黑名单与 HTTP 风控服务，未配置 `risk` 段时不拦截但仍记录每次转出；
+/// 筛查记录写入 `sys_risk_screening`，记录失败时拒绝转出
+pub async fn get_risk_gate() -> Result<Arc<RiskGate>, AppError> {
+    RISK_GATE
+        .get_or_try_init(|| async {
+            let mut screeners: Vec<Arc<dyn RiskScreener>> = Vec::new();
+            if let Some(config) = global::get_config::<RiskConfig>().await {
+                if !config.deny_list_path.is_empty() {
+                    let deny_list = Arc::new(DenyListScreener::from_file(&config.deny_list_path)?);
+                    deny_list
+                        .clone()
+                        .spawn_watcher(Duration::from_secs(config.deny_list_reload_secs));
+                    screeners.push(deny_list);
+                }
+                if !config.provider_url.is_empty() {
+                    let api_key =
+                        Some(config.provider_api_key.clone()).filter(|key| !key.is_empty());
+                    screeners.push(Arc::new(HttpRiskScreener::new(
+                        &config.provider_url,
+                        api_key,
+                        Duration::from_secs(config.provider_timeout_secs),
+                    )?));
+                }
+            }
+
+            let gate = RiskGate::new(Arc::new(CompositeScreener::new(screeners)))
+                .with_storage(Arc::new(RiskScreeningStorage));
+            Ok::<_, AppError>(Arc::new(gate))
+        })
+        .await
+        .cloned()
+}
+
+/// 获取 Token 管理器，转出前筛查交易对手、检查转出钱包是否被冻结，并按钱包串行化转出
+pub async fn get_token_manager() -> Result<Arc<TokenManager>, AppError> {
+    let pool = get_rpc_pool().await?;
+    let risk_gate = get_risk_gate().await?;
+    let wallet_lock = lock_helper::get_distributed_lock().await;
+    TokenManager::from_pool(&pool)
+        .map(|manager| {
+            Arc::new(
+                manager
+                    .with_risk_gate(risk_gate)
+                    .with_hold_registry(Arc::new(CustodyHoldRegistry))
+                    .with_wallet_lock(wallet_lock),
+            )
+        })
+        .map_err(AppError::from)
+}
+
+/// 获取租金回收器，签名路径均按钱包加锁
+pub async fn get_rent_reclaimer() -> Result<RentReclaimer, AppError> {
+    let pool = get_rpc_pool().await?;
+    let wallet_lock = lock_helper::get_distributed_lock().await;
+    RentReclaimer::from_pool(&pool)
+        .map(|manager| manager.with_wallet_lock(wallet_lock))
+        .map_err(AppError::from)
+}
+
+/// 获取批量发放执行器，发送前筛查每一行的接收方
+pub async fn get_payout_executor() -> Result<PayoutExecutor, AppError> {
+    let pool = get_rpc_pool().await?;
+    let risk_gate = get_risk_gate().await?;
+    let wallet_lock = lock_helper::get_distributed_lock().await;
+    PayoutExecutor::from_pool(&pool)
+        .map(|manager| {
+            manager
+                .with_risk_gate(risk_gate)
+                .with_wallet_lock(wallet_lock)
+        })
+        .map_err(AppError::from)
+}
+
+/// 获取地址查找表管理器
+pub async fn get_lookup_table_manager() -> Result<LookupTableManager, AppError> {
+    let pool = get_rpc_pool().await?;
+    let wallet_lock = lock_helper::get_distributed_lock().await;
+    LookupTableManager::from_pool(&pool)
+        .map(|manager| manager.with_wallet_lock(wallet_lock))
+        .map_err(AppError::from)
+}
+
+/// 获取 Mint 管理器
+pub async fn get_mint_admin() -> Result<MintAdmin, AppError> {
+    let pool = get_rpc_pool().await?;
+    let wallet_lock = lock_helper::get_distributed_lock().await;
+    MintAdmin::from_pool(&pool)
+        .map(|manager| manager.with_wallet_lock(wallet_lock))
+        .map_err(AppError::from)
+}
+
+/// 获取链上备注读取器
+pub async fn get_memo_reader() -> Result<MemoReader, AppError> {
+    let pool = get_rpc_pool().await?;
+    MemoReader::from_pool(&pool).map_err(AppError::from)
+}
+
+/// 获取 Solana Pay 付款查询器
+pub async fn get_payment_checker() -> Result<PaymentChecker, AppError> {
+    let pool = get_rpc_pool().await?;
+    PaymentChecker::from_pool(&pool).map_err(AppError::from)
+}
+
+/// 获取质押管理器，委托与提取前筛查交易对手
+pub async fn get_stake_manager() -> Result<StakeManager, AppError> {
+    let pool = get_rpc_pool().await?;
+    let risk_gate = get_risk_gate().await?;
+    let wallet_lock = lock_helper::get_distributed_lock().await;
+    StakeManager::from_pool(&pool)
+        .map(|manager| {
+            manager
+                .with_risk_gate(risk_gate)
+                .with_wallet_lock(wallet_lock)
+        })
+        .map_err(AppError::from)
+}
+
+/// 获取密钥轮换执行器，迁移前筛查新钱包
+pub async fn get_key_rotator() -> Result<KeyRotator, AppError> {
+    let pool = get_rpc_pool().await?;
+    let risk_gate = get_risk_gate().await?;
+    let wallet_lock = lock_helper::get_distributed_lock().await;
+    KeyRotator::from_pool(&pool)
+        .map(|manager| {
+            manager
+                .with_risk_gate(risk_gate)
+                .with_wallet_lock(wallet_lock)
+        })
+        .map_err(AppError::from)
+}
+
+/// 获取交换管理器，被冻结的钱包不能兑换，同一钱包的兑换按顺序执行
+pub async fn get_swap_manager() -> Result<SwapManager, AppError> {
+    let pool = get_rpc_pool().await?;
+    let config = get_solana_config().await?;
+    let wallet_lock = lock_helper::get_distributed_lock().await;
+    SwapManager::from_pool(&pool, &config)
+        .map(|manager| {
+            manager
+                .with_hold_registry(Arc::new(CustodyHoldRegistry))
+                .with_wallet_lock(wallet_lock)
+        })
+        .map_err(AppError::from)
+}
+
+/// 获取系统钱包密钥对
+pub async fn get_system_keypair() -> Result<Keypair, AppError> {
+    let config = get_solana_config().await?;
+    keypair_from_base58(&config.system_wallet_private_key).map_err(AppError::from)
+}
+
+/// 替换运行中的系统钱包密钥
+///
+/// 只更新内存中的全局配置，新私钥已加密落库，重启时由密钥轮换服务恢复；
+/// 未配置 `solana` 段时返回 false
+pub async fn replace_system_keypair(keypair: &Keypair) -> bool {
+    let Some(config) = global::get_config::<SolanaConfig>().await else {
+        return false;
+    };
+
+    let mut config = (*config).clone();
+    config.system_wallet_private_key = keypair.to_base58_string();
+    global::init_config(config).await;
+    true
+}
+
+/// 用口令加密私钥
+///
+/// 密钥派生刻意放慢，放到阻塞线程池中执行
+pub async fn seal_keypair(keypair: &Keypair, passphrase: &str) -> Result<EncryptedKey, AppError> {
+    let keypair = keypair.insecure_clone();
+    let passphrase = passphrase.to_string();
+    tokio::task::spawn_blocking(move || keystore::seal(&keypair, &passphrase))
+        .await
+        .map_err(|e| AppError {
+            code: 500,
+            message: e.to_string(),
+        })?
+        .map_err(AppError::from)
+}
+
+/// 用口令解密私钥
+pub async fn open_keypair(encrypted: EncryptedKey, passphrase: &str) -> Result<Keypair, AppError> {
+    let passphrase = passphrase.to_string();
+    tokio::task::spawn_blocking(move || keystore::open(&encrypted, &passphrase))
+        .await
+        .map_err(|e| AppError {
+            code: 500,
+            message: e.to_string(),
+        })?
+        .map_err(AppError::from)
+}
+
+async fn get_keystore_passphrase() -> Result<String, AppError> {
+    let config = get_solana_config().await?;
+    if config.keystore_passphrase.is_empty() {
+        return Err(KeystoreError::KeystoreNotConfigured.into());
+    }
+    Ok(config.keystore_passphrase)
+}
+
+/// 用密钥库主口令加密托管钱包私钥，返回落库的密文
+pub async fn seal_custody_key(keypair: &Keypair) -> Result<String, AppError> {
+    let passphrase = get_keystore_passphrase().await?;
+    let encrypted = seal_keypair(keypair, &passphrase).await?;
+    serde_json::to_string(&encrypted).map_err(|e| AppError::from(SolanaError::from(e)))
+}
+
+/// 解密落库的托管钱包私钥
+pub async fn open_custody_key(encrypted_key: &str) -> Result<Keypair, AppError> {
+    let passphrase = get_keystore_passphrase().await?;
+    let encrypted: EncryptedKey =
+        serde_json::from_str(encrypted_key).map_err(|e| AppError::from(SolanaError::from(e)))?;
+    open_keypair(encrypted, &passphrase).await
+}
@@ -74,7 +74,7 @@ async fn main() -> Result<()> {
     println!("   目标代币账户: {}", user_target_token_account);
     
     // 7. 创建交换管理器
-    let swap_manager = SwapManager::from_config(&config);
+    let swap_manager = SwapManager::from_config(&config)?;
     println!("7. 交换管理器创建完成");
     
     // 8. 模拟购买稳定币并转换为目标代币
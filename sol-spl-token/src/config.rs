@@ -1,7 +1,12 @@
 //! Solana 配置模块
 
 use serde::{Deserialize, Serialize};
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::rpc_pool::RpcEndpointConfig;
 
 /// Solana 配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,6 +14,14 @@ pub struct SolanaConfig {
     /// RPC 端点 URL
     pub rpc_url: String,
     
+    /// RPC 端点池，为空时仅使用 `rpc_url`
+    #[serde(default)]
+    pub rpc_endpoints: Vec<RpcEndpointConfig>,
+    
+    /// 端点允许落后于最高 slot 的数量
+    #[serde(default = "default_rpc_max_slot_lag")]
+    pub rpc_max_slot_lag: u64,
+    
     /// WebSocket 端点 URL
     pub ws_url: String,
     
@@ -16,46 +29,94 @@ pub struct SolanaConfig {
     pub network: String,
     
     /// 系统钱包私钥（base58编码）
+    #[serde(default)]
     pub system_wallet_private_key: String,
     
+    /// 密钥库主口令，用于加密落库的托管钱包私钥
+    #[serde(default)]
+    pub keystore_passphrase: String,
+    
     /// 默认稳定币 mint 地址（如 USDC）
+    #[serde(default)]
     pub default_stablecoin_mint: String,
     
     /// 目标代币 mint 地址
+    #[serde(default)]
     pub target_token_mint: String,
     
     /// 交易确认超时（秒）
+    #[serde(default = "default_confirmation_timeout_secs")]
     pub confirmation_timeout_secs: u64,
     
     /// 最大重试次数
+    #[serde(default = "default_max_retries")]
     pub max_retries: u32,
+    
+    /// 价格预言机地址，为空时不校验报价（仅模拟模式允许）
+    #[serde(default)]
+    pub price_oracle_url: String,
+    
+    /// 报价相对参考价允许的最大价格冲击（bps）
+    #[serde(default = "default_max_price_impact_bps")]
+    pub max_price_impact_bps: u32,
+    
+    /// 报价与参考价的最大时效（秒）
+    #[serde(default = "default_max_quote_age_secs")]
+    pub max_quote_age_secs: u64,
+    
+    /// 按交易对配置的滑点容忍度，格式 `FROM_MINT:TO_MINT=0.005,...`
+    #[serde(default)]
+    pub pair_slippage: String,
+    
+    /// 可委托质押的验证者投票账户，逗号分隔
+    #[serde(default)]
+    pub stake_validators: String,
+}
+
+fn default_rpc_max_slot_lag() -> u64 {
+    50
+}
+
+fn default_confirmation_timeout_secs() -> u64 {
+    30
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_max_price_impact_bps() -> u32 {
+    100
+}
+
+fn default_max_quote_age_secs() -> u64 {
+    30
 }
 
 impl Default for SolanaConfig {
     fn default() -> Self {
         Self {
             rpc_url: "https://api.devnet.solana.com".to_string(),
+            rpc_endpoints: Vec::new(),
+            rpc_max_slot_lag: default_rpc_max_slot_lag(),
             ws_url: "wss://api.devnet.solana.com".to_string(),
             network: "devnet".to_string(),
             system_wallet_private_key: "".to_string(),
+            keystore_passphrase: "".to_string(),
             default_stablecoin_mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(), // USDC
             target_token_mint: "".to_string(),
-            confirmation_timeout_secs: 30,
-            max_retries: 3,
+            confirmation_timeout_secs: default_confirmation_timeout_secs(),
+            max_retries: default_max_retries(),
+            price_oracle_url: "".to_string(),
+            max_price_impact_bps: default_max_price_impact_bps(),
+            max_quote_age_secs: default_max_quote_age_secs(),
+            pair_slippage: "".to_string(),
+            stake_validators: "".to_string(),
         }
     }
 }
 
 impl SolanaConfig {
-    /// 从环境变量加载配置
-    pub fn from_env() -> Result<Self, crate::error::SolanaError> {
-        let config = envy::prefixed("SOLANA_")
-            .from_env::<Self>()
-            .map_err(|e| crate::error::SolanaError::ConfigError(e.to_string()))?;
-        
-        Ok(config)
-    }
-    
     /// 获取系统钱包公钥
     pub fn get_system_wallet_pubkey(&self) -> Result<Pubkey, crate::error::SolanaError> {
         if self.system_wallet_private_key.is_empty() {
@@ -64,8 +125,7 @@ impl SolanaConfig {
             ));
         }
         
-        let keypair = solana_sdk::signature::Keypair::from_base58_string(&self.system_wallet_private_key)
-            .map_err(|e| crate::error::SolanaError::ConfigError(e.to_string()))?;
+        let keypair = keypair_from_base58(&self.system_wallet_private_key)?;
         
         Ok(keypair.pubkey())
     }
@@ -87,6 +147,32 @@ impl SolanaConfig {
         Pubkey::from_str(&self.target_token_mint)
             .map_err(|e| crate::error::SolanaError::ConfigError(e.to_string()))
     }
+    
+    /// 获取可委托质押的验证者投票账户
+    pub fn get_stake_validators(&self) -> Result<Vec<Pubkey>, crate::error::SolanaError> {
+        self.stake_validators
+            .split(',')
+            .map(str::trim)
+            .filter(|vote| !vote.is_empty())
+            .map(|vote| {
+                Pubkey::from_str(vote).map_err(|e| {
+                    crate::error::SolanaError::ConfigError(format!("{}: {}", vote, e))
+                })
+            })
+            .collect()
+    }
+}
+
+/// 解析 base58 编码的私钥
+///
+/// `Keypair::from_base58_string` 在输入非法时会直接 panic，配置类输入统一走这里
+pub fn keypair_from_base58(encoded: &str) -> Result<Keypair, crate::error::SolanaError> {
+    let bytes = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|e| crate::error::SolanaError::ConfigError(e.to_string()))?;
+    
+    Keypair::try_from(bytes.as_slice())
+        .map_err(|e| crate::error::SolanaError::ConfigError(e.to_string()))
 }
 
 use std::str::FromStr;
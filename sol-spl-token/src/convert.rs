@@ -0,0 +1,672 @@
+//! 入金自动兑换模块
+//!
+//! 按策略将入金的一部分兑换为目标代币：
+//! 1. 策略决定哪些币种触发兑换、兑换比例和最小交易额
+//! 2. 每一腿交换单独记录，失败按策略重试
+//! 3. 前一腿确认落地后才会开始下一腿，下一腿只花费前一腿实际到账的数量
+//! 4. dry-run 模式只报价、不发送交易
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use std::{sync::Arc, time::Duration};
+
+use crate::error::{Result, SolanaError};
+use crate::swap::{SwapManager, SwapResult};
+
+/// 万分比基数
+const BPS_DENOMINATOR: u64 = 10_000;
+
+/// 自动兑换策略
+#[derive(Debug, Clone)]
+pub struct AutoConvertPolicy {
+    /// 触发兑换的入金币种
+    pub source_mints: Vec<Pubkey>,
+
+    /// 中间稳定币 mint
+    pub stablecoin_mint: Pubkey,
+
+    /// 目标代币 mint
+    pub target_token_mint: Pubkey,
+
+    /// 兑换比例（万分比，10000 表示全部兑换）
+    pub convert_bps: u16,
+
+    /// 最小交易额（按入金币种最小单位），低于此值不兑换
+    pub min_trade_amount: u64,
+
+    /// 每一腿允许的最大滑点（如 0.01 表示 1%）
+    pub max_slippage: f64,
+
+    /// 只报价、不发送交易
+    pub dry_run: bool,
+
+    /// 每一腿的最大重试次数
+    pub max_retries: u32,
+
+    /// 重试基础间隔，按尝试次数线性递增
+    pub retry_backoff: Duration,
+}
+
+impl AutoConvertPolicy {
+    /// 校验策略参数
+    pub fn validate(&self) -> Result<()> {
+        if self.convert_bps == 0 || u64::from(self.convert_bps) > BPS_DENOMINATOR {
+            return Err(SolanaError::ConfigError(format!(
+                "convert_bps must be within 1..=10000, got {}",
+                self.convert_bps
+            )));
+        }
+
+        if !(0.0..1.0).contains(&self.max_slippage) {
+            return Err(SolanaError::ConfigError(format!(
+                "max_slippage must be within [0, 1), got {}",
+                self.max_slippage
+            )));
+        }
+
+        if self.stablecoin_mint == self.target_token_mint {
+            return Err(SolanaError::ConfigError(
+                "Stablecoin and target token must differ".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// 计算一笔入金应兑换的数量，不满足策略时返回跳过原因
+    pub fn convert_amount(&self, deposit: &ConvertDeposit) -> std::result::Result<u64, String> {
+        if !self.source_mints.contains(&deposit.mint) {
+            return Err(format!("mint {} is not a source mint", deposit.mint));
+        }
+
+        if deposit.mint == self.target_token_mint {
+            return Err("deposit is already the target token".to_string());
+        }
+
+        let amount = (u128::from(deposit.amount) * u128::from(self.convert_bps)
+            / u128::from(BPS_DENOMINATOR)) as u64;
+
+        if amount < self.min_trade_amount {
+            return Err(format!(
+                "convert amount {} is below minimum trade size {}",
+                amount, self.min_trade_amount
+            ));
+        }
+
+        Ok(amount)
+    }
+
+    /// 规划兑换路径：入金即为稳定币时只需一腿
+    pub fn plan_legs(&self, source_mint: &Pubkey) -> Vec<(Pubkey, Pubkey)> {
+        if *source_mint == self.stablecoin_mint {
+            vec![(self.stablecoin_mint, self.target_token_mint)]
+        } else {
+            vec![
+                (*source_mint, self.stablecoin_mint),
+                (self.stablecoin_mint, self.target_token_mint),
+            ]
+        }
+    }
+}
+
+/// 触发兑换的入金
+#[derive(Debug, Clone)]
+pub struct ConvertDeposit {
+    /// 入金唯一标识（如入金交易签名）
+    pub deposit_id: String,
+
+    /// 入金币种
+    pub mint: Pubkey,
+
+    /// 入金数量
+    pub amount: u64,
+}
+
+/// 单腿执行状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LegStatus {
+    /// 仅报价
+    DryRun,
+    /// 已在链上确认
+    Landed,
+    /// 本次尝试失败
+    Failed,
+}
+
+/// 单腿交换记录，每次尝试一条
+#[derive(Debug, Clone)]
+pub struct ConvertLegRecord {
+    /// 所属兑换任务 ID
+    pub job_id: String,
+
+    /// 腿序号，从 0 开始
+    pub leg_index: u32,
+
+    /// 尝试次数，从 1 开始
+    pub attempt: u32,
+
+    pub from_mint: Pubkey,
+    pub to_mint: Pubkey,
+    pub from_amount: u64,
+    pub to_amount: u64,
+    pub min_to_amount: u64,
+    pub slippage: f64,
+
+    /// 实际成交价格与执行前的参考价格
+    pub execution_price: Option<f64>,
+    pub oracle_price: Option<f64>,
+    pub price_impact_bps: Option<u32>,
+
+    /// 交易签名，发送前失败时为空
+    pub signature: Option<String>,
+
+    pub status: LegStatus,
+    pub error: Option<String>,
+}
+
+/// 兑换记录存储 trait
+#[async_trait]
+pub trait AutoConvertStorage: Send + Sync {
+    /// 保存单腿记录
+    async fn save_leg(&self, record: &ConvertLegRecord) -> Result<()>;
+}
+
+/// 兑换结果
+#[derive(Debug, Clone)]
+pub enum ConvertOutcome {
+    /// 不满足策略，未兑换
+    Skipped(String),
+
+    /// 所有腿均已完成（dry-run 时为报价结果）
+    Completed {
+        legs: Vec<SwapResult>,
+        dry_run: bool,
+    },
+}
+
+/// 兑换对用户余额的结算：扣减源资产，入账目标资产
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvertSettlement {
+    pub from_mint: Pubkey,
+    pub from_amount: u64,
+    pub to_mint: Pubkey,
+    pub to_amount: u64,
+}
+
+impl ConvertOutcome {
+    /// 需要记入用户余额的结算
+    ///
+    /// 只有实际上链的兑换才结算；dry-run 与任一腿为模拟交易时返回 None，避免凭空增减余额
+    pub fn settlement(&self) -> Option<ConvertSettlement> {
+        let ConvertOutcome::Completed { legs, dry_run } = self else {
+            return None;
+        };
+        if *dry_run || legs.iter().any(|leg| leg.is_simulation) {
+            return None;
+        }
+        let (first, last) = (legs.first()?, legs.last()?);
+        Some(ConvertSettlement {
+            from_mint: first.from_token,
+            from_amount: first.from_amount,
+            to_mint: last.to_token,
+            to_amount: last.to_amount,
+        })
+    }
+}
+
+/// 自动兑换执行器
+pub struct AutoConverter {
+    swap_manager: Arc<SwapManager>,
+    storage: Option<Arc<dyn AutoConvertStorage>>,
+}
+
+impl AutoConverter {
+    /// 创建执行器
+    pub fn new(swap_manager: Arc<SwapManager>) -> Self {
+        Self {
+            swap_manager,
+            storage: None,
+        }
+    }
+
+    /// 设置单腿记录存储
+    pub fn with_storage(mut self, storage: Arc<dyn AutoConvertStorage>) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// 按策略处理一笔入金
+    pub async fn run(
+        &self,
+        job_id: &str,
+        policy: &AutoConvertPolicy,
+        keypair: &Keypair,
+        deposit: &ConvertDeposit,
+    ) -> Result<ConvertOutcome> {
+        policy.validate()?;
+        // 模拟模式的交换不会上链，非 dry-run 执行会产生不存在的成交
+        if !policy.dry_run && self.swap_manager.is_simulation() {
+            return Err(SolanaError::SwapError(
+                "Swap manager is in simulation mode, only dry-run conversions are allowed".to_string(),
+            ));
+        }
+
+        let mut amount = match policy.convert_amount(deposit) {
+            Ok(amount) => amount,
+            Err(reason) => return Ok(ConvertOutcome::Skipped(reason)),
+        };
+
+        let mut legs = Vec::new();
+        for (leg_index, (from_mint, to_mint)) in
+            policy.plan_legs(&deposit.mint).into_iter().enumerate()
+        {
+            let result = self
+                .run_leg(job_id, leg_index as u32, policy, keypair, &from_mint, &to_mint, amount)
+                .await?;
+
+            if !policy.dry_run && result.is_simulation {
+                return Err(SolanaError::SwapError(format!(
+                    "Leg {} was simulated in a live conversion",
+                    leg_index
+                )));
+            }
+            if result.to_amount == 0 {
+                return Err(SolanaError::SwapError(format!(
+                    "Leg {} produced no output",
+                    leg_index
+                )));
+            }
+
+            // 下一腿只花费本腿实际到账的数量
+            amount = result.to_amount;
+            legs.push(result);
+        }
+
+        Ok(ConvertOutcome::Completed {
+            legs,
+            dry_run: policy.dry_run,
+        })
+    }
+
+    /// 执行单腿交换，返回前确保交易已落地，返回结果的产出为实际到账数量
+    #[allow(clippy::too_many_arguments)]
+    async fn run_leg(
+        &self,
+        job_id: &str,
+        leg_index: u32,
+        policy: &AutoConvertPolicy,
+        keypair: &Keypair,
+        from_mint: &Pubkey,
+        to_mint: &Pubkey,
+        amount: u64,
+    ) -> Result<SwapResult> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+
+            let executed = if policy.dry_run {
+                self.swap_manager
+                    .quote_swap(keypair, from_mint, to_mint, amount, Some(policy.max_slippage))
+                    .await
+            } else {
+                self.swap_manager
+                    .execute_swap(keypair, from_mint, to_mint, amount, Some(policy.max_slippage))
+                    .await
+            };
+
+            let outcome = match executed {
+                Ok(result) => match Self::check_slippage(policy, &result) {
+                    Ok(()) if policy.dry_run => Ok(result),
+                    Ok(()) => match self.swap_manager.confirm_swap_landed(&result).await {
+                        Ok(()) => Ok(result),
+                        Err(e) => Err((Some(result.signature.clone()), e)),
+                    },
+                    Err(e) => Err((None, e)),
+                },
+                Err(e) => Err((None, e)),
+            };
+
+            match outcome {
+                Ok(result) => {
+                    let status = if policy.dry_run {
+                        LegStatus::DryRun
+                    } else {
+                        LegStatus::Landed
+                    };
+                    // 交易已落地，读取到账失败时仍按落地记录，任务失败后需人工处理
+                    let received = self
+                        .swap_manager
+                        .received_amount(&result, &keypair.pubkey())
+                        .await;
+                    let (to_amount, error) = match &received {
+                        Ok(received) => (*received, None),
+                        Err(e) => (result.to_amount, Some(e.to_string())),
+                    };
+                    self.record(ConvertLegRecord {
+                        job_id: job_id.to_string(),
+                        leg_index,
+                        attempt,
+                        from_mint: *from_mint,
+                        to_mint: *to_mint,
+                        from_amount: result.from_amount,
+                        to_amount,
+                        min_to_amount: result.min_to_amount,
+                        slippage: result.slippage,
+                        execution_price: Some(result.execution_price),
+                        oracle_price: result.oracle_price,
+                        price_impact_bps: result.price_impact_bps,
+                        signature: Some(result.signature.clone()),
+                        status,
+                        error,
+                    })
+                    .await?;
+                    return Ok(SwapResult {
+                        to_amount: received?,
+                        ..result
+                    });
+                }
+                Err((signature, e)) => {
+                    let retryable = signature.is_none() && is_retryable(&e);
+                    self.record(ConvertLegRecord {
+                        job_id: job_id.to_string(),
+                        leg_index,
+                        attempt,
+                        from_mint: *from_mint,
+                        to_mint: *to_mint,
+                        from_amount: amount,
+                        to_amount: 0,
+                        min_to_amount: 0,
+                        slippage: policy.max_slippage,
+                        execution_price: None,
+                        oracle_price: None,
+                        price_impact_bps: None,
+                        signature,
+                        status: LegStatus::Failed,
+                        error: Some(e.to_string()),
+                    })
+                    .await?;
+
+                    if !retryable || attempt > policy.max_retries {
+                        return Err(e);
+                    }
+
+                    tracing::warn!(
+                        "Auto-convert job {} leg {} attempt {} failed, retrying: {}",
+                        job_id,
+                        leg_index,
+                        attempt,
+                        e
+                    );
+                    tokio::time::sleep(policy.retry_backoff * attempt).await;
+                }
+            }
+        }
+    }
+
+    /// 滑点保护：成交的最小产出不得低于按独立参考价格计算的下限
+    ///
+    /// 下限以参考价格而不是报价产出推算，报价本身偏离时也能拦截；
+    /// 只有模拟交易允许没有参考价格
+    fn check_slippage(policy: &AutoConvertPolicy, result: &SwapResult) -> Result<()> {
+        if result.slippage > policy.max_slippage {
+            return Err(SolanaError::SwapError(format!(
+                "Swap slippage {} exceeds policy limit {}",
+                result.slippage, policy.max_slippage
+            )));
+        }
+
+        let Some(oracle_price) = result.oracle_price else {
+            if result.is_simulation {
+                return Ok(());
+            }
+            return Err(SolanaError::QuoteRejected(
+                "No independent reference price for slippage check".to_string(),
+            ));
+        };
+
+        let floor = (result.from_amount as f64 * oracle_price * (1.0 - policy.max_slippage)) as u64;
+        if result.min_to_amount < floor {
+            return Err(SolanaError::SwapError(format!(
+                "Minimum output {} is below slippage floor {} at reference price {}",
+                result.min_to_amount, floor, oracle_price
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn record(&self, record: ConvertLegRecord) -> Result<()> {
+        if let Some(storage) = &self.storage {
+            storage.save_leg(&record).await?;
+        }
+        Ok(())
+    }
+}
+
+/// 只有交易未发出前的错误可以安全重试；已发出但未确认的交易重发可能导致重复兑换
+fn is_retryable(error: &SolanaError) -> bool {
+    matches!(
+        error,
+        SolanaError::RpcError(_) | SolanaError::SendError(_) | SolanaError::StaleQuote(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::swap::DexConfig;
+    use tokio::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryStorage {
+        legs: Mutex<Vec<ConvertLegRecord>>,
+    }
+
+    #[async_trait]
+    impl AutoConvertStorage for MemoryStorage {
+        async fn save_leg(&self, record: &ConvertLegRecord) -> Result<()> {
+            self.legs.lock().await.push(record.clone());
+            Ok(())
+        }
+    }
+
+    fn policy(sol: Pubkey, usdc: Pubkey, target: Pubkey) -> AutoConvertPolicy {
+        AutoConvertPolicy {
+            source_mints: vec![sol, usdc],
+            stablecoin_mint: usdc,
+            target_token_mint: target,
+            convert_bps: 5_000,
+            min_trade_amount: 100,
+            max_slippage: 0.01,
+            dry_run: true,
+            max_retries: 2,
+            retry_backoff: Duration::from_millis(1),
+        }
+    }
+
+    #[test]
+    fn convert_amount_applies_ratio_and_minimum() {
+        let (sol, usdc, target) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let policy = policy(sol, usdc, target);
+
+        let deposit = ConvertDeposit {
+            deposit_id: "d1".to_string(),
+            mint: sol,
+            amount: 1_000,
+        };
+        assert_eq!(policy.convert_amount(&deposit), Ok(500));
+
+        let small = ConvertDeposit { amount: 150, ..deposit.clone() };
+        assert!(policy.convert_amount(&small).is_err());
+
+        let other = ConvertDeposit { mint: Pubkey::new_unique(), ..deposit };
+        assert!(policy.convert_amount(&other).is_err());
+    }
+
+    #[test]
+    fn stablecoin_deposit_needs_single_leg() {
+        let (sol, usdc, target) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let policy = policy(sol, usdc, target);
+
+        assert_eq!(policy.plan_legs(&usdc), vec![(usdc, target)]);
+        assert_eq!(policy.plan_legs(&sol), vec![(sol, usdc), (usdc, target)]);
+    }
+
+    #[test]
+    fn invalid_policy_is_rejected() {
+        let (sol, usdc, target) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut policy = policy(sol, usdc, target);
+        policy.convert_bps = 10_001;
+        assert!(policy.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn dry_run_records_every_leg() {
+        let (sol, usdc, target) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let policy = policy(sol, usdc, target);
+        let storage = Arc::new(MemoryStorage::default());
+        let converter = AutoConverter::new(Arc::new(SwapManager::new(DexConfig::default())))
+            .with_storage(storage.clone());
+
+        let deposit = ConvertDeposit {
+            deposit_id: "d1".to_string(),
+            mint: sol,
+            amount: 10_000,
+        };
+        let outcome = converter
+            .run("job-1", &policy, &Keypair::new(), &deposit)
+            .await
+            .unwrap();
+
+        match outcome {
+            ConvertOutcome::Completed { legs, dry_run } => {
+                assert!(dry_run);
+                assert_eq!(legs.len(), 2);
+                assert_eq!(legs[0].from_amount, 5_000);
+                assert_eq!(legs[1].from_amount, legs[0].to_amount);
+            }
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+
+        let legs = storage.legs.lock().await;
+        assert_eq!(legs.len(), 2);
+        assert!(legs.iter().all(|leg| leg.status == LegStatus::DryRun));
+    }
+
+    fn swap_result(min_to_amount: u64, oracle_price: Option<f64>) -> SwapResult {
+        SwapResult {
+            from_token: Pubkey::new_unique(),
+            to_token: Pubkey::new_unique(),
+            from_amount: 1_000,
+            to_amount: 1_000,
+            min_to_amount,
+            slippage: 0.01,
+            signature: "sig".to_string(),
+            is_simulation: false,
+            execution_price: 1.0,
+            oracle_price,
+            price_impact_bps: None,
+        }
+    }
+
+    #[test]
+    fn slippage_floor_uses_reference_price() {
+        let (sol, usdc, target) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let policy = policy(sol, usdc, target);
+
+        // 报价自洽，但参考价格下应得 2000，最小产出远低于下限
+        let result = swap_result(990, Some(2.0));
+        assert!(AutoConverter::check_slippage(&policy, &result).is_err());
+
+        let result = swap_result(990, Some(1.0));
+        assert!(AutoConverter::check_slippage(&policy, &result).is_ok());
+
+        let result = swap_result(990, None);
+        assert!(AutoConverter::check_slippage(&policy, &result).is_err());
+        let simulated = SwapResult {
+            is_simulation: true,
+            ..result
+        };
+        assert!(AutoConverter::check_slippage(&policy, &simulated).is_ok());
+    }
+
+    #[tokio::test]
+    async fn live_conversion_is_refused_in_simulation_mode() {
+        let (sol, usdc, target) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut policy = policy(sol, usdc, target);
+        policy.dry_run = false;
+        let storage = Arc::new(MemoryStorage::default());
+        let converter = AutoConverter::new(Arc::new(SwapManager::new(DexConfig::default())))
+            .with_storage(storage.clone());
+
+        let deposit = ConvertDeposit {
+            deposit_id: "d1".to_string(),
+            mint: sol,
+            amount: 10_000,
+        };
+        assert!(converter
+            .run("job-1", &policy, &Keypair::new(), &deposit)
+            .await
+            .is_err());
+        assert!(storage.legs.lock().await.is_empty());
+    }
+
+    #[test]
+    fn simulated_leg_is_not_settled() {
+        let landed = swap_result(1_000, Some(1.0));
+        let outcome = ConvertOutcome::Completed {
+            legs: vec![landed.clone()],
+            dry_run: false,
+        };
+        assert_eq!(
+            outcome.settlement(),
+            Some(ConvertSettlement {
+                from_mint: landed.from_token,
+                from_amount: 1_000,
+                to_mint: landed.to_token,
+                to_amount: 1_000,
+            })
+        );
+
+        let simulated = SwapResult {
+            is_simulation: true,
+            ..swap_result(1_000, Some(1.0))
+        };
+        let outcome = ConvertOutcome::Completed {
+            legs: vec![landed.clone(), simulated],
+            dry_run: false,
+        };
+        assert_eq!(outcome.settlement(), None);
+
+        let outcome = ConvertOutcome::Completed {
+            legs: vec![landed],
+            dry_run: true,
+        };
+        assert_eq!(outcome.settlement(), None);
+    }
+
+    #[tokio::test]
+    async fn unconfirmed_swap_is_not_treated_as_landed() {
+        let manager = SwapManager::new(DexConfig::default());
+        let result = SwapResult {
+            from_token: Pubkey::new_unique(),
+            to_token: Pubkey::new_unique(),
+            from_amount: 1,
+            to_amount: 1,
+            min_to_amount: 1,
+            slippage: 0.0,
+            signature: "not-a-signature".to_string(),
+            is_simulation: false,
+            execution_price: 1.0,
+            oracle_price: None,
+            price_impact_bps: None,
+        };
+
+        assert!(manager.confirm_swap_landed(&result).await.is_err());
+    }
+}
@@ -49,6 +49,74 @@ pub enum SolanaError {
     #[error("Swap error: {0}")]
     SwapError(String),
 
+    /// Token 账户已冻结
+    #[error("Account frozen: {0}")]
+    AccountFrozen(String),
+
+    /// 托管钱包已被冻结，禁止转出与兑换
+    #[error("Account on hold: {0}")]
+    AccountOnHold(String),
+
+    /// 钱包正被其他交易占用（等待加锁超时或持有的锁已失效）
+    #[error("Wallet busy: {0}")]
+    WalletBusy(String),
+
+    /// 成交价超出滑点容忍度
+    #[error("Slippage exceeded: {0}")]
+    SlippageExceeded(String),
+
+    /// 区块哈希过期或不存在，可重新签名后重试
+    #[error("Blockhash not found: {0}")]
+    BlockhashNotFound(String),
+
+    /// 交易模拟失败（未能归类的程序错误）
+    #[error("Transaction simulation failed: {message}")]
+    SimulationFailed { message: String, logs: Vec<String> },
+
+    /// 报价未通过校验（价格冲击过大或缺少参考价）
+    #[error("Quote rejected: {0}")]
+    QuoteRejected(String),
+
+    /// 报价或参考价已过期
+    #[error("Stale quote: {0}")]
+    StaleQuote(String),
+
+    /// 风险筛查服务错误
+    #[error("Risk screening error: {0}")]
+    RiskScreeningError(String),
+
+    /// 风险筛查拒绝转出
+    #[error("Transfer denied by risk screening: {0}")]
+    RiskDenied(String),
+
+    /// 风险筛查要求人工复核，转出按拒绝处理
+    #[error("Transfer rejected pending manual review: {0}")]
+    RiskReviewRejected(String),
+
+    /// 储备金证明错误
+    #[error("Proof of reserves error: {0}")]
+    ReservesError(String),
+
+    /// 地址查找表错误
+    #[error("Address lookup table error: {0}")]
+    LookupTableError(String),
+
+    /// Solana Pay 请求或二维码错误
+    #[error("Solana Pay error: {0}")]
+    SolanaPayError(String),
+
+    /// 质押账户操作错误
+    #[error("Stake error: {0}")]
+    StakeError(String),
+
+    /// 密钥库加密或解密错误
+    #[error("Keystore error: {0}")]
+    KeystoreError(String),
+
+    /// 分布式锁存储不可用
+    #[error("Lock error: {0}")]
+    LockError(String),
+
     /// 序列化/反序列化错误
     #[error("Serialization error: {0}")]
     SerializationError(String),
@@ -58,6 +126,13 @@ pub enum SolanaError {
     Other(String),
 }
 
+impl SolanaError {
+    /// 交易可能已经广播，成败未知，需按签名核对链上状态后再判定
+    pub fn is_outcome_unknown(&self) -> bool {
+        matches!(self, SolanaError::SendError(_) | SolanaError::ConfirmationError(_))
+    }
+}
+
 impl From<solana_client::client_error::ClientError> for SolanaError {
     fn from(err: solana_client::client_error::ClientError) -> Self {
         SolanaError::RpcError(err.to_string())
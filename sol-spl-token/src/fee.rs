@@ -0,0 +1,201 @@
+//! 提现手续费模块
+//!
+//! 手续费从提现金额中扣除，收款方实际到账 `amount - fee`。支持三种计费方式：
+//! 1. 固定金额
+//! 2. 按比例（基点，向上取整）
+//! 3. 按用户角色分档，命中多个档位时取费用最低的一档，未命中时按基础费率计费
+//!
+//! 计算结果统一受最低/最高手续费约束
+
+use serde::{Deserialize, Serialize};
+
+/// 基点分母
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// 计费方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeKind {
+    /// 固定金额
+    Flat,
+    /// 按比例
+    Percentage,
+    /// 按用户角色分档
+    Tiered,
+}
+
+/// 费率：固定部分加比例部分
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeRate {
+    /// 固定费用（最小单位）
+    pub flat: u64,
+
+    /// 比例费用（基点）
+    pub bps: u16,
+}
+
+impl FeeRate {
+    /// 按比例计算的部分向上取整，避免小额提现不收费
+    pub fn apply(&self, amount: u64) -> u64 {
+        let proportional = (amount as u128 * self.bps as u128).div_ceil(BPS_DENOMINATOR);
+        u64::try_from(proportional)
+            .unwrap_or(u64::MAX)
+            .saturating_add(self.flat)
+    }
+}
+
+/// 角色档位
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeTier {
+    /// 角色编码
+    pub role: String,
+
+    /// 该角色适用的费率
+    pub rate: FeeRate,
+}
+
+/// 手续费规则
+#[derive(Debug, Clone)]
+pub struct FeeSchedule {
+    /// 计费方式
+    pub kind: FeeKind,
+
+    /// 基础费率；固定计费只取 `flat`，按比例计费只取 `bps`
+    pub rate: FeeRate,
+
+    /// 角色档位，仅分档计费使用
+    pub tiers: Vec<FeeTier>,
+
+    /// 最低手续费
+    pub min_fee: u64,
+
+    /// 最高手续费
+    pub max_fee: Option<u64>,
+}
+
+/// 手续费报价
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeQuote {
+    /// 提现金额
+    pub amount: u64,
+
+    /// 手续费
+    pub fee: u64,
+
+    /// 收款方实际到账金额
+    pub net_amount: u64,
+
+    /// 命中的角色档位
+    pub tier: Option<String>,
+}
+
+impl FeeSchedule {
+    /// 固定手续费规则
+    pub fn flat(fee: u64) -> Self {
+        Self {
+            kind: FeeKind::Flat,
+            rate: FeeRate { flat: fee, bps: 0 },
+            tiers: Vec::new(),
+            min_fee: 0,
+            max_fee: None,
+        }
+    }
+
+    /// 计算提现手续费，手续费不低于提现金额时返回 `None`
+    pub fn quote(&self, amount: u64, roles: &[String]) -> Option<FeeQuote> {
+        let (fee, tier) = match self.kind {
+            FeeKind::Flat => (self.rate.flat, None),
+            FeeKind::Percentage => (
+                FeeRate {
+                    flat: 0,
+                    bps: self.rate.bps,
+                }
+                .apply(amount),
+                None,
+            ),
+            FeeKind::Tiered => self
+                .tiers
+                .iter()
+                .filter(|tier| roles.contains(&tier.role))
+                .map(|tier| (tier.rate.apply(amount), Some(tier.role.clone())))
+                .min_by_key(|(fee, _)| *fee)
+                .unwrap_or((self.rate.apply(amount), None)),
+        };
+
+        let fee = fee.max(self.min_fee);
+        let fee = self.max_fee.map_or(fee, |max_fee| fee.min(max_fee));
+
+        if fee >= amount {
+            return None;
+        }
+
+        Some(FeeQuote {
+            amount,
+            fee,
+            net_amount: amount - fee,
+            tier,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roles(roles: &[&str]) -> Vec<String> {
+        roles.iter().map(|role| role.to_string()).collect()
+    }
+
+    #[test]
+    fn test_flat_and_percentage() {
+        let quote = FeeSchedule::flat(1_000).quote(50_000, &[]).unwrap();
+        assert_eq!(quote.fee, 1_000);
+        assert_eq!(quote.net_amount, 49_000);
+        assert!(FeeSchedule::flat(1_000).quote(1_000, &[]).is_none());
+
+        let schedule = FeeSchedule {
+            kind: FeeKind::Percentage,
+            rate: FeeRate { flat: 500, bps: 25 },
+            tiers: Vec::new(),
+            min_fee: 10,
+            max_fee: Some(2_000),
+        };
+        // 比例部分向上取整，固定部分被忽略
+        assert_eq!(schedule.quote(10_001, &[]).unwrap().fee, 26);
+        assert_eq!(schedule.quote(1_000, &[]).unwrap().fee, 10);
+        assert_eq!(schedule.quote(10_000_000, &[]).unwrap().fee, 2_000);
+    }
+
+    #[test]
+    fn test_tiered_by_role() {
+        let schedule = FeeSchedule {
+            kind: FeeKind::Tiered,
+            rate: FeeRate { flat: 100, bps: 50 },
+            tiers: vec![
+                FeeTier {
+                    role: "R_VIP".to_string(),
+                    rate: FeeRate { flat: 0, bps: 10 },
+                },
+                FeeTier {
+                    role: "R_PARTNER".to_string(),
+                    rate: FeeRate { flat: 50, bps: 0 },
+                },
+            ],
+            min_fee: 0,
+            max_fee: None,
+        };
+
+        let quote = schedule.quote(100_000, &roles(&["R_USER"])).unwrap();
+        assert_eq!((quote.fee, quote.tier), (600, None));
+
+        let quote = schedule.quote(100_000, &roles(&["R_VIP"])).unwrap();
+        assert_eq!((quote.fee, quote.tier.as_deref()), (100, Some("R_VIP")));
+
+        // 同时命中多个档位取费用最低的一档
+        let quote = schedule
+            .quote(100_000, &roles(&["R_VIP", "R_PARTNER"]))
+            .unwrap();
+        assert_eq!((quote.fee, quote.tier.as_deref()), (50, Some("R_PARTNER")));
+    }
+}
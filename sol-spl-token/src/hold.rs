@@ -0,0 +1,164 @@
+//! 托管冻结模块
+//!
+//! 合规调查或争议处理期间，对托管钱包设置冻结（hold）：
+//! 冻结期间该钱包的所有转出与兑换都被拒绝，入金不受影响。
+//!
+//! 冻结记录由调用方维护（通常落库），通过 [`HoldRegistry`] 提供给
+//! `TokenManager` / `WalletManager` / `SwapManager` 在签名前查询
+
+use async_trait::async_trait;
+use solana_sdk::pubkey::Pubkey;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::error::{Result, SolanaError};
+
+/// 冻结记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hold {
+    /// 冻结原因
+    pub reason: String,
+
+    /// 到期时间（Unix 秒），None 表示直到人工解除
+    pub expires_at: Option<i64>,
+}
+
+impl Hold {
+    /// 在给定时刻是否仍然生效
+    pub fn is_active_at(&self, now: i64) -> bool {
+        self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+}
+
+/// 冻结记录查询 trait
+#[async_trait]
+pub trait HoldRegistry: Send + Sync {
+    /// 查询钱包当前的冻结记录，未冻结返回 None
+    async fn find_hold(&self, wallet: &Pubkey) -> Result<Option<Hold>>;
+}
+
+/// 内存冻结名单
+#[derive(Default)]
+pub struct InMemoryHoldRegistry {
+    holds: RwLock<HashMap<Pubkey, Hold>>,
+}
+
+impl InMemoryHoldRegistry {
+    /// 创建空名单
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 冻结钱包，已冻结时覆盖原记录
+    pub fn place(&self, wallet: Pubkey, hold: Hold) {
+        self.holds
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(wallet, hold);
+    }
+
+    /// 解除冻结
+    pub fn release(&self, wallet: &Pubkey) -> Option<Hold> {
+        self.holds
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(wallet)
+    }
+}
+
+#[async_trait]
+impl HoldRegistry for InMemoryHoldRegistry {
+    async fn find_hold(&self, wallet: &Pubkey) -> Result<Option<Hold>> {
+        Ok(self
+            .holds
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(wallet)
+            .cloned())
+    }
+}
+
+/// 签名前检查转出钱包是否被冻结
+///
+/// 未配置冻结名单时直接放行；已过期的冻结视为解除
+pub async fn ensure_not_held(
+    registry: Option<&Arc<dyn HoldRegistry>>,
+    wallet: &Pubkey,
+) -> Result<()> {
+    let Some(registry) = registry else {
+        return Ok(());
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+
+    match registry.find_hold(wallet).await? {
+        Some(hold) if hold.is_active_at(now) => Err(SolanaError::AccountOnHold(format!(
+            "{}: {}",
+            wallet, hold.reason
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hold_expiry() {
+        let hold = Hold {
+            reason: "dispute".to_string(),
+            expires_at: Some(1_000),
+        };
+
+        assert!(hold.is_active_at(999));
+        assert!(!hold.is_active_at(1_000));
+        assert!(Hold {
+            expires_at: None,
+            ..hold
+        }
+        .is_active_at(i64::MAX));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_not_held() {
+        let wallet = Pubkey::new_unique();
+        let registry = Arc::new(InMemoryHoldRegistry::new());
+        let dyn_registry: Arc<dyn HoldRegistry> = registry.clone();
+
+        assert!(ensure_not_held(None, &wallet).await.is_ok());
+        assert!(ensure_not_held(Some(&dyn_registry), &wallet).await.is_ok());
+
+        registry.place(
+            wallet,
+            Hold {
+                reason: "compliance review".to_string(),
+                expires_at: None,
+            },
+        );
+        let err = ensure_not_held(Some(&dyn_registry), &wallet)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, SolanaError::AccountOnHold(ref msg) if msg.contains("compliance review"))
+        );
+
+        registry.place(
+            wallet,
+            Hold {
+                reason: "expired".to_string(),
+                expires_at: Some(0),
+            },
+        );
+        assert!(ensure_not_held(Some(&dyn_registry), &wallet).await.is_ok());
+
+        registry.release(&wallet);
+        assert!(ensure_not_held(Some(&dyn_registry), &wallet).await.is_ok());
+    }
+}
@@ -0,0 +1,205 @@
+//! 加密密钥库模块
+//!
+//! 托管私钥只以密文形式落库或交付：口令经 PBKDF2-HMAC-SHA256 派生出
+//! AES-256-GCM 密钥，钱包地址作为附加认证数据，密文无法挪用到其他地址。
+//!
+//! 同一格式既用于服务端主口令加密的密钥库，也用于用户退出托管时
+//! 以用户自设口令加密的一次性导出文件
+
+use std::num::NonZeroU32;
+
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+use serde::{Deserialize, Serialize};
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::error::{Result, SolanaError};
+
+/// 当前密文格式版本
+pub const KEYSTORE_VERSION: u8 = 1;
+
+/// 密钥派生算法标识
+pub const KEYSTORE_KDF: &str = "pbkdf2-hmac-sha256";
+
+/// 密钥派生迭代次数
+pub const KEYSTORE_PBKDF2_ITERATIONS: u32 = 210_000;
+
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+const TOKEN_LEN: usize = 32;
+
+/// 加密后的私钥
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedKey {
+    /// 格式版本
+    pub version: u8,
+
+    /// 钱包地址（同时作为附加认证数据）
+    pub address: String,
+
+    /// 密钥派生算法
+    pub kdf: String,
+
+    /// 密钥派生迭代次数
+    pub iterations: u32,
+
+    /// 盐（base58）
+    pub salt: String,
+
+    /// AES-GCM nonce（base58）
+    pub nonce: String,
+
+    /// 密文与认证标签（base58）
+    pub ciphertext: String,
+}
+
+fn keystore_error(message: impl Into<String>) -> SolanaError {
+    SolanaError::KeystoreError(message.into())
+}
+
+fn random_bytes<const N: usize>(rng: &SystemRandom) -> Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    rng.fill(&mut bytes)
+        .map_err(|_| keystore_error("system random source unavailable"))?;
+    Ok(bytes)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> Result<LessSafeKey> {
+    let iterations =
+        NonZeroU32::new(iterations).ok_or_else(|| keystore_error("iterations must be positive"))?;
+
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+
+    UnboundKey::new(&AES_256_GCM, &key)
+        .map(LessSafeKey::new)
+        .map_err(|_| keystore_error("invalid key length"))
+}
+
+fn decode(field: &str, encoded: &str) -> Result<Vec<u8>> {
+    bs58::decode(encoded)
+        .into_vec()
+        .map_err(|e| keystore_error(format!("{}: {}", field, e)))
+}
+
+fn seal_with_iterations(
+    keypair: &Keypair,
+    passphrase: &str,
+    iterations: u32,
+) -> Result<EncryptedKey> {
+    if passphrase.is_empty() {
+        return Err(keystore_error("passphrase is empty"));
+    }
+
+    let rng = SystemRandom::new();
+    let salt = random_bytes::<SALT_LEN>(&rng)?;
+    let nonce = random_bytes::<NONCE_LEN>(&rng)?;
+    let address = keypair.pubkey().to_string();
+
+    let key = derive_key(passphrase, &salt, iterations)?;
+    let mut in_out = keypair.to_bytes().to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(address.as_bytes()),
+        &mut in_out,
+    )
+    .map_err(|_| keystore_error("encryption failed"))?;
+
+    Ok(EncryptedKey {
+        version: KEYSTORE_VERSION,
+        address,
+        kdf: KEYSTORE_KDF.to_string(),
+        iterations,
+        salt: bs58::encode(salt).into_string(),
+        nonce: bs58::encode(nonce).into_string(),
+        ciphertext: bs58::encode(in_out).into_string(),
+    })
+}
+
+/// 用口令加密私钥
+pub fn seal(keypair: &Keypair, passphrase: &str) -> Result<EncryptedKey> {
+    seal_with_iterations(keypair, passphrase, KEYSTORE_PBKDF2_ITERATIONS)
+}
+
+/// 用口令解密私钥
+///
+/// 口令错误、密文被篡改或地址被替换都会导致认证失败
+pub fn open(encrypted: &EncryptedKey, passphrase: &str) -> Result<Keypair> {
+    if encrypted.version != KEYSTORE_VERSION || encrypted.kdf != KEYSTORE_KDF {
+        return Err(keystore_error(format!(
+            "unsupported keystore format: v{} {}",
+            encrypted.version, encrypted.kdf
+        )));
+    }
+
+    let salt = decode("salt", &encrypted.salt)?;
+    let nonce: [u8; NONCE_LEN] = decode("nonce", &encrypted.nonce)?
+        .try_into()
+        .map_err(|_| keystore_error("nonce: invalid length"))?;
+    let mut in_out = decode("ciphertext", &encrypted.ciphertext)?;
+
+    let key = derive_key(passphrase, &salt, encrypted.iterations)?;
+    let plaintext = key
+        .open_in_place(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(encrypted.address.as_bytes()),
+            &mut in_out,
+        )
+        .map_err(|_| keystore_error("wrong passphrase or corrupted keystore"))?;
+
+    let keypair = Keypair::try_from(&*plaintext).map_err(|e| keystore_error(e.to_string()))?;
+    if keypair.pubkey().to_string() != encrypted.address {
+        return Err(keystore_error("keystore address does not match the key"));
+    }
+
+    Ok(keypair)
+}
+
+/// 生成一次性凭证（base58），用于导出下载等只能使用一次的操作
+pub fn generate_token() -> Result<String> {
+    let bytes = random_bytes::<TOKEN_LEN>(&SystemRandom::new())?;
+    Ok(bs58::encode(bytes).into_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_and_open() {
+        let keypair = Keypair::new();
+        let encrypted = seal_with_iterations(&keypair, "correct horse", 1_000).unwrap();
+
+        assert_eq!(encrypted.address, keypair.pubkey().to_string());
+
+        let opened = open(&encrypted, "correct horse").unwrap();
+        assert_eq!(opened.to_bytes(), keypair.to_bytes());
+    }
+
+    #[test]
+    fn test_open_rejects_wrong_passphrase_and_swapped_address() {
+        let keypair = Keypair::new();
+        let encrypted = seal_with_iterations(&keypair, "correct horse", 1_000).unwrap();
+
+        assert!(matches!(
+            open(&encrypted, "battery staple"),
+            Err(SolanaError::KeystoreError(_))
+        ));
+
+        let swapped = EncryptedKey {
+            address: Keypair::new().pubkey().to_string(),
+            ..encrypted
+        };
+        assert!(open(&swapped, "correct horse").is_err());
+    }
+}
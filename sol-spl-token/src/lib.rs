@@ -3,20 +3,72 @@
 //! 提供以下功能：
 //! 1. 钱包创建和管理（系统托管）
 //! 2. SPL Token 余额查询和转账
-//! 3. 稳定币购买和代币转换
+//! 3. 稳定币购买和代币转换（报价经独立价格源校验）
 //! 4. 代币转账到外部钱包
+//! 5. 转出前的风险筛查
+//! 6. 储备金证明
+//! 7. 入金自动兑换
+//! 8. 多 RPC 端点池与故障切换
+//! 9. 发送前交易预检与错误解码
+//! 10. 空 Token 账户租金回收
+//! 11. 提现手续费计算
+//! 12. 批量发放
+//! 13. 地址查找表与 v0 版本化交易
+//! 14. 已发行 Token 的 mint 管理
+//! 15. 转出交易的备注与引用标记、入金备注解析
+//! 16. Solana Pay 收款请求、二维码与付款核对
+//! 17. 原生质押账户的委托、提取与奖励查询
+//! 18. 托管钱包冻结（禁止转出与兑换，入金不受影响）
+//! 19. 托管密钥轮换与钱包资产迁移
+//! 20. 私钥加密存储与用户自托管导出
+//! 21. 按钱包串行化签名的分布式锁
 
 pub mod error;
 pub mod wallet;
 pub mod token;
 pub mod swap;
+pub mod oracle;
 pub mod config;
+pub mod risk;
+pub mod reserves;
+pub mod convert;
+pub mod rpc_pool;
+pub mod simulation;
+pub mod reclaim;
+pub mod fee;
+pub mod payout;
+pub mod lookup_table;
+pub mod mint_admin;
+pub mod memo;
+pub mod solana_pay;
+pub mod stake;
+pub mod hold;
+pub mod rotation;
+pub mod keystore;
+pub mod lock;
 
 pub use error::SolanaError;
 pub use wallet::WalletManager;
-pub use token::TokenManager;
+pub use token::{TokenManager, TokenProgram};
 pub use swap::SwapManager;
+pub use oracle::PriceOracle;
 pub use config::SolanaConfig;
+pub use risk::{RiskGate, RiskScreener};
+pub use reserves::ReservesManager;
+pub use convert::AutoConverter;
+pub use rpc_pool::RpcPool;
+pub use reclaim::RentReclaimer;
+pub use fee::FeeSchedule;
+pub use payout::PayoutExecutor;
+pub use lookup_table::LookupTableManager;
+pub use mint_admin::MintAdmin;
+pub use memo::{MemoReader, TransferTag};
+pub use solana_pay::PaymentChecker;
+pub use stake::StakeManager;
+pub use hold::HoldRegistry;
+pub use rotation::KeyRotator;
+pub use keystore::EncryptedKey;
+pub use lock::DistributedLock;
 
 /// 重新导出常用的Solana类型
 pub use solana_sdk::{
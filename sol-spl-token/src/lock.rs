@@ -0,0 +1,360 @@
+//! 分布式锁模块
+//!
+//! 同一托管钱包的并发转出会在余额检查与发送之间相互竞争：两笔请求可能都通过
+//! 余额检查，最终一笔在链上失败。签名路径在检查余额前按钱包加锁，
+//! 同一时刻每个钱包只有一笔交易在构建和发送。
+//!
+//! 每次加锁都会得到单调递增的 fencing token。锁在持有期间可能因超时被其他实例取得，
+//! 持锁方在发送前通过 [`WalletLock::ensure_held`] 确认锁仍归自己所有，否则放弃发送。
+//! 一次加锁内发送多笔交易时，每笔发送前通过 [`WalletLock::renew`] 确认并续期。
+//!
+//! 锁的存储由调用方提供（多实例部署时通常为 Redis），通过 [`DistributedLock`] 注入
+
+use async_trait::async_trait;
+use solana_sdk::pubkey::Pubkey;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::error::{Result, SolanaError};
+use crate::keystore::generate_token;
+
+/// 钱包锁的持有时长，覆盖一次余额检查、签名与发送
+pub const WALLET_LOCK_TTL: Duration = Duration::from_secs(60);
+
+/// 等待钱包锁的最长时间，超时返回 [`SolanaError::WalletBusy`]
+pub const WALLET_LOCK_WAIT: Duration = Duration::from_secs(15);
+
+/// 等待期间重试加锁的间隔
+const RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// 加锁凭证
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockGuard {
+    /// 锁的键
+    pub key: String,
+
+    /// 持有者标识，每次加锁随机生成
+    pub owner: String,
+
+    /// fencing token，同一个键上每次加锁递增
+    pub fencing_token: u64,
+}
+
+/// 分布式锁 trait
+#[async_trait]
+pub trait DistributedLock: Send + Sync {
+    /// 尝试加锁，已被占用时返回 None
+    async fn try_acquire(&self, key: &str, ttl: Duration) -> Result<Option<LockGuard>>;
+
+    /// 锁是否仍由凭证的持有者持有，且没有更新的 fencing token
+    async fn is_held(&self, guard: &LockGuard) -> Result<bool>;
+
+    /// 仍持有锁时把过期时间延长为 `ttl` 之后，返回是否续期成功
+    async fn extend(&self, guard: &LockGuard, ttl: Duration) -> Result<bool>;
+
+    /// 释放锁，锁已不归凭证持有者所有时不做任何事
+    async fn release(&self, guard: &LockGuard) -> Result<()>;
+}
+
+struct MemoryLockEntry {
+    owner: String,
+    fencing_token: u64,
+    expires_at: Instant,
+}
+
+#[derive(Default)]
+struct MemoryLockState {
+    locks: HashMap<String, MemoryLockEntry>,
+    fencing_tokens: HashMap<String, u64>,
+}
+
+/// 进程内分布式锁，只在单实例部署或测试中使用
+#[derive(Default)]
+pub struct InMemoryDistributedLock {
+    state: Mutex<MemoryLockState>,
+}
+
+impl InMemoryDistributedLock {
+    /// 创建空锁表
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DistributedLock for InMemoryDistributedLock {
+    async fn try_acquire(&self, key: &str, ttl: Duration) -> Result<Option<LockGuard>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        if state
+            .locks
+            .get(key)
+            .is_some_and(|entry| entry.expires_at > now)
+        {
+            return Ok(None);
+        }
+
+        let fencing_token = state.fencing_tokens.entry(key.to_string()).or_default();
+        *fencing_token += 1;
+        let guard = LockGuard {
+            key: key.to_string(),
+            owner: generate_token()?,
+            fencing_token: *fencing_token,
+        };
+        state.locks.insert(
+            key.to_string(),
+            MemoryLockEntry {
+                owner: guard.owner.clone(),
+                fencing_token: guard.fencing_token,
+                expires_at: now + ttl,
+            },
+        );
+        Ok(Some(guard))
+    }
+
+    async fn is_held(&self, guard: &LockGuard) -> Result<bool> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(state.locks.get(&guard.key).is_some_and(|entry| {
+            entry.owner == guard.owner
+                && entry.fencing_token == guard.fencing_token
+                && entry.expires_at > Instant::now()
+        }))
+    }
+
+    async fn extend(&self, guard: &LockGuard, ttl: Duration) -> Result<bool> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        match state.locks.get_mut(&guard.key) {
+            Some(entry)
+                if entry.owner == guard.owner
+                    && entry.fencing_token == guard.fencing_token
+                    && entry.expires_at > now =>
+            {
+                entry.expires_at = now + ttl;
+                Ok(true)
+            },
+            _ => Ok(false),
+        }
+    }
+
+    async fn release(&self, guard: &LockGuard) -> Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state
+            .locks
+            .get(&guard.key)
+            .is_some_and(|entry| entry.owner == guard.owner)
+        {
+            state.locks.remove(&guard.key);
+        }
+        Ok(())
+    }
+}
+
+/// 钱包锁的键
+pub fn wallet_lock_key(wallet: &Pubkey) -> String {
+    format!("wallet:{}", wallet)
+}
+
+/// 已持有的钱包锁
+///
+/// 释放时机以 drop 为准：离开作用域后在后台释放，没有运行时的场景由 TTL 兜底
+pub struct WalletLock {
+    lock: Arc<dyn DistributedLock>,
+    guard: LockGuard,
+}
+
+impl WalletLock {
+    /// 加锁，锁被占用时按间隔重试，超过 [`WALLET_LOCK_WAIT`] 仍未取得则返回钱包繁忙
+    pub async fn acquire(lock: Arc<dyn DistributedLock>, wallet: &Pubkey) -> Result<Self> {
+        let key = wallet_lock_key(wallet);
+        let deadline = Instant::now() + WALLET_LOCK_WAIT;
+        loop {
+            if let Some(guard) = lock.try_acquire(&key, WALLET_LOCK_TTL).await? {
+                tracing::debug!(
+                    "Acquired lock for wallet {} with fencing token {}",
+                    wallet,
+                    guard.fencing_token
+                );
+                return Ok(Self { lock, guard });
+            }
+            if Instant::now() >= deadline {
+                return Err(SolanaError::WalletBusy(format!(
+                    "{}: another transaction is in progress",
+                    wallet
+                )));
+            }
+            tokio::time::sleep(RETRY_INTERVAL).await;
+        }
+    }
+
+    /// 本次加锁的 fencing token
+    pub fn fencing_token(&self) -> u64 {
+        self.guard.fencing_token
+    }
+
+    /// 发送前确认锁仍归自己所有
+    pub async fn ensure_held(&self) -> Result<()> {
+        if self.lock.is_held(&self.guard).await? {
+            return Ok(());
+        }
+        Err(self.lost())
+    }
+
+    /// 确认锁仍归自己所有并重新计时 [`WALLET_LOCK_TTL`]，用于一次加锁内的多笔发送
+    pub async fn renew(&self) -> Result<()> {
+        if self.lock.extend(&self.guard, WALLET_LOCK_TTL).await? {
+            return Ok(());
+        }
+        Err(self.lost())
+    }
+
+    fn lost(&self) -> SolanaError {
+        SolanaError::WalletBusy(format!(
+            "{}: lock with fencing token {} is no longer held",
+            self.guard.key, self.guard.fencing_token
+        ))
+    }
+}
+
+impl Drop for WalletLock {
+    fn drop(&mut self) {
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let lock = self.lock.clone();
+        let guard = self.guard.clone();
+        handle.spawn(async move {
+            if let Err(e) = lock.release(&guard).await {
+                tracing::warn!("Failed to release lock {}: {}", guard.key, e);
+            }
+        });
+    }
+}
+
+/// 签名前对转出钱包加锁
+///
+/// 未配置分布式锁时直接放行，返回 None
+pub async fn lock_wallet(
+    lock: Option<&Arc<dyn DistributedLock>>,
+    wallet: &Pubkey,
+) -> Result<Option<WalletLock>> {
+    match lock {
+        Some(lock) => WalletLock::acquire(lock.clone(), wallet).await.map(Some),
+        None => Ok(None),
+    }
+}
+
+/// 发送前确认钱包锁仍然有效，未加锁时直接放行
+pub async fn ensure_lock_held(wallet_lock: Option<&WalletLock>) -> Result<()> {
+    match wallet_lock {
+        Some(wallet_lock) => wallet_lock.ensure_held().await,
+        None => Ok(()),
+    }
+}
+
+/// 多笔发送中的下一笔发送前确认并续期钱包锁，未加锁时直接放行
+pub async fn renew_lock(wallet_lock: Option<&WalletLock>) -> Result<()> {
+    match wallet_lock {
+        Some(wallet_lock) => wallet_lock.renew().await,
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lock_is_exclusive_and_fenced() {
+        let lock = InMemoryDistributedLock::new();
+
+        let first = lock
+            .try_acquire("wallet:a", Duration::from_secs(60))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(lock
+            .try_acquire("wallet:a", Duration::from_secs(60))
+            .await
+            .unwrap()
+            .is_none());
+        assert!(lock.is_held(&first).await.unwrap());
+
+        lock.release(&first).await.unwrap();
+        assert!(!lock.is_held(&first).await.unwrap());
+
+        let second = lock
+            .try_acquire("wallet:a", Duration::from_secs(60))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(second.fencing_token > first.fencing_token);
+
+        // 过期的凭证不能释放他人的锁
+        lock.release(&first).await.unwrap();
+        assert!(lock.is_held(&second).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_expired_lock_is_taken_over() {
+        let lock = InMemoryDistributedLock::new();
+
+        let stale = lock
+            .try_acquire("wallet:b", Duration::ZERO)
+            .await
+            .unwrap()
+            .unwrap();
+        let current = lock
+            .try_acquire("wallet:b", Duration::from_secs(60))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(!lock.is_held(&stale).await.unwrap());
+        assert!(lock.is_held(&current).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_extend_only_by_current_holder() {
+        let lock = InMemoryDistributedLock::new();
+
+        let stale = lock
+            .try_acquire("wallet:c", Duration::ZERO)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!lock.extend(&stale, Duration::from_secs(60)).await.unwrap());
+
+        let current = lock
+            .try_acquire("wallet:c", Duration::from_millis(1))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(lock
+            .extend(&current, Duration::from_secs(60))
+            .await
+            .unwrap());
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        assert!(lock.is_held(&current).await.unwrap());
+        assert!(!lock.extend(&stale, Duration::from_secs(60)).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_wallet_lock_released_on_drop() {
+        let wallet = Pubkey::new_unique();
+        let lock: Arc<dyn DistributedLock> = Arc::new(InMemoryDistributedLock::new());
+
+        assert!(lock_wallet(None, &wallet).await.unwrap().is_none());
+
+        let held = lock_wallet(Some(&lock), &wallet).await.unwrap().unwrap();
+        ensure_lock_held(Some(&held)).await.unwrap();
+        let first_token = held.fencing_token();
+        drop(held);
+
+        let next = WalletLock::acquire(lock, &wallet).await.unwrap();
+        assert!(next.fencing_token() > first_token);
+    }
+}
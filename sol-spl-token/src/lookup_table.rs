@@ -0,0 +1,342 @@
+//! 地址查找表模块
+//!
+//! 旧版交易的每个账户都要占 32 字节，批量发放、归集这类重复引用同一批
+//! 程序、mint 与热钱包的交易很快就会撞上 1232 字节的上限。
+//! 本模块由系统钱包创建并扩展地址查找表（ALT），
+//! 把这些公共地址放进表中，再编译为引用查找表的 v0 版本化交易，
+//! 每个公共地址在交易中只占 1 字节下标
+//!
+//! 新扩展的地址要到下一个 slot 才能被交易引用
+
+use solana_address_lookup_table_interface::{
+    instruction::{create_lookup_table, extend_lookup_table},
+    state::{AddressLookupTable, LOOKUP_TABLE_MAX_ADDRESSES},
+};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, VersionedMessage},
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::{Transaction, VersionedTransaction},
+};
+use spl_associated_token_account::get_associated_token_address;
+use std::{collections::HashSet, sync::Arc};
+
+use crate::error::{Result, SolanaError};
+use crate::lock::{ensure_lock_held, lock_wallet, renew_lock, DistributedLock};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+use crate::simulation::send_with_preflight;
+
+pub use solana_sdk::message::AddressLookupTableAccount;
+
+/// 单笔扩展交易写入的地址数，保证交易不超过大小上限
+pub const MAX_ADDRESSES_PER_EXTEND: usize = 20;
+
+/// 一次扩展的结果
+#[derive(Debug, Clone, Default)]
+pub struct LookupTableExtension {
+    /// 本次新写入的地址，已在表中的地址不会重复写入
+    pub added: Vec<Pubkey>,
+
+    /// 扩展交易签名
+    pub signatures: Vec<Signature>,
+}
+
+/// 托管交易常用的公共地址：Token、关联账户与 System 程序、各 mint、
+/// 热钱包及其在各 mint 下的关联 Token 账户，去重并保持顺序
+pub fn custody_addresses(hot_wallets: &[Pubkey], mints: &[Pubkey]) -> Vec<Pubkey> {
+    let mut seen = HashSet::new();
+    let programs = [
+        spl_token::id(),
+        spl_associated_token_account::id(),
+        solana_system_interface::program::id(),
+    ];
+    let wallet_accounts = hot_wallets.iter().flat_map(|wallet| {
+        std::iter::once(*wallet).chain(
+            mints
+                .iter()
+                .map(move |mint| get_associated_token_address(wallet, mint)),
+        )
+    });
+
+    programs
+        .into_iter()
+        .chain(mints.iter().copied())
+        .chain(wallet_accounts)
+        .filter(|address| seen.insert(*address))
+        .collect()
+}
+
+fn compile_message(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> Result<VersionedMessage> {
+    v0::Message::try_compile(payer, instructions, lookup_tables, recent_blockhash)
+        .map(VersionedMessage::V0)
+        .map_err(|e| SolanaError::LookupTableError(e.to_string()))
+}
+
+/// 编译并签名引用查找表的 v0 交易
+pub fn compile_versioned_transaction(
+    payer: &Keypair,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> Result<VersionedTransaction> {
+    let message = compile_message(
+        &payer.pubkey(),
+        instructions,
+        lookup_tables,
+        recent_blockhash,
+    )?;
+    VersionedTransaction::try_new(message, &[payer]).map_err(SolanaError::from)
+}
+
+/// v0 交易签名后的序列化大小
+pub fn versioned_transaction_size(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+) -> Result<usize> {
+    let message = compile_message(payer, instructions, lookup_tables, Hash::default())?;
+    let signatures = message.header().num_required_signatures as usize;
+    Ok(1 + signatures * 64 + message.serialize().len())
+}
+
+/// 地址查找表管理器
+pub struct LookupTableManager {
+    rpc_client: RpcHandle,
+    write_client: RpcHandle,
+    wallet_lock: Option<Arc<dyn DistributedLock>>,
+}
+
+impl LookupTableManager {
+    /// 创建新的地址查找表管理器
+    pub fn new(rpc_url: &str) -> Self {
+        let rpc_client = RpcHandle::new(rpc_url);
+
+        Self {
+            write_client: rpc_client.clone(),
+            rpc_client,
+            wallet_lock: None,
+        }
+    }
+
+    /// 从 RPC 端点池创建地址查找表管理器
+    pub fn from_pool(pool: &Arc<RpcPool>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcHandle::pooled(pool, RpcTraffic::Read),
+            write_client: RpcHandle::pooled(pool, RpcTraffic::Write),
+            wallet_lock: None,
+        })
+    }
+
+    /// 设置分布式锁，查找表的租金与手续费由权限钱包支付，与其他转出按钱包串行化
+    pub fn with_wallet_lock(mut self, wallet_lock: Arc<dyn DistributedLock>) -> Self {
+        self.wallet_lock = Some(wallet_lock);
+        self
+    }
+
+    /// 创建由 `authority` 管理并付费的查找表，返回表地址与交易签名
+    pub async fn create_lookup_table(&self, authority: &Keypair) -> Result<(Pubkey, Signature)> {
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &authority.pubkey()).await?;
+        // 表地址由 authority 与最近的 slot 派生，该 slot 必须仍在 SlotHashes 中
+        let recent_slot = self
+            .rpc_client
+            .call(|client| client.get_slot_with_commitment(CommitmentConfig::finalized()))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+        let (instruction, table) =
+            create_lookup_table(authority.pubkey(), authority.pubkey(), recent_slot);
+
+        ensure_lock_held(wallet_lock.as_ref()).await?;
+        let signature = self.send(authority, &[instruction])?;
+        tracing::info!("Created address lookup table {} ({})", table, signature);
+
+        Ok((table, signature))
+    }
+
+    /// 读取查找表
+    pub fn fetch_lookup_table(&self, table: &Pubkey) -> Result<AddressLookupTableAccount> {
+        let account = self
+            .rpc_client
+            .call(|client| client.get_account(table))
+            .map_err(|e| SolanaError::AccountNotFound(e.to_string()))?;
+
+        Self::decode(table, &account.data)
+    }
+
+    /// 批量读取查找表，任一表不存在时返回错误
+    pub fn fetch_lookup_tables(&self, tables: &[Pubkey]) -> Result<Vec<AddressLookupTableAccount>> {
+        if tables.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let accounts = self
+            .rpc_client
+            .call(|client| client.get_multiple_accounts(tables))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        tables
+            .iter()
+            .zip(accounts)
+            .map(|(table, account)| {
+                let account = account.ok_or_else(|| {
+                    SolanaError::AccountNotFound(format!("Lookup table {} not found", table))
+                })?;
+                Self::decode(table, &account.data)
+            })
+            .collect()
+    }
+
+    /// 把表中尚未包含的地址写入查找表
+    ///
+    /// 地址按 [`MAX_ADDRESSES_PER_EXTEND`] 分批发送，中途失败时已发送的批次仍然生效
+    pub async fn extend_lookup_table(
+        &self,
+        authority: &Keypair,
+        table: &Pubkey,
+        addresses: &[Pubkey],
+    ) -> Result<LookupTableExtension> {
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &authority.pubkey()).await?;
+        let account = self
+            .rpc_client
+            .call(|client| client.get_account(table))
+            .map_err(|e| SolanaError::AccountNotFound(e.to_string()))?;
+        let state = AddressLookupTable::deserialize(&account.data)
+            .map_err(|e| SolanaError::LookupTableError(e.to_string()))?;
+
+        if state.meta.authority != Some(authority.pubkey()) {
+            return Err(SolanaError::LookupTableError(format!(
+                "Lookup table {} is frozen or managed by another authority",
+                table
+            )));
+        }
+
+        let mut seen: HashSet<Pubkey> = state.addresses.iter().copied().collect();
+        let added: Vec<Pubkey> = addresses
+            .iter()
+            .copied()
+            .filter(|address| seen.insert(*address))
+            .collect();
+
+        if state.addresses.len() + added.len() > LOOKUP_TABLE_MAX_ADDRESSES {
+            return Err(SolanaError::LookupTableError(format!(
+                "Lookup table {} would exceed {} addresses",
+                table, LOOKUP_TABLE_MAX_ADDRESSES
+            )));
+        }
+
+        let mut signatures = Vec::new();
+        for chunk in added.chunks(MAX_ADDRESSES_PER_EXTEND) {
+            let instruction = extend_lookup_table(
+                *table,
+                authority.pubkey(),
+                Some(authority.pubkey()),
+                chunk.to_vec(),
+            );
+            renew_lock(wallet_lock.as_ref()).await?;
+            signatures.push(self.send(authority, &[instruction])?);
+        }
+
+        if !added.is_empty() {
+            tracing::info!(
+                "Extended address lookup table {} with {} addresses",
+                table,
+                added.len()
+            );
+        }
+
+        Ok(LookupTableExtension { added, signatures })
+    }
+
+    fn decode(table: &Pubkey, data: &[u8]) -> Result<AddressLookupTableAccount> {
+        let state = AddressLookupTable::deserialize(data)
+            .map_err(|e| SolanaError::LookupTableError(format!("{}: {}", table, e)))?;
+
+        Ok(AddressLookupTableAccount {
+            key: *table,
+            addresses: state.addresses.to_vec(),
+        })
+    }
+
+    fn send(&self, authority: &Keypair, instructions: &[Instruction]) -> Result<Signature> {
+        let recent_blockhash = self
+            .write_client
+            .call(|client| client.get_latest_blockhash())
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&authority.pubkey()));
+        transaction.sign(&[authority], recent_blockhash);
+
+        send_with_preflight(&self.write_client, &transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custody_addresses() {
+        let wallet = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let addresses = custody_addresses(&[wallet, wallet], &[mint, mint]);
+
+        assert_eq!(addresses.len(), 6);
+        assert_eq!(addresses[0], spl_token::id());
+        assert!(addresses.contains(&mint));
+        assert!(addresses.contains(&get_associated_token_address(&wallet, &mint)));
+    }
+
+    #[test]
+    fn test_compile_versioned_transaction() {
+        let payer = Keypair::new();
+        let mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let instructions = vec![
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                &recipient,
+                &mint,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::transfer(
+                &spl_token::id(),
+                &get_associated_token_address(&payer.pubkey(), &mint),
+                &get_associated_token_address(&recipient, &mint),
+                &payer.pubkey(),
+                &[],
+                1,
+            )
+            .unwrap(),
+        ];
+        let table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: custody_addresses(&[payer.pubkey()], &[mint]),
+        };
+
+        // 签名者与被调用的程序不能从查找表加载，其余公共地址都改为 1 字节下标
+        let with_table = versioned_transaction_size(
+            &payer.pubkey(),
+            &instructions,
+            std::slice::from_ref(&table),
+        )
+        .unwrap();
+        let without_table =
+            versioned_transaction_size(&payer.pubkey(), &instructions, &[]).unwrap();
+        assert!(with_table < without_table);
+
+        let transaction =
+            compile_versioned_transaction(&payer, &instructions, &[table], Hash::default())
+                .unwrap();
+        assert_eq!(
+            transaction.message.address_table_lookups().unwrap().len(),
+            1
+        );
+        assert!(transaction.verify_with_results().iter().all(|ok| *ok));
+    }
+}
@@ -0,0 +1,224 @@
+//! 转账备注与引用标记模块
+//!
+//! 每笔转出交易都带上 SPL Memo（内容为内部提现或发放 ID），
+//! 并按 Solana Pay 的约定在转账指令末尾附加一个只读、非签名的引用公钥。
+//! 引用公钥由内部 ID 确定性派生，不保存签名也能通过
+//! `getSignaturesForAddress` 找回对应的链上交易。
+//!
+//! 入金方向则读取交易中的备注，用于把资金路由到子账户
+
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::{
+    hash::hashv,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::VersionedTransaction,
+};
+use solana_transaction_status_client_types::UiTransactionEncoding;
+use std::sync::Arc;
+
+use crate::error::{Result, SolanaError};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+
+/// SPL Memo 程序（v2）
+pub const MEMO_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// 入金备注中子账户标识的最大长度
+pub const MAX_SUB_ACCOUNT_LEN: usize = 64;
+
+/// 派生引用公钥时使用的域分隔前缀
+const REFERENCE_SEED: &[u8] = b"custody-reference";
+
+/// 由内部 ID 派生引用公钥，同一 ID 总是得到同一个公钥
+pub fn reference_for(internal_id: &str) -> Pubkey {
+    Pubkey::new_from_array(hashv(&[REFERENCE_SEED, internal_id.as_bytes()]).to_bytes())
+}
+
+/// 构造备注指令，`signer` 必须是交易的签名者
+pub fn memo_instruction(memo: &str, signer: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(*signer, true)],
+        data: memo.as_bytes().to_vec(),
+    }
+}
+
+/// 一笔转出交易的备注与引用标记
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferTag {
+    /// 备注内容，即内部提现或发放 ID
+    pub memo: String,
+
+    /// 引用公钥
+    pub reference: Pubkey,
+}
+
+impl TransferTag {
+    /// 以内部 ID 作为备注并派生引用公钥
+    pub fn new(internal_id: &str) -> Self {
+        Self {
+            memo: internal_id.to_string(),
+            reference: reference_for(internal_id),
+        }
+    }
+
+    /// 返回备注指令与附加了引用公钥的转账指令，二者按此顺序放入交易
+    ///
+    /// Token 与 System 程序的转账会忽略多出的只读账户，Memo 程序则要求所有账户都签名，
+    /// 所以引用公钥只能挂在转账指令上
+    pub fn attach(&self, mut transfer: Instruction, signer: &Pubkey) -> [Instruction; 2] {
+        transfer
+            .accounts
+            .push(AccountMeta::new_readonly(self.reference, false));
+        [memo_instruction(&self.memo, signer), transfer]
+    }
+}
+
+/// 提取交易中所有 Memo 指令的内容，非 UTF-8 的备注会被跳过
+pub fn transaction_memos(transaction: &VersionedTransaction) -> Vec<String> {
+    // 被调用的程序只能出现在静态账户列表中，不会来自查找表
+    let keys = transaction.message.static_account_keys();
+    transaction
+        .message
+        .instructions()
+        .iter()
+        .filter(|instruction| {
+            keys.get(instruction.program_id_index as usize) == Some(&MEMO_PROGRAM_ID)
+        })
+        .filter_map(|instruction| String::from_utf8(instruction.data.clone()).ok())
+        .collect()
+}
+
+/// 从入金备注中解析子账户标识
+///
+/// 备注去掉首尾空白后只能包含字母、数字、`-` 与 `_`，否则视为没有路由信息
+pub fn parse_sub_account(memo: &str) -> Option<String> {
+    let memo = memo.trim();
+    let valid = !memo.is_empty()
+        && memo.len() <= MAX_SUB_ACCOUNT_LEN
+        && memo
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    valid.then(|| memo.to_string())
+}
+
+/// 链上备注与引用读取器
+pub struct MemoReader {
+    rpc_client: RpcHandle,
+}
+
+impl MemoReader {
+    /// 创建新的备注读取器
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            rpc_client: RpcHandle::new(rpc_url),
+        }
+    }
+
+    /// 从 RPC 端点池创建备注读取器
+    pub fn from_pool(pool: &Arc<RpcPool>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcHandle::pooled(pool, RpcTraffic::Read),
+        })
+    }
+
+    /// 读取已确认交易中的备注
+    pub fn fetch_memos(&self, signature: &Signature) -> Result<Vec<String>> {
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(self.rpc_client.commitment()),
+            max_supported_transaction_version: Some(0),
+        };
+        let confirmed = self
+            .rpc_client
+            .call(|client| client.get_transaction_with_config(signature, config))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+        let transaction = confirmed.transaction.transaction.decode().ok_or_else(|| {
+            SolanaError::RpcError(format!("Undecodable transaction {}", signature))
+        })?;
+
+        Ok(transaction_memos(&transaction))
+    }
+
+    /// 按引用公钥查找链上交易签名，最新的在前
+    pub fn find_by_reference(&self, reference: &Pubkey) -> Result<Vec<Signature>> {
+        self.rpc_client
+            .call(|client| client.get_signatures_for_address(reference))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?
+            .into_iter()
+            .map(|status| {
+                status
+                    .signature
+                    .parse()
+                    .map_err(|e| SolanaError::RpcError(format!("{}: {}", status.signature, e)))
+            })
+            .collect()
+    }
+
+    /// 按引用公钥查找已成功上链的交易签名，执行失败的交易不计入
+    pub fn find_landed_by_reference(&self, reference: &Pubkey) -> Result<Option<Signature>> {
+        self.rpc_client
+            .call(|client| client.get_signatures_for_address(reference))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?
+            .into_iter()
+            .find(|status| status.err.is_none())
+            .map(|status| {
+                status
+                    .signature
+                    .parse()
+                    .map_err(|e| SolanaError::RpcError(format!("{}: {}", status.signature, e)))
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        hash::Hash,
+        message::Message,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+    use solana_system_interface::instruction as system_instruction;
+
+    #[test]
+    fn test_transfer_tag() {
+        let payer = Keypair::new();
+        let recipient = Pubkey::new_unique();
+        let tag = TransferTag::new("01J9ZP4Q6V2X8N3M5K7H1G0F2D");
+        assert_eq!(tag.reference, reference_for("01J9ZP4Q6V2X8N3M5K7H1G0F2D"));
+        assert_ne!(tag.reference, reference_for("01J9ZP4Q6V2X8N3M5K7H1G0F2E"));
+
+        let [memo, transfer] = tag.attach(
+            system_instruction::transfer(&payer.pubkey(), &recipient, 1),
+            &payer.pubkey(),
+        );
+        assert_eq!(memo.program_id, MEMO_PROGRAM_ID);
+        let reference = transfer.accounts.last().unwrap();
+        assert_eq!(reference.pubkey, tag.reference);
+        assert!(!reference.is_signer && !reference.is_writable);
+
+        let message = Message::new(&[memo, transfer], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, Hash::default());
+        assert_eq!(
+            transaction_memos(&VersionedTransaction::from(transaction)),
+            vec![tag.memo]
+        );
+    }
+
+    #[test]
+    fn test_parse_sub_account() {
+        assert_eq!(parse_sub_account(" user-42 "), Some("user-42".to_string()));
+        assert_eq!(parse_sub_account(""), None);
+        assert_eq!(parse_sub_account("drop table;"), None);
+        assert_eq!(
+            parse_sub_account(&"a".repeat(MAX_SUB_ACCOUNT_LEN + 1)),
+            None
+        );
+    }
+}
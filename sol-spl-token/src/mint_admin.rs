@@ -0,0 +1,384 @@
+//! Mint 管理模块
+//!
+//! 面向发行方场景：系统钱包持有已发行 SPL Token 的铸造或冻结权限时，
+//! 执行增发、销毁、冻结/解冻 Token 账户与转移权限等操作，
+//! 并提供供应量与持有人数查询。审批与审计由调用方负责
+
+use serde::{Deserialize, Serialize};
+use solana_client::{
+    rpc_config::{
+        RpcAccountInfoConfig, RpcProgramAccountsConfig, UiAccountEncoding, UiDataSliceConfig,
+    },
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::{program_option::COption, program_pack::Pack};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+use spl_token::{
+    instruction::{burn, freeze_account, mint_to, set_authority, thaw_account, AuthorityType},
+    state::{Account as TokenAccount, Mint},
+};
+use std::sync::Arc;
+
+use crate::error::{Result, SolanaError};
+use crate::lock::{ensure_lock_held, lock_wallet, DistributedLock};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+use crate::simulation::send_with_preflight;
+
+/// Token 账户数据中 amount 字段的偏移
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+/// mint 上可转移的权限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MintAuthority {
+    /// 铸造权限
+    MintTokens,
+    /// 冻结权限
+    FreezeAccount,
+}
+
+/// Mint 管理操作
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MintOperation {
+    /// 向钱包增发，接收方关联 Token 账户不存在时一并创建
+    MintTo { recipient: Pubkey, amount: u64 },
+    /// 从权限钱包自己的关联 Token 账户销毁
+    Burn { amount: u64 },
+    /// 冻结 Token 账户
+    FreezeAccount { account: Pubkey },
+    /// 解冻 Token 账户
+    ThawAccount { account: Pubkey },
+    /// 转移或放弃（`new_authority` 为空）mint 权限
+    SetAuthority {
+        authority: MintAuthority,
+        new_authority: Option<Pubkey>,
+    },
+}
+
+impl MintOperation {
+    /// 执行该操作需要的 mint 权限
+    pub fn required_authority(&self) -> MintAuthority {
+        match self {
+            MintOperation::MintTo { .. } | MintOperation::Burn { .. } => MintAuthority::MintTokens,
+            MintOperation::FreezeAccount { .. } | MintOperation::ThawAccount { .. } => {
+                MintAuthority::FreezeAccount
+            },
+            MintOperation::SetAuthority { authority, .. } => *authority,
+        }
+    }
+}
+
+/// mint 的供应量与权限
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintSupply {
+    pub mint: Pubkey,
+
+    /// 当前供应量（最小单位）
+    pub supply: u64,
+
+    pub decimals: u8,
+
+    /// 铸造权限，已放弃时为空
+    pub mint_authority: Option<Pubkey>,
+
+    /// 冻结权限，未设置时为空
+    pub freeze_authority: Option<Pubkey>,
+}
+
+impl MintSupply {
+    fn authority(&self, authority: MintAuthority) -> Option<Pubkey> {
+        match authority {
+            MintAuthority::MintTokens => self.mint_authority,
+            MintAuthority::FreezeAccount => self.freeze_authority,
+        }
+    }
+}
+
+fn token_error(e: impl ToString) -> SolanaError {
+    SolanaError::TokenTransferError(e.to_string())
+}
+
+/// 构造操作对应的指令，`authority` 为持有所需权限的钱包
+///
+/// 销毁从 `authority` 自己的关联 Token 账户扣减，其他钱包的余额需先转入
+pub fn operation_instructions(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    operation: &MintOperation,
+) -> Result<Vec<Instruction>> {
+    let token_program = spl_token::id();
+    let instructions = match operation {
+        MintOperation::MintTo { recipient, amount } => {
+            let destination = get_associated_token_address(recipient, mint);
+            vec![
+                create_associated_token_account_idempotent(
+                    authority,
+                    recipient,
+                    mint,
+                    &token_program,
+                ),
+                mint_to(&token_program, mint, &destination, authority, &[], *amount)
+                    .map_err(token_error)?,
+            ]
+        },
+        MintOperation::Burn { amount } => {
+            let source = get_associated_token_address(authority, mint);
+            vec![burn(&token_program, &source, mint, authority, &[], *amount)
+                .map_err(token_error)?]
+        },
+        MintOperation::FreezeAccount { account } => {
+            vec![
+                freeze_account(&token_program, account, mint, authority, &[])
+                    .map_err(token_error)?,
+            ]
+        },
+        MintOperation::ThawAccount { account } => {
+            vec![thaw_account(&token_program, account, mint, authority, &[]).map_err(token_error)?]
+        },
+        MintOperation::SetAuthority {
+            authority: authority_type,
+            new_authority,
+        } => {
+            let authority_type = match authority_type {
+                MintAuthority::MintTokens => AuthorityType::MintTokens,
+                MintAuthority::FreezeAccount => AuthorityType::FreezeAccount,
+            };
+            vec![set_authority(
+                &token_program,
+                mint,
+                new_authority.as_ref(),
+                authority_type,
+                authority,
+                &[],
+            )
+            .map_err(token_error)?]
+        },
+    };
+
+    Ok(instructions)
+}
+
+/// Mint 管理器
+pub struct MintAdmin {
+    rpc_client: RpcHandle,
+    write_client: RpcHandle,
+    wallet_lock: Option<Arc<dyn DistributedLock>>,
+}
+
+impl MintAdmin {
+    /// 创建新的 Mint 管理器
+    pub fn new(rpc_url: &str) -> Self {
+        let rpc_client = RpcHandle::new(rpc_url);
+
+        Self {
+            write_client: rpc_client.clone(),
+            rpc_client,
+            wallet_lock: None,
+        }
+    }
+
+    /// 从 RPC 端点池创建 Mint 管理器
+    pub fn from_pool(pool: &Arc<RpcPool>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcHandle::pooled(pool, RpcTraffic::Read),
+            write_client: RpcHandle::pooled(pool, RpcTraffic::Write),
+            wallet_lock: None,
+        })
+    }
+
+    /// 设置分布式锁，权限钱包同时支付手续费，与其他转出按钱包串行化
+    pub fn with_wallet_lock(mut self, wallet_lock: Arc<dyn DistributedLock>) -> Self {
+        self.wallet_lock = Some(wallet_lock);
+        self
+    }
+
+    /// 查询供应量与权限
+    pub fn get_supply(&self, mint: &Pubkey) -> Result<MintSupply> {
+        let data = self
+            .rpc_client
+            .call(|client| client.get_account_data(mint))
+            .map_err(|e| SolanaError::AccountNotFound(e.to_string()))?;
+        let state = Mint::unpack(&data).map_err(|e| {
+            SolanaError::TokenAccountNotFound(format!("{} is not a mint: {}", mint, e))
+        })?;
+
+        let option = |authority: COption<Pubkey>| match authority {
+            COption::Some(authority) => Some(authority),
+            COption::None => None,
+        };
+
+        Ok(MintSupply {
+            mint: *mint,
+            supply: state.supply,
+            decimals: state.decimals,
+            mint_authority: option(state.mint_authority),
+            freeze_authority: option(state.freeze_authority),
+        })
+    }
+
+    /// 统计余额大于零的 Token 账户数
+    ///
+    /// 只读取每个账户的 amount 字段；同一钱包持有多个账户时分别计数
+    pub fn get_holder_count(&self, mint: &Pubkey) -> Result<u64> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(TokenAccount::LEN as u64),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, mint.to_bytes().to_vec())),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: Some(UiDataSliceConfig {
+                    offset: TOKEN_ACCOUNT_AMOUNT_OFFSET,
+                    length: 8,
+                }),
+                commitment: Some(self.rpc_client.commitment()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let accounts = self
+            .rpc_client
+            .call(|client| {
+                client.get_program_ui_accounts_with_config(&spl_token::id(), config.clone())
+            })
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        let mut holders = 0;
+        for (address, account) in accounts {
+            let data = account.data.decode().ok_or_else(|| {
+                SolanaError::TokenAccountNotFound(format!("Undecodable token account {}", address))
+            })?;
+            let amount = data
+                .get(..8)
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u64::from_le_bytes)
+                .unwrap_or_default();
+            if amount > 0 {
+                holders += 1;
+            }
+        }
+
+        Ok(holders)
+    }
+
+    /// 确认 `authority` 持有操作所需的权限
+    pub fn check_authority(
+        &self,
+        authority: &Pubkey,
+        mint: &Pubkey,
+        operation: &MintOperation,
+    ) -> Result<MintSupply> {
+        let supply = self.get_supply(mint)?;
+        let required = operation.required_authority();
+        if supply.authority(required) != Some(*authority) {
+            return Err(SolanaError::ConfigError(format!(
+                "{} does not hold the {:?} authority of mint {}",
+                authority, required, mint
+            )));
+        }
+
+        Ok(supply)
+    }
+
+    /// 校验权限后发送操作交易
+    pub async fn execute(
+        &self,
+        authority: &Keypair,
+        mint: &Pubkey,
+        operation: &MintOperation,
+    ) -> Result<Signature> {
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &authority.pubkey()).await?;
+        self.check_authority(&authority.pubkey(), mint, operation)?;
+        let instructions = operation_instructions(&authority.pubkey(), mint, operation)?;
+
+        let recent_blockhash = self
+            .write_client
+            .call(|client| client.get_latest_blockhash())
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+        let mut transaction = Transaction::new_with_payer(&instructions, Some(&authority.pubkey()));
+        transaction.sign(&[authority], recent_blockhash);
+
+        ensure_lock_held(wallet_lock.as_ref()).await?;
+        let signature = send_with_preflight(&self.write_client, &transaction)?;
+        tracing::info!(
+            "Mint operation {:?} on {} sent: {}",
+            operation,
+            mint,
+            signature
+        );
+
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_authority() {
+        let account = Pubkey::new_unique();
+
+        assert_eq!(
+            MintOperation::Burn { amount: 1 }.required_authority(),
+            MintAuthority::MintTokens
+        );
+        assert_eq!(
+            MintOperation::ThawAccount { account }.required_authority(),
+            MintAuthority::FreezeAccount
+        );
+        assert_eq!(
+            MintOperation::SetAuthority {
+                authority: MintAuthority::FreezeAccount,
+                new_authority: None,
+            }
+            .required_authority(),
+            MintAuthority::FreezeAccount
+        );
+    }
+
+    #[test]
+    fn test_operation_instructions() {
+        let authority = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+
+        let instructions = operation_instructions(
+            &authority,
+            &mint,
+            &MintOperation::MintTo {
+                recipient,
+                amount: 100,
+            },
+        )
+        .unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[1].program_id, spl_token::id());
+        assert_eq!(
+            instructions[1].accounts[1].pubkey,
+            get_associated_token_address(&recipient, &mint)
+        );
+
+        let instructions = operation_instructions(
+            &authority,
+            &mint,
+            &MintOperation::SetAuthority {
+                authority: MintAuthority::MintTokens,
+                new_authority: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0].accounts[0].pubkey, mint);
+    }
+}
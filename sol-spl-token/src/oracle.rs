@@ -0,0 +1,315 @@
+//! 价格预言机模块
+//!
+//! 为交换提供独立于 DEX 报价的参考价格，用于：
+//! 1. 计算报价相对参考价的价格冲击（bps）
+//! 2. 拒绝过期的报价或参考价
+//!
+//! 价格统一表示为「每个 base 最小单位可换得的 quote 最小单位数」，
+//! 精度换算由各预言机适配器负责
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+use std::{
+    collections::HashMap,
+    sync::RwLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::error::{Result, SolanaError};
+
+/// 万分比基数
+const BPS_DENOMINATOR: f64 = 10_000.0;
+
+/// 参考价格
+#[derive(Debug, Clone)]
+pub struct OraclePrice {
+    /// 每个 base 最小单位可换得的 quote 最小单位数
+    pub price: f64,
+
+    /// 价格发布时间
+    pub published_at: SystemTime,
+
+    /// 价格来源
+    pub source: String,
+}
+
+/// 价格预言机 trait
+#[async_trait]
+pub trait PriceOracle: Send + Sync {
+    /// 预言机名称
+    fn name(&self) -> &str;
+
+    /// 获取 base -> quote 的参考价格
+    async fn get_price(&self, base: &Pubkey, quote: &Pubkey) -> Result<OraclePrice>;
+}
+
+/// 报价校验规则
+#[derive(Debug, Clone)]
+pub struct QuoteGuard {
+    /// 允许的最大价格冲击（bps）
+    pub max_price_impact_bps: u32,
+
+    /// 报价与参考价的最大时效
+    pub max_quote_age: Duration,
+}
+
+impl Default for QuoteGuard {
+    fn default() -> Self {
+        Self {
+            max_price_impact_bps: 100,
+            max_quote_age: Duration::from_secs(30),
+        }
+    }
+}
+
+/// 报价校验结果
+#[derive(Debug, Clone)]
+pub struct QuoteCheck {
+    /// 参考价格
+    pub oracle_price: f64,
+
+    /// 报价劣于参考价的幅度（bps），报价优于参考价时为 0
+    pub price_impact_bps: u32,
+}
+
+impl QuoteGuard {
+    /// 校验报价价格与报价时间
+    pub fn check(
+        &self,
+        quoted_price: f64,
+        quoted_at: SystemTime,
+        oracle: &OraclePrice,
+    ) -> Result<QuoteCheck> {
+        Self::check_age("quote", quoted_at, self.max_quote_age)?;
+        Self::check_age(&oracle.source, oracle.published_at, self.max_quote_age)?;
+
+        if !(oracle.price.is_finite() && oracle.price > 0.0) {
+            return Err(SolanaError::QuoteRejected(format!(
+                "Invalid oracle price {} from {}",
+                oracle.price, oracle.source
+            )));
+        }
+
+        let impact = ((oracle.price - quoted_price) / oracle.price * BPS_DENOMINATOR).max(0.0);
+        let price_impact_bps = impact.round() as u32;
+
+        if price_impact_bps > self.max_price_impact_bps {
+            return Err(SolanaError::QuoteRejected(format!(
+                "Price impact {} bps exceeds limit {} bps (quote {}, oracle {})",
+                price_impact_bps, self.max_price_impact_bps, quoted_price, oracle.price
+            )));
+        }
+
+        Ok(QuoteCheck {
+            oracle_price: oracle.price,
+            price_impact_bps,
+        })
+    }
+
+    fn check_age(label: &str, at: SystemTime, max_age: Duration) -> Result<()> {
+        // 时间在未来视为时钟偏差，按新鲜处理
+        let age = SystemTime::now().duration_since(at).unwrap_or_default();
+        if age > max_age {
+            return Err(SolanaError::StaleQuote(format!(
+                "{} is {}s old, limit {}s",
+                label,
+                age.as_secs(),
+                max_age.as_secs()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 固定价格预言机，用于开发环境与测试
+#[derive(Default)]
+pub struct StaticPriceOracle {
+    prices: RwLock<HashMap<(Pubkey, Pubkey), f64>>,
+}
+
+impl StaticPriceOracle {
+    /// 创建空的固定价格预言机
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置交易对价格
+    pub fn set_price(&self, base: Pubkey, quote: Pubkey, price: f64) {
+        if let Ok(mut prices) = self.prices.write() {
+            prices.insert((base, quote), price);
+        }
+    }
+}
+
+#[async_trait]
+impl PriceOracle for StaticPriceOracle {
+    fn name(&self) -> &str {
+        "static"
+    }
+
+    async fn get_price(&self, base: &Pubkey, quote: &Pubkey) -> Result<OraclePrice> {
+        let prices = self
+            .prices
+            .read()
+            .map_err(|e| SolanaError::Other(e.to_string()))?;
+
+        // 只配置了反向价格时取倒数
+        let price = match prices.get(&(*base, *quote)) {
+            Some(price) => *price,
+            None => match prices.get(&(*quote, *base)) {
+                Some(price) if *price > 0.0 => 1.0 / *price,
+                _ => {
+                    return Err(SolanaError::QuoteRejected(format!(
+                        "No oracle price for {} -> {}",
+                        base, quote
+                    )))
+                }
+            },
+        };
+
+        Ok(OraclePrice {
+            price,
+            published_at: SystemTime::now(),
+            source: self.name().to_string(),
+        })
+    }
+}
+
+/// HTTP 价格服务适配器
+///
+/// 请求 `GET {endpoint}?base=<mint>&quote=<mint>`，
+/// 响应 `{"price": <f64>, "publishTime": <unix 秒>}`
+pub struct HttpPriceOracle {
+    client: reqwest::Client,
+    endpoint: String,
+    api_key: Option<String>,
+}
+
+/// HTTP 价格服务响应
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HttpPriceResponse {
+    price: f64,
+    publish_time: u64,
+}
+
+impl HttpPriceOracle {
+    /// 创建 HTTP 价格适配器
+    pub fn new(endpoint: &str, api_key: Option<String>, timeout: Duration) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| SolanaError::ConfigError(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            endpoint: endpoint.to_string(),
+            api_key,
+        })
+    }
+}
+
+#[async_trait]
+impl PriceOracle for HttpPriceOracle {
+    fn name(&self) -> &str {
+        "http_oracle"
+    }
+
+    async fn get_price(&self, base: &Pubkey, quote: &Pubkey) -> Result<OraclePrice> {
+        let mut builder = self
+            .client
+            .get(&self.endpoint)
+            .query(&[("base", base.to_string()), ("quote", quote.to_string())]);
+        if let Some(api_key) = &self.api_key {
+            builder = builder.bearer_auth(api_key);
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(SolanaError::RpcError(format!(
+                "Price oracle returned {}",
+                status
+            )));
+        }
+
+        let body: HttpPriceResponse = response
+            .json()
+            .await
+            .map_err(|e| SolanaError::SerializationError(e.to_string()))?;
+
+        Ok(OraclePrice {
+            price: body.price,
+            published_at: UNIX_EPOCH + Duration::from_secs(body.publish_time),
+            source: self.name().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oracle_price(price: f64, age: Duration) -> OraclePrice {
+        OraclePrice {
+            price,
+            published_at: SystemTime::now() - age,
+            source: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_price_impact_within_limit() {
+        let guard = QuoteGuard::default();
+        let check = guard
+            .check(0.995, SystemTime::now(), &oracle_price(1.0, Duration::ZERO))
+            .unwrap();
+
+        assert_eq!(check.price_impact_bps, 50);
+    }
+
+    #[test]
+    fn test_better_than_oracle_has_no_impact() {
+        let guard = QuoteGuard::default();
+        let check = guard
+            .check(1.2, SystemTime::now(), &oracle_price(1.0, Duration::ZERO))
+            .unwrap();
+
+        assert_eq!(check.price_impact_bps, 0);
+    }
+
+    #[test]
+    fn test_price_impact_over_limit_is_rejected() {
+        let guard = QuoteGuard::default();
+        let result = guard.check(0.9, SystemTime::now(), &oracle_price(1.0, Duration::ZERO));
+
+        assert!(matches!(result, Err(SolanaError::QuoteRejected(_))));
+    }
+
+    #[test]
+    fn test_stale_oracle_price_is_rejected() {
+        let guard = QuoteGuard::default();
+        let result = guard.check(
+            1.0,
+            SystemTime::now(),
+            &oracle_price(1.0, Duration::from_secs(120)),
+        );
+
+        assert!(matches!(result, Err(SolanaError::StaleQuote(_))));
+    }
+
+    #[tokio::test]
+    async fn test_static_oracle_inverts_reverse_pair() {
+        let (sol, usdc) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let oracle = StaticPriceOracle::new();
+        oracle.set_price(sol, usdc, 4.0);
+
+        let price = oracle.get_price(&usdc, &sol).await.unwrap();
+        assert_eq!(price.price, 0.25);
+    }
+}
@@ -0,0 +1,672 @@
+//! 批量发放模块
+//!
+//! 空投、发薪等场景一次要向大量地址转账。本模块把每一行拆成
+//! “幂等创建接收方关联账户 + 转账”两条指令（带标记的行在转账前再加一条备注指令），
+//! 再按交易大小上限尽量多地装进同一笔交易，
+//! 逐笔签名发送；某一笔失败只影响其中的行，其余交易照常发送。
+//! 提供地址查找表时改为 v0 交易，mint、程序与付款方账户只占 1 字节下标，单笔能装下更多行
+
+use solana_client::rpc_config::{RpcAccountInfoConfig, UiAccountEncoding};
+use solana_program::program_pack::Pack;
+use solana_sdk::{
+    instruction::Instruction,
+    message::{AddressLookupTableAccount, Message},
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use spl_token::state::Account as TokenAccount;
+use std::sync::Arc;
+
+use crate::error::{Result, SolanaError};
+use crate::lock::{ensure_lock_held, lock_wallet, DistributedLock};
+use crate::lookup_table::{compile_versioned_transaction, versioned_transaction_size};
+use crate::memo::TransferTag;
+use crate::risk::{RiskGate, ScreeningRequest};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+use crate::simulation::send_with_preflight;
+use crate::token::{unpack_token_amount, TokenProgram, MAX_MULTIPLE_ACCOUNTS};
+
+/// 单笔交易序列化后的大小上限（UDP 包负载）
+const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// 单笔交易最多包含的转账行数，避免计算单元超限
+pub const MAX_TRANSFERS_PER_TRANSACTION: usize = 8;
+
+/// 使用地址查找表时单笔交易最多包含的转账行数
+pub const MAX_TRANSFERS_PER_VERSIONED_TRANSACTION: usize = 16;
+
+/// 每个签名的基础网络费用（lamports）
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// 一行发放
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayoutTransfer {
+    /// 接收方钱包地址
+    pub recipient: Pubkey,
+
+    /// mint
+    pub mint: Pubkey,
+
+    /// mint 所属的 Token 程序
+    pub token_program: TokenProgram,
+
+    /// mint 的小数位，转账指令据此校验
+    pub decimals: u8,
+
+    /// 数量（最小单位）
+    pub amount: u64,
+
+    /// 备注与引用标记，通常由发放行 ID 生成
+    pub tag: Option<TransferTag>,
+}
+
+/// 一笔交易的发送结果
+#[derive(Debug, Clone)]
+pub struct PayoutPackResult {
+    /// 交易包含的行在输入中的下标
+    pub rows: Vec<usize>,
+
+    /// 交易签名，确定失败时为空
+    pub signature: Option<Signature>,
+
+    /// 失败原因
+    pub error: Option<String>,
+
+    /// 交易已签名发出但成败未知，需按签名或引用核对链上状态后再判定
+    pub outcome_unknown: bool,
+}
+
+fn transfer_instructions(payer: &Pubkey, row: &PayoutTransfer) -> Result<Vec<Instruction>> {
+    let source = row.token_program.associated_token_address(payer, &row.mint);
+    let destination = row
+        .token_program
+        .associated_token_address(&row.recipient, &row.mint);
+
+    let transfer_ix = row.token_program.transfer_checked(
+        &source,
+        &row.mint,
+        &destination,
+        payer,
+        row.amount,
+        row.decimals,
+    )?;
+
+    let mut instructions = vec![create_associated_token_account_idempotent(
+        payer,
+        &row.recipient,
+        &row.mint,
+        &row.token_program.id(),
+    )];
+    match &row.tag {
+        Some(tag) => instructions.extend(tag.attach(transfer_ix, payer)),
+        None => instructions.push(transfer_ix),
+    }
+
+    Ok(instructions)
+}
+
+/// 交易签名后的序列化大小：签名数量前缀 + 各签名 + 消息
+fn transaction_size(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+) -> Result<usize> {
+    if !lookup_tables.is_empty() {
+        return versioned_transaction_size(payer, instructions, lookup_tables);
+    }
+
+    let message = Message::new(instructions, Some(payer));
+    let signatures = message.header.num_required_signatures as usize;
+    Ok(1 + signatures * 64 + message.serialize().len())
+}
+
+/// 按交易大小与行数上限把发放行分组，每组对应一笔交易
+///
+/// `lookup_tables` 为空时按旧版交易计算大小；单行就超出大小上限时返回错误
+pub fn pack_transfers(
+    payer: &Pubkey,
+    rows: &[PayoutTransfer],
+    lookup_tables: &[AddressLookupTableAccount],
+) -> Result<Vec<Vec<usize>>> {
+    let max_rows = if lookup_tables.is_empty() {
+        MAX_TRANSFERS_PER_TRANSACTION
+    } else {
+        MAX_TRANSFERS_PER_VERSIONED_TRANSACTION
+    };
+    let mut packs = Vec::new();
+    let mut current = Vec::new();
+    let mut instructions = Vec::new();
+
+    for (index, row) in rows.iter().enumerate() {
+        let row_instructions = transfer_instructions(payer, row)?;
+
+        let mut candidate = instructions.clone();
+        candidate.extend(row_instructions.iter().cloned());
+        if current.len() < max_rows
+            && transaction_size(payer, &candidate, lookup_tables)? <= MAX_TRANSACTION_SIZE
+        {
+            current.push(index);
+            instructions = candidate;
+            continue;
+        }
+
+        if current.is_empty() {
+            return Err(SolanaError::TokenTransferError(format!(
+                "Payout row {} does not fit in a single transaction",
+                index
+            )));
+        }
+        packs.push(std::mem::take(&mut current));
+
+        if transaction_size(payer, &row_instructions, lookup_tables)? > MAX_TRANSACTION_SIZE {
+            return Err(SolanaError::TokenTransferError(format!(
+                "Payout row {} does not fit in a single transaction",
+                index
+            )));
+        }
+        current.push(index);
+        instructions = row_instructions;
+    }
+
+    if !current.is_empty() {
+        packs.push(current);
+    }
+
+    Ok(packs)
+}
+
+fn failed_pack(pack: &[usize], error: SolanaError) -> PayoutPackResult {
+    tracing::warn!(
+        "Payout transaction with {} rows failed: {}",
+        pack.len(),
+        error
+    );
+    PayoutPackResult {
+        rows: pack.to_vec(),
+        signature: None,
+        error: Some(error.to_string()),
+        outcome_unknown: false,
+    }
+}
+
+/// 按分组估算网络费用，每笔交易只有付款方一个签名
+pub fn estimate_network_fee(packs: &[Vec<usize>]) -> u64 {
+    packs.len() as u64 * LAMPORTS_PER_SIGNATURE
+}
+
+/// 按 mint 汇总需要转出的数量，返回 (mint, 付款方关联账户, 数量)，按首次出现的顺序排列
+pub fn required_token_amounts(
+    payer: &Pubkey,
+    rows: &[PayoutTransfer],
+) -> Result<Vec<(Pubkey, Pubkey, u64)>> {
+    let mut required: Vec<(Pubkey, Pubkey, u64)> = Vec::new();
+    for row in rows {
+        let total = match required.iter_mut().find(|(mint, _, _)| *mint == row.mint) {
+            Some((_, _, total)) => total,
+            None => {
+                let source = row.token_program.associated_token_address(payer, &row.mint);
+                required.push((row.mint, source, 0));
+                &mut required.last_mut().expect("just pushed").2
+            },
+        };
+        *total = total.checked_add(row.amount).ok_or_else(|| {
+            SolanaError::TokenTransferError(format!("Payout total of mint {} overflows", row.mint))
+        })?;
+    }
+    Ok(required)
+}
+
+/// 批量发放执行器
+pub struct PayoutExecutor {
+    rpc_client: RpcHandle,
+    write_client: RpcHandle,
+    lookup_tables: Vec<AddressLookupTableAccount>,
+    risk_gate: Option<Arc<RiskGate>>,
+    wallet_lock: Option<Arc<dyn DistributedLock>>,
+}
+
+impl PayoutExecutor {
+    /// 创建新的批量发放执行器
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            rpc_client: RpcHandle::new(rpc_url),
+            write_client: RpcHandle::new(rpc_url),
+            lookup_tables: Vec::new(),
+            risk_gate: None,
+            wallet_lock: None,
+        }
+    }
+
+    /// 从 RPC 端点池创建批量发放执行器
+    pub fn from_pool(pool: &Arc<RpcPool>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcHandle::pooled(pool, RpcTraffic::Read),
+            write_client: RpcHandle::pooled(pool, RpcTraffic::Write),
+            lookup_tables: Vec::new(),
+            risk_gate: None,
+            wallet_lock: None,
+        })
+    }
+
+    /// 设置风险闸门，每笔交易发送前筛查其中的全部接收方
+    pub fn with_risk_gate(mut self, risk_gate: Arc<RiskGate>) -> Self {
+        self.risk_gate = Some(risk_gate);
+        self
+    }
+
+    /// 设置分布式锁，每笔交易发送前对付款方钱包加锁，与其他转出串行化
+    pub fn with_wallet_lock(mut self, wallet_lock: Arc<dyn DistributedLock>) -> Self {
+        self.wallet_lock = Some(wallet_lock);
+        self
+    }
+
+    /// 设置发送时引用的地址查找表，设置后以 v0 交易发送
+    pub fn with_lookup_tables(mut self, lookup_tables: Vec<AddressLookupTableAccount>) -> Self {
+        self.lookup_tables = lookup_tables;
+        self
+    }
+
+    /// 按执行器的发送方式分组，分组结果与 [`Self::execute_pack`] 发送的交易一一对应
+    pub fn pack(&self, payer: &Pubkey, rows: &[PayoutTransfer]) -> Result<Vec<Vec<usize>>> {
+        pack_transfers(payer, rows, &self.lookup_tables)
+    }
+
+    /// 检查付款方余额足以覆盖整批发放，不足时返回 `InsufficientBalance`
+    ///
+    /// 逐个 mint 核对付款方关联账户余额；SOL 需覆盖全部交易的网络费用，
+    /// 以及尚未创建的接收方关联账户租金（按不带扩展的账户大小估算）
+    pub async fn check_funds(
+        &self,
+        payer: &Pubkey,
+        rows: &[PayoutTransfer],
+        packs: &[Vec<usize>],
+    ) -> Result<()> {
+        let required = required_token_amounts(payer, rows)?;
+        let sources: Vec<Pubkey> = required.iter().map(|(_, source, _)| *source).collect();
+        for ((mint, _, amount), account) in required.iter().zip(self.read_accounts(&sources)?) {
+            let balance = match account {
+                Some(data) => unpack_token_amount(&data)?,
+                None => 0,
+            };
+            if balance < *amount {
+                return Err(SolanaError::InsufficientBalance(format!(
+                    "Payout needs {} of mint {}, payer holds {}",
+                    amount, mint, balance
+                )));
+            }
+        }
+
+        let mut destinations: Vec<Pubkey> = rows
+            .iter()
+            .map(|row| {
+                row.token_program
+                    .associated_token_address(&row.recipient, &row.mint)
+            })
+            .collect();
+        destinations.sort();
+        destinations.dedup();
+        let missing = self
+            .read_accounts(&destinations)?
+            .iter()
+            .filter(|account| account.is_none())
+            .count() as u64;
+        let rent = if missing == 0 {
+            0
+        } else {
+            self.rpc_client
+                .call(|client| client.get_minimum_balance_for_rent_exemption(TokenAccount::LEN))
+                .map_err(|e| SolanaError::RpcError(e.to_string()))?
+        };
+
+        let needed = estimate_network_fee(packs).saturating_add(rent.saturating_mul(missing));
+        let lamports = self
+            .rpc_client
+            .call(|client| client.get_balance(payer))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+        if lamports < needed {
+            return Err(SolanaError::InsufficientBalance(format!(
+                "Payout needs {} lamports for fees and {} new token accounts, payer holds {}",
+                needed, missing, lamports
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// 批量读取账户数据，账户不存在时为 None
+    fn read_accounts(&self, accounts: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>> {
+        let mut data = Vec::with_capacity(accounts.len());
+        for chunk in accounts.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let response = self
+                .rpc_client
+                .call(|client| {
+                    client.get_multiple_ui_accounts_with_config(
+                        chunk,
+                        RpcAccountInfoConfig {
+                            encoding: Some(UiAccountEncoding::Base64),
+                            commitment: Some(client.commitment()),
+                            ..RpcAccountInfoConfig::default()
+                        },
+                    )
+                })
+                .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+            for account in response.value {
+                data.push(match account {
+                    Some(account) => Some(account.data.decode().ok_or_else(|| {
+                        SolanaError::RpcError("Failed to decode account data".to_string())
+                    })?),
+                    None => None,
+                });
+            }
+        }
+        Ok(data)
+    }
+
+    /// 发送一组发放行组成的交易，发送失败记录在结果中而不是返回错误
+    ///
+    /// 任一接收方未通过风险筛查时整笔交易不发送，原因记录在结果中
+    pub async fn execute_pack(
+        &self,
+        payer: &Keypair,
+        rows: &[PayoutTransfer],
+        pack: &[usize],
+    ) -> Result<PayoutPackResult> {
+        let payer_pubkey = payer.pubkey();
+        let mut pack_rows = Vec::with_capacity(pack.len());
+        for &index in pack {
+            pack_rows.push(rows.get(index).ok_or_else(|| {
+                SolanaError::TokenTransferError(format!("Payout row {} out of range", index))
+            })?);
+        }
+        if let Err(e) = self.screen(&payer_pubkey, &pack_rows).await {
+            return Ok(failed_pack(pack, e));
+        }
+
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &payer_pubkey).await?;
+        let mut instructions = Vec::with_capacity(pack.len() * 2);
+        for row in &pack_rows {
+            instructions.extend(transfer_instructions(&payer_pubkey, row)?);
+        }
+
+        let transaction = self
+            .write_client
+            .call(|client| client.get_latest_blockhash())
+            .map_err(|e| SolanaError::RpcError(e.to_string()))
+            .and_then(|recent_blockhash| {
+                if self.lookup_tables.is_empty() {
+                    let mut transaction =
+                        Transaction::new_with_payer(&instructions, Some(&payer_pubkey));
+                    transaction.sign(&[payer], recent_blockhash);
+                    return Ok(transaction.into());
+                }
+
+                compile_versioned_transaction(
+                    payer,
+                    &instructions,
+                    &self.lookup_tables,
+                    recent_blockhash,
+                )
+            });
+        let sent = match transaction {
+            Ok(transaction) => match ensure_lock_held(wallet_lock.as_ref()).await {
+                Ok(()) => send_with_preflight(&self.write_client, &transaction)
+                    .map_err(|e| (e, transaction.signatures.first().copied())),
+                Err(e) => Err((e, None)),
+            },
+            Err(e) => Err((e, None)),
+        };
+
+        Ok(match sent {
+            Ok(signature) => PayoutPackResult {
+                rows: pack.to_vec(),
+                signature: Some(signature),
+                error: None,
+                outcome_unknown: false,
+            },
+            // 交易可能已经上链，保留签名供核对，不能按失败处理
+            Err((e, signature)) if e.is_outcome_unknown() => {
+                tracing::warn!(
+                    "Payout transaction with {} rows has unknown outcome: {}",
+                    pack.len(),
+                    e
+                );
+                PayoutPackResult {
+                    rows: pack.to_vec(),
+                    signature,
+                    error: Some(e.to_string()),
+                    outcome_unknown: true,
+                }
+            },
+            Err((e, _)) => failed_pack(pack, e),
+        })
+    }
+
+    /// 逐行筛查接收方，第一个未通过的行中止筛查
+    async fn screen(&self, payer: &Pubkey, rows: &[&PayoutTransfer]) -> Result<()> {
+        let Some(risk_gate) = &self.risk_gate else {
+            return Ok(());
+        };
+        for row in rows {
+            risk_gate
+                .check(&ScreeningRequest {
+                    from: *payer,
+                    to: row.recipient,
+                    token_mint: Some(row.mint),
+                    amount: row.amount,
+                    reference: row.tag.as_ref().map(|tag| tag.memo.clone()),
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// 由付款方钱包逐笔发送已分组的发放行
+    pub async fn execute(
+        &self,
+        payer: &Keypair,
+        rows: &[PayoutTransfer],
+        packs: &[Vec<usize>],
+    ) -> Result<Vec<PayoutPackResult>> {
+        let mut results = Vec::with_capacity(packs.len());
+        for pack in packs {
+            results.push(self.execute_pack(payer, rows, pack).await?);
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(mint: Pubkey) -> PayoutTransfer {
+        PayoutTransfer {
+            recipient: Pubkey::new_unique(),
+            mint,
+            token_program: TokenProgram::SplToken,
+            decimals: 6,
+            amount: 1_000,
+            tag: None,
+        }
+    }
+
+    #[test]
+    fn test_pack_transfers() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let rows: Vec<_> = (0..20).map(|_| row(mint)).collect();
+
+        let packs = pack_transfers(&payer, &rows, &[]).unwrap();
+        let flattened: Vec<usize> = packs.iter().flatten().copied().collect();
+        assert_eq!(flattened, (0..20).collect::<Vec<_>>());
+
+        for pack in &packs {
+            assert!(pack.len() <= MAX_TRANSFERS_PER_TRANSACTION);
+            let instructions: Vec<_> = pack
+                .iter()
+                .flat_map(|&index| transfer_instructions(&payer, &rows[index]).unwrap())
+                .collect();
+            assert!(transaction_size(&payer, &instructions, &[]).unwrap() <= MAX_TRANSACTION_SIZE);
+        }
+        assert_eq!(
+            estimate_network_fee(&packs),
+            packs.len() as u64 * LAMPORTS_PER_SIGNATURE
+        );
+    }
+
+    #[test]
+    fn test_pack_transfers_mixed_mints() {
+        // 每行使用不同的 mint 时账户更多，单笔交易装下的行数更少
+        let payer = Pubkey::new_unique();
+        let rows: Vec<_> = (0..8).map(|_| row(Pubkey::new_unique())).collect();
+        let packs = pack_transfers(&payer, &rows, &[]).unwrap();
+
+        assert!(packs.len() > 1);
+        assert_eq!(packs.iter().map(Vec::len).sum::<usize>(), 8);
+        assert!(pack_transfers(&payer, &[], &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pack_transfers_with_lookup_table() {
+        let payer = Pubkey::new_unique();
+        let mints: Vec<_> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let rows: Vec<_> = (0..40).map(|i| row(mints[i % mints.len()])).collect();
+        let table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: crate::lookup_table::custody_addresses(&[payer], &mints),
+        };
+
+        let legacy = pack_transfers(&payer, &rows, &[]).unwrap();
+        let versioned = pack_transfers(&payer, &rows, std::slice::from_ref(&table)).unwrap();
+
+        assert!(versioned.len() < legacy.len());
+        assert_eq!(versioned.iter().map(Vec::len).sum::<usize>(), 40);
+        for pack in &versioned {
+            assert!(pack.len() <= MAX_TRANSFERS_PER_VERSIONED_TRANSACTION);
+        }
+    }
+
+    #[test]
+    fn test_pack_tagged_transfers() {
+        // 每行多出备注指令与引用账户，单笔交易装下的行数更少
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let plain: Vec<_> = (0..16).map(|_| row(mint)).collect();
+        let tagged: Vec<_> = plain
+            .iter()
+            .enumerate()
+            .map(|(i, row)| PayoutTransfer {
+                tag: Some(TransferTag::new(&format!(
+                    "01J9ZP4Q6V2X8N3M5K7H1G0F{:02}",
+                    i
+                ))),
+                ..row.clone()
+            })
+            .collect();
+
+        let packs = pack_transfers(&payer, &tagged, &[]).unwrap();
+        assert!(packs.len() > pack_transfers(&payer, &plain, &[]).unwrap().len());
+
+        let instructions = transfer_instructions(&payer, &tagged[0]).unwrap();
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(instructions[1].program_id, crate::memo::MEMO_PROGRAM_ID);
+        assert_eq!(
+            instructions[2].accounts.last().unwrap().pubkey,
+            tagged[0].tag.as_ref().unwrap().reference
+        );
+    }
+
+    #[test]
+    fn test_required_token_amounts() {
+        let payer = Pubkey::new_unique();
+        let (usdc, pyusd) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let rows = vec![
+            row(usdc),
+            PayoutTransfer {
+                token_program: TokenProgram::Token2022,
+                ..row(pyusd)
+            },
+            row(usdc),
+        ];
+
+        let required = required_token_amounts(&payer, &rows).unwrap();
+        assert_eq!(
+            required,
+            vec![
+                (
+                    usdc,
+                    TokenProgram::SplToken.associated_token_address(&payer, &usdc),
+                    2_000
+                ),
+                (
+                    pyusd,
+                    TokenProgram::Token2022.associated_token_address(&payer, &pyusd),
+                    1_000
+                ),
+            ]
+        );
+
+        let overflow = vec![
+            PayoutTransfer {
+                amount: u64::MAX,
+                ..row(usdc)
+            },
+            row(usdc),
+        ];
+        assert!(required_token_amounts(&payer, &overflow).is_err());
+    }
+
+    #[test]
+    fn test_transfer_instructions_use_registered_program() {
+        let payer = Pubkey::new_unique();
+        let row = PayoutTransfer {
+            token_program: TokenProgram::Token2022,
+            ..row(Pubkey::new_unique())
+        };
+        let destination =
+            TokenProgram::Token2022.associated_token_address(&row.recipient, &row.mint);
+
+        let instructions = transfer_instructions(&payer, &row).unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert!(instructions
+            .iter()
+            .any(|ix| ix.accounts.iter().any(|meta| meta.pubkey == destination)));
+        assert_eq!(instructions[1].program_id, TokenProgram::Token2022.id());
+        assert_ne!(
+            destination,
+            TokenProgram::SplToken.associated_token_address(&row.recipient, &row.mint)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_pack_screens_recipients() {
+        use crate::risk::{DenyListEntry, DenyListScreener, ScreeningDecision};
+
+        let payer = Keypair::new();
+        let rows: Vec<_> = (0..3).map(|_| row(Pubkey::new_unique())).collect();
+        let screener = DenyListScreener::from_entries(
+            [(
+                rows[1].recipient,
+                DenyListEntry {
+                    decision: ScreeningDecision::Deny,
+                    reason: "OFAC SDN".to_string(),
+                },
+            )]
+            .into(),
+        );
+        // 被拒绝的交易不会请求 RPC，端点不可达也不影响结果
+        let executor = PayoutExecutor::new("http://127.0.0.1:1")
+            .with_risk_gate(Arc::new(RiskGate::new(Arc::new(screener))));
+
+        let result = executor
+            .execute_pack(&payer, &rows, &[0, 1, 2])
+            .await
+            .unwrap();
+        assert_eq!(result.rows, vec![0, 1, 2]);
+        assert!(result.signature.is_none());
+        assert!(!result.outcome_unknown);
+        assert!(result.error.unwrap().contains("OFAC SDN"));
+    }
+}
@@ -0,0 +1,437 @@
+//! 租金回收模块
+//!
+//! 每创建一个关联 Token 账户都会锁定一笔租金豁免押金，
+//! 本模块找出托管钱包名下余额为零的 Token 账户，在以下情况下关闭并把租金退回系统钱包：
+//! 1. 账户的 mint 已不在支持列表内
+//! 2. 账户最近一次链上活动距今超过闲置天数
+//!
+//! 无法确定最近活动时间（节点未保留历史签名）的账户按未闲置处理
+
+use serde::{Deserialize, Serialize};
+use solana_client::{
+    rpc_client::GetConfirmedSignaturesForAddress2Config,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, UiAccountEncoding},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::{program_option::COption, program_pack::Pack};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use spl_token::{
+    instruction::close_account,
+    state::{Account as TokenAccount, AccountState},
+};
+use std::{collections::HashSet, sync::Arc};
+
+use crate::error::{Result, SolanaError};
+use crate::lock::{lock_wallet, renew_lock, DistributedLock};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+use crate::simulation::send_with_preflight;
+
+/// Token 账户数据中 owner 字段的偏移
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+
+/// 每天的秒数
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// 回收策略
+#[derive(Debug, Clone)]
+pub struct ReclaimPolicy {
+    /// 仍在支持的 mint，不在其中的空账户直接回收
+    pub supported_mints: HashSet<Pubkey>,
+
+    /// 闲置天数，支持的 mint 超过该天数无活动才回收
+    pub idle_days: u32,
+
+    /// 只列出可回收账户、不发送交易
+    pub dry_run: bool,
+}
+
+/// 回收原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReclaimReason {
+    /// mint 不再支持
+    UnsupportedMint,
+    /// 长期闲置
+    Idle,
+}
+
+/// 余额为零的 Token 账户
+#[derive(Debug, Clone)]
+pub struct EmptyTokenAccount {
+    /// Token 账户地址
+    pub address: Pubkey,
+
+    /// 账户所有者（托管钱包）
+    pub owner: Pubkey,
+
+    /// mint
+    pub mint: Pubkey,
+
+    /// 账户持有的 lamports，即关闭后可回收的租金
+    pub lamports: u64,
+
+    /// 有权关闭账户的地址，未单独设置时为所有者
+    pub close_authority: Pubkey,
+
+    /// 账户是否被冻结，冻结账户无法关闭
+    pub frozen: bool,
+}
+
+/// 单个账户的回收状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReclaimStatus {
+    /// 已关闭，租金已退回
+    Closed,
+    /// dry-run，仅列出
+    DryRun,
+    /// 无法处理，如缺少关闭权限的签名密钥或账户被冻结
+    Skipped,
+    /// 发送失败
+    Failed,
+}
+
+/// 单个账户的回收结果
+#[derive(Debug, Clone)]
+pub struct ReclaimOutcome {
+    pub account: EmptyTokenAccount,
+    pub reason: ReclaimReason,
+    pub status: ReclaimStatus,
+    /// 实际回收的 lamports，未关闭时为 0
+    pub reclaimed_lamports: u64,
+    pub signature: Option<Signature>,
+    pub error: Option<String>,
+}
+
+/// 关闭权限的签名密钥来源
+///
+/// 托管钱包的私钥不一定由本服务持有，取不到密钥的账户会被跳过
+pub trait ReclaimSigner: Send + Sync {
+    /// 返回 `authority` 对应的密钥
+    fn keypair_for(&self, authority: &Pubkey) -> Option<&Keypair>;
+}
+
+impl ReclaimSigner for Keypair {
+    fn keypair_for(&self, authority: &Pubkey) -> Option<&Keypair> {
+        (self.pubkey() == *authority).then_some(self)
+    }
+}
+
+/// 多把密钥，按关闭权限地址依次匹配
+impl ReclaimSigner for Vec<&Keypair> {
+    fn keypair_for(&self, authority: &Pubkey) -> Option<&Keypair> {
+        self.iter()
+            .copied()
+            .find(|keypair| keypair.pubkey() == *authority)
+    }
+}
+
+/// 判断空账户是否应回收
+///
+/// `last_activity` 为最近一次交易的区块时间（Unix 秒），`now` 为当前时间
+pub fn classify(
+    account: &EmptyTokenAccount,
+    policy: &ReclaimPolicy,
+    last_activity: Option<i64>,
+    now: i64,
+) -> Option<ReclaimReason> {
+    if !policy.supported_mints.contains(&account.mint) {
+        return Some(ReclaimReason::UnsupportedMint);
+    }
+
+    let idle_secs = i64::from(policy.idle_days) * SECONDS_PER_DAY;
+    match last_activity {
+        Some(block_time) if now.saturating_sub(block_time) >= idle_secs => {
+            Some(ReclaimReason::Idle)
+        },
+        _ => None,
+    }
+}
+
+/// 租金回收器
+pub struct RentReclaimer {
+    rpc_client: RpcHandle,
+    write_client: RpcHandle,
+    wallet_lock: Option<Arc<dyn DistributedLock>>,
+}
+
+impl RentReclaimer {
+    /// 创建新的租金回收器
+    pub fn new(rpc_url: &str) -> Self {
+        let rpc_client = RpcHandle::new(rpc_url);
+
+        Self {
+            write_client: rpc_client.clone(),
+            rpc_client,
+            wallet_lock: None,
+        }
+    }
+
+    /// 从 RPC 端点池创建租金回收器
+    pub fn from_pool(pool: &Arc<RpcPool>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcHandle::pooled(pool, RpcTraffic::Read),
+            write_client: RpcHandle::pooled(pool, RpcTraffic::Write),
+            wallet_lock: None,
+        })
+    }
+
+    /// 设置分布式锁，回收期间锁住钱包，每笔关闭交易发送前确认并续期
+    pub fn with_wallet_lock(mut self, wallet_lock: Arc<dyn DistributedLock>) -> Self {
+        self.wallet_lock = Some(wallet_lock);
+        self
+    }
+
+    /// 列出钱包名下余额为零的 SPL Token 账户
+    pub fn find_empty_token_accounts(&self, owner: &Pubkey) -> Result<Vec<EmptyTokenAccount>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(TokenAccount::LEN as u64),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                    TOKEN_ACCOUNT_OWNER_OFFSET,
+                    owner.to_bytes().to_vec(),
+                )),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(self.rpc_client.commitment()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let accounts = self
+            .rpc_client
+            .call(|client| {
+                client.get_program_ui_accounts_with_config(&spl_token::id(), config.clone())
+            })
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        let mut empty = Vec::new();
+        for (address, account) in accounts {
+            let data = account.data.decode().ok_or_else(|| {
+                SolanaError::TokenAccountNotFound(format!("Undecodable token account {}", address))
+            })?;
+            let token_account = TokenAccount::unpack(&data)
+                .map_err(|e| SolanaError::TokenAccountNotFound(e.to_string()))?;
+            if token_account.amount != 0 {
+                continue;
+            }
+
+            empty.push(EmptyTokenAccount {
+                address,
+                owner: token_account.owner,
+                mint: token_account.mint,
+                lamports: account.lamports,
+                close_authority: match token_account.close_authority {
+                    COption::Some(authority) => authority,
+                    COption::None => token_account.owner,
+                },
+                frozen: token_account.state == AccountState::Frozen,
+            });
+        }
+
+        Ok(empty)
+    }
+
+    /// 账户最近一次交易的区块时间
+    pub fn last_activity(&self, address: &Pubkey) -> Result<Option<i64>> {
+        let signatures = self
+            .rpc_client
+            .call(|client| {
+                client.get_signatures_for_address_with_config(
+                    address,
+                    GetConfirmedSignaturesForAddress2Config {
+                        limit: Some(1),
+                        ..Default::default()
+                    },
+                )
+            })
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        Ok(signatures.first().and_then(|status| status.block_time))
+    }
+
+    /// 关闭 Token 账户，租金退回付款方
+    fn close(
+        &self,
+        account: &EmptyTokenAccount,
+        authority: &Keypair,
+        payer: &Keypair,
+    ) -> Result<Signature> {
+        let close_ix = close_account(
+            &spl_token::id(),
+            &account.address,
+            &payer.pubkey(),
+            &authority.pubkey(),
+            &[],
+        )
+        .map_err(|e| SolanaError::Other(e.to_string()))?;
+
+        let mut transaction = Transaction::new_with_payer(&[close_ix], Some(&payer.pubkey()));
+        let recent_blockhash = self
+            .write_client
+            .call(|client| client.get_latest_blockhash())
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        if authority.pubkey() == payer.pubkey() {
+            transaction.sign(&[payer], recent_blockhash);
+        } else {
+            transaction.sign(&[payer, authority], recent_blockhash);
+        }
+
+        send_with_preflight(&self.write_client, &transaction)
+    }
+
+    /// 回收单个钱包的空 Token 账户
+    ///
+    /// 单个账户关闭失败不影响其余账户，失败原因记录在结果中
+    pub async fn reclaim_wallet(
+        &self,
+        owner: &Pubkey,
+        policy: &ReclaimPolicy,
+        signer: &dyn ReclaimSigner,
+        payer: &Keypair,
+        now: i64,
+    ) -> Result<Vec<ReclaimOutcome>> {
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), owner).await?;
+        let mut outcomes = Vec::new();
+
+        for account in self.find_empty_token_accounts(owner)? {
+            // 不再支持的 mint 无需查询活动时间
+            let last_activity = if policy.supported_mints.contains(&account.mint) {
+                self.last_activity(&account.address)?
+            } else {
+                None
+            };
+            let Some(reason) = classify(&account, policy, last_activity, now) else {
+                continue;
+            };
+
+            let outcome = |status, signature, error| ReclaimOutcome {
+                reclaimed_lamports: if status == ReclaimStatus::Closed {
+                    account.lamports
+                } else {
+                    0
+                },
+                account: account.clone(),
+                reason,
+                status,
+                signature,
+                error,
+            };
+
+            if policy.dry_run {
+                outcomes.push(outcome(ReclaimStatus::DryRun, None, None));
+                continue;
+            }
+            if account.frozen {
+                outcomes.push(outcome(
+                    ReclaimStatus::Skipped,
+                    None,
+                    Some("Token account is frozen".to_string()),
+                ));
+                continue;
+            }
+            let Some(authority) = signer.keypair_for(&account.close_authority) else {
+                outcomes.push(outcome(
+                    ReclaimStatus::Skipped,
+                    None,
+                    Some(format!(
+                        "No signing key for close authority {}",
+                        account.close_authority
+                    )),
+                ));
+                continue;
+            };
+
+            // 锁已被其他实例取得时停止回收，剩余账户下一轮再处理
+            if let Err(e) = renew_lock(wallet_lock.as_ref()).await {
+                outcomes.push(outcome(ReclaimStatus::Failed, None, Some(e.to_string())));
+                break;
+            }
+
+            match self.close(&account, authority, payer) {
+                Ok(signature) => {
+                    tracing::info!(
+                        "Closed empty token account {} ({:?}), reclaimed {} lamports",
+                        account.address,
+                        reason,
+                        account.lamports
+                    );
+                    outcomes.push(outcome(ReclaimStatus::Closed, Some(signature), None));
+                },
+                Err(e) => {
+                    tracing::warn!("Failed to close token account {}: {}", account.address, e);
+                    outcomes.push(outcome(ReclaimStatus::Failed, None, Some(e.to_string())));
+                },
+            }
+        }
+
+        Ok(outcomes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_account(mint: Pubkey) -> EmptyTokenAccount {
+        let owner = Pubkey::new_unique();
+        EmptyTokenAccount {
+            address: Pubkey::new_unique(),
+            owner,
+            mint,
+            lamports: 2_039_280,
+            close_authority: owner,
+            frozen: false,
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let supported = Pubkey::new_unique();
+        let policy = ReclaimPolicy {
+            supported_mints: HashSet::from([supported]),
+            idle_days: 30,
+            dry_run: false,
+        };
+        let now = 1_800_000_000;
+
+        assert_eq!(
+            classify(&empty_account(Pubkey::new_unique()), &policy, None, now),
+            Some(ReclaimReason::UnsupportedMint)
+        );
+
+        let account = empty_account(supported);
+        assert_eq!(
+            classify(&account, &policy, Some(now - 31 * SECONDS_PER_DAY), now),
+            Some(ReclaimReason::Idle)
+        );
+        assert_eq!(
+            classify(&account, &policy, Some(now - SECONDS_PER_DAY), now),
+            None
+        );
+        assert_eq!(classify(&account, &policy, None, now), None);
+    }
+
+    #[test]
+    fn test_keypair_signer() {
+        let keypair = Keypair::new();
+
+        assert!(keypair.keypair_for(&keypair.pubkey()).is_some());
+        assert!(keypair.keypair_for(&Pubkey::new_unique()).is_none());
+
+        let other = Keypair::new();
+        let signers = vec![&keypair, &other];
+        assert_eq!(
+            signers.keypair_for(&other.pubkey()).map(Keypair::pubkey),
+            Some(other.pubkey())
+        );
+        assert!(signers.keypair_for(&Pubkey::new_unique()).is_none());
+    }
+}
@@ -0,0 +1,560 @@
+//! 储备金证明（Proof of Reserves）模块
+//!
+//! 1. 在同一 slot 上快照所有托管钱包的 Token 余额（储备）
+//! 2. 用用户负债构建 Merkle 求和树，公开根哈希与负债总额
+//! 3. 为每个用户生成包含性证明，用户可离线验证自己的余额被计入
+//! 4. 导出由系统钱包签名的 JSON / CSV 报告
+//!
+//! 叶子哈希包含快照 ID 作为盐，避免跨快照比对同一用户的余额变化
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    hash::{hashv, Hash},
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use crate::error::{Result, SolanaError};
+use crate::token::{TokenManager, TokenProgram};
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// 用户负债
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Liability {
+    /// 用户 ID
+    pub user_id: String,
+
+    /// 负债数量（最小单位）
+    pub amount: u64,
+}
+
+/// Merkle 求和树节点
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SumNode {
+    /// 节点哈希
+    pub hash: Hash,
+
+    /// 子树负债总和
+    pub sum: u64,
+}
+
+impl SumNode {
+    /// 计算叶子节点
+    pub fn leaf(salt: &str, user_id: &str, amount: u64) -> Self {
+        Self {
+            hash: hashv(&[
+                LEAF_PREFIX,
+                salt.as_bytes(),
+                user_id.as_bytes(),
+                &amount.to_le_bytes(),
+            ]),
+            sum: amount,
+        }
+    }
+
+    /// 合并左右子节点，父节点同时承诺子树哈希与子树总和
+    pub fn parent(left: &SumNode, right: &SumNode) -> Result<Self> {
+        let sum = left.sum.checked_add(right.sum).ok_or_else(|| {
+            SolanaError::ReservesError("Liability sum overflows u64".to_string())
+        })?;
+
+        Ok(Self {
+            hash: hashv(&[
+                NODE_PREFIX,
+                left.hash.as_ref(),
+                &left.sum.to_le_bytes(),
+                right.hash.as_ref(),
+                &right.sum.to_le_bytes(),
+            ]),
+            sum,
+        })
+    }
+}
+
+/// 用户负债 Merkle 求和树
+///
+/// 奇数个节点时，最后一个节点直接晋升到上一层，不做复制
+pub struct LiabilityTree {
+    salt: String,
+    levels: Vec<Vec<SumNode>>,
+    positions: HashMap<String, usize>,
+    liabilities: Vec<Liability>,
+}
+
+impl LiabilityTree {
+    /// 构建负债树，叶子顺序即传入顺序
+    pub fn build(salt: &str, liabilities: Vec<Liability>) -> Result<Self> {
+        let mut positions = HashMap::with_capacity(liabilities.len());
+        let mut leaves = Vec::with_capacity(liabilities.len());
+
+        for (index, liability) in liabilities.iter().enumerate() {
+            if positions.insert(liability.user_id.clone(), index).is_some() {
+                return Err(SolanaError::ReservesError(format!(
+                    "Duplicate liability for user {}",
+                    liability.user_id
+                )));
+            }
+            leaves.push(SumNode::leaf(salt, &liability.user_id, liability.amount));
+        }
+
+        let mut levels = vec![leaves];
+        while levels.last().map(|l| l.len() > 1).unwrap_or(false) {
+            let current = levels.last().unwrap();
+            let mut next = Vec::with_capacity(current.len().div_ceil(2));
+            for pair in current.chunks(2) {
+                match pair {
+                    [left, right] => next.push(SumNode::parent(left, right)?),
+                    [single] => next.push(*single),
+                    _ => unreachable!(),
+                }
+            }
+            levels.push(next);
+        }
+
+        Ok(Self {
+            salt: salt.to_string(),
+            levels,
+            positions,
+            liabilities,
+        })
+    }
+
+    /// 根节点，空树返回默认哈希与 0
+    pub fn root(&self) -> SumNode {
+        self.levels
+            .last()
+            .and_then(|level| level.first().copied())
+            .unwrap_or(SumNode {
+                hash: Hash::default(),
+                sum: 0,
+            })
+    }
+
+    /// 叶子数量
+    pub fn leaf_count(&self) -> usize {
+        self.liabilities.len()
+    }
+
+    /// 负债列表（按叶子顺序）
+    pub fn liabilities(&self) -> &[Liability] {
+        &self.liabilities
+    }
+
+    /// 生成指定用户的包含性证明
+    pub fn proof(&self, user_id: &str) -> Option<InclusionProof> {
+        let leaf_index = *self.positions.get(user_id)?;
+        let mut index = leaf_index;
+        let mut steps = Vec::new();
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = if index % 2 == 0 { index + 1 } else { index - 1 };
+            if let Some(node) = level.get(sibling) {
+                steps.push(ProofStep {
+                    hash: node.hash.to_string(),
+                    sum: node.sum,
+                    is_left: sibling < index,
+                });
+            }
+            index /= 2;
+        }
+
+        let root = self.root();
+        Some(InclusionProof {
+            salt: self.salt.clone(),
+            user_id: user_id.to_string(),
+            amount: self.liabilities[leaf_index].amount,
+            leaf_index,
+            steps,
+            root_hash: root.hash.to_string(),
+            root_sum: root.sum,
+        })
+    }
+}
+
+/// 证明路径上的兄弟节点
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofStep {
+    /// 兄弟节点哈希（base58）
+    pub hash: String,
+
+    /// 兄弟节点子树总和
+    pub sum: u64,
+
+    /// 兄弟节点是否在左侧
+    pub is_left: bool,
+}
+
+/// 包含性证明
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InclusionProof {
+    /// 叶子盐（快照 ID）
+    pub salt: String,
+
+    /// 用户 ID
+    pub user_id: String,
+
+    /// 用户负债
+    pub amount: u64,
+
+    /// 叶子位置
+    pub leaf_index: usize,
+
+    /// 自底向上的兄弟节点
+    pub steps: Vec<ProofStep>,
+
+    /// 公开的根哈希（base58）
+    pub root_hash: String,
+
+    /// 公开的负债总额
+    pub root_sum: u64,
+}
+
+impl InclusionProof {
+    /// 从叶子重算到根，校验哈希与总和都与公开值一致
+    pub fn verify(&self) -> bool {
+        let mut node = SumNode::leaf(&self.salt, &self.user_id, self.amount);
+
+        for step in &self.steps {
+            let Ok(hash) = Hash::from_str(&step.hash) else {
+                return false;
+            };
+            let sibling = SumNode {
+                hash,
+                sum: step.sum,
+            };
+            let parent = if step.is_left {
+                SumNode::parent(&sibling, &node)
+            } else {
+                SumNode::parent(&node, &sibling)
+            };
+            match parent {
+                Ok(parent) => node = parent,
+                Err(_) => return false,
+            }
+        }
+
+        node.hash.to_string() == self.root_hash && node.sum == self.root_sum
+    }
+}
+
+/// 托管钱包余额
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WalletBalance {
+    /// 钱包地址
+    pub wallet: String,
+
+    /// 关联 Token 账户地址
+    pub token_account: String,
+
+    /// 余额（最小单位）
+    pub balance: u64,
+}
+
+/// 储备金快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReservesSnapshot {
+    /// 快照 ID（同时作为负债树的盐）
+    pub snapshot_id: String,
+
+    /// 余额读取所在 slot
+    pub slot: u64,
+
+    /// Token mint
+    pub mint: String,
+
+    /// 各托管钱包余额
+    pub balances: Vec<WalletBalance>,
+
+    /// 储备总额
+    pub total_reserves: u64,
+
+    /// 负债总额（等于负债树根节点总和）
+    pub total_liabilities: u64,
+
+    /// 负债树根哈希（base58）
+    pub liabilities_root: String,
+
+    /// 负债树叶子数量
+    pub leaf_count: usize,
+
+    /// 快照时间（Unix 秒）
+    pub created_at: i64,
+}
+
+impl ReservesSnapshot {
+    /// 储备是否覆盖负债
+    pub fn is_solvent(&self) -> bool {
+        self.total_reserves >= self.total_liabilities
+    }
+
+    /// 导出 JSON 报告
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// 导出 CSV 报告，格式为 `section,key,value`
+    pub fn to_csv(&self) -> String {
+        let mut lines = vec!["section,key,value".to_string()];
+        lines.push(format!("summary,snapshot_id,{}", self.snapshot_id));
+        lines.push(format!("summary,slot,{}", self.slot));
+        lines.push(format!("summary,mint,{}", self.mint));
+        lines.push(format!("summary,total_reserves,{}", self.total_reserves));
+        lines.push(format!("summary,total_liabilities,{}", self.total_liabilities));
+        lines.push(format!("summary,liabilities_root,{}", self.liabilities_root));
+        lines.push(format!("summary,leaf_count,{}", self.leaf_count));
+        lines.push(format!("summary,created_at,{}", self.created_at));
+        for balance in &self.balances {
+            lines.push(format!("wallet,{},{}", balance.wallet, balance.balance));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+/// 报告格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+/// 签名报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedReport {
+    /// 报告格式
+    pub format: ReportFormat,
+
+    /// 报告原文
+    pub content: String,
+
+    /// 签名者公钥（系统钱包）
+    pub signer: String,
+
+    /// 对报告原文的 ed25519 签名（base58）
+    pub signature: String,
+}
+
+impl SignedReport {
+    /// 使用系统钱包对报告签名
+    pub fn sign(keypair: &Keypair, format: ReportFormat, content: String) -> Self {
+        let signature = keypair.sign_message(content.as_bytes());
+
+        Self {
+            format,
+            content,
+            signer: keypair.pubkey().to_string(),
+            signature: signature.to_string(),
+        }
+    }
+
+    /// 校验签名
+    pub fn verify(&self) -> bool {
+        let (Ok(signer), Ok(signature)) = (
+            Pubkey::from_str(&self.signer),
+            Signature::from_str(&self.signature),
+        ) else {
+            return false;
+        };
+
+        signature.verify(signer.as_ref(), self.content.as_bytes())
+    }
+}
+
+/// 储备金证明管理器
+pub struct ReservesManager {
+    token_manager: Arc<TokenManager>,
+}
+
+impl ReservesManager {
+    /// 创建储备金证明管理器
+    pub fn new(token_manager: Arc<TokenManager>) -> Self {
+        Self { token_manager }
+    }
+
+    /// 读取托管钱包在同一 slot 上的余额
+    ///
+    /// 余额通过 `getMultipleAccounts` 读取，返回的 slot 为读取所在 slot。
+    /// 关联账户按资产登记的 Token 程序推导
+    pub async fn read_balances(
+        &self,
+        token_mint: &Pubkey,
+        token_program: TokenProgram,
+        custody_wallets: &[Pubkey],
+    ) -> Result<(u64, Vec<WalletBalance>)> {
+        let token_accounts: Vec<Pubkey> = custody_wallets
+            .iter()
+            .map(|wallet| token_program.associated_token_address(wallet, token_mint))
+            .collect();
+
+        let (slot, amounts) = self.token_manager.get_token_balances(&token_accounts).await?;
+
+        let balances = custody_wallets
+            .iter()
+            .zip(&token_accounts)
+            .zip(amounts)
+            .map(|((wallet, token_account), balance)| WalletBalance {
+                wallet: wallet.to_string(),
+                token_account: token_account.to_string(),
+                balance,
+            })
+            .collect();
+
+        Ok((slot, balances))
+    }
+
+    /// 由已读取的余额和负债构建快照与负债树
+    pub fn build_snapshot(
+        snapshot_id: &str,
+        token_mint: &Pubkey,
+        slot: u64,
+        balances: Vec<WalletBalance>,
+        liabilities: Vec<Liability>,
+    ) -> Result<(ReservesSnapshot, LiabilityTree)> {
+        let total_reserves = balances
+            .iter()
+            .try_fold(0u64, |total, b| total.checked_add(b.balance))
+            .ok_or_else(|| SolanaError::ReservesError("Reserve sum overflows u64".to_string()))?;
+
+        let tree = LiabilityTree::build(snapshot_id, liabilities)?;
+        let root = tree.root();
+
+        let snapshot = ReservesSnapshot {
+            snapshot_id: snapshot_id.to_string(),
+            slot,
+            mint: token_mint.to_string(),
+            balances,
+            total_reserves,
+            total_liabilities: root.sum,
+            liabilities_root: root.hash.to_string(),
+            leaf_count: tree.leaf_count(),
+            created_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or_default(),
+        };
+
+        if !snapshot.is_solvent() {
+            tracing::warn!(
+                "Reserves snapshot {} is under-collateralized: reserves {}, liabilities {}",
+                snapshot_id,
+                snapshot.total_reserves,
+                snapshot.total_liabilities
+            );
+        }
+
+        Ok((snapshot, tree))
+    }
+
+    /// 快照托管钱包余额并构建负债树
+    pub async fn take_snapshot(
+        &self,
+        snapshot_id: &str,
+        token_mint: &Pubkey,
+        token_program: TokenProgram,
+        custody_wallets: &[Pubkey],
+        liabilities: Vec<Liability>,
+    ) -> Result<(ReservesSnapshot, LiabilityTree)> {
+        let (slot, balances) = self
+            .read_balances(token_mint, token_program, custody_wallets)
+            .await?;
+
+        Self::build_snapshot(snapshot_id, token_mint, slot, balances, liabilities)
+    }
+}
+
+/// 储备金快照存储 trait
+#[async_trait]
+pub trait ReservesStorage: Send + Sync {
+    /// 保存快照、负债明细与签名报告
+    async fn save_snapshot(
+        &self,
+        snapshot: &ReservesSnapshot,
+        liabilities: &[Liability],
+        report: &SignedReport,
+    ) -> Result<()>;
+
+    /// 获取快照的负债明细（按叶子顺序），用于重建负债树
+    async fn get_liabilities(&self, snapshot_id: &str) -> Result<Vec<Liability>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn liabilities(n: usize) -> Vec<Liability> {
+        (0..n)
+            .map(|i| Liability {
+                user_id: format!("user-{}", i),
+                amount: (i as u64 + 1) * 100,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_every_proof_verifies() {
+        for n in [1, 2, 3, 7, 8, 13] {
+            let tree = LiabilityTree::build("snapshot", liabilities(n)).unwrap();
+            let expected: u64 = (1..=n as u64).map(|i| i * 100).sum();
+            assert_eq!(tree.root().sum, expected);
+
+            for liability in tree.liabilities() {
+                let proof = tree.proof(&liability.user_id).unwrap();
+                assert!(proof.verify(), "proof for {} of {} leaves", liability.user_id, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tampered_proof_fails() {
+        let tree = LiabilityTree::build("snapshot", liabilities(5)).unwrap();
+        let proof = tree.proof("user-2").unwrap();
+
+        let mut understated = proof.clone();
+        understated.amount -= 1;
+        assert!(!understated.verify());
+
+        let mut hidden_sum = proof.clone();
+        hidden_sum.steps[0].sum -= 1;
+        assert!(!hidden_sum.verify());
+
+        let mut other_salt = proof;
+        other_salt.salt = "another-snapshot".to_string();
+        assert!(!other_salt.verify());
+    }
+
+    #[test]
+    fn test_duplicate_user_rejected() {
+        let mut items = liabilities(2);
+        items.push(items[0].clone());
+        assert!(LiabilityTree::build("snapshot", items).is_err());
+        assert!(LiabilityTree::build("snapshot", liabilities(2)).unwrap().proof("missing").is_none());
+    }
+
+    #[test]
+    fn test_signed_report() {
+        let keypair = Keypair::new();
+        let snapshot = ReservesSnapshot {
+            snapshot_id: "snapshot".to_string(),
+            slot: 42,
+            mint: Pubkey::new_unique().to_string(),
+            balances: vec![],
+            total_reserves: 10,
+            total_liabilities: 10,
+            liabilities_root: Hash::default().to_string(),
+            leaf_count: 0,
+            created_at: 0,
+        };
+
+        let report = SignedReport::sign(&keypair, ReportFormat::Csv, snapshot.to_csv());
+        assert!(report.verify());
+
+        let mut tampered = report;
+        tampered.content = tampered.content.replace("total_reserves,10", "total_reserves,99");
+        assert!(!tampered.verify());
+    }
+}
@@ -0,0 +1,582 @@
+//! 风险筛查模块
+//!
+//! 资金离开托管之前，对交易对手地址进行制裁 / 风险筛查：
+//! 1. 本地黑名单（文件或数据表加载，支持热加载）
+//! 2. HTTP 风控服务适配器
+//! 3. 多个筛查器组合，取最严格的结果
+//!
+//! 筛查结果（含原因）通过 [`ScreeningStorage`] 落库
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use crate::error::{Result, SolanaError};
+
+/// 筛查结论
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreeningDecision {
+    /// 放行
+    Allow,
+
+    /// 需要人工复核
+    ///
+    /// 风险闸门不排队待审，与 Deny 一样直接拒绝转出；复核需在风控服务中完成，
+    /// 放行后由调用方重新发起转出
+    ManualReview,
+
+    /// 拒绝
+    Deny,
+}
+
+/// 筛查请求
+#[derive(Debug, Clone, Serialize)]
+pub struct ScreeningRequest {
+    /// 转出方（托管钱包）
+    pub from: Pubkey,
+
+    /// 交易对手地址
+    pub to: Pubkey,
+
+    /// Token mint，SOL 转账为 None
+    pub token_mint: Option<Pubkey>,
+
+    /// 转账数量（最小单位）
+    pub amount: u64,
+
+    /// 业务侧引用 ID（如提现单号）
+    pub reference: Option<String>,
+}
+
+/// 筛查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreeningResult {
+    /// 结论
+    pub decision: ScreeningDecision,
+
+    /// 原因（命中的名单、风险标签等）
+    pub reasons: Vec<String>,
+
+    /// 给出结论的筛查器名称
+    pub provider: String,
+}
+
+impl ScreeningResult {
+    /// 放行结果
+    pub fn allow(provider: &str) -> Self {
+        Self {
+            decision: ScreeningDecision::Allow,
+            reasons: Vec::new(),
+            provider: provider.to_string(),
+        }
+    }
+}
+
+/// 风险筛查 trait
+#[async_trait]
+pub trait RiskScreener: Send + Sync {
+    /// 筛查器名称（写入筛查记录）
+    fn name(&self) -> &str;
+
+    /// 筛查一笔转出
+    async fn screen(&self, request: &ScreeningRequest) -> Result<ScreeningResult>;
+}
+
+/// 筛查记录存储 trait
+#[async_trait]
+pub trait ScreeningStorage: Send + Sync {
+    /// 保存筛查记录
+    async fn save_screening_result(
+        &self,
+        request: &ScreeningRequest,
+        result: &ScreeningResult,
+    ) -> Result<()>;
+}
+
+/// 黑名单条目
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenyListEntry {
+    /// 命中后的结论（deny 或 manual_review）
+    pub decision: ScreeningDecision,
+
+    /// 原因
+    pub reason: String,
+}
+
+/// 本地黑名单筛查器
+///
+/// 文件格式：每行 `address[,deny|review][,reason]`，`#` 开头为注释。
+/// 从数据表加载时由调用方查询后通过 [`DenyListScreener::replace_entries`] 整体替换
+pub struct DenyListScreener {
+    path: Option<PathBuf>,
+    entries: RwLock<HashMap<Pubkey, DenyListEntry>>,
+    modified_at: RwLock<Option<SystemTime>>,
+}
+
+impl DenyListScreener {
+    /// 从内存条目创建（数据表来源）
+    pub fn from_entries(entries: HashMap<Pubkey, DenyListEntry>) -> Self {
+        Self {
+            path: None,
+            entries: RwLock::new(entries),
+            modified_at: RwLock::new(None),
+        }
+    }
+
+    /// 从文件创建
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let screener = Self {
+            path: Some(path.as_ref().to_path_buf()),
+            entries: RwLock::new(HashMap::new()),
+            modified_at: RwLock::new(None),
+        };
+        screener.reload()?;
+
+        Ok(screener)
+    }
+
+    /// 整体替换名单
+    pub fn replace_entries(&self, entries: HashMap<Pubkey, DenyListEntry>) {
+        let count = entries.len();
+        *self.entries.write().unwrap_or_else(|e| e.into_inner()) = entries;
+        tracing::info!("Deny list replaced, {} entries", count);
+    }
+
+    /// 当前名单条目数
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// 名单是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 重新读取文件
+    ///
+    /// 解析失败时保留旧名单，避免名单文件写到一半时清空黑名单
+    pub fn reload(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let modified = std::fs::metadata(path)?.modified().ok();
+        let content = std::fs::read_to_string(path)?;
+        let entries = Self::parse(&content)?;
+
+        self.replace_entries(entries);
+        *self.modified_at.write().unwrap_or_else(|e| e.into_inner()) = modified;
+
+        Ok(())
+    }
+
+    /// 文件修改时间变化时重新读取，返回是否发生了重载
+    pub fn reload_if_modified(&self) -> Result<bool> {
+        let Some(path) = &self.path else {
+            return Ok(false);
+        };
+
+        let modified = std::fs::metadata(path)?.modified().ok();
+        let last = *self.modified_at.read().unwrap_or_else(|e| e.into_inner());
+        if modified.is_some() && modified == last {
+            return Ok(false);
+        }
+
+        self.reload()?;
+        Ok(true)
+    }
+
+    /// 启动后台任务，按固定间隔检查文件变化并热加载
+    pub fn spawn_watcher(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = self.reload_if_modified() {
+                    tracing::error!("Failed to reload deny list: {}", e);
+                }
+            }
+        })
+    }
+
+    /// 解析名单文件内容
+    pub fn parse(content: &str) -> Result<HashMap<Pubkey, DenyListEntry>> {
+        let mut entries = HashMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, ',').map(str::trim);
+            let address = fields.next().unwrap_or_default();
+            let pubkey = address.parse::<Pubkey>().map_err(|e| {
+                SolanaError::ConfigError(format!("Deny list line {}: {}", index + 1, e))
+            })?;
+
+            let decision = match fields.next() {
+                None | Some("") | Some("deny") => ScreeningDecision::Deny,
+                Some("review") | Some("manual_review") => ScreeningDecision::ManualReview,
+                Some(other) => {
+                    return Err(SolanaError::ConfigError(format!(
+                        "Deny list line {}: unknown action '{}'",
+                        index + 1,
+                        other
+                    )))
+                }
+            };
+
+            let reason = fields
+                .next()
+                .filter(|r| !r.is_empty())
+                .unwrap_or("address on local deny list")
+                .to_string();
+
+            entries.insert(pubkey, DenyListEntry { decision, reason });
+        }
+
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl RiskScreener for DenyListScreener {
+    fn name(&self) -> &str {
+        "deny_list"
+    }
+
+    async fn screen(&self, request: &ScreeningRequest) -> Result<ScreeningResult> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+
+        Ok(match entries.get(&request.to) {
+            Some(entry) => ScreeningResult {
+                decision: entry.decision,
+                reasons: vec![entry.reason.clone()],
+                provider: self.name().to_string(),
+            },
+            None => ScreeningResult::allow(self.name()),
+        })
+    }
+}
+
+/// HTTP 风控服务适配器
+///
+/// 向 `endpoint` POST [`ScreeningRequest`]（JSON），期望返回
+/// `{"decision": "allow|deny|manual_review", "reasons": [...]}`。
+/// 服务不可用或返回无法解析时返回错误，由调用方拒绝转出（fail closed）
+pub struct HttpRiskScreener {
+    client: reqwest::Client,
+    endpoint: String,
+    api_key: Option<String>,
+}
+
+/// HTTP 风控服务响应
+#[derive(Debug, Deserialize)]
+struct HttpScreeningResponse {
+    decision: ScreeningDecision,
+    #[serde(default)]
+    reasons: Vec<String>,
+}
+
+impl HttpRiskScreener {
+    /// 创建 HTTP 风控适配器
+    pub fn new(endpoint: &str, api_key: Option<String>, timeout: Duration) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| SolanaError::ConfigError(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            endpoint: endpoint.to_string(),
+            api_key,
+        })
+    }
+}
+
+#[async_trait]
+impl RiskScreener for HttpRiskScreener {
+    fn name(&self) -> &str {
+        "http_provider"
+    }
+
+    async fn screen(&self, request: &ScreeningRequest) -> Result<ScreeningResult> {
+        let mut builder = self.client.post(&self.endpoint).json(request);
+        if let Some(api_key) = &self.api_key {
+            builder = builder.bearer_auth(api_key);
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| SolanaError::RiskScreeningError(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(SolanaError::RiskScreeningError(format!(
+                "Screening provider returned {}",
+                status
+            )));
+        }
+
+        let body: HttpScreeningResponse = response
+            .json()
+            .await
+            .map_err(|e| SolanaError::RiskScreeningError(e.to_string()))?;
+
+        Ok(ScreeningResult {
+            decision: body.decision,
+            reasons: body.reasons,
+            provider: self.name().to_string(),
+        })
+    }
+}
+
+/// 组合筛查器
+///
+/// 依次执行所有筛查器，取最严格的结论；遇到 Deny 立即返回，不再请求后续服务
+pub struct CompositeScreener {
+    screeners: Vec<Arc<dyn RiskScreener>>,
+}
+
+impl CompositeScreener {
+    /// 创建组合筛查器
+    pub fn new(screeners: Vec<Arc<dyn RiskScreener>>) -> Self {
+        Self { screeners }
+    }
+}
+
+#[async_trait]
+impl RiskScreener for CompositeScreener {
+    fn name(&self) -> &str {
+        "composite"
+    }
+
+    async fn screen(&self, request: &ScreeningRequest) -> Result<ScreeningResult> {
+        let mut decision = ScreeningDecision::Allow;
+        let mut reasons = Vec::new();
+        let mut providers = Vec::new();
+
+        for screener in &self.screeners {
+            let result = screener.screen(request).await?;
+            if result.decision == ScreeningDecision::Allow {
+                continue;
+            }
+
+            decision = decision.max(result.decision);
+            reasons.extend(result.reasons);
+            providers.push(result.provider);
+
+            if decision == ScreeningDecision::Deny {
+                break;
+            }
+        }
+
+        Ok(ScreeningResult {
+            decision,
+            reasons,
+            provider: if providers.is_empty() {
+                self.name().to_string()
+            } else {
+                providers.join(",")
+            },
+        })
+    }
+}
+
+/// 转出前的风险闸门
+///
+/// 执行筛查、保存筛查记录，并把 Deny / ManualReview 转换为错误中断转出。
+/// ManualReview 同样是硬拒绝，不会保留待审的转出
+pub struct RiskGate {
+    screener: Arc<dyn RiskScreener>,
+    storage: Option<Arc<dyn ScreeningStorage>>,
+}
+
+impl RiskGate {
+    /// 创建风险闸门
+    pub fn new(screener: Arc<dyn RiskScreener>) -> Self {
+        Self {
+            screener,
+            storage: None,
+        }
+    }
+
+    /// 设置筛查记录存储
+    pub fn with_storage(mut self, storage: Arc<dyn ScreeningStorage>) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// 筛查转出，仅 Allow 时返回 Ok
+    pub async fn check(&self, request: &ScreeningRequest) -> Result<ScreeningResult> {
+        let result = self.screener.screen(request).await?;
+
+        if let Some(storage) = &self.storage {
+            storage.save_screening_result(request, &result).await?;
+        }
+
+        match result.decision {
+            ScreeningDecision::Allow => Ok(result),
+            ScreeningDecision::ManualReview => {
+                tracing::warn!("Transfer to {} rejected pending manual review: {:?}", request.to, result.reasons);
+                Err(SolanaError::RiskReviewRejected(result.reasons.join("; ")))
+            }
+            ScreeningDecision::Deny => {
+                tracing::warn!("Transfer to {} denied: {:?}", request.to, result.reasons);
+                Err(SolanaError::RiskDenied(result.reasons.join("; ")))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn request_to(to: Pubkey) -> ScreeningRequest {
+        ScreeningRequest {
+            from: Pubkey::new_unique(),
+            to,
+            token_mint: None,
+            amount: 1_000,
+            reference: Some("test".to_string()),
+        }
+    }
+
+    /// 启动只返回固定响应的本地 HTTP 桩服务
+    async fn spawn_stub(status_line: &'static str, body: &'static str) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 8192];
+                let _ = socket.read(&mut buf).await;
+                let response = format!(
+                    "{}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status_line,
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        format!("http://{}/screen", addr)
+    }
+
+    #[test]
+    fn test_parse_deny_list() {
+        let denied = Pubkey::new_unique();
+        let reviewed = Pubkey::new_unique();
+        let content = format!("# sanctions\n{}\n{},review,exchange flagged\n\n", denied, reviewed);
+
+        let entries = DenyListScreener::parse(&content).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[&denied].decision, ScreeningDecision::Deny);
+        assert_eq!(entries[&reviewed].decision, ScreeningDecision::ManualReview);
+        assert_eq!(entries[&reviewed].reason, "exchange flagged");
+
+        assert!(DenyListScreener::parse("not-a-pubkey").is_err());
+        assert!(DenyListScreener::parse(&format!("{},block", denied)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_deny_list_hot_reload() {
+        let path = std::env::temp_dir().join(format!("deny_list_{}.txt", Pubkey::new_unique()));
+        let target = Pubkey::new_unique();
+        std::fs::write(&path, "").unwrap();
+
+        let screener = DenyListScreener::from_file(&path).unwrap();
+        let result = screener.screen(&request_to(target)).await.unwrap();
+        assert_eq!(result.decision, ScreeningDecision::Allow);
+
+        std::fs::write(&path, format!("{},deny,OFAC SDN\n", target)).unwrap();
+        screener.reload().unwrap();
+        let result = screener.screen(&request_to(target)).await.unwrap();
+        assert_eq!(result.decision, ScreeningDecision::Deny);
+        assert_eq!(result.reasons, vec!["OFAC SDN".to_string()]);
+
+        // 写坏文件时保留旧名单
+        std::fs::write(&path, "garbage").unwrap();
+        assert!(screener.reload().is_err());
+        assert_eq!(screener.len(), 1);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_http_screener_with_stub() {
+        let endpoint = spawn_stub(
+            "HTTP/1.1 200 OK",
+            r#"{"decision":"manual_review","reasons":["mixer exposure"]}"#,
+        )
+        .await;
+        let screener = HttpRiskScreener::new(&endpoint, Some("key".to_string()), Duration::from_secs(5)).unwrap();
+
+        let result = screener.screen(&request_to(Pubkey::new_unique())).await.unwrap();
+        assert_eq!(result.decision, ScreeningDecision::ManualReview);
+        assert_eq!(result.reasons, vec!["mixer exposure".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_http_screener_fails_closed() {
+        let endpoint = spawn_stub("HTTP/1.1 503 Service Unavailable", "{}").await;
+        let screener = HttpRiskScreener::new(&endpoint, None, Duration::from_secs(5)).unwrap();
+        let gate = RiskGate::new(Arc::new(screener));
+
+        let err = gate.check(&request_to(Pubkey::new_unique())).await.unwrap_err();
+        assert!(matches!(err, SolanaError::RiskScreeningError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_gate_rejects_manual_review() {
+        let reviewed = Pubkey::new_unique();
+        let mut entries = HashMap::new();
+        entries.insert(
+            reviewed,
+            DenyListEntry {
+                decision: ScreeningDecision::ManualReview,
+                reason: "watch list".to_string(),
+            },
+        );
+        let gate = RiskGate::new(Arc::new(DenyListScreener::from_entries(entries)));
+
+        let err = gate.check(&request_to(reviewed)).await.unwrap_err();
+        assert!(matches!(err, SolanaError::RiskReviewRejected(_)));
+    }
+
+    #[tokio::test]
+    async fn test_composite_takes_strictest() {
+        let reviewed = Pubkey::new_unique();
+        let mut entries = HashMap::new();
+        entries.insert(
+            reviewed,
+            DenyListEntry {
+                decision: ScreeningDecision::ManualReview,
+                reason: "watch list".to_string(),
+            },
+        );
+        let endpoint = spawn_stub("HTTP/1.1 200 OK", r#"{"decision":"deny","reasons":["sanctioned"]}"#).await;
+
+        let composite = CompositeScreener::new(vec![
+            Arc::new(DenyListScreener::from_entries(entries)),
+            Arc::new(HttpRiskScreener::new(&endpoint, None, Duration::from_secs(5)).unwrap()),
+        ]);
+        let result = composite.screen(&request_to(reviewed)).await.unwrap();
+
+        assert_eq!(result.decision, ScreeningDecision::Deny);
+        assert_eq!(result.reasons, vec!["watch list".to_string(), "sanctioned".to_string()]);
+        assert_eq!(result.provider, "deny_list,http_provider");
+    }
+}
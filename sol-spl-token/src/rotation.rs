@@ -0,0 +1,650 @@
+//! 密钥轮换模块
+//!
+//! 托管密钥泄露时，把旧钱包的全部资产迁移到新生成的钱包：
+//! 1. 旧钱包名下 SPL Token 与 Token-2022 程序的每个 Token 账户：在新钱包下按所属程序创建关联账户、
+//!    转入全部余额、关闭旧账户，租金押金一并退到新钱包
+//! 2. 最后把旧钱包剩余的 SOL 全部转入新钱包
+//!
+//! 多个 Token 账户合并在同一笔交易中迁移，同一笔交易内的账户要么全部迁移要么全部不动；
+//! 冻结的账户以及带转账钩子、不可转让等扩展而无法直接转出的账户记为跳过，需处理后重新执行。
+//! 迁移可重复执行，已迁移的部分不会重复转账
+
+use serde::Serialize;
+use solana_program::program_option::COption;
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, UiAccountEncoding},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::instruction::Instruction;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use solana_system_interface::instruction as system_instruction;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use spl_token_2022_interface::{
+    extension::{
+        default_account_state::DefaultAccountState, pausable::PausableConfig,
+        transfer_fee::TransferFeeAmount, transfer_hook::TransferHook, BaseStateWithExtensions,
+        ExtensionType, StateWithExtensions,
+    },
+    instruction::close_account,
+    state::{Account as TokenAccount, AccountState, Mint},
+};
+use std::{collections::HashMap, sync::Arc};
+
+use crate::error::{Result, SolanaError};
+use crate::lock::{lock_wallet, renew_lock, DistributedLock, WalletLock};
+use crate::risk::{RiskGate, ScreeningRequest};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+use crate::simulation::send_with_preflight;
+use crate::token::{TokenProgram, MAX_MULTIPLE_ACCOUNTS};
+
+/// Token 账户数据中 owner 字段的偏移，两种程序相同
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+
+/// 不带扩展的 Token 账户大小，两种程序相同
+const TOKEN_ACCOUNT_LEN: u64 = 165;
+
+/// 每笔交易迁移的 Token 账户数，保证交易大小在限制内
+pub const TOKEN_ACCOUNTS_PER_TRANSACTION: usize = 4;
+
+/// 钱包名下的 Token 账户
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WalletTokenAccount {
+    pub address: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub frozen: bool,
+    pub token_program: TokenProgram,
+    pub decimals: u8,
+    /// 无法直接迁移的原因，如带转账钩子或不可转让的扩展
+    pub blocked_by: Option<String>,
+}
+
+/// 未迁移的 Token 账户
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedTokenAccount {
+    pub address: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+}
+
+/// 迁移结果
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MigrationReport {
+    /// 已发送的交易签名，按发送顺序
+    pub signatures: Vec<Signature>,
+
+    /// 已迁移的 Token 账户
+    pub migrated_token_accounts: Vec<WalletTokenAccount>,
+
+    /// 跳过的 Token 账户
+    pub skipped_token_accounts: Vec<SkippedTokenAccount>,
+
+    /// 转入新钱包的 SOL（不含 Token 账户退回的租金）
+    pub migrated_lamports: u64,
+
+    /// 中途失败的原因，已发送的交易不会回滚
+    pub error: Option<String>,
+}
+
+impl MigrationReport {
+    /// 全部资产都已迁移
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none() && self.skipped_token_accounts.is_empty()
+    }
+}
+
+/// 把 Token 账户分成可迁移的批次与跳过的账户
+pub fn plan_batches(
+    accounts: Vec<WalletTokenAccount>,
+) -> (Vec<Vec<WalletTokenAccount>>, Vec<SkippedTokenAccount>) {
+    let (blocked, movable): (Vec<_>, Vec<_>) = accounts
+        .into_iter()
+        .partition(|a| a.frozen || a.blocked_by.is_some());
+
+    let skipped = blocked
+        .into_iter()
+        .map(|account| SkippedTokenAccount {
+            address: account.address,
+            mint: account.mint,
+            amount: account.amount,
+            reason: if account.frozen {
+                "token account is frozen".to_string()
+            } else {
+                account.blocked_by.unwrap_or_default()
+            },
+        })
+        .collect();
+    let batches = movable
+        .chunks(TOKEN_ACCOUNTS_PER_TRANSACTION)
+        .map(<[WalletTokenAccount]>::to_vec)
+        .collect();
+
+    (batches, skipped)
+}
+
+/// Token 账户无法用 transfer_checked 转出并关闭的原因
+fn account_blocker(account: &StateWithExtensions<TokenAccount>, owner: &Pubkey) -> Option<String> {
+    if let COption::Some(close_authority) = account.base.close_authority {
+        if close_authority != *owner {
+            return Some(format!("close authority is {}", close_authority));
+        }
+    }
+
+    let Ok(extensions) = account.get_extension_types() else {
+        return Some("unreadable token account extensions".to_string());
+    };
+    for extension in extensions {
+        match extension {
+            ExtensionType::NonTransferableAccount => {
+                return Some("token is non-transferable".to_string())
+            },
+            ExtensionType::TransferHookAccount => {
+                return Some("mint requires a transfer hook".to_string())
+            },
+            ExtensionType::ConfidentialTransferAccount
+            | ExtensionType::ConfidentialTransferFeeAmount => {
+                return Some("account may hold confidential balances".to_string())
+            },
+            ExtensionType::TransferFeeAmount => {
+                let withheld = account
+                    .get_extension::<TransferFeeAmount>()
+                    .map(|fee| u64::from(fee.withheld_amount))
+                    .unwrap_or(u64::MAX);
+                if withheld > 0 {
+                    return Some("withheld transfer fees must be harvested first".to_string());
+                }
+            },
+            _ => {},
+        }
+    }
+    None
+}
+
+/// mint 的扩展导致无法转入新钱包的原因
+fn mint_blocker(mint: &StateWithExtensions<Mint>) -> Option<String> {
+    let Ok(extensions) = mint.get_extension_types() else {
+        return Some("unreadable mint extensions".to_string());
+    };
+    for extension in extensions {
+        let blocker = match extension {
+            ExtensionType::NonTransferable => Some("token is non-transferable"),
+            ExtensionType::TransferHook => mint
+                .get_extension::<TransferHook>()
+                .map_or(true, |hook| Option::<Pubkey>::from(hook.program_id).is_some())
+                .then_some("mint requires a transfer hook"),
+            ExtensionType::DefaultAccountState => mint
+                .get_extension::<DefaultAccountState>()
+                .map_or(true, |state| state.state == AccountState::Frozen as u8)
+                .then_some("new token accounts of the mint start frozen"),
+            ExtensionType::Pausable => mint
+                .get_extension::<PausableConfig>()
+                .map_or(true, |config| bool::from(config.paused))
+                .then_some("mint is paused"),
+            _ => None,
+        };
+        if let Some(blocker) = blocker {
+            return Some(blocker.to_string());
+        }
+    }
+    None
+}
+
+/// 扫走旧钱包 SOL 时可转出的数量
+///
+/// 旧钱包自己支付手续费时需要预留手续费，否则全部转出
+pub fn sweep_amount(balance: u64, fee: u64, owner_pays_fee: bool) -> u64 {
+    if owner_pays_fee {
+        balance.saturating_sub(fee)
+    } else {
+        balance
+    }
+}
+
+/// 密钥轮换执行器
+pub struct KeyRotator {
+    rpc_client: RpcHandle,
+    write_client: RpcHandle,
+    risk_gate: Option<Arc<RiskGate>>,
+    wallet_lock: Option<Arc<dyn DistributedLock>>,
+}
+
+impl KeyRotator {
+    /// 创建新的密钥轮换执行器
+    pub fn new(rpc_url: &str) -> Self {
+        let rpc_client = RpcHandle::new(rpc_url);
+
+        Self {
+            write_client: rpc_client.clone(),
+            rpc_client,
+            risk_gate: None,
+            wallet_lock: None,
+        }
+    }
+
+    /// 从 RPC 端点池创建密钥轮换执行器
+    pub fn from_pool(pool: &Arc<RpcPool>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcHandle::pooled(pool, RpcTraffic::Read),
+            write_client: RpcHandle::pooled(pool, RpcTraffic::Write),
+            risk_gate: None,
+            wallet_lock: None,
+        })
+    }
+
+    /// 设置风险闸门，迁移前筛查接收资产的新钱包
+    pub fn with_risk_gate(mut self, risk_gate: Arc<RiskGate>) -> Self {
+        self.risk_gate = Some(risk_gate);
+        self
+    }
+
+    /// 设置分布式锁，迁移期间锁住旧钱包，每笔交易发送前确认并续期
+    pub fn with_wallet_lock(mut self, wallet_lock: Arc<dyn DistributedLock>) -> Self {
+        self.wallet_lock = Some(wallet_lock);
+        self
+    }
+
+    /// 列出钱包名下 SPL Token 与 Token-2022 程序的全部 Token 账户（含非关联账户）
+    ///
+    /// 同时读取各 mint 的精度与扩展，无法直接迁移的账户在 `blocked_by` 中记录原因
+    pub fn find_token_accounts(&self, owner: &Pubkey) -> Result<Vec<WalletTokenAccount>> {
+        let mut accounts = Vec::new();
+        for program in [TokenProgram::SplToken, TokenProgram::Token2022] {
+            accounts.extend(self.find_program_token_accounts(owner, program)?);
+        }
+
+        let mut mints: Vec<Pubkey> = accounts.iter().map(|a| a.mint).collect();
+        mints.sort();
+        mints.dedup();
+        let mint_states: HashMap<Pubkey, std::result::Result<u8, String>> = mints
+            .iter()
+            .copied()
+            .zip(self.read_accounts(&mints)?)
+            .map(|(mint, data)| {
+                let state = match data {
+                    Some(data) => match StateWithExtensions::<Mint>::unpack(&data) {
+                        Ok(state) => match mint_blocker(&state) {
+                            Some(blocker) => Err(blocker),
+                            None => Ok(state.base.decimals),
+                        },
+                        Err(e) => Err(format!("unreadable mint: {}", e)),
+                    },
+                    None => Err("mint account not found".to_string()),
+                };
+                (mint, state)
+            })
+            .collect();
+
+        for account in &mut accounts {
+            match mint_states.get(&account.mint) {
+                Some(Ok(decimals)) => account.decimals = *decimals,
+                Some(Err(blocker)) => {
+                    account.blocked_by.get_or_insert_with(|| blocker.clone());
+                },
+                None => {},
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// 列出钱包在指定 Token 程序下的账户
+    ///
+    /// Token-2022 账户带扩展时长度不固定，按账户类型标记过滤而不是按长度过滤
+    fn find_program_token_accounts(
+        &self,
+        owner: &Pubkey,
+        program: TokenProgram,
+    ) -> Result<Vec<WalletTokenAccount>> {
+        let layout_filter = match program {
+            TokenProgram::SplToken => RpcFilterType::DataSize(TOKEN_ACCOUNT_LEN),
+            TokenProgram::Token2022 => RpcFilterType::TokenAccountState,
+        };
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                layout_filter,
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                    TOKEN_ACCOUNT_OWNER_OFFSET,
+                    owner.to_bytes().to_vec(),
+                )),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(self.rpc_client.commitment()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let accounts = self
+            .rpc_client
+            .call(|client| {
+                client.get_program_ui_accounts_with_config(&program.id(), config.clone())
+            })
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        accounts
+            .into_iter()
+            .map(|(address, account)| {
+                let data = account.data.decode().ok_or_else(|| {
+                    SolanaError::TokenAccountNotFound(format!(
+                        "Undecodable token account {}",
+                        address
+                    ))
+                })?;
+                let token_account = StateWithExtensions::<TokenAccount>::unpack(&data)
+                    .map_err(|e| SolanaError::TokenAccountNotFound(e.to_string()))?;
+
+                Ok(WalletTokenAccount {
+                    address,
+                    mint: token_account.base.mint,
+                    amount: token_account.base.amount,
+                    frozen: token_account.base.state == AccountState::Frozen,
+                    token_program: program,
+                    decimals: 0,
+                    blocked_by: account_blocker(&token_account, owner),
+                })
+            })
+            .collect()
+    }
+
+    /// 分批读取账户数据，不存在的账户返回 None
+    fn read_accounts(&self, accounts: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>> {
+        let mut data = Vec::with_capacity(accounts.len());
+        for chunk in accounts.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let response = self
+                .rpc_client
+                .call(|client| {
+                    client.get_multiple_ui_accounts_with_config(
+                        chunk,
+                        RpcAccountInfoConfig {
+                            encoding: Some(UiAccountEncoding::Base64),
+                            commitment: Some(client.commitment()),
+                            ..RpcAccountInfoConfig::default()
+                        },
+                    )
+                })
+                .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+            for account in response.value {
+                data.push(match account {
+                    Some(account) => Some(account.data.decode().ok_or_else(|| {
+                        SolanaError::RpcError("Failed to decode account data".to_string())
+                    })?),
+                    None => None,
+                });
+            }
+        }
+        Ok(data)
+    }
+
+    /// 把旧钱包的全部资产迁移到新钱包
+    ///
+    /// `payer` 支付手续费与新关联账户的租金，可以就是旧钱包本身。
+    /// 某笔交易失败时停止迁移并在结果中记录原因，修复后可重新执行
+    pub async fn migrate(
+        &self,
+        old_owner: &Keypair,
+        new_owner: &Pubkey,
+        payer: &Keypair,
+    ) -> Result<MigrationReport> {
+        if old_owner.pubkey() == *new_owner {
+            return Err(SolanaError::Other(
+                "New wallet must differ from the rotated wallet".to_string(),
+            ));
+        }
+
+        if let Some(risk_gate) = &self.risk_gate {
+            risk_gate
+                .check(&ScreeningRequest {
+                    from: old_owner.pubkey(),
+                    to: *new_owner,
+                    token_mint: None,
+                    amount: 0,
+                    reference: None,
+                })
+                .await?;
+        }
+
+        // 迁移期间锁住旧钱包，避免与进行中的转出争用余额
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &old_owner.pubkey()).await?;
+        let (batches, skipped) = plan_batches(self.find_token_accounts(&old_owner.pubkey())?);
+        let mut report = MigrationReport {
+            skipped_token_accounts: skipped,
+            ..Default::default()
+        };
+
+        for batch in batches {
+            let mut instructions = Vec::new();
+            for account in &batch {
+                instructions.extend(self.migrate_token_account_instructions(
+                    account,
+                    &old_owner.pubkey(),
+                    new_owner,
+                    &payer.pubkey(),
+                )?);
+            }
+
+            let sent = match renew_lock(wallet_lock.as_ref()).await {
+                Ok(()) => self.send(&instructions, old_owner, payer),
+                Err(e) => Err(e),
+            };
+            match sent {
+                Ok(signature) => {
+                    report.signatures.push(signature);
+                    report.migrated_token_accounts.extend(batch);
+                },
+                Err(e) => {
+                    report.error = Some(e.to_string());
+                    return Ok(report);
+                },
+            }
+        }
+
+        // Token 账户处理完再扫走 SOL，旧账户的租金已直接退到新钱包
+        match self
+            .sweep_lamports(old_owner, new_owner, payer, wallet_lock.as_ref())
+            .await
+        {
+            Ok(Some((lamports, signature))) => {
+                report.migrated_lamports = lamports;
+                report.signatures.push(signature);
+            },
+            Ok(None) => {},
+            Err(e) => report.error = Some(e.to_string()),
+        }
+
+        Ok(report)
+    }
+
+    /// 单个 Token 账户的迁移指令：创建新关联账户、转出余额、关闭旧账户
+    fn migrate_token_account_instructions(
+        &self,
+        account: &WalletTokenAccount,
+        old_owner: &Pubkey,
+        new_owner: &Pubkey,
+        payer: &Pubkey,
+    ) -> Result<Vec<Instruction>> {
+        let program = account.token_program;
+        let destination = program.associated_token_address(new_owner, &account.mint);
+        let mut instructions = vec![create_associated_token_account_idempotent(
+            payer,
+            new_owner,
+            &account.mint,
+            &program.id(),
+        )];
+
+        if account.amount > 0 {
+            instructions.push(program.transfer_checked(
+                &account.address,
+                &account.mint,
+                &destination,
+                old_owner,
+                account.amount,
+                account.decimals,
+            )?);
+        }
+        instructions.push(
+            close_account(
+                &program.id(),
+                &account.address,
+                new_owner,
+                old_owner,
+                &[],
+            )
+            .map_err(|e| SolanaError::Other(e.to_string()))?,
+        );
+
+        Ok(instructions)
+    }
+
+    /// 转出旧钱包剩余的 SOL，没有可转出的余额时返回 None
+    async fn sweep_lamports(
+        &self,
+        old_owner: &Keypair,
+        new_owner: &Pubkey,
+        payer: &Keypair,
+        wallet_lock: Option<&WalletLock>,
+    ) -> Result<Option<(u64, Signature)>> {
+        let balance = self
+            .rpc_client
+            .call(|client| client.get_balance(&old_owner.pubkey()))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+        let owner_pays_fee = old_owner.pubkey() == payer.pubkey();
+
+        let fee = if owner_pays_fee {
+            let message = Transaction::new_with_payer(
+                &[system_instruction::transfer(
+                    &old_owner.pubkey(),
+                    new_owner,
+                    balance,
+                )],
+                Some(&payer.pubkey()),
+            )
+            .message;
+            self.rpc_client
+                .call(|client| client.get_fee_for_message(&message))
+                .map_err(|e| SolanaError::RpcError(e.to_string()))?
+        } else {
+            0
+        };
+
+        let lamports = sweep_amount(balance, fee, owner_pays_fee);
+        if lamports == 0 {
+            return Ok(None);
+        }
+
+        renew_lock(wallet_lock).await?;
+        let signature = self.send(
+            &[system_instruction::transfer(
+                &old_owner.pubkey(),
+                new_owner,
+                lamports,
+            )],
+            old_owner,
+            payer,
+        )?;
+
+        Ok(Some((lamports, signature)))
+    }
+
+    fn send(
+        &self,
+        instructions: &[Instruction],
+        old_owner: &Keypair,
+        payer: &Keypair,
+    ) -> Result<Signature> {
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&payer.pubkey()));
+        let recent_blockhash = self
+            .write_client
+            .call(|client| client.get_latest_blockhash())
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        if old_owner.pubkey() == payer.pubkey() {
+            transaction.sign(&[payer], recent_blockhash);
+        } else {
+            transaction.sign(&[payer, old_owner], recent_blockhash);
+        }
+
+        send_with_preflight(&self.write_client, &transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token_account(amount: u64, frozen: bool) -> WalletTokenAccount {
+        WalletTokenAccount {
+            address: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount,
+            frozen,
+            token_program: TokenProgram::SplToken,
+            decimals: 6,
+            blocked_by: None,
+        }
+    }
+
+    #[test]
+    fn test_plan_batches_skips_frozen_accounts() {
+        let mut accounts: Vec<_> = (0..9).map(|i| token_account(i, false)).collect();
+        let frozen = token_account(100, true);
+        accounts.push(frozen.clone());
+
+        let (batches, skipped) = plan_batches(accounts);
+
+        assert_eq!(
+            batches.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![4, 4, 1]
+        );
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].address, frozen.address);
+        assert_eq!(skipped[0].amount, 100);
+    }
+
+    #[test]
+    fn test_plan_batches_skips_blocked_accounts() {
+        let mut blocked = token_account(50, false);
+        blocked.token_program = TokenProgram::Token2022;
+        blocked.blocked_by = Some("mint requires a transfer hook".to_string());
+
+        let (batches, skipped) = plan_batches(vec![token_account(1, false), blocked.clone()]);
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 1);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].address, blocked.address);
+        assert_eq!(skipped[0].reason, "mint requires a transfer hook");
+    }
+
+    #[test]
+    fn test_account_blocker_rejects_foreign_close_authority() {
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0u8; TOKEN_ACCOUNT_LEN as usize];
+        let mut account = TokenAccount {
+            mint: Pubkey::new_unique(),
+            owner,
+            amount: 10,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        solana_program::program_pack::Pack::pack(account, &mut data).unwrap();
+        let state = StateWithExtensions::<TokenAccount>::unpack(&data).unwrap();
+        assert_eq!(account_blocker(&state, &owner), None);
+
+        account.close_authority = COption::Some(Pubkey::new_unique());
+        solana_program::program_pack::Pack::pack(account, &mut data).unwrap();
+        let state = StateWithExtensions::<TokenAccount>::unpack(&data).unwrap();
+        assert!(account_blocker(&state, &owner).is_some());
+    }
+
+    #[test]
+    fn test_sweep_amount() {
+        assert_eq!(sweep_amount(1_000_000, 5_000, true), 995_000);
+        assert_eq!(sweep_amount(1_000_000, 5_000, false), 1_000_000);
+        assert_eq!(sweep_amount(3_000, 5_000, true), 0);
+    }
+}
@@ -0,0 +1,689 @@
+//! RPC 端点池模块
+//!
+//! 在同一网络的多个 RPC 端点之间分发请求：
+//! 1. 定期执行 `getHealth` / `getSlot` 检查，落后超过阈值的端点暂不参与调度
+//! 2. 按权重平滑轮询（smooth weighted round-robin）选择端点
+//! 3. 连续遇到 429 / 5xx / 连接错误时熔断，冷却后半开试探
+//! 4. 读流量与写流量（`sendTransaction`）可以指向不同端点
+
+use serde::{Deserialize, Serialize};
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_client::RpcClient,
+    rpc_custom_error::JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+    rpc_request::RpcError,
+};
+use solana_commitment_config::CommitmentConfig;
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+use tokio::task::JoinHandle;
+
+use crate::error::{Result, SolanaError};
+
+/// 端点承担的流量类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RpcRole {
+    /// 只读查询
+    Read,
+    /// 交易发送
+    Write,
+    /// 读写均可
+    #[default]
+    Both,
+}
+
+impl RpcRole {
+    fn serves(self, traffic: RpcTraffic) -> bool {
+        matches!(
+            (self, traffic),
+            (RpcRole::Both, _)
+                | (RpcRole::Read, RpcTraffic::Read)
+                | (RpcRole::Write, RpcTraffic::Write)
+        )
+    }
+}
+
+/// 请求的流量类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcTraffic {
+    Read,
+    Write,
+}
+
+/// 单个 RPC 端点配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcEndpointConfig {
+    /// 端点 URL
+    pub url: String,
+
+    /// 轮询权重
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+
+    /// 承担的流量类型
+    #[serde(default)]
+    pub role: RpcRole,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+impl RpcEndpointConfig {
+    /// 创建读写均可、权重为 1 的端点
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            weight: default_weight(),
+            role: RpcRole::Both,
+        }
+    }
+}
+
+/// 端点池配置
+#[derive(Debug, Clone)]
+pub struct RpcPoolConfig {
+    /// 端点列表
+    pub endpoints: Vec<RpcEndpointConfig>,
+
+    /// 允许落后于最高 slot 的数量
+    pub max_slot_lag: u64,
+
+    /// 连续失败多少次后熔断
+    pub failure_threshold: u32,
+
+    /// 熔断后的冷却时间
+    pub open_duration: Duration,
+
+    /// 健康检查间隔
+    pub health_check_interval: Duration,
+
+    /// 单次请求超时
+    pub request_timeout: Duration,
+}
+
+impl Default for RpcPoolConfig {
+    fn default() -> Self {
+        Self {
+            endpoints: Vec::new(),
+            max_slot_lag: 50,
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+            health_check_interval: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RpcPoolConfig {
+    /// 从 Solana 配置构建，未配置端点列表时退化为单个 `rpc_url`
+    pub fn from_config(config: &crate::config::SolanaConfig) -> Self {
+        let endpoints = if config.rpc_endpoints.is_empty() {
+            vec![RpcEndpointConfig::new(&config.rpc_url)]
+        } else {
+            config.rpc_endpoints.clone()
+        };
+
+        Self {
+            endpoints,
+            max_slot_lag: config.rpc_max_slot_lag,
+            ..Self::default()
+        }
+    }
+}
+
+/// 熔断器状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+/// 单个端点的运行指标
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcEndpointMetrics {
+    /// 端点 URL（去掉查询参数，避免泄露 API Key）
+    pub url: String,
+    pub role: RpcRole,
+    pub weight: u32,
+    pub healthy: bool,
+    pub circuit: CircuitState,
+    pub slot: Option<u64>,
+    pub slot_lag: Option<u64>,
+    pub requests: u64,
+    pub failures: u64,
+    pub consecutive_failures: u32,
+    pub last_latency_ms: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+/// 端点池指标快照
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcPoolMetrics {
+    pub highest_slot: Option<u64>,
+    pub endpoints: Vec<RpcEndpointMetrics>,
+}
+
+/// 端点运行状态
+#[derive(Debug)]
+struct EndpointState {
+    healthy: bool,
+    circuit: CircuitState,
+    open_until: Option<Instant>,
+    consecutive_failures: u32,
+    current_weight: i64,
+    slot: Option<u64>,
+    requests: u64,
+    failures: u64,
+    last_latency: Option<Duration>,
+    last_error: Option<String>,
+}
+
+impl Default for EndpointState {
+    fn default() -> Self {
+        Self {
+            healthy: true,
+            circuit: CircuitState::Closed,
+            open_until: None,
+            consecutive_failures: 0,
+            current_weight: 0,
+            slot: None,
+            requests: 0,
+            failures: 0,
+            last_latency: None,
+            last_error: None,
+        }
+    }
+}
+
+/// 管理器持有的 RPC 入口
+///
+/// 单个客户端直接调用；端点池按流量类型经 [`RpcPool::execute`] 调用，
+/// 端点故障计入熔断并切换到下一个端点
+#[derive(Clone)]
+pub enum RpcHandle {
+    Single(Arc<RpcClient>),
+    Pool(Arc<RpcPool>, RpcTraffic),
+}
+
+impl RpcHandle {
+    /// 连接单个端点，使用 confirmed 确认级别
+    pub fn new(rpc_url: &str) -> Self {
+        Self::Single(Arc::new(RpcClient::new_with_commitment(
+            rpc_url.to_string(),
+            CommitmentConfig::confirmed(),
+        )))
+    }
+
+    /// 端点池中指定流量的入口
+    pub fn pooled(pool: &Arc<RpcPool>, traffic: RpcTraffic) -> Self {
+        Self::Pool(Arc::clone(pool), traffic)
+    }
+
+    /// 执行请求
+    pub fn call<T, F>(&self, f: F) -> std::result::Result<T, ClientError>
+    where
+        F: Fn(&RpcClient) -> std::result::Result<T, ClientError>,
+    {
+        match self {
+            RpcHandle::Single(client) => f(client),
+            RpcHandle::Pool(pool, traffic) => pool.execute(*traffic, f),
+        }
+    }
+
+    /// 请求使用的确认级别
+    pub fn commitment(&self) -> CommitmentConfig {
+        match self {
+            RpcHandle::Single(client) => client.commitment(),
+            RpcHandle::Pool(pool, _) => pool.clients[0].commitment(),
+        }
+    }
+}
+
+impl From<Arc<RpcClient>> for RpcHandle {
+    fn from(client: Arc<RpcClient>) -> Self {
+        Self::Single(client)
+    }
+}
+
+/// RPC 端点池
+pub struct RpcPool {
+    config: RpcPoolConfig,
+    clients: Vec<Arc<RpcClient>>,
+    states: Mutex<Vec<EndpointState>>,
+}
+
+impl RpcPool {
+    /// 创建端点池
+    pub fn new(config: RpcPoolConfig) -> Result<Self> {
+        if config.endpoints.is_empty() {
+            return Err(SolanaError::ConfigError(
+                "RPC pool requires at least one endpoint".to_string(),
+            ));
+        }
+        for traffic in [RpcTraffic::Read, RpcTraffic::Write] {
+            if !config.endpoints.iter().any(|e| e.role.serves(traffic)) {
+                return Err(SolanaError::ConfigError(format!(
+                    "RPC pool has no endpoint for {:?} traffic",
+                    traffic
+                )));
+            }
+        }
+
+        let clients = config
+            .endpoints
+            .iter()
+            .map(|endpoint| {
+                Arc::new(RpcClient::new_with_timeout_and_commitment(
+                    endpoint.url.clone(),
+                    config.request_timeout,
+                    CommitmentConfig::confirmed(),
+                ))
+            })
+            .collect();
+        let states = config
+            .endpoints
+            .iter()
+            .map(|_| EndpointState::default())
+            .collect();
+
+        Ok(Self {
+            config,
+            clients,
+            states: Mutex::new(states),
+        })
+    }
+
+    /// 从 Solana 配置创建端点池
+    pub fn from_config(config: &crate::config::SolanaConfig) -> Result<Self> {
+        Self::new(RpcPoolConfig::from_config(config))
+    }
+
+    /// 在池中执行请求，端点级故障时切换到下一个端点
+    ///
+    /// 业务错误（如交易模拟失败）原样返回，不计入熔断；所有端点都失败时返回最后一个错误
+    pub fn execute<T, F>(&self, traffic: RpcTraffic, f: F) -> std::result::Result<T, ClientError>
+    where
+        F: Fn(&RpcClient) -> std::result::Result<T, ClientError>,
+    {
+        let mut tried = Vec::new();
+        let mut last_error = None;
+
+        while let Ok(index) = self.select(traffic, &tried) {
+            tried.push(index);
+            let started = Instant::now();
+
+            match f(&self.clients[index]) {
+                Ok(value) => {
+                    self.record_success(index, started.elapsed());
+                    return Ok(value);
+                },
+                Err(e) if is_endpoint_failure(&e) => {
+                    tracing::warn!(
+                        "RPC endpoint {} failed, trying next: {}",
+                        redact_url(&self.config.endpoints[index].url),
+                        e
+                    );
+                    self.record_failure(index, e.to_string());
+                    last_error = Some(e);
+                },
+                Err(e) => {
+                    self.record_success(index, started.elapsed());
+                    return Err(e);
+                },
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            ClientErrorKind::Custom(format!(
+                "No available RPC endpoint for {:?} traffic",
+                traffic
+            ))
+            .into()
+        }))
+    }
+
+    /// 对所有端点执行一次健康检查（阻塞调用）
+    pub fn check_health(&self) {
+        let probes: Vec<(bool, Option<u64>)> = self
+            .clients
+            .iter()
+            .map(|client| {
+                let healthy = client.get_health().is_ok();
+                (healthy, client.get_slot().ok())
+            })
+            .collect();
+
+        let highest_slot = probes.iter().filter_map(|(_, slot)| *slot).max();
+        let mut states = self.lock_states();
+
+        for (state, (healthy, slot)) in states.iter_mut().zip(probes) {
+            let lagging = match (slot, highest_slot) {
+                (Some(slot), Some(highest)) => {
+                    highest.saturating_sub(slot) > self.config.max_slot_lag
+                },
+                _ => true,
+            };
+            state.healthy = healthy && !lagging;
+            state.slot = slot;
+        }
+    }
+
+    /// 在后台定期执行健康检查
+    pub fn spawn_health_checker(self: &Arc<Self>) -> JoinHandle<()> {
+        let pool = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(pool.config.health_check_interval);
+            loop {
+                interval.tick().await;
+                let probe = Arc::clone(&pool);
+                if let Err(e) = tokio::task::spawn_blocking(move || probe.check_health()).await {
+                    tracing::error!("RPC pool health check panicked: {}", e);
+                }
+            }
+        })
+    }
+
+    /// 当前指标快照
+    pub fn metrics(&self) -> RpcPoolMetrics {
+        let states = self.lock_states();
+        let highest_slot = states.iter().filter_map(|s| s.slot).max();
+
+        let endpoints = self
+            .config
+            .endpoints
+            .iter()
+            .zip(states.iter())
+            .map(|(endpoint, state)| RpcEndpointMetrics {
+                url: redact_url(&endpoint.url),
+                role: endpoint.role,
+                weight: endpoint.weight,
+                healthy: state.healthy,
+                circuit: state.circuit,
+                slot: state.slot,
+                slot_lag: state
+                    .slot
+                    .zip(highest_slot)
+                    .map(|(slot, highest)| highest.saturating_sub(slot)),
+                requests: state.requests,
+                failures: state.failures,
+                consecutive_failures: state.consecutive_failures,
+                last_latency_ms: state.last_latency.map(|d| d.as_millis() as u64),
+                last_error: state.last_error.clone(),
+            })
+            .collect();
+
+        RpcPoolMetrics {
+            highest_slot,
+            endpoints,
+        }
+    }
+
+    /// 平滑加权轮询选择端点
+    ///
+    /// 优先选择健康且未熔断的端点；全部不健康时退而使用未熔断的端点，
+    /// 避免健康检查误判导致整个池不可用
+    fn select(&self, traffic: RpcTraffic, exclude: &[usize]) -> Result<usize> {
+        let mut states = self.lock_states();
+        let now = Instant::now();
+
+        for state in states.iter_mut() {
+            if state.circuit == CircuitState::Open && state.open_until.is_some_and(|t| now >= t) {
+                state.circuit = CircuitState::HalfOpen;
+                state.open_until = None;
+            }
+        }
+
+        let eligible = |index: usize, state: &EndpointState| {
+            self.config.endpoints[index].role.serves(traffic)
+                && !exclude.contains(&index)
+                && state.circuit != CircuitState::Open
+        };
+        let mut candidates: Vec<usize> = states
+            .iter()
+            .enumerate()
+            .filter(|(i, s)| eligible(*i, s) && s.healthy)
+            .map(|(i, _)| i)
+            .collect();
+        if candidates.is_empty() {
+            candidates = states
+                .iter()
+                .enumerate()
+                .filter(|(i, s)| eligible(*i, s))
+                .map(|(i, _)| i)
+                .collect();
+        }
+        if candidates.is_empty() {
+            return Err(SolanaError::RpcError(format!(
+                "No available RPC endpoint for {:?} traffic",
+                traffic
+            )));
+        }
+
+        // 权重全为 0 时按 1 处理，保证仍可轮询
+        let weight = |index: usize| i64::from(self.config.endpoints[index].weight.max(1));
+        let total: i64 = candidates.iter().map(|i| weight(*i)).sum();
+
+        let mut selected = candidates[0];
+        for &index in &candidates {
+            states[index].current_weight += weight(index);
+            if states[index].current_weight > states[selected].current_weight {
+                selected = index;
+            }
+        }
+        states[selected].current_weight -= total;
+
+        Ok(selected)
+    }
+
+    fn record_success(&self, index: usize, latency: Duration) {
+        let mut states = self.lock_states();
+        let state = &mut states[index];
+        state.requests += 1;
+        state.consecutive_failures = 0;
+        state.circuit = CircuitState::Closed;
+        state.open_until = None;
+        state.last_latency = Some(latency);
+    }
+
+    fn record_failure(&self, index: usize, error: String) {
+        let mut states = self.lock_states();
+        let state = &mut states[index];
+        state.requests += 1;
+        state.failures += 1;
+        state.consecutive_failures += 1;
+        state.last_error = Some(error);
+
+        // 半开状态下试探失败立即重新熔断
+        if state.circuit == CircuitState::HalfOpen
+            || state.consecutive_failures >= self.config.failure_threshold
+        {
+            state.circuit = CircuitState::Open;
+            state.open_until = Some(Instant::now() + self.config.open_duration);
+        }
+    }
+
+    fn lock_states(&self) -> MutexGuard<'_, Vec<EndpointState>> {
+        // 状态更新不会在持锁期间 panic，中毒时沿用已有数据
+        self.states.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 判断错误是否由端点本身引起（限流、服务端错误、网络故障）
+pub fn is_endpoint_failure(error: &ClientError) -> bool {
+    match error.kind() {
+        ClientErrorKind::Io(_) => true,
+        ClientErrorKind::Reqwest(e) => {
+            e.is_timeout()
+                || e.is_connect()
+                || e.status()
+                    .is_some_and(|status| status.as_u16() == 429 || status.is_server_error())
+        },
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => {
+            *code == JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY
+        },
+        _ => false,
+    }
+}
+
+/// 去掉 URL 中的查询参数
+fn redact_url(url: &str) -> String {
+    url.split('?').next().unwrap_or(url).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(endpoints: Vec<RpcEndpointConfig>) -> RpcPool {
+        RpcPool::new(RpcPoolConfig {
+            endpoints,
+            failure_threshold: 2,
+            open_duration: Duration::from_millis(50),
+            ..RpcPoolConfig::default()
+        })
+        .unwrap()
+    }
+
+    fn endpoint(url: &str, weight: u32, role: RpcRole) -> RpcEndpointConfig {
+        RpcEndpointConfig {
+            url: url.to_string(),
+            weight,
+            role,
+        }
+    }
+
+    #[test]
+    fn test_weighted_round_robin() {
+        let pool = pool(vec![
+            endpoint("http://a", 3, RpcRole::Both),
+            endpoint("http://b", 1, RpcRole::Both),
+        ]);
+
+        let picks: Vec<usize> = (0..8)
+            .map(|_| pool.select(RpcTraffic::Read, &[]).unwrap())
+            .collect();
+
+        assert_eq!(picks.iter().filter(|i| **i == 0).count(), 6);
+        assert_eq!(picks.iter().filter(|i| **i == 1).count(), 2);
+    }
+
+    #[test]
+    fn test_read_and_write_traffic_are_split() {
+        let pool = pool(vec![
+            endpoint("http://read", 1, RpcRole::Read),
+            endpoint("http://write", 1, RpcRole::Write),
+        ]);
+
+        assert_eq!(pool.select(RpcTraffic::Read, &[]).unwrap(), 0);
+        assert_eq!(pool.select(RpcTraffic::Write, &[]).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_circuit_opens_and_half_opens() {
+        let pool = pool(vec![
+            endpoint("http://a", 1, RpcRole::Both),
+            endpoint("http://b", 1, RpcRole::Both),
+        ]);
+
+        pool.record_failure(0, "429".to_string());
+        pool.record_failure(0, "429".to_string());
+        assert_eq!(pool.metrics().endpoints[0].circuit, CircuitState::Open);
+        for _ in 0..4 {
+            assert_eq!(pool.select(RpcTraffic::Read, &[]).unwrap(), 1);
+        }
+
+        std::thread::sleep(Duration::from_millis(60));
+        pool.select(RpcTraffic::Read, &[]).unwrap();
+        assert_eq!(pool.metrics().endpoints[0].circuit, CircuitState::HalfOpen);
+
+        pool.record_failure(0, "503".to_string());
+        assert_eq!(pool.metrics().endpoints[0].circuit, CircuitState::Open);
+    }
+
+    #[test]
+    fn test_unhealthy_endpoints_are_fallback_only() {
+        let pool = pool(vec![
+            endpoint("http://a", 1, RpcRole::Both),
+            endpoint("http://b", 1, RpcRole::Both),
+        ]);
+        pool.lock_states()[0].healthy = false;
+
+        for _ in 0..3 {
+            assert_eq!(pool.select(RpcTraffic::Read, &[]).unwrap(), 1);
+        }
+        assert_eq!(pool.select(RpcTraffic::Read, &[1]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_pool_requires_write_endpoint() {
+        let result = RpcPool::new(RpcPoolConfig {
+            endpoints: vec![endpoint("http://read", 1, RpcRole::Read)],
+            ..RpcPoolConfig::default()
+        });
+
+        assert!(matches!(result, Err(SolanaError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_execute_fails_over_to_next_endpoint() {
+        let pool = pool(vec![
+            endpoint("http://a", 1, RpcRole::Both),
+            endpoint("http://b", 1, RpcRole::Both),
+        ]);
+        let calls = Mutex::new(Vec::new());
+
+        let url = pool
+            .execute(RpcTraffic::Read, |client| {
+                calls.lock().unwrap().push(client.url());
+                if client.url() == "http://a" {
+                    let error = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "down");
+                    return Err(ClientErrorKind::Io(error).into());
+                }
+                Ok(client.url())
+            })
+            .unwrap();
+
+        assert_eq!(url, "http://b");
+        assert_eq!(calls.lock().unwrap().len(), 2);
+        let metrics = pool.metrics();
+        assert_eq!(metrics.endpoints[0].failures, 1);
+        assert_eq!(metrics.endpoints[1].failures, 0);
+    }
+
+    #[test]
+    fn test_execute_returns_business_errors() {
+        let pool = pool(vec![
+            endpoint("http://a", 1, RpcRole::Both),
+            endpoint("http://b", 1, RpcRole::Both),
+        ]);
+        let calls = Mutex::new(0);
+
+        let result: std::result::Result<(), ClientError> = pool.execute(RpcTraffic::Write, |_| {
+            *calls.lock().unwrap() += 1;
+            Err(ClientErrorKind::Custom("invalid transaction".to_string()).into())
+        });
+
+        assert!(result.is_err());
+        assert_eq!(*calls.lock().unwrap(), 1);
+        assert!(pool.metrics().endpoints.iter().all(|e| e.failures == 0));
+    }
+
+    #[test]
+    fn test_metrics_redact_query() {
+        let pool = pool(vec![endpoint(
+            "https://rpc.example/?api-key=secret",
+            1,
+            RpcRole::Both,
+        )]);
+
+        assert_eq!(pool.metrics().endpoints[0].url, "https://rpc.example/");
+    }
+}
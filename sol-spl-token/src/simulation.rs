@@ -0,0 +1,342 @@
+//! 交易预检模块
+//!
+//! 托管交易发送前先执行 `simulateTransaction`，
+//! 并把 `TransactionError` 与程序日志解码为结构化的 [`SolanaError`]，
+//! 避免调用方只能拿到 `e.to_string()` 之后的字符串。
+//! 旧版交易与使用地址查找表的 v0 交易走同一套预检流程
+
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_client::SerializableTransaction,
+    rpc_request::{RpcError, RpcResponseErrorData},
+};
+use solana_program::instruction::InstructionError;
+use solana_sdk::{
+    message::compiled_instruction::CompiledInstruction,
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::{Transaction, TransactionError, VersionedTransaction},
+};
+use spl_token::error::TokenError;
+
+use crate::error::{Result, SolanaError};
+use crate::rpc_pool::RpcHandle;
+
+/// System Program 的 `ResultWithNegativeLamports` 错误码
+const SYSTEM_ERROR_RESULT_WITH_NEGATIVE_LAMPORTS: u32 = 1;
+
+/// 预检结果
+#[derive(Debug, Clone, Default)]
+pub struct SimulationReport {
+    /// 程序日志
+    pub logs: Vec<String>,
+
+    /// 消耗的计算单元
+    pub units_consumed: Option<u64>,
+}
+
+/// 可预检的交易
+///
+/// 错误解码只需要静态账户与编译后的指令：程序账户不能通过查找表加载，
+/// 出错指令的程序总能在静态账户中找到
+pub trait PreflightTransaction: SerializableTransaction {
+    /// 消息中直接列出的账户
+    fn static_account_keys(&self) -> &[Pubkey];
+
+    /// 编译后的指令
+    fn compiled_instructions(&self) -> &[CompiledInstruction];
+}
+
+impl PreflightTransaction for Transaction {
+    fn static_account_keys(&self) -> &[Pubkey] {
+        &self.message.account_keys
+    }
+
+    fn compiled_instructions(&self) -> &[CompiledInstruction] {
+        &self.message.instructions
+    }
+}
+
+impl PreflightTransaction for VersionedTransaction {
+    fn static_account_keys(&self) -> &[Pubkey] {
+        self.message.static_account_keys()
+    }
+
+    fn compiled_instructions(&self) -> &[CompiledInstruction] {
+        self.message.instructions()
+    }
+}
+
+/// 模拟执行交易，失败时返回解码后的错误
+pub fn preflight<T: PreflightTransaction>(
+    client: &RpcHandle,
+    transaction: &T,
+) -> Result<SimulationReport> {
+    let result = client
+        .call(|client| client.simulate_transaction(transaction))
+        .map_err(|e| decode_client_error(&e, transaction))?
+        .value;
+
+    let logs = result.logs.unwrap_or_default();
+    if let Some(err) = result.err {
+        return Err(decode_transaction_error(&err.into(), &logs, transaction));
+    }
+
+    Ok(SimulationReport {
+        logs,
+        units_consumed: result.units_consumed,
+    })
+}
+
+/// 预检通过后发送并确认交易
+pub fn send_with_preflight<T: PreflightTransaction>(
+    client: &RpcHandle,
+    transaction: &T,
+) -> Result<Signature> {
+    let report = preflight(client, transaction)?;
+    tracing::debug!(
+        "Preflight passed, {} compute units consumed",
+        report.units_consumed.unwrap_or_default()
+    );
+
+    // 同一笔已签名交易换端点重发不会重复执行
+    client
+        .call(|client| client.send_and_confirm_transaction(transaction))
+        .map_err(|e| decode_client_error(&e, transaction))
+}
+
+/// 解码 RPC 客户端错误，包含交易错误时按交易错误解码
+pub fn decode_client_error<T: PreflightTransaction>(
+    error: &ClientError,
+    transaction: &T,
+) -> SolanaError {
+    if let ClientErrorKind::RpcError(RpcError::RpcResponseError {
+        data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+        ..
+    }) = error.kind()
+    {
+        if let Some(err) = &result.err {
+            let logs = result.logs.as_deref().unwrap_or_default();
+            return decode_transaction_error(&err.clone().into(), logs, transaction);
+        }
+    }
+
+    match error.get_transaction_error() {
+        Some(err) => decode_transaction_error(&err, &[], transaction),
+        None => SolanaError::SendError(error.to_string()),
+    }
+}
+
+/// 将交易错误与日志解码为结构化错误
+pub fn decode_transaction_error<T: PreflightTransaction>(
+    error: &TransactionError,
+    logs: &[String],
+    transaction: &T,
+) -> SolanaError {
+    // DEX 程序的滑点错误码各不相同，统一按日志识别
+    if let Some(line) = logs
+        .iter()
+        .find(|line| line.to_ascii_lowercase().contains("slippage"))
+    {
+        return SolanaError::SlippageExceeded(line.clone());
+    }
+
+    match error {
+        TransactionError::BlockhashNotFound => {
+            SolanaError::BlockhashNotFound("Blockhash expired or not found".to_string())
+        },
+        TransactionError::InsufficientFundsForFee => {
+            SolanaError::InsufficientBalance("Fee payer cannot cover the fee".to_string())
+        },
+        TransactionError::InsufficientFundsForRent { account_index } => {
+            SolanaError::InsufficientBalance(format!(
+                "Account {} would fall below rent exemption",
+                account_key(transaction, *account_index as usize)
+            ))
+        },
+        TransactionError::AccountNotFound => {
+            SolanaError::AccountNotFound("Fee payer account not found".to_string())
+        },
+        TransactionError::InstructionError(index, instruction_error) => {
+            decode_instruction_error(*index, instruction_error, logs, transaction)
+        },
+        other => simulation_failed(other.to_string(), logs),
+    }
+}
+
+fn decode_instruction_error<T: PreflightTransaction>(
+    index: u8,
+    error: &InstructionError,
+    logs: &[String],
+    transaction: &T,
+) -> SolanaError {
+    let program_id = transaction
+        .compiled_instructions()
+        .get(index as usize)
+        .map(|ix| account_key(transaction, ix.program_id_index as usize));
+    let is_token_program = program_id == Some(spl_token::id());
+    let is_system_program = program_id == Some(solana_system_interface::program::id());
+    let detail = format!("instruction {}: {}", index, error);
+
+    match error {
+        InstructionError::Custom(code) if is_token_program => match *code {
+            c if c == TokenError::InsufficientFunds as u32 => {
+                SolanaError::InsufficientBalance(detail)
+            },
+            c if c == TokenError::AccountFrozen as u32 => SolanaError::AccountFrozen(detail),
+            c if c == TokenError::UninitializedState as u32 => {
+                SolanaError::TokenAccountNotFound(detail)
+            },
+            _ => simulation_failed(detail, logs),
+        },
+        // 目标 ATA 不存在时，Token Program 读取到的是空数据
+        InstructionError::InvalidAccountData | InstructionError::UninitializedAccount
+            if is_token_program =>
+        {
+            SolanaError::TokenAccountNotFound(detail)
+        },
+        InstructionError::Custom(SYSTEM_ERROR_RESULT_WITH_NEGATIVE_LAMPORTS)
+            if is_system_program =>
+        {
+            SolanaError::InsufficientBalance(detail)
+        },
+        InstructionError::InsufficientFunds => SolanaError::InsufficientBalance(detail),
+        _ => simulation_failed(detail, logs),
+    }
+}
+
+/// 下标超出静态账户时（账户来自查找表）返回默认地址
+fn account_key<T: PreflightTransaction>(transaction: &T, index: usize) -> Pubkey {
+    transaction
+        .static_account_keys()
+        .get(index)
+        .copied()
+        .unwrap_or_default()
+}
+
+fn simulation_failed(message: String, logs: &[String]) -> SolanaError {
+    SolanaError::SimulationFailed {
+        message,
+        logs: logs.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    fn token_transfer() -> Transaction {
+        let owner = Keypair::new();
+        let ix = spl_token::instruction::transfer(
+            &spl_token::id(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &owner.pubkey(),
+            &[],
+            1,
+        )
+        .unwrap();
+        Transaction::new_with_payer(&[ix], Some(&owner.pubkey()))
+    }
+
+    fn sol_transfer() -> Transaction {
+        let from = Keypair::new();
+        let ix = solana_system_interface::instruction::transfer(
+            &from.pubkey(),
+            &Pubkey::new_unique(),
+            1,
+        );
+        Transaction::new_with_payer(&[ix], Some(&from.pubkey()))
+    }
+
+    #[test]
+    fn test_decode_token_errors() {
+        let tx = token_transfer();
+        let decode = |code: TokenError| {
+            decode_transaction_error(
+                &TransactionError::InstructionError(0, InstructionError::Custom(code as u32)),
+                &[],
+                &tx,
+            )
+        };
+
+        assert!(matches!(
+            decode(TokenError::InsufficientFunds),
+            SolanaError::InsufficientBalance(_)
+        ));
+        assert!(matches!(
+            decode(TokenError::AccountFrozen),
+            SolanaError::AccountFrozen(_)
+        ));
+    }
+
+    #[test]
+    fn test_decode_missing_ata() {
+        let tx = token_transfer();
+        let err = decode_transaction_error(
+            &TransactionError::InstructionError(0, InstructionError::InvalidAccountData),
+            &[],
+            &tx,
+        );
+
+        assert!(matches!(err, SolanaError::TokenAccountNotFound(_)));
+    }
+
+    #[test]
+    fn test_decode_system_insufficient_lamports() {
+        let tx = sol_transfer();
+        let err = decode_transaction_error(
+            &TransactionError::InstructionError(0, InstructionError::Custom(1)),
+            &[],
+            &tx,
+        );
+
+        assert!(matches!(err, SolanaError::InsufficientBalance(_)));
+    }
+
+    #[test]
+    fn test_decode_slippage_from_logs() {
+        let tx = token_transfer();
+        let logs = vec![
+            "Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 invoke [1]".to_string(),
+            "Program log: Error: SlippageToleranceExceeded".to_string(),
+        ];
+        let err = decode_transaction_error(
+            &TransactionError::InstructionError(0, InstructionError::Custom(6001)),
+            &logs,
+            &tx,
+        );
+
+        assert!(matches!(err, SolanaError::SlippageExceeded(_)));
+    }
+
+    #[test]
+    fn test_decode_versioned_token_error() {
+        let tx = VersionedTransaction::from(token_transfer());
+        let err = decode_transaction_error(
+            &TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(TokenError::InsufficientFunds as u32),
+            ),
+            &[],
+            &tx,
+        );
+
+        assert!(matches!(err, SolanaError::InsufficientBalance(_)));
+    }
+
+    #[test]
+    fn test_decode_blockhash_and_unknown() {
+        let tx = sol_transfer();
+
+        assert!(matches!(
+            decode_transaction_error(&TransactionError::BlockhashNotFound, &[], &tx),
+            SolanaError::BlockhashNotFound(_)
+        ));
+        assert!(matches!(
+            decode_transaction_error(&TransactionError::AccountInUse, &[], &tx),
+            SolanaError::SimulationFailed { .. }
+        ));
+    }
+}
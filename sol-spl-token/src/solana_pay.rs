@@ -0,0 +1,291 @@
+//! Solana Pay 模块
+//!
+//! 为托管钱包生成 Solana Pay 转账请求（`solana:` URL）及其二维码，
+//! 付款方钱包扫码后会把请求中的引用公钥附加在转账指令上。
+//! 收款侧按引用公钥查找付款交易，再从交易前后余额核对收款方实际到账的数量
+
+use qrcode::{render::svg, QrCode};
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_transaction_status_client_types::{
+    EncodedConfirmedTransactionWithStatusMeta, UiLoadedAddresses, UiTransactionEncoding,
+    UiTransactionStatusMeta, UiTransactionTokenBalance,
+};
+use std::{str::FromStr, sync::Arc};
+
+use crate::error::{Result, SolanaError};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+
+/// 二维码的最小边长（像素）
+const QR_MIN_DIMENSION: u32 = 256;
+
+/// 把最小单位的数量格式化为十进制字符串，去掉小数部分末尾的 0
+pub fn format_amount(amount: u64, decimals: u8) -> String {
+    if decimals == 0 {
+        return amount.to_string();
+    }
+
+    let digits = format!("{:0>width$}", amount, width = decimals as usize + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+/// Solana Pay 转账请求
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferRequest {
+    /// 收款钱包地址
+    pub recipient: Pubkey,
+
+    /// 数量（最小单位），为空时由付款方填写
+    pub amount: Option<u64>,
+
+    /// 数量的小数位数，SOL 为 9
+    pub decimals: u8,
+
+    /// SPL Token 的 mint，为空表示 SOL
+    pub spl_token: Option<Pubkey>,
+
+    /// 引用公钥，付款交易会把它们作为只读账户附加在转账指令上
+    pub references: Vec<Pubkey>,
+
+    /// 收款方名称
+    pub label: Option<String>,
+
+    /// 付款说明
+    pub message: Option<String>,
+
+    /// 付款交易中附带的备注
+    pub memo: Option<String>,
+}
+
+impl TransferRequest {
+    /// 生成 `solana:` URL，各参数按 Solana Pay 规范编码
+    pub fn to_url(&self) -> String {
+        let mut params = Vec::new();
+        if let Some(amount) = self.amount {
+            params.push(format!("amount={}", format_amount(amount, self.decimals)));
+        }
+        if let Some(mint) = &self.spl_token {
+            params.push(format!("spl-token={}", mint));
+        }
+        for reference in &self.references {
+            params.push(format!("reference={}", reference));
+        }
+        for (key, value) in [
+            ("label", &self.label),
+            ("message", &self.message),
+            ("memo", &self.memo),
+        ] {
+            if let Some(value) = value {
+                params.push(format!("{}={}", key, urlencoding::encode(value)));
+            }
+        }
+
+        if params.is_empty() {
+            format!("solana:{}", self.recipient)
+        } else {
+            format!("solana:{}?{}", self.recipient, params.join("&"))
+        }
+    }
+}
+
+/// 生成内容的 SVG 二维码
+pub fn qr_svg(content: &str) -> Result<String> {
+    let code =
+        QrCode::new(content.as_bytes()).map_err(|e| SolanaError::SolanaPayError(e.to_string()))?;
+
+    Ok(code
+        .render::<svg::Color>()
+        .min_dimensions(QR_MIN_DIMENSION, QR_MIN_DIMENSION)
+        .build())
+}
+
+/// 按交易前后余额计算 `recipient` 收到的数量
+///
+/// `mint` 为空时比较 SOL 余额，否则比较 `recipient` 持有的该 mint 的 Token 账户余额；
+/// `account_keys` 须与交易的账户顺序一致（静态账户在前，查找表加载的可写、只读账户在后）
+pub fn received_amount(
+    meta: &UiTransactionStatusMeta,
+    account_keys: &[Pubkey],
+    recipient: &Pubkey,
+    mint: Option<&Pubkey>,
+) -> u64 {
+    let Some(mint) = mint else {
+        return account_keys
+            .iter()
+            .position(|key| key == recipient)
+            .and_then(|index| {
+                let pre = meta.pre_balances.get(index)?;
+                let post = meta.post_balances.get(index)?;
+                Some(post.saturating_sub(*pre))
+            })
+            .unwrap_or_default();
+    };
+
+    let (owner, mint) = (recipient.to_string(), mint.to_string());
+    let pre = token_total(
+        Option::from(meta.pre_token_balances.as_ref()),
+        &owner,
+        &mint,
+    );
+    let post = token_total(
+        Option::from(meta.post_token_balances.as_ref()),
+        &owner,
+        &mint,
+    );
+    post.saturating_sub(pre)
+}
+
+/// 按已确认交易（Base64 编码读取）计算 `recipient` 收到的数量，账户顺序含查找表加载的账户
+pub(crate) fn confirmed_received_amount(
+    confirmed: EncodedConfirmedTransactionWithStatusMeta,
+    signature: &Signature,
+    recipient: &Pubkey,
+    mint: Option<&Pubkey>,
+) -> Result<u64> {
+    let transaction = confirmed.transaction.transaction.decode().ok_or_else(|| {
+        SolanaError::RpcError(format!("Undecodable transaction {}", signature))
+    })?;
+    let meta = confirmed.transaction.meta.ok_or_else(|| {
+        SolanaError::RpcError(format!("Transaction {} has no status meta", signature))
+    })?;
+
+    let mut account_keys = transaction.message.static_account_keys().to_vec();
+    let loaded: Option<&UiLoadedAddresses> = Option::from(meta.loaded_addresses.as_ref());
+    if let Some(loaded) = loaded {
+        for address in loaded.writable.iter().chain(&loaded.readonly) {
+            account_keys.push(
+                Pubkey::from_str(address)
+                    .map_err(|e| SolanaError::RpcError(format!("{}: {}", address, e)))?,
+            );
+        }
+    }
+
+    Ok(received_amount(&meta, &account_keys, recipient, mint))
+}
+
+/// `owner` 持有的 `mint` Token 账户余额之和，交易中新建的账户没有交易前余额
+fn token_total(balances: Option<&Vec<UiTransactionTokenBalance>>, owner: &str, mint: &str) -> u64 {
+    balances
+        .into_iter()
+        .flatten()
+        .filter(|balance| {
+            balance.mint == mint
+                && Option::<&String>::from(balance.owner.as_ref()).map(String::as_str)
+                    == Some(owner)
+        })
+        .filter_map(|balance| balance.ui_token_amount.amount.parse::<u64>().ok())
+        .sum()
+}
+
+/// Solana Pay 付款查询器
+pub struct PaymentChecker {
+    rpc_client: RpcHandle,
+}
+
+impl PaymentChecker {
+    /// 创建新的付款查询器
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            rpc_client: RpcHandle::new(rpc_url),
+        }
+    }
+
+    /// 从 RPC 端点池创建付款查询器
+    pub fn from_pool(pool: &Arc<RpcPool>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcHandle::pooled(pool, RpcTraffic::Read),
+        })
+    }
+
+    /// 查找引用公钥下最早一笔执行成功的交易
+    pub fn find_payment(&self, reference: &Pubkey) -> Result<Option<Signature>> {
+        let statuses = self
+            .rpc_client
+            .call(|client| client.get_signatures_for_address(reference))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        // 结果按时间倒序排列
+        statuses
+            .iter()
+            .rev()
+            .find(|status| status.err.is_none())
+            .map(|status| {
+                Signature::from_str(&status.signature)
+                    .map_err(|e| SolanaError::RpcError(format!("{}: {}", status.signature, e)))
+            })
+            .transpose()
+    }
+
+    /// 读取交易并计算 `recipient` 收到的数量
+    pub fn received_amount(
+        &self,
+        signature: &Signature,
+        recipient: &Pubkey,
+        mint: Option<&Pubkey>,
+    ) -> Result<u64> {
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(self.rpc_client.commitment()),
+            max_supported_transaction_version: Some(0),
+        };
+        let confirmed = self
+            .rpc_client
+            .call(|client| client.get_transaction_with_config(signature, config))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        confirmed_received_amount(confirmed, signature, recipient, mint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_amount() {
+        assert_eq!(format_amount(1_500_000, 6), "1.5");
+        assert_eq!(format_amount(1_000_000, 6), "1");
+        assert_eq!(format_amount(1, 9), "0.000000001");
+        assert_eq!(format_amount(42, 0), "42");
+    }
+
+    #[test]
+    fn test_transfer_request_url() {
+        let recipient = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let reference = Pubkey::new_unique();
+        let request = TransferRequest {
+            recipient,
+            amount: Some(2_500_000),
+            decimals: 6,
+            spl_token: Some(mint),
+            references: vec![reference],
+            label: Some("Acme Store".to_string()),
+            message: Some("Order #42".to_string()),
+            memo: None,
+        };
+
+        assert_eq!(
+            request.to_url(),
+            format!(
+                "solana:{}?amount=2.5&spl-token={}&reference={}&label=Acme%20Store&message=Order%20%2342",
+                recipient, mint, reference
+            )
+        );
+        assert_eq!(
+            TransferRequest {
+                recipient,
+                ..Default::default()
+            }
+            .to_url(),
+            format!("solana:{}", recipient)
+        );
+        assert!(qr_svg(&request.to_url()).unwrap().starts_with("<?xml"));
+    }
+}
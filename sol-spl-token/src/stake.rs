@@ -0,0 +1,442 @@
+//! 质押模块
+//!
+//! 用托管中闲置的 SOL 创建原生质押账户并委托给验证者，
+//! 支持解除委托、提取与按 epoch 查询通胀奖励。
+//! 质押与提取权限都设为出资钱包，质押账户自身的密钥只在创建时签名一次
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use solana_stake_interface::{
+    instruction as stake_instruction, program as stake_program,
+    state::{Authorized, Lockup, StakeStateV2},
+};
+use std::sync::Arc;
+
+use crate::error::{Result, SolanaError};
+use crate::lock::{ensure_lock_held, lock_wallet, DistributedLock};
+use crate::risk::{RiskGate, ScreeningRequest};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+use crate::simulation::send_with_preflight;
+
+/// 质押账户的数据长度
+pub const STAKE_ACCOUNT_SPACE: usize = StakeStateV2::size_of();
+
+/// 质押账户的委托状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StakeStatus {
+    /// 已初始化但未委托
+    Initialized,
+    /// 委托后等待下一个 epoch 生效
+    Activating,
+    /// 已生效，参与奖励分配
+    Active,
+    /// 已解除委托，当前 epoch 结束后冷却完成
+    Deactivating,
+    /// 冷却完成，可全部提取
+    Inactive,
+}
+
+impl StakeStatus {
+    /// 是否可以提取全部余额
+    pub fn is_withdrawable(&self) -> bool {
+        matches!(self, StakeStatus::Initialized | StakeStatus::Inactive)
+    }
+}
+
+/// 质押账户的链上状态
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeAccountInfo {
+    pub address: Pubkey,
+
+    /// 账户余额，含租金储备
+    pub lamports: u64,
+
+    pub status: StakeStatus,
+
+    /// 提取权限
+    pub withdrawer: Pubkey,
+
+    /// 委托的验证者投票账户，未委托时为空
+    pub voter: Option<Pubkey>,
+
+    /// 委托数量
+    pub delegated_stake: u64,
+
+    pub activation_epoch: Option<u64>,
+
+    pub deactivation_epoch: Option<u64>,
+}
+
+/// 质押账户在某个 epoch 获得的通胀奖励
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochReward {
+    pub address: Pubkey,
+
+    pub epoch: u64,
+
+    /// 奖励数量（lamports）
+    pub amount: u64,
+
+    /// 奖励入账后的账户余额
+    pub post_balance: u64,
+
+    /// 发放奖励时验证者的佣金比例（%）
+    pub commission: Option<u8>,
+}
+
+fn stake_error(e: impl ToString) -> SolanaError {
+    SolanaError::StakeError(e.to_string())
+}
+
+/// 解析质押账户数据
+///
+/// 状态只比较激活与解除委托的 epoch，不计算预热与冷却的速率限制，
+/// 全网质押变动较大时标为生效的委托可能仍有一部分在预热
+pub fn parse_stake_account(
+    address: &Pubkey,
+    lamports: u64,
+    data: &[u8],
+    current_epoch: u64,
+) -> Result<StakeAccountInfo> {
+    let state: StakeStateV2 = bincode::deserialize(data)
+        .map_err(|e| stake_error(format!("{} is not a stake account: {}", address, e)))?;
+
+    let (meta, delegation) = match state {
+        StakeStateV2::Initialized(meta) => (meta, None),
+        StakeStateV2::Stake(meta, stake, _) => (meta, Some(stake.delegation)),
+        _ => {
+            return Err(stake_error(format!(
+                "{} is not an initialized stake account",
+                address
+            )))
+        },
+    };
+
+    let status = match delegation {
+        None => StakeStatus::Initialized,
+        Some(delegation) if delegation.deactivation_epoch != u64::MAX => {
+            if current_epoch > delegation.deactivation_epoch {
+                StakeStatus::Inactive
+            } else {
+                StakeStatus::Deactivating
+            }
+        },
+        Some(delegation) if current_epoch > delegation.activation_epoch => StakeStatus::Active,
+        Some(_) => StakeStatus::Activating,
+    };
+
+    Ok(StakeAccountInfo {
+        address: *address,
+        lamports,
+        status,
+        withdrawer: meta.authorized.withdrawer,
+        voter: delegation.map(|delegation| delegation.voter_pubkey),
+        delegated_stake: delegation.map_or(0, |delegation| delegation.stake),
+        activation_epoch: delegation.map(|delegation| delegation.activation_epoch),
+        deactivation_epoch: delegation
+            .map(|delegation| delegation.deactivation_epoch)
+            .filter(|epoch| *epoch != u64::MAX),
+    })
+}
+
+/// 质押管理器
+pub struct StakeManager {
+    rpc_client: RpcHandle,
+    write_client: RpcHandle,
+    risk_gate: Option<Arc<RiskGate>>,
+    wallet_lock: Option<Arc<dyn DistributedLock>>,
+}
+
+impl StakeManager {
+    /// 创建新的质押管理器
+    pub fn new(rpc_url: &str) -> Self {
+        let rpc_client = RpcHandle::new(rpc_url);
+
+        Self {
+            write_client: rpc_client.clone(),
+            rpc_client,
+            risk_gate: None,
+            wallet_lock: None,
+        }
+    }
+
+    /// 从 RPC 端点池创建质押管理器
+    pub fn from_pool(pool: &Arc<RpcPool>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcHandle::pooled(pool, RpcTraffic::Read),
+            write_client: RpcHandle::pooled(pool, RpcTraffic::Write),
+            risk_gate: None,
+            wallet_lock: None,
+        })
+    }
+
+    /// 设置风险闸门，委托的验证者与提取的收款方在签名前筛查
+    pub fn with_risk_gate(mut self, risk_gate: Arc<RiskGate>) -> Self {
+        self.risk_gate = Some(risk_gate);
+        self
+    }
+
+    /// 设置分布式锁，出资和质押账户的交易按账户串行化
+    pub fn with_wallet_lock(mut self, wallet_lock: Arc<dyn DistributedLock>) -> Self {
+        self.wallet_lock = Some(wallet_lock);
+        self
+    }
+
+    /// 当前 epoch
+    pub fn current_epoch(&self) -> Result<u64> {
+        self.rpc_client
+            .call(|client| client.get_epoch_info())
+            .map(|info| info.epoch)
+            .map_err(|e| SolanaError::RpcError(e.to_string()))
+    }
+
+    /// 单个质押账户允许的最小委托数量
+    pub fn minimum_delegation(&self) -> Result<u64> {
+        self.rpc_client
+            .call(|client| client.get_stake_minimum_delegation())
+            .map_err(|e| SolanaError::RpcError(e.to_string()))
+    }
+
+    /// 质押账户的租金储备
+    pub fn rent_exempt_reserve(&self) -> Result<u64> {
+        self.rpc_client
+            .call(|client| client.get_minimum_balance_for_rent_exemption(STAKE_ACCOUNT_SPACE))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))
+    }
+
+    /// 读取质押账户的链上状态
+    pub fn get_stake_account(&self, address: &Pubkey) -> Result<StakeAccountInfo> {
+        let account = self
+            .rpc_client
+            .call(|client| client.get_account(address))
+            .map_err(|e| SolanaError::AccountNotFound(format!("{}: {}", address, e)))?;
+        if account.owner != stake_program::id() {
+            return Err(stake_error(format!(
+                "{} is not owned by the stake program",
+                address
+            )));
+        }
+
+        parse_stake_account(
+            address,
+            account.lamports,
+            &account.data,
+            self.current_epoch()?,
+        )
+    }
+
+    /// 由 `funder` 出资创建质押账户并委托给 `vote_account`
+    ///
+    /// `lamports` 为委托数量，租金储备另外从 `funder` 扣除；返回质押账户地址与交易签名
+    pub async fn create_and_delegate(
+        &self,
+        funder: &Keypair,
+        vote_account: &Pubkey,
+        lamports: u64,
+    ) -> Result<(Pubkey, Signature)> {
+        self.screen(&funder.pubkey(), vote_account, lamports)
+            .await?;
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &funder.pubkey()).await?;
+        let minimum = self.minimum_delegation()?;
+        if lamports < minimum {
+            return Err(stake_error(format!(
+                "Delegation of {} lamports is below the minimum of {}",
+                lamports, minimum
+            )));
+        }
+
+        let stake_keypair = Keypair::new();
+        let total = lamports
+            .checked_add(self.rent_exempt_reserve()?)
+            .ok_or_else(|| stake_error("Stake amount overflows"))?;
+        let instructions = stake_instruction::create_account_and_delegate_stake(
+            &funder.pubkey(),
+            &stake_keypair.pubkey(),
+            vote_account,
+            &Authorized::auto(&funder.pubkey()),
+            &Lockup::default(),
+            total,
+        );
+
+        ensure_lock_held(wallet_lock.as_ref()).await?;
+        let signature = self.send(&instructions, &[funder, &stake_keypair])?;
+        tracing::info!(
+            "Created stake account {} delegated to {}: {}",
+            stake_keypair.pubkey(),
+            vote_account,
+            signature
+        );
+
+        Ok((stake_keypair.pubkey(), signature))
+    }
+
+    /// 解除委托，当前 epoch 结束后余额才可提取
+    pub async fn deactivate(
+        &self,
+        authority: &Keypair,
+        stake_account: &Pubkey,
+    ) -> Result<Signature> {
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), stake_account).await?;
+        let instruction = stake_instruction::deactivate_stake(stake_account, &authority.pubkey());
+        ensure_lock_held(wallet_lock.as_ref()).await?;
+        self.send(&[instruction], &[authority])
+    }
+
+    /// 把冷却完成的质押账户余额全部提取到 `recipient`
+    ///
+    /// 返回提取数量与交易签名，提取后账户被关闭
+    pub async fn withdraw(
+        &self,
+        authority: &Keypair,
+        stake_account: &Pubkey,
+        recipient: &Pubkey,
+    ) -> Result<(u64, Signature)> {
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), stake_account).await?;
+        let info = self.get_stake_account(stake_account)?;
+        if !info.status.is_withdrawable() {
+            return Err(stake_error(format!(
+                "Stake account {} is {:?} and cannot be withdrawn",
+                stake_account, info.status
+            )));
+        }
+        self.screen(stake_account, recipient, info.lamports).await?;
+
+        let instruction = stake_instruction::withdraw(
+            stake_account,
+            &authority.pubkey(),
+            recipient,
+            info.lamports,
+            None,
+        );
+        ensure_lock_held(wallet_lock.as_ref()).await?;
+        let signature = self.send(&[instruction], &[authority])?;
+
+        Ok((info.lamports, signature))
+    }
+
+    /// 资金离开钱包前筛查交易对手
+    async fn screen(&self, from: &Pubkey, to: &Pubkey, lamports: u64) -> Result<()> {
+        if let Some(risk_gate) = &self.risk_gate {
+            risk_gate
+                .check(&ScreeningRequest {
+                    from: *from,
+                    to: *to,
+                    token_mint: None,
+                    amount: lamports,
+                    reference: None,
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// 查询质押账户在 `epoch`（为空时为上一个 epoch）获得的奖励
+    ///
+    /// 结果与 `addresses` 一一对应，没有奖励的账户为空
+    pub fn get_epoch_rewards(
+        &self,
+        addresses: &[Pubkey],
+        epoch: Option<u64>,
+    ) -> Result<Vec<Option<EpochReward>>> {
+        let rewards = self
+            .rpc_client
+            .call(|client| client.get_inflation_reward(addresses, epoch))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        Ok(addresses
+            .iter()
+            .zip(rewards)
+            .map(|(address, reward)| {
+                reward.map(|reward| EpochReward {
+                    address: *address,
+                    epoch: reward.epoch,
+                    amount: reward.amount,
+                    post_balance: reward.post_balance,
+                    commission: reward.commission,
+                })
+            })
+            .collect())
+    }
+
+    fn send(&self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<Signature> {
+        let payer = signers
+            .first()
+            .ok_or_else(|| SolanaError::SignError("No signer".to_string()))?;
+        let recent_blockhash = self
+            .write_client
+            .call(|client| client.get_latest_blockhash())
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&payer.pubkey()));
+        transaction.sign(signers, recent_blockhash);
+
+        send_with_preflight(&self.write_client, &transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_stake_interface::{
+        stake_flags::StakeFlags,
+        state::{Delegation, Meta, Stake},
+    };
+
+    fn stake_data(deactivation_epoch: Option<u64>) -> Vec<u8> {
+        let owner = Pubkey::new_unique();
+        let meta = Meta {
+            rent_exempt_reserve: 2_282_880,
+            authorized: Authorized::auto(&owner),
+            lockup: Lockup::default(),
+        };
+        let mut delegation = Delegation::new(&Pubkey::new_unique(), 1_000_000_000, 10);
+        if let Some(epoch) = deactivation_epoch {
+            delegation.deactivation_epoch = epoch;
+        }
+        let stake = Stake {
+            delegation,
+            credits_observed: 0,
+        };
+
+        bincode::serialize(&StakeStateV2::Stake(meta, stake, StakeFlags::empty())).unwrap()
+    }
+
+    #[test]
+    fn test_stake_status() {
+        let address = Pubkey::new_unique();
+        let status = |data: &[u8], epoch| {
+            parse_stake_account(&address, 0, data, epoch)
+                .unwrap()
+                .status
+        };
+
+        let delegated = stake_data(None);
+        assert_eq!(status(&delegated, 10), StakeStatus::Activating);
+        assert_eq!(status(&delegated, 11), StakeStatus::Active);
+
+        let deactivated = stake_data(Some(20));
+        assert_eq!(status(&deactivated, 20), StakeStatus::Deactivating);
+        assert_eq!(status(&deactivated, 21), StakeStatus::Inactive);
+        assert!(status(&deactivated, 21).is_withdrawable());
+
+        let info = parse_stake_account(&address, 0, &delegated, 11).unwrap();
+        assert_eq!(info.delegated_stake, 1_000_000_000);
+        assert_eq!(info.deactivation_epoch, None);
+    }
+
+    #[test]
+    fn test_parse_rejects_other_accounts() {
+        let address = Pubkey::new_unique();
+        let uninitialized = bincode::serialize(&StakeStateV2::Uninitialized).unwrap();
+
+        assert!(parse_stake_account(&address, 0, &uninitialized, 0).is_err());
+        assert!(parse_stake_account(&address, 0, &[1, 2], 0).is_err());
+    }
+}
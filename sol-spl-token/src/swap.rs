@@ -3,13 +3,29 @@
 //! 提供稳定币购买和代币转换功能
 
 use async_trait::async_trait;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_commitment_config::CommitmentConfig;
 use solana_sdk::{
     pubkey::Pubkey,
-    signature::Keypair,
+    signature::{Keypair, Signature, Signer},
+};
+use solana_transaction_status_client_types::UiTransactionEncoding;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, SystemTime},
 };
-use std::collections::HashMap;
 
 use crate::error::{Result, SolanaError};
+use crate::hold::{ensure_not_held, HoldRegistry};
+use crate::lock::{ensure_lock_held, lock_wallet, DistributedLock};
+use crate::oracle::{HttpPriceOracle, PriceOracle, QuoteCheck, QuoteGuard};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+use crate::solana_pay::confirmed_received_amount;
+
+/// 轮询交易状态的间隔
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(500);
 
 /// 交换管理器
 pub struct SwapManager {
@@ -18,6 +34,21 @@ pub struct SwapManager {
     
     /// 代币价格缓存
     price_cache: HashMap<Pubkey, f64>,
+    
+    /// 用于确认交换交易落地的 RPC 客户端
+    rpc_client: Option<RpcHandle>,
+    
+    /// 交易确认超时
+    confirmation_timeout: Duration,
+    
+    /// 独立参考价格源
+    oracle: Option<Arc<dyn PriceOracle>>,
+    
+    /// 冻结名单
+    hold_registry: Option<Arc<dyn HoldRegistry>>,
+    
+    /// 按钱包串行化兑换的分布式锁
+    wallet_lock: Option<Arc<dyn DistributedLock>>,
 }
 
 impl SwapManager {
@@ -26,19 +57,82 @@ impl SwapManager {
         Self {
             dex_config,
             price_cache: HashMap::new(),
+            rpc_client: None,
+            confirmation_timeout: Duration::from_secs(30),
+            oracle: None,
+            hold_registry: None,
+            wallet_lock: None,
         }
     }
     
-    /// 从配置创建交换管理器
-    pub fn from_config(config: &crate::config::SolanaConfig) -> Self {
+    /// 设置用于校验报价的参考价格源
+    pub fn with_oracle(mut self, oracle: Arc<dyn PriceOracle>) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    
+    /// 设置冻结名单，被冻结的钱包不能发起兑换
+    pub fn with_hold_registry(mut self, hold_registry: Arc<dyn HoldRegistry>) -> Self {
+        self.hold_registry = Some(hold_registry);
+        self
+    }
+    
+    /// 设置分布式锁，同一钱包的兑换按顺序执行
+    pub fn with_wallet_lock(mut self, wallet_lock: Arc<dyn DistributedLock>) -> Self {
+        self.wallet_lock = Some(wallet_lock);
+        self
+    }
+    
+    /// 设置用于确认交易的 RPC 客户端
+    pub fn with_rpc_client(mut self, rpc_client: RpcHandle, confirmation_timeout: Duration) -> Self {
+        self.rpc_client = Some(rpc_client);
+        self.confirmation_timeout = confirmation_timeout;
+        self
+    }
+    
+    /// 是否为模拟模式，模拟模式下的兑换不会上链
+    pub fn is_simulation(&self) -> bool {
+        self.dex_config.use_simulation
+    }
+    
+    /// 从配置创建交换管理器，连接配置中的单个 RPC 端点
+    pub fn from_config(config: &crate::config::SolanaConfig) -> Result<Self> {
+        Self::with_config(config, RpcHandle::new(&config.rpc_url))
+    }
+    
+    /// 从 RPC 端点池创建交换管理器，确认与读取到账数量走读端点
+    pub fn from_pool(pool: &Arc<RpcPool>, config: &crate::config::SolanaConfig) -> Result<Self> {
+        Self::with_config(config, RpcHandle::pooled(pool, RpcTraffic::Read))
+    }
+    
+    fn with_config(config: &crate::config::SolanaConfig, rpc_client: RpcHandle) -> Result<Self> {
         let dex_config = DexConfig {
             dex_program_id: Pubkey::default(), // 实际应根据配置设置
             use_simulation: true, // 默认使用模拟模式
             slippage_tolerance: 0.01, // 1% 滑点容忍度
+            pair_slippage: DexConfig::parse_pair_slippage(&config.pair_slippage)?,
+            quote_guard: QuoteGuard {
+                max_price_impact_bps: config.max_price_impact_bps,
+                max_quote_age: Duration::from_secs(config.max_quote_age_secs),
+            },
             max_retries: config.max_retries,
         };
         
-        Self::new(dex_config)
+        let mut manager = Self::new(dex_config).with_rpc_client(
+            rpc_client,
+            Duration::from_secs(config.confirmation_timeout_secs),
+        );
+        
+        if !config.price_oracle_url.is_empty() {
+            let oracle = HttpPriceOracle::new(
+                &config.price_oracle_url,
+                None,
+                Duration::from_secs(config.confirmation_timeout_secs),
+            )?;
+            manager = manager.with_oracle(Arc::new(oracle));
+        }
+        
+        Ok(manager)
     }
     
     /// 获取代币价格（从缓存或实时获取）
@@ -98,32 +192,160 @@ impl SwapManager {
         amount: u64,
         slippage_tolerance: Option<f64>,
     ) -> Result<SwapResult> {
-        let slippage = slippage_tolerance.unwrap_or(self.dex_config.slippage_tolerance);
+        ensure_not_held(self.hold_registry.as_ref(), &user_keypair.pubkey()).await?;
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &user_keypair.pubkey()).await?;
+        
+        let slippage = slippage_tolerance
+            .unwrap_or_else(|| self.dex_config.slippage_for(from_token_mint, to_token_mint));
         
-        if self.dex_config.use_simulation {
+        // 执行前先用独立价格源校验报价
+        let quote = self.get_quote(from_token_mint, to_token_mint, amount).await?;
+        let check = self.verify_quote(&quote).await?;
+        
+        ensure_lock_held(wallet_lock.as_ref()).await?;
+        let result = if self.dex_config.use_simulation {
             // 模拟模式 - 用于开发和测试
-            self.simulate_swap(user_keypair, from_token_mint, to_token_mint, amount, slippage).await
+            self.simulate_swap(user_keypair, &quote, slippage).await
         } else {
             // 实际执行交换
-            self.real_swap(user_keypair, from_token_mint, to_token_mint, amount, slippage).await
+            self.real_swap(user_keypair, &quote, slippage).await
+        }?;
+        
+        Ok(result.with_quote_check(check))
+    }
+    
+    /// 获取 DEX 报价
+    ///
+    /// 目前使用模拟价格，接入真实 DEX 后应替换为路由报价
+    pub async fn get_quote(
+        &self,
+        from_token_mint: &Pubkey,
+        to_token_mint: &Pubkey,
+        amount: u64,
+    ) -> Result<SwapQuote> {
+        let from_price = 1.0; // 假设 from_token 价格为 1
+        let to_price = self.simulate_token_price(to_token_mint, from_token_mint).await?;
+        
+        Ok(SwapQuote {
+            from_token: *from_token_mint,
+            to_token: *to_token_mint,
+            in_amount: amount,
+            out_amount: (amount as f64 * from_price / to_price) as u64,
+            quoted_at: SystemTime::now(),
+        })
+    }
+    
+    /// 用参考价格校验报价
+    ///
+    /// 未配置参考价格源时，只有模拟模式允许跳过校验
+    pub async fn verify_quote(&self, quote: &SwapQuote) -> Result<Option<QuoteCheck>> {
+        match &self.oracle {
+            Some(oracle) => {
+                let oracle_price = oracle.get_price(&quote.from_token, &quote.to_token).await?;
+                let check = self
+                    .dex_config
+                    .quote_guard
+                    .check(quote.price(), quote.quoted_at, &oracle_price)?;
+                Ok(Some(check))
+            }
+            None if self.dex_config.use_simulation => Ok(None),
+            None => Err(SolanaError::QuoteRejected(
+                "No independent price source configured".to_string(),
+            )),
         }
     }
     
-    /// 模拟交换（不实际执行链上交易）
-    async fn simulate_swap(
+    /// 仅报价，不发送任何交易（用于 dry-run）
+    pub async fn quote_swap(
         &self,
         user_keypair: &Keypair,
         from_token_mint: &Pubkey,
         to_token_mint: &Pubkey,
         amount: u64,
-        slippage_tolerance: f64,
+        slippage_tolerance: Option<f64>,
     ) -> Result<SwapResult> {
-        // 获取价格
-        let from_price = 1.0; // 假设 from_token 价格为 1
-        let to_price = self.simulate_token_price(to_token_mint, from_token_mint).await?;
+        let slippage = slippage_tolerance
+            .unwrap_or_else(|| self.dex_config.slippage_for(from_token_mint, to_token_mint));
+        let quote = self.get_quote(from_token_mint, to_token_mint, amount).await?;
+        let check = self.verify_quote(&quote).await?;
         
-        // 计算交换后的数量
-        let expected_amount = (amount as f64 * from_price / to_price) as u64;
+        let result = self.simulate_swap(user_keypair, &quote, slippage).await?;
+        Ok(result.with_quote_check(check))
+    }
+    
+    /// 确认交换交易已在链上落地
+    ///
+    /// 模拟交易直接视为已落地；真实交易在超时前轮询签名状态，
+    /// 超时或链上执行失败都返回错误，调用方不应据此重发同一笔交换
+    pub async fn confirm_swap_landed(&self, swap_result: &SwapResult) -> Result<()> {
+        if swap_result.is_simulation {
+            return Ok(());
+        }
+        
+        let rpc_client = self.rpc_client.as_ref().ok_or_else(|| {
+            SolanaError::ConfigError("RPC client is required to confirm swaps".to_string())
+        })?;
+        let signature = Signature::from_str(&swap_result.signature)
+            .map_err(|e| SolanaError::ConfirmationError(e.to_string()))?;
+        
+        let deadline = tokio::time::Instant::now() + self.confirmation_timeout;
+        loop {
+            match rpc_client.call(|client| {
+                client.get_signature_status_with_commitment(&signature, CommitmentConfig::confirmed())
+            })? {
+                Some(Ok(())) => return Ok(()),
+                Some(Err(e)) => {
+                    return Err(SolanaError::ConfirmationError(format!(
+                        "swap {} failed on chain: {}",
+                        signature, e
+                    )));
+                }
+                None if tokio::time::Instant::now() >= deadline => {
+                    return Err(SolanaError::ConfirmationError(format!(
+                        "swap {} not confirmed within {:?}",
+                        signature, self.confirmation_timeout
+                    )));
+                }
+                None => tokio::time::sleep(CONFIRM_POLL_INTERVAL).await,
+            }
+        }
+    }
+    
+    /// 读取已落地的交换交易中 `owner` 实际收到的目标代币数量
+    ///
+    /// 报价产出只是预估，后续步骤应以实际到账数量为准；模拟交易返回报价产出
+    pub async fn received_amount(&self, swap_result: &SwapResult, owner: &Pubkey) -> Result<u64> {
+        if swap_result.is_simulation {
+            return Ok(swap_result.to_amount);
+        }
+        
+        let rpc_client = self.rpc_client.as_ref().ok_or_else(|| {
+            SolanaError::ConfigError("RPC client is required to read swap output".to_string())
+        })?;
+        let signature = Signature::from_str(&swap_result.signature)
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+        let confirmed = rpc_client
+            .call(|client| client.get_transaction_with_config(&signature, config))
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+        
+        confirmed_received_amount(confirmed, &signature, owner, Some(&swap_result.to_token))
+    }
+    
+    /// 模拟交换（不实际执行链上交易）
+    async fn simulate_swap(
+        &self,
+        _user_keypair: &Keypair,
+        quote: &SwapQuote,
+        slippage_tolerance: f64,
+    ) -> Result<SwapResult> {
+        let (from_token_mint, to_token_mint) = (&quote.from_token, &quote.to_token);
+        let amount = quote.in_amount;
+        let expected_amount = quote.out_amount;
         
         // 应用滑点
         let min_amount = (expected_amount as f64 * (1.0 - slippage_tolerance)) as u64;
@@ -146,6 +368,9 @@ impl SwapManager {
             slippage: slippage_tolerance,
             signature: "SIMULATED_SWAP_SIGNATURE".to_string(),
             is_simulation: true,
+            execution_price: quote.price(),
+            oracle_price: None,
+            price_impact_bps: None,
         })
     }
     
@@ -153,9 +378,7 @@ impl SwapManager {
     async fn real_swap(
         &self,
         user_keypair: &Keypair,
-        from_token_mint: &Pubkey,
-        to_token_mint: &Pubkey,
-        amount: u64,
+        quote: &SwapQuote,
         slippage_tolerance: f64,
     ) -> Result<SwapResult> {
         // 实际实现应该：
@@ -165,7 +388,7 @@ impl SwapManager {
         
         // 这里返回一个模拟结果，实际项目需要集成真正的 DEX
         tracing::warn!("Real swap not implemented, using simulation");
-        self.simulate_swap(user_keypair, from_token_mint, to_token_mint, amount, slippage_tolerance).await
+        self.simulate_swap(user_keypair, quote, slippage_tolerance).await
     }
     
     /// 购买稳定币（使用 SOL 或其他代币）
@@ -181,7 +404,7 @@ impl SwapManager {
             from_token_mint,
             stablecoin_mint,
             amount,
-            Some(0.005), // 稳定币交换使用较小的滑点
+            None, // 按交易对配置取滑点
         ).await
     }
     
@@ -198,7 +421,7 @@ impl SwapManager {
             stablecoin_mint,
             target_token_mint,
             stablecoin_amount,
-            Some(0.01), // 目标代币交换使用标准滑点
+            None, // 按交易对配置取滑点
         ).await
     }
     
@@ -221,14 +444,25 @@ impl SwapManager {
             amount,
         ).await?;
         
-        results.push(stablecoin_swap.clone());
+        // 第一阶段必须落地且确有产出，才能花费其产出的稳定币
+        self.confirm_swap_landed(&stablecoin_swap).await?;
+        let received = self.received_amount(&stablecoin_swap, &user_keypair.pubkey()).await?;
+        results.push(SwapResult {
+            to_amount: received,
+            ..stablecoin_swap
+        });
+        if received == 0 {
+            return Err(SolanaError::SwapError(
+                "Stablecoin leg produced no output".to_string(),
+            ));
+        }
         
-        // 第二阶段：使用稳定币购买目标代币
+        // 第二阶段：只花费第一阶段实际到账的稳定币，滑点下报价产出可能多于到账
         let target_token_swap = self.buy_target_token_with_stablecoin(
             user_keypair,
             stablecoin_mint,
             target_token_mint,
-            stablecoin_swap.to_amount,
+            received,
         ).await?;
         
         results.push(target_token_swap);
@@ -246,19 +480,65 @@ pub struct DexConfig {
     /// 是否使用模拟模式
     pub use_simulation: bool,
     
-    /// 滑点容忍度（百分比，如 0.01 表示 1%）
+    /// 默认滑点容忍度（百分比，如 0.01 表示 1%）
     pub slippage_tolerance: f64,
     
+    /// 按交易对 (from, to) 配置的滑点容忍度，未配置时使用默认值
+    pub pair_slippage: HashMap<(Pubkey, Pubkey), f64>,
+    
+    /// 报价校验规则
+    pub quote_guard: QuoteGuard,
+    
     /// 最大重试次数
     pub max_retries: u32,
 }
 
+impl DexConfig {
+    /// 设置交易对滑点容忍度
+    pub fn with_pair_slippage(mut self, from: Pubkey, to: Pubkey, slippage_tolerance: f64) -> Self {
+        self.pair_slippage.insert((from, to), slippage_tolerance);
+        self
+    }
+    
+    /// 获取交易对滑点容忍度
+    pub fn slippage_for(&self, from: &Pubkey, to: &Pubkey) -> f64 {
+        self.pair_slippage
+            .get(&(*from, *to))
+            .copied()
+            .unwrap_or(self.slippage_tolerance)
+    }
+    
+    /// 解析 `FROM_MINT:TO_MINT=0.005,...` 格式的交易对滑点配置
+    pub fn parse_pair_slippage(spec: &str) -> Result<HashMap<(Pubkey, Pubkey), f64>> {
+        let invalid = |entry: &str| {
+            SolanaError::ConfigError(format!("Invalid pair slippage entry: {}", entry))
+        };
+        
+        let mut pairs = HashMap::new();
+        for entry in spec.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (pair, value) = entry.split_once('=').ok_or_else(|| invalid(entry))?;
+            let (from, to) = pair.split_once(':').ok_or_else(|| invalid(entry))?;
+            let from = Pubkey::from_str(from.trim()).map_err(|_| invalid(entry))?;
+            let to = Pubkey::from_str(to.trim()).map_err(|_| invalid(entry))?;
+            let slippage: f64 = value.trim().parse().map_err(|_| invalid(entry))?;
+            if !(0.0..1.0).contains(&slippage) {
+                return Err(invalid(entry));
+            }
+            pairs.insert((from, to), slippage);
+        }
+        
+        Ok(pairs)
+    }
+}
+
 impl Default for DexConfig {
     fn default() -> Self {
         Self {
             dex_program_id: Pubkey::default(),
             use_simulation: true,
             slippage_tolerance: 0.01, // 1%
+            pair_slippage: HashMap::new(),
+            quote_guard: QuoteGuard::default(),
             max_retries: 3,
         }
     }
@@ -290,6 +570,58 @@ pub struct SwapResult {
     
     /// 是否为模拟交易
     pub is_simulation: bool,
+    
+    /// 实际成交价格（每个 from 最小单位换得的 to 最小单位数）
+    pub execution_price: f64,
+    
+    /// 执行前的参考价格，未校验时为空
+    pub oracle_price: Option<f64>,
+    
+    /// 报价相对参考价的价格冲击（bps），未校验时为空
+    pub price_impact_bps: Option<u32>,
+}
+
+impl SwapResult {
+    /// 记录报价校验结果，并按实际成交量更新成交价格
+    fn with_quote_check(mut self, check: Option<QuoteCheck>) -> Self {
+        if self.from_amount > 0 {
+            self.execution_price = self.to_amount as f64 / self.from_amount as f64;
+        }
+        if let Some(check) = check {
+            self.oracle_price = Some(check.oracle_price);
+            self.price_impact_bps = Some(check.price_impact_bps);
+        }
+        self
+    }
+}
+
+/// DEX 报价
+#[derive(Debug, Clone)]
+pub struct SwapQuote {
+    /// 源代币 mint
+    pub from_token: Pubkey,
+    
+    /// 目标代币 mint
+    pub to_token: Pubkey,
+    
+    /// 输入数量
+    pub in_amount: u64,
+    
+    /// 预计产出数量
+    pub out_amount: u64,
+    
+    /// 报价时间
+    pub quoted_at: SystemTime,
+}
+
+impl SwapQuote {
+    /// 报价价格（每个 from 最小单位换得的 to 最小单位数）
+    pub fn price(&self) -> f64 {
+        if self.in_amount == 0 {
+            return 0.0;
+        }
+        self.out_amount as f64 / self.in_amount as f64
+    }
 }
 
 /// 交换存储 trait
@@ -305,3 +637,74 @@ pub trait SwapStorage: Send + Sync {
         limit: usize,
     ) -> Result<Vec<SwapResult>>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::StaticPriceOracle;
+
+    fn usdc() -> Pubkey {
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap()
+    }
+
+    #[test]
+    fn test_pair_slippage_overrides_default() {
+        let (from, to) = (Pubkey::new_unique(), usdc());
+        let spec = format!("{}:{}=0.005", from, to);
+        let config = DexConfig {
+            pair_slippage: DexConfig::parse_pair_slippage(&spec).unwrap(),
+            ..DexConfig::default()
+        };
+
+        assert_eq!(config.slippage_for(&from, &to), 0.005);
+        assert_eq!(config.slippage_for(&to, &from), 0.01);
+        assert!(DexConfig::parse_pair_slippage("not-a-pair=0.1").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_swap_records_oracle_price() {
+        let target = Pubkey::new_unique();
+        let oracle = Arc::new(StaticPriceOracle::new());
+        // 模拟报价为 1 USDC -> 1/1.5 目标代币
+        oracle.set_price(usdc(), target, 1.0 / 1.5);
+        let manager = SwapManager::new(DexConfig::default()).with_oracle(oracle);
+
+        let result = manager
+            .execute_swap(&Keypair::new(), &usdc(), &target, 1_500_000, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.to_amount, 1_000_000);
+        assert!(result.oracle_price.is_some());
+        assert_eq!(result.price_impact_bps, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_swap_rejected_on_price_impact() {
+        let target = Pubkey::new_unique();
+        let oracle = Arc::new(StaticPriceOracle::new());
+        // 参考价远好于报价，价格冲击超过默认 100 bps
+        oracle.set_price(usdc(), target, 1.0);
+        let manager = SwapManager::new(DexConfig::default()).with_oracle(oracle);
+
+        let result = manager
+            .execute_swap(&Keypair::new(), &usdc(), &target, 1_500_000, None)
+            .await;
+
+        assert!(matches!(result, Err(SolanaError::QuoteRejected(_))));
+    }
+
+    #[tokio::test]
+    async fn test_real_swap_requires_oracle() {
+        let manager = SwapManager::new(DexConfig {
+            use_simulation: false,
+            ..DexConfig::default()
+        });
+
+        let result = manager
+            .execute_swap(&Keypair::new(), &usdc(), &Pubkey::new_unique(), 1_000, None)
+            .await;
+
+        assert!(matches!(result, Err(SolanaError::QuoteRejected(_))));
+    }
+}
@@ -3,37 +3,147 @@
 //! 提供 SPL Token 的余额查询、转账、关联账户创建等功能
 
 use async_trait::async_trait;
-use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, UiAccountEncoding};
 use solana_sdk::{
-    commitment_config::CommitmentConfig,
     instruction::Instruction,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
     transaction::Transaction,
 };
-use spl_associated_token_account::get_associated_token_address;
-use spl_token::{
-    instruction::{transfer, TransferCheckedParams},
+use spl_associated_token_account::{
+    get_associated_token_address,
+    get_associated_token_address_with_program_id,
+};
+use spl_token_2022_interface::{
+    extension::StateWithExtensions,
+    instruction::transfer_checked,
     state::Account as TokenAccount,
 };
 use std::sync::Arc;
 
 use crate::error::{Result, SolanaError};
+use crate::hold::{ensure_not_held, HoldRegistry};
+use crate::lock::{ensure_lock_held, lock_wallet, DistributedLock};
+use crate::memo::TransferTag;
+use crate::simulation::send_with_preflight;
+use crate::risk::{RiskGate, ScreeningRequest};
+use crate::rpc_pool::{RpcHandle, RpcPool, RpcTraffic};
+
+/// `getMultipleAccounts` 单次最多查询的账户数
+pub(crate) const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// 分批读取余额时各批 slot 不一致的最大整体重读次数
+const MAX_SLOT_READ_ATTEMPTS: usize = 3;
+
+/// Token 所属的程序
+///
+/// 资产登记时确定，关联账户地址与转账指令都按所属程序生成
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenProgram {
+    /// SPL Token 程序
+    #[default]
+    SplToken,
+    /// Token-2022 程序
+    Token2022,
+}
+
+impl TokenProgram {
+    /// 程序 ID
+    pub fn id(&self) -> Pubkey {
+        match self {
+            Self::SplToken => spl_token::id(),
+            Self::Token2022 => spl_token_2022_interface::id(),
+        }
+    }
+    
+    /// 钱包在该程序下的关联 Token 账户地址
+    pub fn associated_token_address(&self, wallet: &Pubkey, token_mint: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(wallet, token_mint, &self.id())
+    }
+    
+    /// 带 mint 与小数位校验的转账指令，两种程序通用
+    pub fn transfer_checked(
+        &self,
+        source: &Pubkey,
+        token_mint: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> Result<Instruction> {
+        transfer_checked(
+            &self.id(),
+            source,
+            token_mint,
+            destination,
+            authority,
+            &[],
+            amount,
+            decimals,
+        )
+        .map_err(|e| SolanaError::TokenTransferError(e.to_string()))
+    }
+}
+
+/// 读取 Token 账户余额，兼容带扩展的 Token-2022 账户
+pub(crate) fn unpack_token_amount(data: &[u8]) -> Result<u64> {
+    StateWithExtensions::<TokenAccount>::unpack(data)
+        .map(|account| account.base.amount)
+        .map_err(|e| SolanaError::TokenAccountNotFound(e.to_string()))
+}
 
 /// Token 管理器
 pub struct TokenManager {
-    rpc_client: Arc<RpcClient>,
+    rpc_client: RpcHandle,
+    /// 交易发送使用的客户端，可与查询分流到不同端点
+    write_client: RpcHandle,
+    risk_gate: Option<Arc<RiskGate>>,
+    hold_registry: Option<Arc<dyn HoldRegistry>>,
+    wallet_lock: Option<Arc<dyn DistributedLock>>,
 }
 
 impl TokenManager {
     /// 创建新的 Token 管理器
     pub fn new(rpc_url: &str) -> Self {
-        let rpc_client = Arc::new(RpcClient::new_with_commitment(
-            rpc_url.to_string(),
-            CommitmentConfig::confirmed(),
-        ));
+        let rpc_client = RpcHandle::new(rpc_url);
         
-        Self { rpc_client }
+        Self {
+            write_client: rpc_client.clone(),
+            rpc_client,
+            risk_gate: None,
+            hold_registry: None,
+            wallet_lock: None,
+        }
+    }
+    
+    /// 从 RPC 端点池创建 Token 管理器，读写分别选取端点
+    pub fn from_pool(pool: &Arc<RpcPool>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RpcHandle::pooled(pool, RpcTraffic::Read),
+            write_client: RpcHandle::pooled(pool, RpcTraffic::Write),
+            risk_gate: None,
+            hold_registry: None,
+            wallet_lock: None,
+        })
+    }
+    
+    /// 设置转出前的风险闸门
+    pub fn with_risk_gate(mut self, risk_gate: Arc<RiskGate>) -> Self {
+        self.risk_gate = Some(risk_gate);
+        self
+    }
+    
+    /// 设置冻结名单，被冻结的钱包不能转出
+    pub fn with_hold_registry(mut self, hold_registry: Arc<dyn HoldRegistry>) -> Self {
+        self.hold_registry = Some(hold_registry);
+        self
+    }
+    
+    /// 设置分布式锁，同一钱包的转出按顺序执行
+    pub fn with_wallet_lock(mut self, wallet_lock: Arc<dyn DistributedLock>) -> Self {
+        self.wallet_lock = Some(wallet_lock);
+        self
     }
     
     /// 从配置创建 Token 管理器
@@ -47,13 +157,77 @@ impl TokenManager {
         token_account: &Pubkey,
     ) -> Result<u64> {
         let account_data = self.rpc_client
-            .get_account_data(token_account)
+            .call(|client| client.get_account_data(token_account))
             .map_err(|e| SolanaError::AccountNotFound(e.to_string()))?;
         
-        let token_account = TokenAccount::unpack(&account_data)
-            .map_err(|e| SolanaError::TokenAccountNotFound(e.to_string()))?;
+        unpack_token_amount(&account_data)
+    }
+    
+    /// 批量获取 Token 余额
+    /// 
+    /// 同一批次通过一次 `getMultipleAccounts` 读取，返回 (slot, 余额列表)，
+    /// 账户不存在时余额记为 0。超过单次上限时分批读取，后续批次以首批 slot
+    /// 作为 `minContextSlot`，各批 slot 不一致时整体重读，重试用尽仍不一致则报错
+    pub async fn get_token_balances(
+        &self,
+        token_accounts: &[Pubkey],
+    ) -> Result<(u64, Vec<u64>)> {
+        let mut last_slots = (0, 0);
         
-        Ok(token_account.amount)
+        for _ in 0..MAX_SLOT_READ_ATTEMPTS {
+            let mut slot: Option<u64> = None;
+            let mut mixed = false;
+            let mut balances = Vec::with_capacity(token_accounts.len());
+            
+            for chunk in token_accounts.chunks(MAX_MULTIPLE_ACCOUNTS) {
+                let response = self.rpc_client
+                    .call(|client| {
+                        client.get_multiple_ui_accounts_with_config(chunk, RpcAccountInfoConfig {
+                            encoding: Some(UiAccountEncoding::Base64),
+                            commitment: Some(client.commitment()),
+                            min_context_slot: slot,
+                            ..RpcAccountInfoConfig::default()
+                        })
+                    })
+                    .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+                
+                match slot {
+                    None => slot = Some(response.context.slot),
+                    Some(first) if first != response.context.slot => {
+                        last_slots = (first, response.context.slot);
+                        mixed = true;
+                        break;
+                    },
+                    Some(_) => {},
+                }
+                for account in response.value {
+                    let amount = match account {
+                        Some(account) => {
+                            let data = account.data.decode().ok_or_else(|| {
+                                SolanaError::RpcError("Failed to decode token account data".to_string())
+                            })?;
+                            unpack_token_amount(&data)?
+                        },
+                        None => 0,
+                    };
+                    balances.push(amount);
+                }
+            }
+            
+            if !mixed {
+                return Ok((slot.unwrap_or_default(), balances));
+            }
+            tracing::debug!(
+                "Token balance chunks read at slots {} and {}, retrying",
+                last_slots.0,
+                last_slots.1
+            );
+        }
+        
+        Err(SolanaError::RpcError(format!(
+            "Token balance chunks read at different slots ({} and {})",
+            last_slots.0, last_slots.1
+        )))
     }
     
     /// 获取关联 Token 账户地址
@@ -72,10 +246,22 @@ impl TokenManager {
         wallet: &Pubkey,
         token_mint: &Pubkey,
     ) -> Result<Pubkey> {
-        let associated_token_account = self.get_associated_token_address(wallet, token_mint);
+        self.create_program_token_account_if_needed(payer, wallet, token_mint, TokenProgram::SplToken)
+            .await
+    }
+    
+    /// 按 mint 所属程序创建关联 Token 账户（如果不存在）
+    pub async fn create_program_token_account_if_needed(
+        &self,
+        payer: &Keypair,
+        wallet: &Pubkey,
+        token_mint: &Pubkey,
+        token_program: TokenProgram,
+    ) -> Result<Pubkey> {
+        let associated_token_account = token_program.associated_token_address(wallet, token_mint);
         
         // 检查账户是否已存在
-        match self.rpc_client.get_account(&associated_token_account) {
+        match self.rpc_client.call(|client| client.get_account(&associated_token_account)) {
             Ok(_) => {
                 tracing::debug!("Associated token account already exists: {}", associated_token_account);
                 return Ok(associated_token_account);
@@ -91,7 +277,7 @@ impl TokenManager {
             &payer.pubkey(),
             wallet,
             token_mint,
-            &spl_token::id(),
+            &token_program.id(),
         );
         
         let mut transaction = Transaction::new_with_payer(
@@ -99,94 +285,163 @@ impl TokenManager {
             Some(&payer.pubkey()),
         );
         
-        let recent_blockhash = self.rpc_client
-            .get_latest_blockhash()
+        let recent_blockhash = self.write_client
+            .call(|client| client.get_latest_blockhash())
             .map_err(|e| SolanaError::RpcError(e.to_string()))?;
         
         transaction.sign(&[payer], recent_blockhash);
         
-        let signature = self.rpc_client
-            .send_and_confirm_transaction(&transaction)
-            .map_err(|e| SolanaError::SendError(e.to_string()))?;
+        let signature = send_with_preflight(&self.write_client, &transaction)?;
         
         tracing::info!("Created associated token account: {} with signature: {}", associated_token_account, signature);
         
         Ok(associated_token_account)
     }
     
-    /// 转账 SPL Token
+    /// 转账 Token 到指定钱包的关联账户
+    /// 
+    /// 与 `transfer_token_with_fee` 相同：检查冻结名单与风险闸门，接收方关联账户不存在时创建，
+    /// 关联账户与 `transfer_checked` 指令按资产登记的 `token_program` 生成。
+    /// 提供 `tag` 时交易附带备注，并在转账指令上附加引用公钥
+    #[allow(clippy::too_many_arguments)]
     pub async fn transfer_token(
         &self,
         from_keypair: &Keypair,
-        from_token_account: &Pubkey,
-        to_token_account: &Pubkey,
+        to_wallet: &Pubkey,
         token_mint: &Pubkey,
-        amount: u64,
+        token_program: TokenProgram,
         decimals: u8,
+        amount: u64,
+        tag: Option<&TransferTag>,
     ) -> Result<String> {
-        // 检查发送方余额
-        let balance = self.get_token_balance(from_token_account).await?;
-        if balance < amount {
-            return Err(SolanaError::InsufficientBalance(format!(
-                "Insufficient token balance: have {}, need {}",
-                balance, amount
-            )));
-        }
-        
-        // 创建转账指令
-        let transfer_ix = transfer(
-            &spl_token::id(),
-            from_token_account,
-            to_token_account,
+        self.transfer_token_with_fee(
+            from_keypair,
+            to_wallet,
             &from_keypair.pubkey(),
-            &[],
+            token_mint,
+            token_program,
+            decimals,
             amount,
-        )?;
-        
-        let mut transaction = Transaction::new_with_payer(
-            &[transfer_ix],
-            Some(&from_keypair.pubkey()),
-        );
-        
-        let recent_blockhash = self.rpc_client
-            .get_latest_blockhash()
-            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
-        
-        transaction.sign(&[from_keypair], recent_blockhash);
-        
-        let signature = self.rpc_client
-            .send_and_confirm_transaction(&transaction)
-            .map_err(|e| SolanaError::SendError(e.to_string()))?;
-        
-        Ok(signature.to_string())
+            0,
+            tag,
+        ).await
     }
     
     /// 转账 Token 到外部钱包
+    #[allow(clippy::too_many_arguments)]
     pub async fn transfer_token_to_external(
         &self,
         from_keypair: &Keypair,
         to_wallet: &Pubkey,
         token_mint: &Pubkey,
-        amount: u64,
+        token_program: TokenProgram,
         decimals: u8,
+        amount: u64,
+        tag: Option<&TransferTag>,
     ) -> Result<String> {
-        let from_token_account = self.get_associated_token_address(&from_keypair.pubkey(), token_mint);
-        let to_token_account = self.get_associated_token_address(to_wallet, token_mint);
-        
-        // 确保接收方的关联 Token 账户存在
-        self.create_associated_token_account_if_needed(from_keypair, to_wallet, token_mint).await?;
-        
-        // 执行转账
         self.transfer_token(
             from_keypair,
-            &from_token_account,
-            &to_token_account,
+            to_wallet,
             token_mint,
-            amount,
+            token_program,
             decimals,
+            amount,
+            tag,
         ).await
     }
-    
+
+    /// 转账 Token 到外部钱包并收取手续费
+    ///
+    /// 到账金额与手续费在同一笔交易中分别转给收款方和手续费钱包，二者同时成功或失败；
+    /// 手续费钱包就是发送方时不再单独转账，手续费自然留在发送方。
+    /// 关联账户与转账指令按资产登记的 `token_program` 生成
+    #[allow(clippy::too_many_arguments)]
+    pub async fn transfer_token_with_fee(
+        &self,
+        from_keypair: &Keypair,
+        to_wallet: &Pubkey,
+        fee_wallet: &Pubkey,
+        token_mint: &Pubkey,
+        token_program: TokenProgram,
+        decimals: u8,
+        net_amount: u64,
+        fee: u64,
+        tag: Option<&TransferTag>,
+    ) -> Result<String> {
+        let amount = net_amount.checked_add(fee).ok_or_else(|| {
+            SolanaError::TokenTransferError("Transfer amount overflow".to_string())
+        })?;
+
+        ensure_not_held(self.hold_registry.as_ref(), &from_keypair.pubkey()).await?;
+
+        if let Some(risk_gate) = &self.risk_gate {
+            risk_gate.check(&ScreeningRequest {
+                from: from_keypair.pubkey(),
+                to: *to_wallet,
+                token_mint: Some(*token_mint),
+                amount,
+                reference: tag.map(|tag| tag.memo.clone()),
+            }).await?;
+        }
+
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &from_keypair.pubkey()).await?;
+        let from_token_account = token_program.associated_token_address(&from_keypair.pubkey(), token_mint);
+        let balance = self.get_token_balance(&from_token_account).await?;
+        if balance < amount {
+            return Err(SolanaError::InsufficientBalance(format!(
+                "Insufficient token balance: have {}, need {}",
+                balance, amount
+            )));
+        }
+
+        let to_token_account = self
+            .create_program_token_account_if_needed(from_keypair, to_wallet, token_mint, token_program)
+            .await?;
+        let transfer_ix = token_program.transfer_checked(
+            &from_token_account,
+            token_mint,
+            &to_token_account,
+            &from_keypair.pubkey(),
+            net_amount,
+            decimals,
+        )?;
+        // 引用公钥挂在到账转账上，手续费转账不单独标记
+        let mut instructions = match tag {
+            Some(tag) => tag.attach(transfer_ix, &from_keypair.pubkey()).to_vec(),
+            None => vec![transfer_ix],
+        };
+
+        if fee > 0 && *fee_wallet != from_keypair.pubkey() {
+            let fee_token_account = self
+                .create_program_token_account_if_needed(from_keypair, fee_wallet, token_mint, token_program)
+                .await?;
+            instructions.push(token_program.transfer_checked(
+                &from_token_account,
+                token_mint,
+                &fee_token_account,
+                &from_keypair.pubkey(),
+                fee,
+                decimals,
+            )?);
+        }
+
+        let mut transaction = Transaction::new_with_payer(
+            &instructions,
+            Some(&from_keypair.pubkey()),
+        );
+
+        let recent_blockhash = self.write_client
+            .call(|client| client.get_latest_blockhash())
+            .map_err(|e| SolanaError::RpcError(e.to_string()))?;
+
+        transaction.sign(&[from_keypair], recent_blockhash);
+
+        ensure_lock_held(wallet_lock.as_ref()).await?;
+        let signature = send_with_preflight(&self.write_client, &transaction)?;
+
+        Ok(signature.to_string())
+    }
+
     /// 获取 Token 元数据（通过 Token List 或自定义配置）
     pub async fn get_token_metadata(
         &self,
@@ -3,42 +3,67 @@
 //! 提供系统托管钱包的创建和管理功能
 
 use async_trait::async_trait;
-use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
-    commitment_config::CommitmentConfig,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    system_instruction,
     transaction::Transaction,
 };
+use solana_system_interface::{instruction as system_instruction, program as system_program};
 use std::sync::Arc;
 
 use crate::error::{Result, SolanaError};
+use crate::hold::{ensure_not_held, HoldRegistry};
+use crate::lock::{ensure_lock_held, lock_wallet, DistributedLock};
+use crate::keystore::{self, EncryptedKey};
+use crate::memo::TransferTag;
+use crate::simulation::send_with_preflight;
+use crate::risk::{RiskGate, ScreeningRequest};
+use crate::rpc_pool::RpcHandle;
 
 /// 钱包管理器
 pub struct WalletManager {
-    rpc_client: Arc<RpcClient>,
+    rpc_client: RpcHandle,
     system_keypair: Keypair,
+    risk_gate: Option<Arc<RiskGate>>,
+    hold_registry: Option<Arc<dyn HoldRegistry>>,
+    wallet_lock: Option<Arc<dyn DistributedLock>>,
 }
 
 impl WalletManager {
     /// 创建新的钱包管理器
     pub fn new(rpc_url: &str, system_keypair: Keypair) -> Self {
-        let rpc_client = Arc::new(RpcClient::new_with_commitment(
-            rpc_url.to_string(),
-            CommitmentConfig::confirmed(),
-        ));
+        let rpc_client = RpcHandle::new(rpc_url);
         
         Self {
             rpc_client,
             system_keypair,
+            risk_gate: None,
+            hold_registry: None,
+            wallet_lock: None,
         }
     }
     
+    /// 设置转出前的风险闸门
+    pub fn with_risk_gate(mut self, risk_gate: Arc<RiskGate>) -> Self {
+        self.risk_gate = Some(risk_gate);
+        self
+    }
+    
+    /// 设置冻结名单，被冻结的钱包不能转出
+    pub fn with_hold_registry(mut self, hold_registry: Arc<dyn HoldRegistry>) -> Self {
+        self.hold_registry = Some(hold_registry);
+        self
+    }
+    
+    /// 设置分布式锁，同一钱包的转出按顺序执行
+    pub fn with_wallet_lock(mut self, wallet_lock: Arc<dyn DistributedLock>) -> Self {
+        self.wallet_lock = Some(wallet_lock);
+        self
+    }
+    
     /// 从配置创建钱包管理器
     pub fn from_config(config: &crate::config::SolanaConfig) -> Result<Self> {
-        let system_keypair = Keypair::from_base58_string(&config.system_wallet_private_key)
-            .map_err(|e| SolanaError::ConfigError(e.to_string()))?;
+        let system_keypair = crate::config::keypair_from_base58(&config.system_wallet_private_key)?;
         
         Ok(Self::new(&config.rpc_url, system_keypair))
     }
@@ -50,6 +75,7 @@ impl WalletManager {
         // 生成新的密钥对
         let user_keypair = Keypair::new();
         let user_pubkey = user_keypair.pubkey();
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &self.system_keypair.pubkey()).await?;
         
         // 创建账户交易
         let create_account_ix = system_instruction::create_account(
@@ -57,7 +83,7 @@ impl WalletManager {
             &user_pubkey,
             initial_lamports,
             0, // 空间大小（系统账户）
-            &solana_sdk::system_program::id(),
+            &system_program::id(),
         );
         
         let mut transaction = Transaction::new_with_payer(
@@ -67,63 +93,92 @@ impl WalletManager {
         
         // 获取最近区块哈希
         let recent_blockhash = self.rpc_client
-            .get_latest_blockhash()
+            .call(|client| client.get_latest_blockhash())
             .map_err(|e| SolanaError::RpcError(e.to_string()))?;
         
         transaction.sign(&[&self.system_keypair, &user_keypair], recent_blockhash);
         
         // 发送交易
-        let signature = self.rpc_client
-            .send_and_confirm_transaction(&transaction)
-            .map_err(|e| SolanaError::SendError(e.to_string()))?;
+        ensure_lock_held(wallet_lock.as_ref()).await?;
+        let signature = send_with_preflight(&self.rpc_client, &transaction)?;
         
         tracing::info!("Created user wallet: {} with signature: {}", user_pubkey, signature);
         
         Ok(UserWallet {
             keypair: user_keypair,
             pubkey: user_pubkey,
-            created_signature: signature,
+            created_signature: signature.to_string(),
         })
     }
     
     /// 获取钱包余额
     pub async fn get_balance(&self, pubkey: &Pubkey) -> Result<u64> {
         self.rpc_client
-            .get_balance(pubkey)
+            .call(|client| client.get_balance(pubkey))
             .map_err(|e| SolanaError::RpcError(e.to_string()))
     }
     
     /// 转账 SOL
+    /// 
+    /// 提供 `tag` 时交易附带备注，并在转账指令上附加引用公钥
     pub async fn transfer_sol(
         &self,
         from_keypair: &Keypair,
         to_pubkey: &Pubkey,
         lamports: u64,
+        tag: Option<&TransferTag>,
     ) -> Result<String> {
+        ensure_not_held(self.hold_registry.as_ref(), &from_keypair.pubkey()).await?;
+        let wallet_lock = lock_wallet(self.wallet_lock.as_ref(), &from_keypair.pubkey()).await?;
+        
         let transfer_ix = system_instruction::transfer(
             &from_keypair.pubkey(),
             to_pubkey,
             lamports,
         );
+        let instructions = match tag {
+            Some(tag) => tag.attach(transfer_ix, &from_keypair.pubkey()).to_vec(),
+            None => vec![transfer_ix],
+        };
         
         let mut transaction = Transaction::new_with_payer(
-            &[transfer_ix],
+            &instructions,
             Some(&from_keypair.pubkey()),
         );
         
         let recent_blockhash = self.rpc_client
-            .get_latest_blockhash()
+            .call(|client| client.get_latest_blockhash())
             .map_err(|e| SolanaError::RpcError(e.to_string()))?;
         
         transaction.sign(&[from_keypair], recent_blockhash);
         
-        let signature = self.rpc_client
-            .send_and_confirm_transaction(&transaction)
-            .map_err(|e| SolanaError::SendError(e.to_string()))?;
+        ensure_lock_held(wallet_lock.as_ref()).await?;
+        let signature = send_with_preflight(&self.rpc_client, &transaction)?;
         
         Ok(signature.to_string())
     }
     
+    /// 转账 SOL 到外部地址（先经过风险筛查）
+    pub async fn transfer_sol_to_external(
+        &self,
+        from_keypair: &Keypair,
+        to_pubkey: &Pubkey,
+        lamports: u64,
+        tag: Option<&TransferTag>,
+    ) -> Result<String> {
+        if let Some(risk_gate) = &self.risk_gate {
+            risk_gate.check(&ScreeningRequest {
+                from: from_keypair.pubkey(),
+                to: *to_pubkey,
+                token_mint: None,
+                amount: lamports,
+                reference: tag.map(|tag| tag.memo.clone()),
+            }).await?;
+        }
+        
+        self.transfer_sol(from_keypair, to_pubkey, lamports, tag).await
+    }
+    
     /// 获取系统钱包余额
     pub async fn get_system_balance(&self) -> Result<u64> {
         self.get_balance(&self.system_keypair.pubkey()).await
@@ -131,7 +186,7 @@ impl WalletManager {
 }
 
 /// 用户钱包信息
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct UserWallet {
     /// 用户密钥对（系统托管存储）
     pub keypair: Keypair,
@@ -149,9 +204,11 @@ impl UserWallet {
         self.pubkey.to_string()
     }
     
-    /// 导出私钥（base58编码）
-    pub fn export_private_key(&self) -> String {
-        self.keypair.to_base58_string()
+    /// 导出私钥
+    /// 
+    /// 私钥不以明文离开托管方，只返回用 `passphrase` 加密的密钥库文件
+    pub fn export_private_key(&self, passphrase: &str) -> Result<EncryptedKey> {
+        keystore::seal(&self.keypair, passphrase)
     }
 }
 